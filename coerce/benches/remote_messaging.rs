@@ -0,0 +1,91 @@
+use bytes::BytesMut;
+use coerce::remote::net::codec::NetworkCodec;
+use coerce::remote::net::message::SessionEvent;
+use coerce::remote::net::proto::network::MessageRequest;
+use coerce::remote::net::StreamData;
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio_util::codec::{Decoder, Encoder};
+
+struct CountingAllocator;
+
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const PAYLOAD_SIZES: [usize; 3] = [1024, 64 * 1024, 1024 * 1024];
+
+fn encoded_frame(payload_size: usize) -> BytesMut {
+    let event = SessionEvent::NotifyActor(MessageRequest {
+        message_id: "message-id".to_string(),
+        handler_type: "BenchmarkActor.Msg".to_string(),
+        actor_id: "benchmark-actor".to_string(),
+        message: vec![0xab; payload_size].into(),
+        ..Default::default()
+    });
+
+    let mut frame = BytesMut::new();
+    NetworkCodec::default()
+        .encode(&event.write_to_bytes().unwrap(), &mut frame)
+        .unwrap();
+
+    frame
+}
+
+fn decode_message_request(mut frame: BytesMut) -> MessageRequest {
+    let bytes = NetworkCodec::default().decode(&mut frame).unwrap().unwrap();
+    match SessionEvent::read_from_bytes(bytes) {
+        Some(SessionEvent::NotifyActor(message)) => message,
+        _ => panic!("unexpected session event"),
+    }
+}
+
+fn remote_message_decoding_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("remote_message_decode");
+
+    for payload_size in PAYLOAD_SIZES {
+        let frame = encoded_frame(payload_size);
+
+        let input = frame.clone();
+        let allocated_before = ALLOCATED_BYTES.load(Ordering::Relaxed);
+        let message = decode_message_request(input);
+        let allocated = ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_before;
+        assert_eq!(message.message.len(), payload_size);
+
+        println!(
+            "remote_message_decode/{}: {} bytes allocated per decode (excluding frame)",
+            payload_size, allocated
+        );
+
+        group.throughput(Throughput::Bytes(payload_size as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(payload_size),
+            &frame,
+            |b, frame| {
+                b.iter_batched(
+                    || frame.clone(),
+                    decode_message_request,
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, remote_message_decoding_benchmark);
+criterion_main!(benches);
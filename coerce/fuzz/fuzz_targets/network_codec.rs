@@ -0,0 +1,20 @@
+#![no_main]
+
+use bytes::{Bytes, BytesMut};
+use coerce::remote::net::codec::NetworkCodec;
+use coerce::remote::net::message::{ClientEvent, SessionEvent};
+use coerce::remote::net::StreamData;
+use libfuzzer_sys::fuzz_target;
+use tokio_util::codec::Decoder;
+
+fuzz_target!(|data: &[u8]| {
+    let mut codec = NetworkCodec::new(64 * 1024);
+    let mut buffer = BytesMut::from(data);
+    while let Ok(Some(frame)) = codec.decode(&mut buffer) {
+        let _ = ClientEvent::read_from_bytes(frame.clone());
+        let _ = SessionEvent::read_from_bytes(frame);
+    }
+
+    let _ = ClientEvent::read_from_bytes(Bytes::copy_from_slice(data));
+    let _ = SessionEvent::read_from_bytes(Bytes::copy_from_slice(data));
+});
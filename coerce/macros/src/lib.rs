@@ -5,6 +5,7 @@ extern crate quote;
 extern crate syn;
 
 mod message;
+mod remote;
 mod snapshot;
 
 use proc_macro2::Span;
@@ -19,12 +20,52 @@ pub fn json_message_derive(input: proc_macro::TokenStream) -> proc_macro::TokenS
     message::json::expand(&ast).into()
 }
 
+#[cfg(feature = "bincode")]
+#[proc_macro_derive(BincodeMessage, attributes(result))]
+pub fn bincode_message_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast: DeriveInput = syn::parse(input).unwrap();
+    message::bincode::expand(&ast).into()
+}
+
+#[cfg(feature = "msgpack")]
+#[proc_macro_derive(MsgPackMessage, attributes(result))]
+pub fn msgpack_message_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast: DeriveInput = syn::parse(input).unwrap();
+    message::msgpack::expand(&ast).into()
+}
+
+/// `#[proto(ProtoType)]` requires `ProtoType: From<&Self>` and `Self: From<ProtoType>`,
+/// `#[result(Result, ProtoResult)]` is optional and works the same way for the result (defaults to `()`).
+#[proc_macro_derive(ProtoMessage, attributes(proto, result))]
+pub fn proto_message_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast: DeriveInput = syn::parse(input).unwrap();
+    message::proto::expand(&ast).into()
+}
+
 #[proc_macro_derive(JsonSnapshot)]
 pub fn json_snapshot_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast: DeriveInput = syn::parse(input).unwrap();
     snapshot::json::expand(&ast).into()
 }
 
+#[cfg(feature = "bincode")]
+#[proc_macro_derive(BincodeSnapshot)]
+pub fn bincode_snapshot_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast: DeriveInput = syn::parse(input).unwrap();
+    snapshot::bincode::expand(&ast).into()
+}
+
+#[cfg(feature = "msgpack")]
+#[proc_macro_derive(MsgPackSnapshot)]
+pub fn msgpack_snapshot_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast: DeriveInput = syn::parse(input).unwrap();
+    snapshot::msgpack::expand(&ast).into()
+}
+
+pub(crate) fn has_attribute(ast: &syn::DeriveInput, name: &str) -> bool {
+    ast.attrs.iter().any(|a| a.path.is_ident(name))
+}
+
 pub(crate) fn get_attribute_type_multiple(
     ast: &syn::DeriveInput,
     name: &str,
@@ -68,7 +109,10 @@ pub(crate) fn meta_item_to_ty(meta_item: &syn::NestedMeta) -> syn::Result<syn::T
         syn::NestedMeta::Meta(syn::Meta::Path(ref path)) => match path.get_ident() {
             Some(ident) => syn::parse_str::<syn::Type>(&ident.to_string())
                 .map_err(|_| syn::Error::new_spanned(ident, "Expect type")),
-            None => Err(syn::Error::new_spanned(path, "Expect type")),
+            None => Ok(syn::Type::Path(syn::TypePath {
+                qself: None,
+                path: path.clone(),
+            })),
         },
         syn::NestedMeta::Meta(syn::Meta::NameValue(val)) => match val.path.get_ident() {
             Some(ident) if ident == "result" => {
@@ -90,6 +134,23 @@ pub(crate) fn meta_item_to_ty(meta_item: &syn::NestedMeta) -> syn::Result<syn::T
         meta => Err(syn::Error::new_spanned(meta, "Expect type")),
     }
 }
+/// Registers a `Handler<M>` impl with every `RemoteActorSystem` built in this binary, the identifier
+/// defaults to `"{Actor}.{Message}"` when not provided, e.g. `#[remote_handler("Echo.Ping")]`.
+#[proc_macro_attribute]
+pub fn remote_handler(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let identifier = if attr.is_empty() {
+        None
+    } else {
+        Some(syn::parse_macro_input!(attr as syn::LitStr))
+    };
+
+    let item = syn::parse_macro_input!(item as syn::ItemImpl);
+    remote::expand_handler(identifier, item).into()
+}
+
 #[proc_macro_attribute]
 pub fn coerce_test(
     _attr: proc_macro::TokenStream,
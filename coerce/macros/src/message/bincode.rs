@@ -0,0 +1,10 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub(crate) fn expand(ast: &syn::DeriveInput) -> TokenStream {
+    super::expand_serde(
+        ast,
+        quote!(bincode::serialize),
+        quote!(bincode::deserialize),
+    )
+}
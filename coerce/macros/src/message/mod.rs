@@ -1 +1,69 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+
+use crate::get_attribute_type_multiple;
+
+#[cfg(feature = "bincode")]
+pub(crate) mod bincode;
 pub(crate) mod json;
+#[cfg(feature = "msgpack")]
+pub(crate) mod msgpack;
+pub(crate) mod proto;
+
+const RESULT_ATTR: &str = "result";
+
+pub(crate) fn expand_serde(
+    ast: &syn::DeriveInput,
+    serialise: TokenStream,
+    deserialise: TokenStream,
+) -> TokenStream {
+    let item_type = {
+        match get_attribute_type_multiple(ast, RESULT_ATTR) {
+            Ok(ty) => match ty.len() {
+                1 => ty[0].clone(),
+                _ => {
+                    return syn::Error::new(
+                        Span::call_site(),
+                        format!(
+                            "#[{}(type)] takes 1 parameters, given {}",
+                            RESULT_ATTR,
+                            ty.len()
+                        ),
+                    )
+                    .to_compile_error()
+                }
+            },
+            Err(err) => return err.to_compile_error(),
+        }
+    };
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let item_type = item_type
+        .map(ToTokens::into_token_stream)
+        .unwrap_or_else(|| quote! { () });
+
+    quote! {
+        impl #impl_generics ::coerce::actor::message::Message for #name #ty_generics #where_clause {
+            type Result = #item_type;
+
+            fn as_bytes(&self) -> Result<Vec<u8>, coerce::actor::message::MessageWrapErr> {
+                #serialise(&self)
+                    .map_err(|_e| coerce::actor::message::MessageWrapErr::SerializationErr)
+            }
+
+            fn from_bytes(bytes: coerce::actor::message::Bytes) -> Result<Self, coerce::actor::message::MessageUnwrapErr> {
+                #deserialise(&bytes).map_err(|_e| coerce::actor::message::MessageUnwrapErr::DeserializationErr)
+            }
+
+            fn read_remote_result(bytes: coerce::actor::message::Bytes) -> Result<Self::Result, coerce::actor::message::MessageUnwrapErr> {
+                #deserialise(&bytes).map_err(|_e| coerce::actor::message::MessageUnwrapErr::DeserializationErr)
+            }
+
+            fn write_remote_result(res: Self::Result) -> Result<Vec<u8>, coerce::actor::message::MessageWrapErr> {
+                #serialise(&res).map_err(|_e| coerce::actor::message::MessageWrapErr::SerializationErr)
+            }
+        }
+    }
+}
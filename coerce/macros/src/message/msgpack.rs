@@ -0,0 +1,10 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub(crate) fn expand(ast: &syn::DeriveInput) -> TokenStream {
+    super::expand_serde(
+        ast,
+        quote!(rmp_serde::to_vec),
+        quote!(rmp_serde::from_slice),
+    )
+}
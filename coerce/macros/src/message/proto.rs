@@ -0,0 +1,102 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+
+use crate::{get_attribute_type_multiple, has_attribute};
+
+const PROTO_ATTR: &str = "proto";
+const RESULT_ATTR: &str = "result";
+
+pub(crate) fn expand(ast: &syn::DeriveInput) -> TokenStream {
+    let proto_type = match get_attribute_type_multiple(ast, PROTO_ATTR) {
+        Ok(ty) => match ty.as_slice() {
+            [Some(ty)] => ty.clone(),
+            _ => {
+                return syn::Error::new(
+                    Span::call_site(),
+                    format!(
+                        "#[{}(type)] takes 1 parameter, given {}",
+                        PROTO_ATTR,
+                        ty.len()
+                    ),
+                )
+                .to_compile_error()
+            }
+        },
+        Err(err) => return err.to_compile_error(),
+    };
+
+    let result_types = if has_attribute(ast, RESULT_ATTR) {
+        match get_attribute_type_multiple(ast, RESULT_ATTR) {
+            Ok(ty) => match ty.as_slice() {
+                [Some(result_type)] => Some((result_type.clone(), result_type.clone())),
+                [Some(result_type), Some(result_proto_type)] => {
+                    Some((result_type.clone(), result_proto_type.clone()))
+                }
+                _ => {
+                    return syn::Error::new(
+                        Span::call_site(),
+                        format!(
+                            "#[{}(type, proto_type)] takes 1 or 2 parameters, given {}",
+                            RESULT_ATTR,
+                            ty.len()
+                        ),
+                    )
+                    .to_compile_error()
+                }
+            },
+            Err(err) => return err.to_compile_error(),
+        }
+    } else {
+        None
+    };
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let result_impl = match result_types {
+        Some((result_type, result_proto_type)) => quote! {
+            type Result = #result_type;
+
+            fn read_remote_result(bytes: ::coerce::actor::message::Bytes) -> Result<Self::Result, ::coerce::actor::message::MessageUnwrapErr> {
+                <#result_proto_type as ::protobuf::Message>::parse_from_bytes(&bytes)
+                    .map(|result| result.into())
+                    .map_err(|_e| ::coerce::actor::message::MessageUnwrapErr::DeserializationErr)
+            }
+
+            fn write_remote_result(res: Self::Result) -> Result<Vec<u8>, ::coerce::actor::message::MessageWrapErr> {
+                let result: #result_proto_type = res.into();
+                ::protobuf::Message::write_to_bytes(&result)
+                    .map_err(|_e| ::coerce::actor::message::MessageWrapErr::SerializationErr)
+            }
+        },
+        None => quote! {
+            type Result = ();
+
+            fn read_remote_result(_: ::coerce::actor::message::Bytes) -> Result<Self::Result, ::coerce::actor::message::MessageUnwrapErr> {
+                Ok(())
+            }
+
+            fn write_remote_result(_: Self::Result) -> Result<Vec<u8>, ::coerce::actor::message::MessageWrapErr> {
+                Ok(vec![])
+            }
+        },
+    };
+
+    quote! {
+        impl #impl_generics ::coerce::actor::message::Message for #name #ty_generics #where_clause {
+            #result_impl
+
+            fn as_bytes(&self) -> Result<Vec<u8>, ::coerce::actor::message::MessageWrapErr> {
+                let message: #proto_type = self.into();
+                ::protobuf::Message::write_to_bytes(&message)
+                    .map_err(|_e| ::coerce::actor::message::MessageWrapErr::SerializationErr)
+            }
+
+            fn from_bytes(bytes: ::coerce::actor::message::Bytes) -> Result<Self, ::coerce::actor::message::MessageUnwrapErr> {
+                <#proto_type as ::protobuf::Message>::parse_from_bytes(&bytes)
+                    .map(|message| message.into())
+                    .map_err(|_e| ::coerce::actor::message::MessageUnwrapErr::DeserializationErr)
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+
+pub(crate) fn expand_handler(identifier: Option<syn::LitStr>, item: syn::ItemImpl) -> TokenStream {
+    if !item.generics.params.is_empty() {
+        return syn::Error::new_spanned(
+            &item.generics,
+            "#[remote_handler] cannot be used on generic impls",
+        )
+        .to_compile_error();
+    }
+
+    let message_type = match handler_message_type(&item) {
+        Some(message_type) => message_type,
+        None => {
+            return syn::Error::new_spanned(
+                &item,
+                "#[remote_handler] must be used on an `impl Handler<M> for A` block",
+            )
+            .to_compile_error()
+        }
+    };
+
+    let actor_type = &item.self_ty;
+    let identifier = identifier.map_or_else(
+        || {
+            format!(
+                "{}.{}",
+                last_ident(actor_type).unwrap_or_default(),
+                last_ident(&message_type).unwrap_or_default()
+            )
+        },
+        |i| i.value(),
+    );
+
+    let export_name = format!("coerce_remote_handler.{}", identifier);
+    let identifier = syn::LitStr::new(&identifier, Span::call_site());
+
+    quote! {
+        #item
+
+        const _: () = {
+            // two handlers registered with the same identifier produce the same symbol,
+            // which fails the build rather than only surfacing once the system is started.
+            #[doc(hidden)]
+            #[export_name = #export_name]
+            static REMOTE_HANDLER_IDENTIFIER: u8 = 0;
+
+            ::coerce::remote::handler::inventory::submit! {
+                ::coerce::remote::handler::RemoteHandlerRegistration::new::<#actor_type, #message_type>(#identifier)
+            }
+        };
+    }
+}
+
+fn handler_message_type(item: &syn::ItemImpl) -> Option<syn::Type> {
+    let (_, trait_path, _) = item.trait_.as_ref()?;
+    let segment = trait_path.segments.last()?;
+    if segment.ident != "Handler" {
+        return None;
+    }
+
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => args.args.iter().find_map(|a| match a {
+            syn::GenericArgument::Type(ty) => Some(ty.clone()),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+fn last_ident(ty: &syn::Type) -> Option<String> {
+    match ty {
+        syn::Type::Path(path) => path.path.segments.last().map(|s| s.ident.to_string()),
+        _ => None,
+    }
+}
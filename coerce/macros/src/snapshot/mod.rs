@@ -1 +1,31 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[cfg(feature = "bincode")]
+pub(crate) mod bincode;
 pub(crate) mod json;
+#[cfg(feature = "msgpack")]
+pub(crate) mod msgpack;
+
+pub(crate) fn expand_serde(
+    ast: &syn::DeriveInput,
+    serialise: TokenStream,
+    deserialise: TokenStream,
+) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::coerce::persistent::journal::snapshot::Snapshot for #name #ty_generics #where_clause {
+            fn into_remote_envelope(self) -> Result<coerce::actor::message::Envelope<Self>, coerce::actor::message::MessageWrapErr> {
+                #serialise(&self)
+                    .map_err(|_e| coerce::actor::message::MessageWrapErr::SerializationErr)
+                    .map(|bytes| coerce::actor::message::Envelope::Remote(bytes.into()))
+            }
+
+            fn from_remote_envelope(bytes: coerce::actor::message::Bytes) -> Result<Self, coerce::actor::message::MessageUnwrapErr> {
+                #deserialise(&bytes).map_err(|_e| coerce::actor::message::MessageUnwrapErr::DeserializationErr)
+            }
+        }
+    }
+}
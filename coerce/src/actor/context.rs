@@ -1,3 +1,4 @@
+use crate::actor::lifecycle::{Unwatch, Watch};
 use crate::actor::message::{Handler, Message};
 use crate::actor::metrics::ActorMetrics;
 use crate::actor::system::ActorSystem;
@@ -5,6 +6,7 @@ use crate::actor::{Actor, ActorId, ActorRefErr, BoxedActorRef, CoreActorRef, Loc
 use crate::persistent::context::ActorPersistence;
 use futures::{Stream, StreamExt};
 use std::any::Any;
+use std::fmt::{Display, Formatter};
 use tokio::sync::oneshot::Sender;
 
 use crate::actor::supervised::Supervised;
@@ -13,8 +15,31 @@ use crate::actor::supervised::Supervised;
 pub enum ActorStatus {
     Starting,
     Started,
-    Stopping,
-    Stopped,
+    Stopping(StopReason),
+    Stopped(StopReason),
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum StopReason {
+    Normal,
+    ParentStopped,
+    SystemShutdown,
+    Passivated,
+    Failed(String),
+    NodeLost,
+}
+
+impl Display for StopReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StopReason::Normal => write!(f, "normal"),
+            StopReason::ParentStopped => write!(f, "parent stopped"),
+            StopReason::SystemShutdown => write!(f, "system shutdown"),
+            StopReason::Passivated => write!(f, "passivated"),
+            StopReason::Failed(err) => write!(f, "failed ({})", err),
+            StopReason::NodeLost => write!(f, "node lost"),
+        }
+    }
 }
 
 pub struct ActorContext {
@@ -25,12 +50,21 @@ pub struct ActorContext {
     supervised: Option<Supervised>,
     system: Option<ActorSystem>,
     on_actor_stopped: Option<Vec<Sender<()>>>,
+    watchers: Option<Vec<BoxedActorRef>>,
 }
 
 impl Drop for ActorContext {
     fn drop(&mut self) {
+        let reason = self.termination_reason();
+
         if let Some(boxed_parent_ref) = &self.boxed_parent_ref {
-            let _ = boxed_parent_ref.notify_child_terminated(self.id().clone());
+            let _ = boxed_parent_ref.notify_child_terminated(self.id().clone(), reason.clone());
+        }
+
+        if let Some(watchers) = self.watchers.take() {
+            for watcher in watchers {
+                let _ = watcher.notify_watched_stopped(self.id().clone(), reason.clone());
+            }
         }
 
         if let Some(mut supervised) = self.supervised.take() {
@@ -39,39 +73,34 @@ impl Drop for ActorContext {
 
         ActorMetrics::incr_actor_stopped(self.boxed_ref.0.actor_type());
 
-        match self.status {
+        match &self.status {
             ActorStatus::Starting => {
                 debug!("actor failed to start, context dropped");
             }
 
             ActorStatus::Started => {
-                if self.system.is_some() && self.system().is_terminated() {
-                    debug!(
-                        "actor (id={}, type={}) has stopped due to system shutdown",
-                        &self.id(),
-                        self.boxed_ref.actor_type()
-                    );
-                } else {
-                    debug!("actor (id={}) has stopped unexpectedly", self.id());
-                }
+                debug!(
+                    "actor (id={}, type={}) has stopped, reason: {}",
+                    &self.id(),
+                    self.boxed_ref.actor_type(),
+                    &reason
+                );
             }
 
-            ActorStatus::Stopping => {
-                if self.system.is_some() && self.system().is_terminated() {
-                    trace!(
-                        "actor (id={}) has stopped due to system shutdown",
-                        &self.id()
-                    );
-                } else {
-                    debug!(
-                        "actor (id={}) was stopping but did not complete the stop procedure",
-                        self.id()
-                    );
-                }
+            ActorStatus::Stopping(_) => {
+                debug!(
+                    "actor (id={}) was stopping but did not complete the stop procedure, reason: {}",
+                    self.id(),
+                    &reason
+                );
             }
 
-            ActorStatus::Stopped => {
-                debug!("actor (id={}) stopped, context dropped", self.id());
+            ActorStatus::Stopped(_) => {
+                debug!(
+                    "actor (id={}) stopped, context dropped, reason: {}",
+                    self.id(),
+                    &reason
+                );
             }
         }
     }
@@ -91,6 +120,7 @@ impl ActorContext {
             persistence: None,
             boxed_parent_ref: None,
             on_actor_stopped: None,
+            watchers: None,
         }
     }
 
@@ -130,6 +160,35 @@ impl ActorContext {
         self.status == ActorStatus::Starting
     }
 
+    pub fn is_stopping(&self) -> bool {
+        matches!(self.status, ActorStatus::Stopping(_))
+    }
+
+    pub fn stop_reason(&self) -> Option<&StopReason> {
+        match &self.status {
+            ActorStatus::Stopping(reason) | ActorStatus::Stopped(reason) => Some(reason),
+            _ => None,
+        }
+    }
+
+    pub fn stop(&mut self, reason: StopReason) {
+        if !self.is_stopping() {
+            self.status = ActorStatus::Stopping(reason);
+        }
+    }
+
+    fn termination_reason(&self) -> StopReason {
+        if let Some(reason) = self.stop_reason() {
+            reason.clone()
+        } else if std::thread::panicking() {
+            StopReason::Failed("actor panicked".to_string())
+        } else if self.system.is_some() && self.system().is_terminated() {
+            StopReason::SystemShutdown
+        } else {
+            StopReason::Failed("actor stopped unexpectedly".to_string())
+        }
+    }
+
     pub fn actor_ref<A: Actor>(&self) -> LocalActorRef<A> {
         (&self.boxed_ref.0)
             .as_any()
@@ -236,6 +295,28 @@ impl ActorContext {
     pub fn take_on_stopped_handlers(&mut self) -> Option<Vec<Sender<()>>> {
         self.on_actor_stopped.take()
     }
+
+    pub fn add_watcher(&mut self, watcher: BoxedActorRef) {
+        if let Some(watchers) = &mut self.watchers {
+            watchers.push(watcher);
+        } else {
+            self.watchers = Some(vec![watcher]);
+        }
+    }
+
+    pub fn remove_watcher(&mut self, watcher_id: &ActorId) {
+        if let Some(watchers) = &mut self.watchers {
+            watchers.retain(|w| w.actor_id() != watcher_id);
+        }
+    }
+
+    pub fn watch<A: Actor>(&self, actor_ref: &LocalActorRef<A>) -> Result<(), ActorRefErr> {
+        actor_ref.notify(Watch(self.boxed_actor_ref()))
+    }
+
+    pub fn unwatch<A: Actor>(&self, actor_ref: &LocalActorRef<A>) -> Result<(), ActorRefErr> {
+        actor_ref.notify(Unwatch(self.id().clone()))
+    }
 }
 
 pub fn attach_stream<S, T, R, E, A, M>(
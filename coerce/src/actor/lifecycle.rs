@@ -1,5 +1,5 @@
-use crate::actor::context::ActorStatus::{Started, Starting, Stopped, Stopping};
-use crate::actor::context::{ActorContext, ActorStatus};
+use crate::actor::context::ActorStatus::{Started, Starting, Stopped};
+use crate::actor::context::{ActorContext, ActorStatus, StopReason};
 use crate::actor::message::{Handler, Message, MessageHandler};
 use crate::actor::metrics::ActorMetrics;
 use crate::actor::scheduler::{ActorType, DeregisterActor};
@@ -12,7 +12,13 @@ use uuid::Uuid;
 
 pub struct Status();
 
-pub struct Stop(pub Option<Sender<()>>);
+pub struct Stop(pub Option<Sender<()>>, pub StopReason);
+
+pub struct Watch(pub BoxedActorRef);
+
+pub struct Unwatch(pub ActorId);
+
+pub struct WatchedStopped(pub ActorId, pub StopReason);
 
 impl Message for Status {
     type Result = ActorStatus;
@@ -22,6 +28,18 @@ impl Message for Stop {
     type Result = ();
 }
 
+impl Message for Watch {
+    type Result = ();
+}
+
+impl Message for Unwatch {
+    type Result = ();
+}
+
+impl Message for WatchedStopped {
+    type Result = ();
+}
+
 #[async_trait]
 impl<A> Handler<Status> for A
 where
@@ -39,7 +57,28 @@ impl<A: Actor> Handler<Stop> for A {
             ctx.add_on_stopped_handler(sender);
         }
 
-        ctx.set_status(Stopping);
+        ctx.stop(stop.1);
+    }
+}
+
+#[async_trait]
+impl<A: Actor> Handler<Watch> for A {
+    async fn handle(&mut self, message: Watch, ctx: &mut ActorContext) {
+        ctx.add_watcher(message.0);
+    }
+}
+
+#[async_trait]
+impl<A: Actor> Handler<Unwatch> for A {
+    async fn handle(&mut self, message: Unwatch, ctx: &mut ActorContext) {
+        ctx.remove_watcher(&message.0);
+    }
+}
+
+#[async_trait]
+impl<A: Actor> Handler<WatchedStopped> for A {
+    async fn handle(&mut self, message: WatchedStopped, ctx: &mut ActorContext) {
+        self.on_watched_stopped(&message.0, &message.1, ctx).await;
     }
 }
 
@@ -73,10 +112,9 @@ impl ActorLoop {
 
         ActorMetrics::incr_actor_created(A::type_name());
 
-        match ctx.get_status() {
-            Stopping => return,
-            _ => {}
-        };
+        if ctx.is_stopping() {
+            return;
+        }
 
         ctx.set_status(Started);
 
@@ -119,9 +157,8 @@ impl ActorLoop {
                 );
             }
 
-            match ctx.get_status() {
-                Stopping => break,
-                _ => {}
+            if ctx.is_stopping() {
+                break;
             }
         }
 
@@ -131,11 +168,12 @@ impl ActorLoop {
             &actor_id
         );
 
-        ctx.set_status(Stopping);
+        ctx.stop(StopReason::Normal);
 
         actor.stopped(&mut ctx).await;
 
-        ctx.set_status(Stopped);
+        let reason = ctx.stop_reason().cloned().unwrap_or(StopReason::Normal);
+        ctx.set_status(Stopped(reason));
 
         if actor_type.is_tracked() {
             if let Some(system) = system.take() {
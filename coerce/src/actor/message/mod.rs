@@ -9,13 +9,15 @@ use std::marker::PhantomData;
 use std::time::Instant;
 use tokio::sync::oneshot;
 
+pub use bytes::Bytes;
+
 pub trait Message: 'static + Sync + Send + Sized {
     type Result: 'static + Sync + Send;
 
     fn into_envelope(self, envelope_type: EnvelopeType) -> Result<Envelope<Self>, MessageWrapErr> {
         match envelope_type {
             EnvelopeType::Local => Ok(Envelope::Local(self)),
-            EnvelopeType::Remote => self.as_bytes().map(Envelope::Remote),
+            EnvelopeType::Remote => self.as_bytes().map(|bytes| Envelope::Remote(bytes.into())),
         }
     }
 
@@ -30,11 +32,11 @@ pub trait Message: 'static + Sync + Send + Sized {
         }
     }
 
-    fn from_bytes(_: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
+    fn from_bytes(_: Bytes) -> Result<Self, MessageUnwrapErr> {
         Err(MessageUnwrapErr::NotTransmittable)
     }
 
-    fn read_remote_result(_: Vec<u8>) -> Result<Self::Result, MessageUnwrapErr> {
+    fn read_remote_result(_: Bytes) -> Result<Self::Result, MessageUnwrapErr> {
         Err(MessageUnwrapErr::NotTransmittable)
     }
 
@@ -138,7 +140,7 @@ where
 
 pub enum Envelope<M> {
     Local(M),
-    Remote(Vec<u8>),
+    Remote(Bytes),
 }
 
 pub enum EnvelopeType {
@@ -185,7 +187,7 @@ impl Display for MessageUnwrapErr {
 impl Error for MessageUnwrapErr {}
 
 impl<M> Envelope<M> {
-    pub fn into_bytes(self) -> Vec<u8> {
+    pub fn into_bytes(self) -> Bytes {
         match self {
             Envelope::Remote(bytes) => bytes,
             _ => panic!("only remote envelopes can yield bytes"),
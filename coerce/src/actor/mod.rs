@@ -1,5 +1,5 @@
-use crate::actor::context::{ActorContext, ActorStatus};
-use crate::actor::lifecycle::{Status, Stop};
+use crate::actor::context::{ActorContext, ActorStatus, StopReason};
+use crate::actor::lifecycle::{Status, Stop, WatchedStopped};
 use crate::actor::message::{
     ActorMessage, Envelope, Exec, Handler, Message, MessageHandler, MessageUnwrapErr,
     MessageWrapErr,
@@ -68,7 +68,21 @@ pub trait Actor: 'static + Send + Sync {
 
     async fn stopped(&mut self, _ctx: &mut ActorContext) {}
 
-    async fn on_child_stopped(&mut self, _id: &ActorId, _ctx: &mut ActorContext) {}
+    async fn on_child_stopped(
+        &mut self,
+        _id: &ActorId,
+        _reason: &StopReason,
+        _ctx: &mut ActorContext,
+    ) {
+    }
+
+    async fn on_watched_stopped(
+        &mut self,
+        _id: &ActorId,
+        _reason: &StopReason,
+        _ctx: &mut ActorContext,
+    ) {
+    }
 
     fn actor_ref(&self, ctx: &ActorContext) -> LocalActorRef<Self>
     where
@@ -251,7 +265,7 @@ impl<A: Actor> ActorRef<A> {
         match &self.inner_ref {
             Ref::Local(local_ref) => local_ref.send(msg).await,
             Ref::Remote(remote_ref) => match msg.as_bytes() {
-                Ok(envelope) => remote_ref.send(Envelope::Remote(envelope)).await,
+                Ok(envelope) => remote_ref.send(Envelope::Remote(envelope.into())).await,
                 Err(e) => Err(ActorRefErr::Serialisation(e)),
             },
         }
@@ -264,7 +278,7 @@ impl<A: Actor> ActorRef<A> {
         match &self.inner_ref {
             Ref::Local(local_ref) => local_ref.notify(msg),
             Ref::Remote(remote_ref) => match msg.as_bytes() {
-                Ok(envelope) => remote_ref.notify(Envelope::Remote(envelope)).await,
+                Ok(envelope) => remote_ref.notify(Envelope::Remote(envelope.into())).await,
                 Err(e) => Err(ActorRefErr::Serialisation(e)),
             },
         }
@@ -359,9 +373,13 @@ pub trait CoreActorRef: Any {
 
     async fn stop(&self) -> Result<(), ActorRefErr>;
 
+    async fn stop_with_reason(&self, reason: StopReason) -> Result<(), ActorRefErr>;
+
     fn notify_stop(&self) -> Result<(), ActorRefErr>;
 
-    fn notify_child_terminated(&self, id: ActorId) -> Result<(), ActorRefErr>;
+    fn notify_child_terminated(&self, id: ActorId, reason: StopReason) -> Result<(), ActorRefErr>;
+
+    fn notify_watched_stopped(&self, id: ActorId, reason: StopReason) -> Result<(), ActorRefErr>;
 
     fn is_valid(&self) -> bool;
 
@@ -428,6 +446,9 @@ pub enum ActorRefErr {
         actor_type: String,
     },
     NotImplemented,
+    AuthenticationFailed,
+    IncompatibleProtocol,
+    NodeUnavailable(u64),
 }
 
 impl Display for ActorRefErr {
@@ -455,6 +476,11 @@ impl Display for ActorRefErr {
             ),
             ActorRefErr::StartChannelClosed => write!(f, "actor failed to start, channel closed"),
             ActorRefErr::NotImplemented => write!(f, "functionality is not yet implemented"),
+            ActorRefErr::AuthenticationFailed => write!(f, "node authentication failed"),
+            ActorRefErr::IncompatibleProtocol => {
+                write!(f, "node protocol versions are incompatible")
+            }
+            ActorRefErr::NodeUnavailable(node_id) => write!(f, "node {} is unavailable", node_id),
         }
     }
 }
@@ -537,8 +563,12 @@ impl<A: Actor> LocalActorRef<A> {
     }
 
     pub async fn stop(&self) -> Result<(), ActorRefErr> {
+        self.stop_with_reason(StopReason::Normal).await
+    }
+
+    pub async fn stop_with_reason(&self, reason: StopReason) -> Result<(), ActorRefErr> {
         let (tx, rx) = oneshot::channel();
-        let res = self.notify(Stop(Some(tx)));
+        let res = self.notify(Stop(Some(tx), reason));
         if res.is_ok() {
             rx.await.map_err(|_| ActorRefErr::InvalidRef)
         } else {
@@ -551,7 +581,11 @@ impl<A: Actor> LocalActorRef<A> {
     }
 
     pub fn notify_stop(&self) -> Result<(), ActorRefErr> {
-        self.notify(Stop(None))
+        self.notify_stop_with_reason(StopReason::Normal)
+    }
+
+    pub fn notify_stop_with_reason(&self, reason: StopReason) -> Result<(), ActorRefErr> {
+        self.notify(Stop(None, reason))
     }
 }
 
@@ -573,12 +607,20 @@ impl<A: Actor> CoreActorRef for LocalActorRef<A> {
         self.stop().await
     }
 
+    async fn stop_with_reason(&self, reason: StopReason) -> Result<(), ActorRefErr> {
+        self.stop_with_reason(reason).await
+    }
+
     fn notify_stop(&self) -> Result<(), ActorRefErr> {
         self.notify_stop()
     }
 
-    fn notify_child_terminated(&self, id: ActorId) -> Result<(), ActorRefErr> {
-        self.notify(Terminated(id))
+    fn notify_child_terminated(&self, id: ActorId, reason: StopReason) -> Result<(), ActorRefErr> {
+        self.notify(Terminated(id, reason))
+    }
+
+    fn notify_watched_stopped(&self, id: ActorId, reason: StopReason) -> Result<(), ActorRefErr> {
+        self.notify(WatchedStopped(id, reason))
     }
 
     fn is_valid(&self) -> bool {
@@ -608,12 +650,20 @@ impl CoreActorRef for BoxedActorRef {
         self.0.stop().await
     }
 
+    async fn stop_with_reason(&self, reason: StopReason) -> Result<(), ActorRefErr> {
+        self.0.stop_with_reason(reason).await
+    }
+
     fn notify_stop(&self) -> Result<(), ActorRefErr> {
         self.0.notify_stop()
     }
 
-    fn notify_child_terminated(&self, id: ActorId) -> Result<(), ActorRefErr> {
-        self.0.notify_child_terminated(id)
+    fn notify_child_terminated(&self, id: ActorId, reason: StopReason) -> Result<(), ActorRefErr> {
+        self.0.notify_child_terminated(id, reason)
+    }
+
+    fn notify_watched_stopped(&self, id: ActorId, reason: StopReason) -> Result<(), ActorRefErr> {
+        self.0.notify_watched_stopped(id, reason)
     }
 
     fn is_valid(&self) -> bool {
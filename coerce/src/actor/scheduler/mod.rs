@@ -1,4 +1,4 @@
-use crate::actor::context::ActorContext;
+use crate::actor::context::{ActorContext, StopReason};
 use crate::actor::message::{Handler, Message};
 use crate::actor::{Actor, ActorId, BoxedActorRef, CoreActorRef, IntoActorId, LocalActorRef};
 
@@ -53,7 +53,10 @@ impl Actor for ActorScheduler {
         let stop_results =
             futures::future::join_all(self.actors.iter().map(|(id, actor)| async move {
                 debug!(target: "ActorScheduler", "stopping actor (id={})", &actor.actor_id());
-                (id.clone(), actor.stop().await)
+                (
+                    id.clone(),
+                    actor.stop_with_reason(StopReason::SystemShutdown).await,
+                )
             }))
             .await;
 
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::actor::context::ActorContext;
+use crate::actor::context::{ActorContext, StopReason};
 use crate::actor::message::{Handler, Message};
 use crate::actor::scheduler::{start_actor, ActorType};
 use crate::actor::system::ActorSystem;
@@ -20,7 +20,7 @@ impl Supervised {
     }
 }
 
-pub struct Terminated(pub ActorId);
+pub struct Terminated(pub ActorId, pub StopReason);
 
 impl Message for Terminated {
     type Result = ();
@@ -33,7 +33,7 @@ impl<A: Actor> Handler<Terminated> for A {
             supervised.on_child_stopped(&message.0).await;
         }
 
-        self.on_child_stopped(&message.0, ctx).await;
+        self.on_child_stopped(&message.0, &message.1, ctx).await;
     }
 }
 
@@ -92,12 +92,14 @@ impl Supervised {
 
     pub async fn stop_all(&mut self) {
         let n = self.children.len();
-        let stop_results = futures::future::join_all(
-            self.children
-                .iter()
-                .map(|(id, actor)| async move { (id.clone(), actor.stop().await) }),
-        )
-        .await;
+        let stop_results =
+            futures::future::join_all(self.children.iter().map(|(id, actor)| async move {
+                (
+                    id.clone(),
+                    actor.stop_with_reason(StopReason::ParentStopped).await,
+                )
+            }))
+            .await;
 
         for (actor_id, stop_result) in stop_results {
             if let Ok(status) = stop_result {
@@ -1,3 +1,4 @@
+use crate::actor::context::StopReason;
 use crate::actor::scheduler::{start_actor, ActorScheduler, ActorType, GetActor, RegisterActor};
 use crate::actor::{
     new_actor_id, Actor, ActorId, ActorRefErr, CoreActorRef, IntoActorId, LocalActorRef,
@@ -164,7 +165,11 @@ impl ActorSystem {
         info!("shutting down");
 
         self.core.is_terminated.store(true, Relaxed);
-        let _ = self.core.scheduler.stop().await;
+        let _ = self
+            .core
+            .scheduler
+            .stop_with_reason(StopReason::SystemShutdown)
+            .await;
 
         if let Some(remote) = &self.core.remote {
             remote.shutdown().await;
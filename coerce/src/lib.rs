@@ -14,6 +14,9 @@ extern crate metrics;
 extern crate tracing;
 extern crate core;
 
+// Lets code generated by `coerce-macros` refer to `::coerce` from within this crate
+extern crate self as coerce;
+
 pub mod actor;
 pub mod persistent;
 pub mod remote;
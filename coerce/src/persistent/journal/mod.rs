@@ -140,7 +140,8 @@ impl<A: PersistentActor> Journal<A> {
         let bytes = snapshot
             .into_remote_envelope()
             .expect("cannot serialize snapshot")
-            .into_bytes();
+            .into_bytes()
+            .into();
 
         let sequence = self.last_sequence_id + 1;
 
@@ -248,7 +249,7 @@ where
     A: Recover<M>,
 {
     async fn recover(&self, actor: &mut A, bytes: Vec<u8>, ctx: &mut ActorContext) {
-        let message = M::from_bytes(bytes);
+        let message = M::from_bytes(bytes.into());
         if let Ok(message) = message {
             actor.recover(message, ctx).await;
         } else {
@@ -263,7 +264,7 @@ where
     A: RecoverSnapshot<S>,
 {
     async fn recover(&self, actor: &mut A, bytes: Vec<u8>, ctx: &mut ActorContext) {
-        let message = S::from_remote_envelope(bytes);
+        let message = S::from_remote_envelope(bytes.into());
         if let Ok(message) = message {
             actor.recover(message, ctx).await;
         } else {
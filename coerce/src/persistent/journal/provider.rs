@@ -80,6 +80,36 @@ pub mod inmemory {
             Ok(())
         }
 
+        async fn write_snapshot_if(
+            &self,
+            persistence_id: &str,
+            expected_sequence: i64,
+            entry: JournalEntry,
+        ) -> anyhow::Result<bool> {
+            let mut store = self.store.write().await;
+            let journal = store.get_mut(persistence_id);
+            let latest_sequence = journal
+                .as_ref()
+                .and_then(|j| j.snapshots.last())
+                .map_or(0, |s| s.sequence);
+
+            if latest_sequence != expected_sequence {
+                return Ok(false);
+            }
+
+            match journal {
+                Some(journal) => journal.snapshots.push(entry),
+                None => {
+                    store.insert(
+                        persistence_id.to_string(),
+                        ActorJournal::from_snapshot(entry),
+                    );
+                }
+            }
+
+            Ok(true)
+        }
+
         async fn write_message(
             &self,
             persistence_id: &str,
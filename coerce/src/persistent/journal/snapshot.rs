@@ -1,4 +1,6 @@
-use crate::actor::message::{Envelope, EnvelopeType, Message, MessageUnwrapErr, MessageWrapErr};
+use crate::actor::message::{
+    Bytes, Envelope, EnvelopeType, Message, MessageUnwrapErr, MessageWrapErr,
+};
 
 pub struct JournalPayload {
     pub message_type: String,
@@ -25,7 +27,7 @@ pub trait Snapshot: 'static + Sync + Send + Sized {
         }
     }
 
-    fn from_remote_envelope(_: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
+    fn from_remote_envelope(_: Bytes) -> Result<Self, MessageUnwrapErr> {
         Err(MessageUnwrapErr::NotTransmittable)
     }
 }
@@ -1,6 +1,7 @@
 use crate::persistent::journal::proto::journal::JournalEntry as ProtoJournalEntry;
 use crate::remote::net::StreamData;
 use anyhow::Result;
+use bytes::Bytes;
 use protobuf::Message;
 use std::sync::Arc;
 
@@ -17,6 +18,21 @@ pub trait JournalStorage: Send + Sync {
 
     async fn write_message(&self, persistence_id: &str, entry: JournalEntry) -> Result<()>;
 
+    /// Writes the snapshot only if the sequence of the latest snapshot is still
+    /// `expected_sequence` (0 if there are no snapshots), returns false if it has moved on.
+    /// Backends that can't write conditionally return an error.
+    async fn write_snapshot_if(
+        &self,
+        persistence_id: &str,
+        expected_sequence: i64,
+        entry: JournalEntry,
+    ) -> Result<bool> {
+        let _ = (persistence_id, expected_sequence, entry);
+        Err(anyhow::anyhow!(
+            "conditional snapshot writes aren't supported by this storage backend"
+        ))
+    }
+
     async fn read_latest_snapshot(&self, persistence_id: &str) -> Result<Option<JournalEntry>>;
 
     async fn read_latest_messages(
@@ -31,7 +47,7 @@ pub trait JournalStorage: Send + Sync {
 pub type JournalStorageRef = Arc<dyn JournalStorage>;
 
 impl StreamData for JournalEntry {
-    fn read_from_bytes(data: Vec<u8>) -> Option<Self> {
+    fn read_from_bytes(data: Bytes) -> Option<Self> {
         let journal_entry = ProtoJournalEntry::parse_from_bytes(&data);
         if let Ok(journal_entry) = journal_entry {
             Some(JournalEntry {
@@ -1,19 +1,25 @@
-use crate::actor::context::ActorContext;
+use crate::actor::context::{ActorContext, StopReason};
 use crate::actor::message::Handler;
 use crate::remote::actor::message::{
-    ClientConnected, ClientWrite, GetActorNode, GetNodes, NewClient, NodeTerminated, RegisterActor,
-    RegisterNode, SetRemote, UpdateNodes,
+    ClientConnected, ClientWrite, FailBufferedWrites, GetActorNode, GetNodes, NewClient,
+    NodeTerminated, RegisterActor, RegisterClientSession, RegisterNode, RemoveNodeClients,
+    SetRemote, UpdateNodes,
 };
-use crate::remote::actor::{RemoteClientRegistry, RemoteRegistry, RemoteResponse};
-use crate::remote::cluster::node::{RemoteNode, RemoteNodeState};
+use crate::remote::actor::{ClientSession, RemoteClientRegistry, RemoteRegistry, RemoteResponse};
+use crate::remote::cluster::node::{NodeStatus, RemoteNode, RemoteNodeState};
+use crate::remote::net::client::buffer::fail_request;
+use crate::remote::net::client::lanes::{ClientLanes, CONTROL_LANE};
 use crate::remote::net::client::RemoteClient;
 use std::collections::hash_map::Entry;
 
-use crate::remote::net::message::SessionEvent;
+use crate::remote::net::message::{ClientEvent, SessionEvent};
 use crate::remote::net::proto::network::{ActorAddress, FindActorEvent};
+use crate::remote::net::StreamData;
+use crate::remote::system::NodeId;
 
 use crate::actor::{Actor, ActorId, LocalActorRef};
 use crate::remote::net::client::send::Write;
+use crate::remote::net::server::session::store::SessionWrite;
 use crate::remote::stream::pubsub::{PubSub, Receive};
 use crate::remote::stream::system::{SystemEvent, SystemTopic};
 
@@ -36,6 +42,13 @@ impl Handler<SetRemote> for RemoteRegistry {
     }
 }
 
+#[async_trait]
+impl Handler<SetRemote> for RemoteClientRegistry {
+    async fn handle(&mut self, message: SetRemote, _ctx: &mut ActorContext) {
+        self.system = Some(message.0);
+    }
+}
+
 #[async_trait]
 impl Handler<GetNodes> for RemoteRegistry {
     async fn handle(
@@ -56,13 +69,32 @@ impl Handler<RegisterNode> for RemoteRegistry {
 
 impl RemoteRegistry {
     pub fn register_node(&mut self, node: RemoteNode) {
+        if let Some(system) = &self.system {
+            system.set_node_terminated(node.id, false);
+        }
+
         self.nodes.add(node);
     }
+
+    fn update_terminated_node(&self, node_id: NodeId, terminated: bool) {
+        let system = match &self.system {
+            Some(system) => system,
+            None => return,
+        };
+
+        if system.set_node_terminated(node_id, terminated) && terminated {
+            let _ = system.client_registry().notify(FailBufferedWrites(node_id));
+        }
+    }
 }
 
 #[async_trait]
 impl Handler<UpdateNodes> for RemoteRegistry {
     async fn handle(&mut self, message: UpdateNodes, _ctx: &mut ActorContext) {
+        for node in &message.0 {
+            self.update_terminated_node(node.id, node.status == NodeStatus::Terminated);
+        }
+
         self.nodes.update_nodes(message.0);
     }
 }
@@ -71,6 +103,7 @@ impl Handler<UpdateNodes> for RemoteRegistry {
 impl Handler<NodeTerminated> for RemoteRegistry {
     async fn handle(&mut self, message: NodeTerminated, _ctx: &mut ActorContext) {
         self.nodes.node_terminated(message.0);
+        self.update_terminated_node(message.0, true);
         debug!("node_id={} marked as terminated", message.0);
 
         // TODO: should this be published to clusterevent subscribers?
@@ -90,19 +123,42 @@ impl Handler<NewClient> for RemoteClientRegistry {
             Entry::Occupied(entry) => {
                 return {
                     debug!("RemoteClient already exists for addr={}", &message.addr);
-                    let client = entry.get();
-                    Some(client.clone())
+                    let lanes = entry.get();
+                    Some(lanes.control().clone())
                 }
             }
         };
 
-        debug!("creating RemoteClient, addr={}", &message.addr);
+        let lane_count = message.system.config().connection_lanes();
+
+        debug!(
+            "creating RemoteClient, addr={}, lanes={}",
+            &message.addr, lane_count
+        );
 
-        let client_actor =
-            RemoteClient::new(message.addr.clone(), message.system, message.client_type).await;
+        let client_actor = RemoteClient::new(
+            message.addr.clone(),
+            CONTROL_LANE,
+            message.system.clone(),
+            message.client_type,
+        )
+        .await;
+
+        let mut message_lanes = vec![];
+        for lane in 1..lane_count {
+            message_lanes.push(
+                RemoteClient::new(
+                    message.addr.clone(),
+                    lane,
+                    message.system.clone(),
+                    message.client_type,
+                )
+                .await,
+            );
+        }
 
         debug!("created RemoteClient, addr={}", &message.addr);
-        entry.insert(client_actor.clone());
+        entry.insert(ClientLanes::new(client_actor.clone(), message_lanes));
 
         Some(client_actor)
     }
@@ -111,8 +167,13 @@ impl Handler<NewClient> for RemoteClientRegistry {
 #[async_trait]
 impl Handler<ClientConnected> for RemoteClientRegistry {
     async fn handle(&mut self, message: ClientConnected, _ctx: &mut ActorContext) {
-        self.node_id_registry
-            .insert(message.remote_node_id, message.client_actor_ref);
+        let lanes = self
+            .node_addr_registry
+            .get(&message.client_addr)
+            .cloned()
+            .unwrap_or_else(|| ClientLanes::new(message.client_actor_ref, vec![]));
+
+        self.node_id_registry.insert(message.remote_node_id, lanes);
     }
 }
 
@@ -122,17 +183,103 @@ impl Handler<ClientWrite> for RemoteClientRegistry {
         let node_id = message.0;
         let message = message.1;
 
-        // TODO: we could open multiple clients per node and use some routing mechanism
-        //       to potentially improve throughput, whilst still maintaining
-        //       message ordering
+        if self.is_node_terminated(node_id) {
+            debug!(target: "RemoteClientRegistry", "attempted to write message to terminated node_id={} (message={:?})", &node_id, &message);
+            self.fail_request(node_id, &message);
+        } else if let Some(lanes) = self.node_id_registry.get(&node_id) {
+            let client = match &self.system {
+                Some(system) => lanes.route(&message, system.config()),
+                None => lanes.control(),
+            };
 
-        if let Some(client) = self.node_id_registry.get(&node_id) {
-            debug!(target: "RemoteClientRegistry", "emitting message ({:?}) to node_id={}", &message, &node_id);
+            debug!(target: "RemoteClientRegistry", "emitting message ({:?}) to node_id={}, client={}", &message, &node_id, client.actor_id());
             client.notify(Write(message)).expect("send client msg");
             debug!(target: "RemoteClientRegistry", "written data to client");
+        } else if let Some(session) = self.client_sessions.get(&node_id) {
+            let event = match message {
+                SessionEvent::Result(result) => ClientEvent::Result(result),
+                SessionEvent::Err(err) => ClientEvent::Err(err),
+                message => {
+                    warn!(target: "RemoteClientRegistry", "attempted to write message to cluster client (node_id={}) but only results can be sent to cluster clients (message={:?})", &node_id, &message);
+                    self.fail_request(node_id, &message);
+                    return;
+                }
+            };
+
+            debug!(target: "RemoteClientRegistry", "emitting result to cluster client (node_id={}, session_id={})", &node_id, &session.session_id);
+            if session
+                .session
+                .notify(SessionWrite(session.session_id, event))
+                .is_err()
+            {
+                debug!(target: "RemoteClientRegistry", "cluster client session closed (node_id={}, session_id={})", &node_id, &session.session_id);
+                self.client_sessions.remove(&node_id);
+            }
         } else {
             // TODO: should we buffer the message incase the client will eventually exist
             warn!(target: "RemoteClientRegistry", "attempted to write message to node_id={} but no client was registered (message={:?})", &node_id, &message);
+            self.fail_request(node_id, &message);
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<RegisterClientSession> for RemoteClientRegistry {
+    async fn handle(&mut self, message: RegisterClientSession, _ctx: &mut ActorContext) {
+        debug!(target: "RemoteClientRegistry", "cluster client session registered (node_id={}, session_id={})", &message.node_id, &message.session_id);
+
+        self.client_sessions.insert(
+            message.node_id,
+            ClientSession {
+                session_id: message.session_id,
+                session: message.session,
+            },
+        );
+    }
+}
+
+impl RemoteClientRegistry {
+    fn is_node_terminated(&self, node_id: NodeId) -> bool {
+        self.system
+            .as_ref()
+            .is_some_and(|system| system.is_node_terminated(node_id))
+    }
+
+    fn fail_request(&self, node_id: NodeId, message: &SessionEvent) {
+        if let (Some(system), Some(request_id)) = (&self.system, message.request_id()) {
+            fail_request(request_id, node_id, system);
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<FailBufferedWrites> for RemoteClientRegistry {
+    async fn handle(&mut self, message: FailBufferedWrites, _ctx: &mut ActorContext) {
+        if let Some(lanes) = self.node_id_registry.get(&message.0) {
+            for client in lanes.all() {
+                let _ = client.notify(FailBufferedWrites(message.0));
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<RemoveNodeClients> for RemoteClientRegistry {
+    async fn handle(&mut self, message: RemoveNodeClients, _ctx: &mut ActorContext) {
+        let node_id = message.0;
+        let lanes = match self.node_id_registry.remove(&node_id) {
+            Some(lanes) => lanes,
+            None => return,
+        };
+
+        let control_id = lanes.control().actor_id().clone();
+        self.node_addr_registry
+            .retain(|_, lanes| lanes.control().actor_id() != &control_id);
+
+        debug!(target: "RemoteClientRegistry", "node_id={} removed, stopping {} client(s)", node_id, lanes.lane_count());
+
+        for client in lanes.all() {
+            let _ = client.notify_stop_with_reason(StopReason::NodeLost);
         }
     }
 }
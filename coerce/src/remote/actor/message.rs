@@ -5,6 +5,7 @@ use crate::remote::system::{NodeId, RemoteActorSystem};
 use crate::actor::message::Message;
 use crate::remote::net::client::{ClientType, RemoteClient};
 use crate::remote::net::message::SessionEvent;
+use crate::remote::net::server::session::RemoteSession;
 
 use crate::actor::{ActorId, LocalActorRef};
 
@@ -46,6 +47,7 @@ impl Message for NewClient {
 
 pub struct ClientConnected {
     pub addr: String,
+    pub client_addr: String,
     pub remote_node_id: NodeId,
     pub client_actor_ref: LocalActorRef<RemoteClient>,
 }
@@ -78,6 +80,40 @@ impl Message for NodeTerminated {
     type Result = ();
 }
 
+/// Sent when a node announces that it's leaving the cluster gracefully.
+pub struct NodeLeaving(pub NodeId);
+
+impl Message for NodeLeaving {
+    type Result = ();
+}
+
+/// Stops the clients connected to a node that has been removed from the cluster, a new client is
+/// created if the node is discovered again.
+pub struct RemoveNodeClients(pub NodeId);
+
+impl Message for RemoveNodeClients {
+    type Result = ();
+}
+
+/// Fails the requests buffered for a node that has been declared terminated.
+pub struct FailBufferedWrites(pub NodeId);
+
+impl Message for FailBufferedWrites {
+    type Result = ();
+}
+
+/// Routes results for a cluster client, which doesn't accept connections, back over the session
+/// it opened with this node.
+pub struct RegisterClientSession {
+    pub node_id: NodeId,
+    pub session_id: Uuid,
+    pub session: LocalActorRef<RemoteSession>,
+}
+
+impl Message for RegisterClientSession {
+    type Result = ();
+}
+
 pub struct ClientWrite(pub NodeId, pub SessionEvent);
 
 impl Message for ClientWrite {
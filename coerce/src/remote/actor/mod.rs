@@ -6,14 +6,27 @@ use crate::actor::{scheduler::ActorType, Actor, ActorId, ActorRefErr, LocalActor
 use crate::remote::handler::{
     ActorHandler, ActorMessageHandler, RemoteActorMarker, RemoteActorMessageMarker,
 };
-use crate::remote::net::client::RemoteClient;
+use crate::remote::net::auth::{AuthenticatorRef, HmacAuthenticator};
+use crate::remote::net::client::buffer::WriteBufferConfig;
+use crate::remote::net::client::config::RemoteClientConfig;
+use crate::remote::net::client::lanes::ClientLanes;
+use crate::remote::net::codec::DEFAULT_MAX_FRAME_SIZE;
+use crate::remote::net::protocol::{ProtocolFeature, ProtocolVersion};
+use crate::remote::net::server::session::RemoteSession;
+use crate::remote::net::tls::TlsConfigRef;
+use crate::remote::net::transport::{TcpTransport, TransportRef};
 use crate::remote::system::{NodeId, RemoteActorSystem};
+use bytes::Bytes;
 use std::any::TypeId;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 use crate::actor::context::ActorContext;
 use crate::actor::scheduler::ActorType::Anonymous;
+use crate::remote::cluster::split_brain::SplitBrainResolverConfig;
+use crate::remote::cluster::swim::SwimConfig;
 use crate::remote::heartbeat::HeartbeatConfig;
+use crate::remote::raft::RaftConfig;
 use crate::remote::stream::pubsub::Subscription;
 use uuid::Uuid;
 
@@ -21,8 +34,15 @@ pub mod handler;
 pub mod message;
 
 pub struct RemoteClientRegistry {
-    node_addr_registry: HashMap<String, LocalActorRef<RemoteClient>>,
-    node_id_registry: HashMap<NodeId, LocalActorRef<RemoteClient>>,
+    node_addr_registry: HashMap<String, ClientLanes>,
+    node_id_registry: HashMap<NodeId, ClientLanes>,
+    client_sessions: HashMap<NodeId, ClientSession>,
+    system: Option<RemoteActorSystem>,
+}
+
+struct ClientSession {
+    session_id: Uuid,
+    session: LocalActorRef<RemoteSession>,
 }
 
 pub struct RemoteRegistry {
@@ -49,7 +69,20 @@ pub struct RemoteSystemConfig {
     message_handlers: HashMap<String, BoxedMessageHandler>,
     actor_handlers: HashMap<String, BoxedActorHandler>,
     heartbeat_config: HeartbeatConfig,
-    server_auth_token: Option<String>,
+    authenticator: Option<AuthenticatorRef>,
+    tls: Option<TlsConfigRef>,
+    transport: TransportRef,
+    max_frame_size: usize,
+    protocol_features: Vec<ProtocolFeature>,
+    connection_lanes: usize,
+    write_buffer: WriteBufferConfig,
+    client_config: RemoteClientConfig,
+    swim_config: Option<SwimConfig>,
+    split_brain_resolver: Option<SplitBrainResolverConfig>,
+    raft_config: Option<RaftConfig>,
+    roles: Vec<String>,
+    leader_role: Option<String>,
+    control_handlers: HashSet<String>,
 }
 
 impl RemoteSystemConfig {
@@ -69,10 +102,100 @@ impl RemoteSystemConfig {
             message_handlers,
             actor_handlers,
             heartbeat_config,
-            server_auth_token,
+            authenticator: server_auth_token
+                .map(|token| Arc::new(HmacAuthenticator::new(token)) as AuthenticatorRef),
+            tls: None,
+            transport: Arc::new(TcpTransport),
+            max_frame_size: DEFAULT_MAX_FRAME_SIZE,
+            protocol_features: ProtocolFeature::all(),
+            connection_lanes: 1,
+            write_buffer: WriteBufferConfig::default(),
+            client_config: RemoteClientConfig::default(),
+            swim_config: None,
+            split_brain_resolver: None,
+            raft_config: None,
+            roles: vec![],
+            leader_role: None,
+            control_handlers: HashSet::new(),
         }
     }
 
+    pub fn with_max_frame_size(mut self, max_frame_size: usize) -> Self {
+        self.max_frame_size = max_frame_size;
+        self
+    }
+
+    pub fn with_protocol_features(mut self, protocol_features: Vec<ProtocolFeature>) -> Self {
+        self.protocol_features = protocol_features;
+        self
+    }
+
+    pub fn with_connection_lanes(mut self, connection_lanes: usize) -> Self {
+        self.connection_lanes = connection_lanes.max(1);
+        self
+    }
+
+    pub fn with_write_buffer(mut self, write_buffer: WriteBufferConfig) -> Self {
+        self.write_buffer = write_buffer;
+        self
+    }
+
+    pub fn with_client_config(mut self, client_config: RemoteClientConfig) -> Self {
+        self.client_config = client_config;
+        self
+    }
+
+    pub fn with_swim_config(mut self, swim_config: Option<SwimConfig>) -> Self {
+        self.swim_config = swim_config;
+        self
+    }
+
+    pub fn with_split_brain_resolver(
+        mut self,
+        split_brain_resolver: Option<SplitBrainResolverConfig>,
+    ) -> Self {
+        self.split_brain_resolver = split_brain_resolver;
+        self
+    }
+
+    pub fn with_raft_config(mut self, raft_config: Option<RaftConfig>) -> Self {
+        self.raft_config = raft_config;
+        self
+    }
+
+    pub fn with_roles(mut self, roles: Vec<String>) -> Self {
+        self.roles = roles;
+        self
+    }
+
+    pub fn with_leader_role(mut self, leader_role: Option<String>) -> Self {
+        self.leader_role = leader_role;
+        self
+    }
+
+    pub fn with_control_handlers(mut self, control_handlers: HashSet<String>) -> Self {
+        self.control_handlers = control_handlers;
+        self
+    }
+
+    pub fn with_authenticator(mut self, authenticator: Option<AuthenticatorRef>) -> Self {
+        if authenticator.is_some() {
+            self.authenticator = authenticator;
+        }
+
+        self
+    }
+
+    pub fn with_tls(mut self, tls: Option<TlsConfigRef>) -> Self {
+        self.tls = tls;
+        self
+    }
+
+    pub fn with_transport(mut self, transport: TransportRef) -> Self {
+        self.transport = transport;
+        self
+    }
+
     pub fn node_tag(&self) -> &str {
         &self.node_tag
     }
@@ -105,6 +228,68 @@ impl RemoteSystemConfig {
         &self.heartbeat_config
     }
 
+    pub fn tls(&self) -> Option<&TlsConfigRef> {
+        self.tls.as_ref()
+    }
+
+    pub fn transport(&self) -> &TransportRef {
+        &self.transport
+    }
+
+    pub fn authenticator(&self) -> Option<&AuthenticatorRef> {
+        self.authenticator.as_ref()
+    }
+
+    pub fn max_frame_size(&self) -> usize {
+        self.max_frame_size
+    }
+
+    pub fn connection_lanes(&self) -> usize {
+        self.connection_lanes
+    }
+
+    pub fn write_buffer(&self) -> &WriteBufferConfig {
+        &self.write_buffer
+    }
+
+    pub fn client_config(&self) -> &RemoteClientConfig {
+        &self.client_config
+    }
+
+    /// The SWIM membership configuration, when set, cluster membership is gossiped
+    /// rather than each node pinging every other node.
+    pub fn swim_config(&self) -> Option<&SwimConfig> {
+        self.swim_config.as_ref()
+    }
+
+    pub fn split_brain_resolver(&self) -> Option<&SplitBrainResolverConfig> {
+        self.split_brain_resolver.as_ref()
+    }
+
+    pub fn raft_config(&self) -> Option<&RaftConfig> {
+        self.raft_config.as_ref()
+    }
+
+    /// The roles this node has, advertised to the rest of the cluster when connecting.
+    pub fn roles(&self) -> &[String] {
+        &self.roles
+    }
+
+    /// Whether the handler was registered with `with_control_handler`, messages to control
+    /// handlers are sent via the control lane.
+    pub fn is_control_handler(&self, handler_type: &str) -> bool {
+        self.control_handlers.contains(handler_type)
+    }
+
+    /// When set, only nodes with this role are elected as the cluster leader.
+    pub fn leader_role(&self) -> Option<&str> {
+        self.leader_role.as_deref()
+    }
+
+    pub fn protocol(&self) -> ProtocolVersion {
+        ProtocolVersion::current(&self.protocol_features)
+    }
+
     pub fn get_capabilities(&self) -> SystemCapabilities {
         let mut actors: Vec<String> = self.actor_types.values().map(|a| a.clone()).collect();
         actors.sort_by(|a, b| a.to_lowercase().cmp(&b.to_lowercase()));
@@ -140,7 +325,7 @@ pub struct RemoteRequest {
 
 #[derive(Debug)]
 pub enum RemoteResponse {
-    Ok(Vec<u8>),
+    Ok(Bytes),
     Err(ActorRefErr),
 }
 
@@ -159,7 +344,7 @@ impl RemoteResponse {
         }
     }
 
-    pub fn into_result(self) -> Result<Vec<u8>, ActorRefErr> {
+    pub fn into_result(self) -> Result<Bytes, ActorRefErr> {
         match self {
             RemoteResponse::Ok(buff) => Ok(buff),
             RemoteResponse::Err(buff) => Err(buff),
@@ -172,8 +357,10 @@ impl Actor for RemoteRegistry {}
 #[async_trait]
 impl Actor for RemoteClientRegistry {
     async fn stopped(&mut self, _ctx: &mut ActorContext) {
-        for client in &self.node_id_registry {
-            let _ = client.1.stop().await;
+        for lanes in self.node_id_registry.values() {
+            for client in lanes.all() {
+                let _ = client.stop().await;
+            }
         }
     }
 }
@@ -185,6 +372,8 @@ impl RemoteClientRegistry {
             RemoteClientRegistry {
                 node_addr_registry: HashMap::new(),
                 node_id_registry: HashMap::new(),
+                client_sessions: HashMap::new(),
+                system: None,
             },
             ActorType::Tracked,
         )
@@ -60,6 +60,10 @@ where
         // let span = tracing::trace_span!("RemoteActorRef::notify", actor_type, message_type);
         // let _enter = span.enter();
 
+        if self.system.is_node_terminated(self.node_id) {
+            return Err(ActorRefErr::NodeUnavailable(self.node_id));
+        }
+
         let id = Uuid::new_v4();
 
         let request = self.create_request(msg, String::new(), id, false);
@@ -87,6 +91,10 @@ where
         // let span = tracing::trace_span!("RemoteActorRef::send", actor_type, message_type);
         // let _enter = span.enter();
 
+        if self.system.is_node_terminated(self.node_id) {
+            return Err(ActorRefErr::NodeUnavailable(self.node_id));
+        }
+
         let id = Uuid::new_v4();
         let event = self.create_request(msg, String::new(), id, true);
 
@@ -95,8 +103,6 @@ where
 
         match event {
             Some(event) => {
-                // TODO: we could make this fail fast if the node is known to be terminated?
-
                 self.system.notify_node(self.node_id, event).await;
                 match res_rx.await {
                     Ok(RemoteResponse::Ok(res)) => match Msg::read_remote_result(res) {
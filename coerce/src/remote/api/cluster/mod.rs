@@ -2,7 +2,7 @@ use crate::remote::api::Routes;
 use crate::remote::cluster::node::NodeStatus;
 use crate::remote::system::{NodeId, RemoteActorSystem};
 use axum::response::IntoResponse;
-use axum::routing::get;
+use axum::routing::{get, post};
 use axum::{Json, Router};
 
 pub struct ClusterApi {
@@ -17,10 +17,15 @@ impl ClusterApi {
 
 impl Routes for ClusterApi {
     fn routes(&self, router: Router) -> Router {
-        router.route("/cluster/nodes", {
-            let system = self.system.clone();
-            get(move || get_nodes(system))
-        })
+        router
+            .route("/cluster/nodes", {
+                let system = self.system.clone();
+                get(move || get_nodes(system))
+            })
+            .route("/cluster/leave", {
+                let system = self.system.clone();
+                post(move || leave(system))
+            })
     }
 }
 
@@ -77,3 +82,18 @@ async fn get_nodes(system: RemoteActorSystem) -> impl IntoResponse {
         nodes,
     })
 }
+
+#[derive(Serialize, Deserialize)]
+pub struct LeftCluster {
+    pub node_id: NodeId,
+    pub shards_handed_off: usize,
+}
+
+async fn leave(system: RemoteActorSystem) -> impl IntoResponse {
+    let shards_handed_off = system.leave().await;
+
+    Json(LeftCluster {
+        node_id: system.node_id(),
+        shards_handed_off,
+    })
+}
@@ -121,7 +121,7 @@ impl Handler<GetClusterStats> for ShardingApi {
             let shard_host = shard_host;
             let coordinator = shard_host.send(GetCoordinator).await;
 
-            if let Ok(coordinator) = coordinator {
+            if let Ok(Some(coordinator)) = coordinator {
                 let sharding_stats = coordinator.send(GetShardingStats).await.unwrap();
 
                 let mut shards: Vec<ActorRef<Shard>> = vec![];
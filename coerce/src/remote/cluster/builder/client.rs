@@ -1,42 +1,83 @@
-use crate::remote::cluster::client::RemoteClusterClient;
+use crate::actor::IntoActor;
+use crate::remote::cluster::client::{ClusterMembership, RefreshMembers, RemoteClusterClient};
 use crate::remote::system::RemoteActorSystem;
+use std::time::Duration;
 
 pub struct ClusterClientBuilder {
     system: RemoteActorSystem,
-    seed_addr: Option<String>,
+    seed_addrs: Vec<String>,
+    refresh_interval: Duration,
 }
 
+#[derive(Debug, Eq, PartialEq)]
+pub enum ClusterClientErr {
+    NoSeedAddrs,
+}
+
+const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
 impl ClusterClientBuilder {
     pub fn new(system: RemoteActorSystem) -> ClusterClientBuilder {
         ClusterClientBuilder {
             system,
-            seed_addr: None,
+            seed_addrs: vec![],
+            refresh_interval: DEFAULT_REFRESH_INTERVAL,
         }
     }
 
+    /// Adds a contact point, contact points are tried in the order they were added until one of
+    /// them responds.
     pub fn with_seed_addr<T: ToString>(mut self, seed_addr: T) -> Self {
-        self.seed_addr = Some(seed_addr.to_string());
+        self.seed_addrs.push(seed_addr.to_string());
+
+        self
+    }
+
+    pub fn with_seed_addrs<T: ToString, I: IntoIterator<Item = T>>(
+        mut self,
+        seed_addrs: I,
+    ) -> Self {
+        self.seed_addrs
+            .extend(seed_addrs.into_iter().map(|addr| addr.to_string()));
+
+        self
+    }
+
+    /// How often the client refreshes its view of the cluster members.
+    pub fn with_refresh_interval(mut self, refresh_interval: Duration) -> Self {
+        self.refresh_interval = refresh_interval;
 
         self
     }
 
-    pub async fn start(self) -> RemoteClusterClient {
-        // let seed_addr = self.seed_addr.expect("no seed addr");
-        // let system = self.system.clone();
-        // let client = RemoteClient::connect(seed_addr.clone(), None, system, None, Client)
-        //     .await
-        //     .expect("failed to connect to seed server");
-        //
-        // self.system
-        //     .register_node(RemoteNode::new(
-        //         client.node_id,
-        //         seed_addr,
-        //         client.node_tag.clone(),
-        //         Some(Utc::now()),
-        //     ))
-        //     .await;
-
-        // self.system.register_client(client.node_id, client).await;
-        RemoteClusterClient::new(self.system)
+    pub async fn start(self) -> Result<RemoteClusterClient, ClusterClientErr> {
+        if self.seed_addrs.is_empty() {
+            return Err(ClusterClientErr::NoSeedAddrs);
+        }
+
+        let membership = ClusterMembership::new(
+            self.system.clone(),
+            self.seed_addrs.clone(),
+            self.refresh_interval,
+        )
+        .into_actor(
+            Some(format!("ClusterMembership-{}", self.system.node_id())),
+            self.system.actor_system(),
+        )
+        .await
+        .expect("create ClusterMembership actor");
+
+        let discovered = membership.send(RefreshMembers).await.unwrap_or(false);
+        if discovered {
+            info!("cluster client started (seed_addrs={:?})", &self.seed_addrs);
+        } else {
+            warn!(
+                "unable to reach any of the seed addrs ({:?}), retrying every {}ms",
+                &self.seed_addrs,
+                self.refresh_interval.as_millis()
+            );
+        }
+
+        Ok(RemoteClusterClient::new(self.system, membership))
     }
 }
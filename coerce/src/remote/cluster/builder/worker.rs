@@ -1,12 +1,15 @@
+use crate::remote::cluster::discovery::dns::DnsClusterSeed;
 use crate::remote::cluster::discovery::{Discover, Seed};
 use crate::remote::cluster::node::RemoteNode;
+use crate::remote::cluster::swim::Swim;
 use crate::remote::net::server::{RemoteServer, RemoteServerConfig};
+use crate::remote::net::tls::TlsConfigRef;
+use crate::remote::raft::Raft;
 use crate::remote::system::RemoteActorSystem;
 use std::env;
 use std::net::SocketAddr;
 use std::str::FromStr;
 use std::time::Duration;
-use tokio::net::lookup_host;
 use tokio::sync::oneshot;
 use tokio::time::sleep;
 
@@ -14,6 +17,8 @@ pub struct ClusterWorkerBuilder {
     server_listen_addr: String,
     server_external_addr: Option<String>,
     seed_addr: Option<String>,
+    dns_seed: Option<DnsClusterSeed>,
+    tls: Option<TlsConfigRef>,
     system: RemoteActorSystem,
 }
 
@@ -22,12 +27,15 @@ impl ClusterWorkerBuilder {
         let server_listen_addr = "0.0.0.0:30101".to_owned();
         let seed_addr = None;
         let server_external_addr = None;
+        let tls = system.config().tls().cloned();
 
         ClusterWorkerBuilder {
             server_listen_addr,
             server_external_addr,
             system,
             seed_addr,
+            dns_seed: None,
+            tls,
         }
     }
 
@@ -37,6 +45,13 @@ impl ClusterWorkerBuilder {
         self
     }
 
+    /// Discovers seed nodes from DNS, both when the worker starts and periodically afterwards.
+    pub fn with_dns_seed(mut self, dns_seed: DnsClusterSeed) -> Self {
+        self.dns_seed = Some(dns_seed);
+
+        self
+    }
+
     pub fn external_addr<T: ToString>(mut self, server_external_addr: T) -> Self {
         self.server_external_addr = Some(server_external_addr.to_string());
         self
@@ -48,6 +63,14 @@ impl ClusterWorkerBuilder {
         self
     }
 
+    /// Overrides the TLS configuration used by the server, which defaults to the TLS configuration
+    /// of the `RemoteActorSystem`.
+    pub fn with_tls(mut self, tls: TlsConfigRef) -> Self {
+        self.tls = Some(tls);
+
+        self
+    }
+
     pub async fn start(mut self) -> RemoteServer {
         // let span = tracing::trace_span!(
         //     "ClusterWorkerBuilder::start",
@@ -59,14 +82,15 @@ impl ClusterWorkerBuilder {
 
         let started_at = *self.system.started_at();
         let cluster_node_addr = self.cluster_node_addr();
-        self.system
-            .register_node(RemoteNode::new(
-                self.system.node_id(),
-                cluster_node_addr.clone(),
-                self.system.node_tag().to_string(),
-                Some(started_at),
-            ))
-            .await;
+        let node = RemoteNode::new(
+            self.system.node_id(),
+            cluster_node_addr.clone(),
+            self.system.node_tag().to_string(),
+            Some(started_at),
+        )
+        .with_roles(self.system.node_roles().to_vec());
+
+        self.system.register_node(node.clone()).await;
 
         let system = self.system.clone();
         let mut server = RemoteServer::new();
@@ -86,17 +110,36 @@ impl ClusterWorkerBuilder {
             .map_or(false, |s| s == "1" || s.to_lowercase() == "true");
 
         let config =
-            RemoteServerConfig::new(listen_addr, cluster_node_addr, override_incoming_node_addr);
+            RemoteServerConfig::new(listen_addr, cluster_node_addr, override_incoming_node_addr)
+                .with_tls(self.tls.clone())
+                .with_tls_handshake_timeout(system.config().client_config().handshake_timeout);
 
         server
             .start(config, system)
             .await
             .expect("failed to start server");
 
+        if let Some(swim_config) = self.system.config().swim_config() {
+            Swim::start(self.system.clone(), node, swim_config.clone()).await;
+        }
+
+        if let Some(raft_config) = self.system.config().raft_config() {
+            Raft::start(self.system.clone(), raft_config.clone()).await;
+        }
+
         if discover_peers {
             self.discover_peers().await;
         }
 
+        if let Some(dns_seed) = self.dns_seed.take() {
+            match dns_seed.discover(&self.system).await {
+                Ok(discovered) => info!("discovered {} nodes from DNS seed", discovered),
+                Err(e) => warn!("failed to discover nodes from DNS seed, err={}", e),
+            }
+
+            dns_seed.start_refresh(self.system.clone());
+        }
+
         server
     }
 
@@ -117,7 +160,7 @@ impl ClusterWorkerBuilder {
                     return;
                 }
 
-                if seed_addr_resolves(&seed_addr).await {
+                if self.system.config().transport().resolves(&seed_addr).await {
                     break;
                 }
 
@@ -142,8 +185,3 @@ impl ClusterWorkerBuilder {
         }
     }
 }
-
-async fn seed_addr_resolves(seed_addr: &str) -> bool {
-    let resolves = lookup_host(seed_addr).await;
-    resolves.is_ok()
-}
@@ -1,14 +1,224 @@
-use crate::remote::system::RemoteActorSystem;
+use crate::actor::context::ActorContext;
+use crate::actor::message::{Handler, Message};
+use crate::actor::scheduler::timer::{Timer, TimerTick};
+use crate::actor::{Actor, ActorId, ActorRef, LocalActorRef};
+use crate::remote::actor::message::{NewClient, NodeTerminated};
+use crate::remote::cluster::node::{NodeStatus, RemoteNode, RemoteNodeState};
+use crate::remote::net::client::{ClientType, RemoteClientRef};
+use crate::remote::stream::pubsub::PubSub;
+use crate::remote::stream::system::{ClusterEvent, SystemEvent, SystemTopic};
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
 
+/// A connection to a cluster from a system that isn't a member of it. The client doesn't accept
+/// connections, host shards or take part in leader election, it only routes requests to the
+/// members of the cluster.
 #[derive(Clone)]
 pub struct RemoteClusterClient {
     system: RemoteActorSystem,
+    membership: LocalActorRef<ClusterMembership>,
 }
 
 impl RemoteClusterClient {
-    pub fn new(system: RemoteActorSystem) -> RemoteClusterClient {
-        RemoteClusterClient { system }
+    pub fn new(
+        system: RemoteActorSystem,
+        membership: LocalActorRef<ClusterMembership>,
+    ) -> RemoteClusterClient {
+        RemoteClusterClient { system, membership }
     }
+
+    pub fn system(&self) -> &RemoteActorSystem {
+        &self.system
+    }
+
+    /// The members of the cluster which requests can currently be routed to.
+    pub async fn members(&self) -> Vec<RemoteNodeState> {
+        self.system
+            .get_nodes()
+            .await
+            .into_iter()
+            .filter(|n| n.status != NodeStatus::Terminated)
+            .collect()
+    }
+
+    /// Refreshes the client's view of the cluster, returns `false` if none of the contact
+    /// points could be reached.
+    pub async fn refresh(&self) -> bool {
+        self.membership.send(RefreshMembers).await.unwrap_or(false)
+    }
+
+    pub async fn actor_ref<A: Actor>(&self, actor_id: ActorId) -> Option<ActorRef<A>> {
+        self.system.actor_ref(actor_id).await
+    }
+}
+
+/// Keeps the client's node registry in line with the members of the cluster, as seen by the first
+/// contact point that responds.
+pub struct ClusterMembership {
+    system: RemoteActorSystem,
+    seed_addrs: Vec<String>,
+    refresh_interval: Duration,
+    members: HashMap<NodeId, RemoteNode>,
+    refresh_timer: Option<Timer>,
 }
 
-impl RemoteClusterClient {}
+#[derive(Clone)]
+pub struct RefreshMembers;
+
+impl Message for RefreshMembers {
+    type Result = bool;
+}
+
+impl TimerTick for RefreshMembers {}
+
+impl ClusterMembership {
+    pub fn new(
+        system: RemoteActorSystem,
+        seed_addrs: Vec<String>,
+        refresh_interval: Duration,
+    ) -> ClusterMembership {
+        ClusterMembership {
+            system,
+            seed_addrs,
+            refresh_interval,
+            members: HashMap::new(),
+            refresh_timer: None,
+        }
+    }
+
+    fn contact_points(&self) -> Vec<String> {
+        let mut contact_points = self.seed_addrs.clone();
+        for member in self.members.values() {
+            if !contact_points.contains(&member.addr) {
+                contact_points.push(member.addr.clone());
+            }
+        }
+
+        contact_points
+    }
+
+    async fn update_members(&mut self, nodes: Vec<RemoteNode>, remote: &RemoteActorSystem) {
+        let nodes: HashMap<NodeId, RemoteNode> = nodes
+            .into_iter()
+            .filter(|n| n.id != remote.node_id())
+            .map(|n| (n.id, n))
+            .collect();
+
+        for (node_id, node) in &nodes {
+            if self.members.contains_key(node_id) {
+                continue;
+            }
+
+            info!(
+                "cluster member added (id={}, addr={}, tag={})",
+                node_id, &node.addr, &node.tag
+            );
+
+            remote.register_node(node.clone()).await;
+            let _ = connect(node.addr.clone(), remote).await;
+
+            PubSub::publish_locally(
+                SystemTopic,
+                SystemEvent::Cluster(ClusterEvent::NodeAdded(Arc::new(node.clone()))),
+                remote,
+            )
+            .await;
+        }
+
+        for (node_id, node) in &self.members {
+            if nodes.contains_key(node_id) {
+                continue;
+            }
+
+            info!(
+                "cluster member removed (id={}, addr={}, tag={})",
+                node_id, &node.addr, &node.tag
+            );
+
+            let _ = remote.heartbeat().send(NodeTerminated(*node_id)).await;
+
+            PubSub::publish_locally(
+                SystemTopic,
+                SystemEvent::Cluster(ClusterEvent::NodeRemoved(Arc::new(node.clone()))),
+                remote,
+            )
+            .await;
+        }
+
+        self.members = nodes;
+    }
+}
+
+#[async_trait]
+impl Actor for ClusterMembership {
+    async fn started(&mut self, ctx: &mut ActorContext) {
+        self.refresh_timer = Some(Timer::start(
+            self.actor_ref(ctx),
+            self.refresh_interval,
+            RefreshMembers,
+        ));
+    }
+
+    async fn stopped(&mut self, _ctx: &mut ActorContext) {
+        if let Some(refresh_timer) = self.refresh_timer.take() {
+            refresh_timer.stop();
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<RefreshMembers> for ClusterMembership {
+    async fn handle(&mut self, _message: RefreshMembers, _ctx: &mut ActorContext) -> bool {
+        let remote = self.system.clone();
+
+        for addr in self.contact_points() {
+            match get_members(addr.clone(), &remote).await {
+                Some(nodes) => {
+                    trace!(
+                        "received {} cluster members from contact point (addr={})",
+                        nodes.len(),
+                        &addr
+                    );
+
+                    self.update_members(nodes, &remote).await;
+                    return true;
+                }
+                None => {
+                    debug!(
+                        "unable to get cluster members from contact point (addr={}), trying next",
+                        &addr
+                    );
+                }
+            }
+        }
+
+        warn!("unable to refresh cluster members, no contact points could be reached");
+        false
+    }
+}
+
+async fn connect(addr: String, remote: &RemoteActorSystem) -> Option<RemoteClientRef> {
+    remote
+        .client_registry()
+        .send(NewClient {
+            addr,
+            client_type: ClientType::Client,
+            system: remote.clone(),
+        })
+        .await
+        .ok()
+        .flatten()
+        .map(|client| RemoteClientRef::new(client, remote.config().client_config().clone()))
+}
+
+async fn get_members(addr: String, remote: &RemoteActorSystem) -> Option<Vec<RemoteNode>> {
+    let client = connect(addr, remote).await?;
+    if !matches!(client.identify().await, Ok(Some(_))) {
+        return None;
+    }
+
+    client.handshake_nodes(Uuid::new_v4(), vec![]).await.ok()
+}
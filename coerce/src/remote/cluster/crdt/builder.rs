@@ -0,0 +1,54 @@
+use crate::actor::IntoActor;
+use crate::remote::cluster::crdt::replicator::{replicator_actor_id, Replicator};
+use crate::remote::cluster::crdt::{DataCore, DistributedData};
+use crate::remote::system::RemoteActorSystem;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub struct DistributedDataBuilder {
+    system: Option<RemoteActorSystem>,
+    gossip_interval: Duration,
+    timeout: Duration,
+}
+
+impl DistributedData {
+    pub fn builder(system: RemoteActorSystem) -> DistributedDataBuilder {
+        DistributedDataBuilder {
+            system: Some(system),
+            gossip_interval: Duration::from_secs(1),
+            timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+impl DistributedDataBuilder {
+    /// How often each node sends its full state to a random peer.
+    pub fn with_gossip_interval(&mut self, gossip_interval: Duration) -> &mut Self {
+        self.gossip_interval = gossip_interval;
+        self
+    }
+
+    /// How long `Majority` and `All` reads and writes wait for other replicas.
+    pub fn with_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub async fn build(&mut self) -> DistributedData {
+        let system = self.system.take().unwrap();
+        let node_id = system.node_id();
+
+        let replicator = Replicator::new(node_id, self.gossip_interval)
+            .into_actor(Some(replicator_actor_id(node_id)), system.actor_system())
+            .await
+            .expect("create Replicator");
+
+        DistributedData {
+            core: Arc::new(DataCore {
+                replicator,
+                system,
+                timeout: self.timeout,
+            }),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::remote::cluster::crdt::Crdt;
+use crate::remote::system::NodeId;
+use std::collections::BTreeMap;
+
+/// A grow-only counter, each node increments its own count and the value is the sum of them.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GCounter {
+    counts: BTreeMap<NodeId, u64>,
+}
+
+impl GCounter {
+    pub fn new() -> GCounter {
+        GCounter::default()
+    }
+
+    pub fn increment(&mut self, node_id: NodeId, n: u64) {
+        *self.counts.entry(node_id).or_default() += n;
+    }
+
+    pub fn value(&self) -> u64 {
+        self.counts.values().sum()
+    }
+
+    pub fn node_value(&self, node_id: NodeId) -> u64 {
+        self.counts.get(&node_id).copied().unwrap_or_default()
+    }
+}
+
+impl Crdt for GCounter {
+    fn merge(&mut self, other: &Self) {
+        for (node_id, count) in &other.counts {
+            let current = self.counts.entry(*node_id).or_default();
+            *current = (*current).max(*count);
+        }
+    }
+}
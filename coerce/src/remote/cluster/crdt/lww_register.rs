@@ -0,0 +1,57 @@
+use crate::remote::cluster::crdt::Crdt;
+use crate::remote::system::NodeId;
+use chrono::Utc;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// A last-write-wins register. Concurrent writes are ordered by their timestamp, then by the id of
+/// the node that wrote them.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(bound = "T: Serialize + DeserializeOwned")]
+pub struct LWWRegister<T> {
+    value: Option<T>,
+    timestamp: i64,
+    node_id: NodeId,
+}
+
+impl<T> Default for LWWRegister<T> {
+    fn default() -> Self {
+        LWWRegister {
+            value: None,
+            timestamp: 0,
+            node_id: 0,
+        }
+    }
+}
+
+impl<T> LWWRegister<T> {
+    pub fn new() -> LWWRegister<T> {
+        LWWRegister::default()
+    }
+
+    /// Writes are always ordered after the current value, even if this node's clock is behind.
+    pub fn set(&mut self, node_id: NodeId, value: T) {
+        self.timestamp = Utc::now().timestamp_millis().max(self.timestamp + 1);
+        self.node_id = node_id;
+        self.value = Some(value);
+    }
+
+    pub fn get(&self) -> Option<&T> {
+        self.value.as_ref()
+    }
+
+    pub fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+}
+
+impl<T> Crdt for LWWRegister<T>
+where
+    T: 'static + Clone + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+{
+    fn merge(&mut self, other: &Self) {
+        if (other.timestamp, other.node_id) > (self.timestamp, self.node_id) {
+            *self = other.clone();
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::actor::message::Message;
+use crate::actor::scheduler::timer::TimerTick;
+use crate::remote::cluster::crdt::proto::crdt as proto;
+use crate::remote::cluster::crdt::{Crdt, DataErr};
+use coerce_macros::ProtoMessage;
+
+/// The replicated state of a single key. States received for a key that haven't been read or
+/// written on this node yet are kept as they are, and merged once the key's type is known.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DataEnvelope {
+    pub key: String,
+    pub type_name: String,
+    pub states: Vec<Vec<u8>>,
+}
+
+impl DataEnvelope {
+    pub fn new<T: Crdt>(key: String, value: &T) -> Result<DataEnvelope, DataErr> {
+        Ok(DataEnvelope {
+            key,
+            type_name: std::any::type_name::<T>().to_string(),
+            states: vec![serde_json::to_vec(value).map_err(|_| DataErr::Serialisation)?],
+        })
+    }
+
+    pub fn value<T: Crdt>(&self) -> Result<T, DataErr> {
+        if self.type_name != std::any::type_name::<T>() {
+            return Err(DataErr::TypeMismatch);
+        }
+
+        let mut value = T::default();
+        for state in &self.states {
+            let state: T = serde_json::from_slice(state).map_err(|_| DataErr::Deserialisation)?;
+            value.merge(&state);
+        }
+
+        Ok(value)
+    }
+}
+
+/// Merges replicated state into a replicator, sent for writes and for gossip.
+#[derive(Clone, ProtoMessage)]
+#[proto(proto::MergeData)]
+pub struct MergeData(pub Vec<DataEnvelope>);
+
+/// Reads the state of a key from a replicator.
+#[derive(Clone, ProtoMessage)]
+#[proto(proto::ReadData)]
+#[result("Option<DataEnvelope>", proto::ReadDataResult)]
+pub struct ReadData(pub String);
+
+pub(crate) struct Update<T: Crdt> {
+    pub key: String,
+    pub modify: Box<dyn FnOnce(&mut T) + Send + Sync>,
+}
+
+pub(crate) struct Get<T: Crdt> {
+    pub key: String,
+    pub _t: std::marker::PhantomData<T>,
+}
+
+#[derive(Clone)]
+pub(crate) struct GossipTick;
+
+impl<T: Crdt> Message for Update<T> {
+    type Result = Result<(T, DataEnvelope), DataErr>;
+}
+
+impl<T: Crdt> Message for Get<T> {
+    type Result = Result<Option<T>, DataErr>;
+}
+
+impl Message for GossipTick {
+    type Result = ();
+}
+
+impl TimerTick for GossipTick {}
+
+impl From<DataEnvelope> for proto::DataEnvelope {
+    fn from(envelope: DataEnvelope) -> Self {
+        proto::DataEnvelope {
+            key: envelope.key,
+            type_name: envelope.type_name,
+            states: envelope.states,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::DataEnvelope> for DataEnvelope {
+    fn from(envelope: proto::DataEnvelope) -> Self {
+        DataEnvelope {
+            key: envelope.key,
+            type_name: envelope.type_name,
+            states: envelope.states,
+        }
+    }
+}
+
+impl From<&MergeData> for proto::MergeData {
+    fn from(msg: &MergeData) -> Self {
+        proto::MergeData {
+            entries: msg.0.iter().cloned().map(|e| e.into()).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::MergeData> for MergeData {
+    fn from(msg: proto::MergeData) -> Self {
+        MergeData(msg.entries.into_iter().map(|e| e.into()).collect())
+    }
+}
+
+impl From<&ReadData> for proto::ReadData {
+    fn from(msg: &ReadData) -> Self {
+        proto::ReadData {
+            key: msg.0.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::ReadData> for ReadData {
+    fn from(msg: proto::ReadData) -> Self {
+        ReadData(msg.key)
+    }
+}
+
+impl From<proto::ReadDataResult> for Option<DataEnvelope> {
+    fn from(res: proto::ReadDataResult) -> Self {
+        res.data.into_option().map(|e| e.into())
+    }
+}
+
+impl From<Option<DataEnvelope>> for proto::ReadDataResult {
+    fn from(data: Option<DataEnvelope>) -> Self {
+        proto::ReadDataResult {
+            data: data.map(|e| e.into()).into(),
+            ..Default::default()
+        }
+    }
+}
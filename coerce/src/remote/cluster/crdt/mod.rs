@@ -0,0 +1,271 @@
+use crate::actor::message::{Handler, Message};
+use crate::actor::{ActorRef, LocalActorRef};
+use crate::remote::cluster::crdt::message::{DataEnvelope, Get, MergeData, ReadData, Update};
+use crate::remote::cluster::crdt::replicator::{replicator_actor_id, Replicator};
+use crate::remote::cluster::node::NodeStatus;
+use crate::remote::net::StreamData;
+use crate::remote::stream::pubsub::Topic;
+use crate::remote::system::builder::RemoteSystemConfigBuilder;
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use crate::remote::RemoteActorRef;
+use bytes::Bytes;
+use protobuf::Message as ProtoMessage;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+pub mod builder;
+pub mod gcounter;
+pub mod lww_register;
+pub mod message;
+pub mod ormap;
+pub mod orset;
+pub mod pncounter;
+pub mod proto;
+pub mod replicator;
+
+/// A conflict-free replicated data type, replicas can be merged in any order and any number of
+/// times and still converge on the same value.
+pub trait Crdt:
+    'static + Clone + Default + PartialEq + Send + Sync + Serialize + DeserializeOwned
+{
+    fn merge(&mut self, other: &Self);
+}
+
+/// How many replicas must acknowledge a write, or respond to a read, before it completes.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Consistency {
+    Local,
+    Majority,
+    All,
+}
+
+impl Consistency {
+    /// The number of replicas required, including the local replica, out of `replicas`.
+    pub fn required_replicas(&self, replicas: usize) -> usize {
+        match self {
+            Consistency::Local => 1,
+            Consistency::Majority => replicas / 2 + 1,
+            Consistency::All => replicas,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum DataErr {
+    Timeout,
+    TypeMismatch,
+    Serialisation,
+    Deserialisation,
+    Unavailable,
+}
+
+/// Changes to a key, published on the node where the change was made or merged.
+pub struct DataTopic(pub String);
+
+pub struct DataChanged(pub DataEnvelope);
+
+impl DataChanged {
+    pub fn key(&self) -> &str {
+        &self.0.key
+    }
+
+    pub fn value<T: Crdt>(&self) -> Result<T, DataErr> {
+        self.0.value()
+    }
+}
+
+impl Topic for DataTopic {
+    type Message = DataChanged;
+
+    fn topic_name() -> &'static str {
+        "coerce-crdt"
+    }
+
+    fn key(&self) -> String {
+        self.0.clone()
+    }
+}
+
+impl StreamData for DataChanged {
+    fn read_from_bytes(data: Bytes) -> Option<Self> {
+        proto::crdt::DataEnvelope::parse_from_bytes(&data)
+            .ok()
+            .map(|e| DataChanged(e.into()))
+    }
+
+    fn write_to_bytes(&self) -> Option<Vec<u8>> {
+        proto::crdt::DataEnvelope::from(self.0.clone())
+            .write_to_bytes()
+            .ok()
+    }
+}
+
+/// Eventually consistent, replicated key-value data. Every node keeps a replica of every key,
+/// updates are pushed to the other replicas and any missed updates are caught up by gossip.
+#[derive(Clone)]
+pub struct DistributedData {
+    core: Arc<DataCore>,
+}
+
+struct DataCore {
+    replicator: LocalActorRef<Replicator>,
+    system: RemoteActorSystem,
+    timeout: Duration,
+}
+
+impl DistributedData {
+    pub fn node_id(&self) -> NodeId {
+        self.core.system.node_id()
+    }
+
+    pub fn system(&self) -> &RemoteActorSystem {
+        &self.core.system
+    }
+
+    pub fn replicator(&self) -> &LocalActorRef<Replicator> {
+        &self.core.replicator
+    }
+
+    /// Applies `modify` to the local replica of `key`, and waits until enough other replicas
+    /// have merged the result to satisfy `consistency`. The local replica is updated even if
+    /// the write times out, and will still reach the other replicas by gossip.
+    pub async fn update<T: Crdt, F>(
+        &self,
+        key: impl ToString,
+        consistency: Consistency,
+        modify: F,
+    ) -> Result<T, DataErr>
+    where
+        F: 'static + FnOnce(&mut T) + Send + Sync,
+    {
+        let (value, envelope) = self
+            .core
+            .replicator
+            .send(Update {
+                key: key.to_string(),
+                modify: Box::new(modify),
+            })
+            .await
+            .map_err(|_| DataErr::Unavailable)??;
+
+        let peers = self.peers().await;
+        let required = consistency.required_replicas(peers.len() + 1);
+        let acks = self
+            .replicate(peers, MergeData(vec![envelope]), required - 1)
+            .await;
+
+        if acks.len() + 1 < required {
+            Err(DataErr::Timeout)
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// Reads `key`, merging the local replica with as many other replicas as `consistency`
+    /// requires. Returns `None` if none of the replicas have a value for `key`.
+    pub async fn get<T: Crdt>(
+        &self,
+        key: impl ToString,
+        consistency: Consistency,
+    ) -> Result<Option<T>, DataErr> {
+        let key = key.to_string();
+        if consistency != Consistency::Local {
+            let peers = self.peers().await;
+            let required = consistency.required_replicas(peers.len() + 1);
+            let responses = self
+                .replicate(peers, ReadData(key.clone()), required - 1)
+                .await;
+
+            if responses.len() + 1 < required {
+                return Err(DataErr::Timeout);
+            }
+
+            let states: Vec<DataEnvelope> = responses.into_iter().flatten().collect();
+            if !states.is_empty() {
+                self.core
+                    .replicator
+                    .send(MergeData(states))
+                    .await
+                    .map_err(|_| DataErr::Unavailable)?;
+            }
+        }
+
+        self.core
+            .replicator
+            .send(Get {
+                key,
+                _t: PhantomData,
+            })
+            .await
+            .map_err(|_| DataErr::Unavailable)?
+    }
+
+    async fn peers(&self) -> Vec<NodeId> {
+        let system = &self.core.system;
+        let node_id = system.node_id();
+
+        system
+            .get_nodes()
+            .await
+            .into_iter()
+            .filter(|n| {
+                n.id != node_id
+                    && matches!(n.status, NodeStatus::Joining | NodeStatus::Healthy)
+                    && !system.is_node_terminated(n.id)
+            })
+            .map(|n| n.id)
+            .collect()
+    }
+
+    /// Sends `message` to the replicator on every peer, resolving once `required` of them have
+    /// responded or the timeout elapses. Remaining peers still receive the message.
+    async fn replicate<M>(&self, peers: Vec<NodeId>, message: M, required: usize) -> Vec<M::Result>
+    where
+        M: Message + Clone,
+        Replicator: Handler<M>,
+    {
+        let (tx, mut rx) = mpsc::channel(peers.len().max(1));
+        for peer in peers {
+            let replicator: ActorRef<Replicator> =
+                RemoteActorRef::new(replicator_actor_id(peer), peer, self.core.system.clone())
+                    .into();
+
+            let message = message.clone();
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                if let Ok(result) = replicator.send(message).await {
+                    let _ = tx.send(result).await;
+                }
+            });
+        }
+
+        drop(tx);
+
+        let mut results = vec![];
+        if required == 0 {
+            return results;
+        }
+
+        let _ = tokio::time::timeout(self.core.timeout, async {
+            while let Some(result) = rx.recv().await {
+                results.push(result);
+                if results.len() >= required {
+                    break;
+                }
+            }
+        })
+        .await;
+
+        results
+    }
+}
+
+pub fn crdt(builder: &mut RemoteSystemConfigBuilder) -> &mut RemoteSystemConfigBuilder {
+    builder
+        .with_control_handler::<Replicator, MergeData>("Replicator.MergeData")
+        .with_control_handler::<Replicator, ReadData>("Replicator.ReadData")
+}
@@ -0,0 +1,92 @@
+use crate::remote::cluster::crdt::orset::{entries, ORSet};
+use crate::remote::cluster::crdt::Crdt;
+use crate::remote::system::NodeId;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A map of CRDT values. Keys are tracked with an `ORSet`, so an update wins over a concurrent
+/// remove of the same key, and values under the same key are merged.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(bound = "K: Ord + Serialize + DeserializeOwned, V: Serialize + DeserializeOwned")]
+pub struct ORMap<K, V> {
+    keys: ORSet<K>,
+    #[serde(with = "entries")]
+    values: BTreeMap<K, V>,
+}
+
+impl<K, V> Default for ORMap<K, V> {
+    fn default() -> Self {
+        ORMap {
+            keys: ORSet::default(),
+            values: BTreeMap::new(),
+        }
+    }
+}
+
+impl<K: Ord + Clone, V: Crdt> ORMap<K, V> {
+    pub fn new() -> ORMap<K, V> {
+        ORMap::default()
+    }
+
+    /// Updates the value under `key`, starting from the default value if it isn't present.
+    pub fn update<F: FnOnce(&mut V)>(&mut self, node_id: NodeId, key: K, f: F) {
+        self.keys.add(node_id, key.clone());
+        f(self.values.entry(key).or_default());
+    }
+
+    pub fn remove(&mut self, key: &K) -> bool {
+        self.values.remove(key);
+        self.keys.remove(key)
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.values.get(key)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.keys.contains(key)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.values.keys()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.values.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl<K, V> Crdt for ORMap<K, V>
+where
+    K: 'static + Ord + Clone + Send + Sync + Serialize + DeserializeOwned,
+    V: Crdt,
+{
+    fn merge(&mut self, other: &Self) {
+        self.keys.merge(&other.keys);
+
+        let keys: BTreeSet<K> = self.keys.elements().cloned().collect();
+        for (key, value) in &other.values {
+            if !keys.contains(key) {
+                continue;
+            }
+
+            match self.values.get_mut(key) {
+                Some(current) => current.merge(value),
+                None => {
+                    self.values.insert(key.clone(), value.clone());
+                }
+            }
+        }
+
+        self.values.retain(|key, _| keys.contains(key));
+    }
+}
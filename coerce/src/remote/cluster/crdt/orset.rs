@@ -0,0 +1,145 @@
+use crate::remote::cluster::crdt::Crdt;
+use crate::remote::system::NodeId;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Identifies a single add, by the node that made it and that node's counter at the time.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub struct Dot {
+    pub node_id: NodeId,
+    pub counter: u64,
+}
+
+/// An observed-remove set. An element is present if any add of it hasn't been observed by a
+/// remove, so an add wins over a concurrent remove of the same element.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(bound = "T: Ord + Serialize + DeserializeOwned")]
+pub struct ORSet<T> {
+    #[serde(with = "entries")]
+    elements: BTreeMap<T, BTreeSet<Dot>>,
+    clock: BTreeMap<NodeId, u64>,
+}
+
+impl<T> Default for ORSet<T> {
+    fn default() -> Self {
+        ORSet {
+            elements: BTreeMap::new(),
+            clock: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T: Ord + Clone> ORSet<T> {
+    pub fn new() -> ORSet<T> {
+        ORSet::default()
+    }
+
+    pub fn add(&mut self, node_id: NodeId, element: T) {
+        let counter = self.clock.entry(node_id).or_default();
+        *counter += 1;
+
+        let dot = Dot {
+            node_id,
+            counter: *counter,
+        };
+
+        // The new add supersedes every add of the element this node has observed
+        self.elements.insert(element, BTreeSet::from([dot]));
+    }
+
+    pub fn remove(&mut self, element: &T) -> bool {
+        self.elements.remove(element).is_some()
+    }
+
+    pub fn clear(&mut self) {
+        self.elements.clear();
+    }
+
+    pub fn contains(&self, element: &T) -> bool {
+        self.elements.contains_key(element)
+    }
+
+    pub fn elements(&self) -> impl Iterator<Item = &T> {
+        self.elements.keys()
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    fn observed(&self, dot: &Dot) -> bool {
+        self.clock
+            .get(&dot.node_id)
+            .is_some_and(|counter| *counter >= dot.counter)
+    }
+}
+
+impl<T> Crdt for ORSet<T>
+where
+    T: 'static + Ord + Clone + Send + Sync + Serialize + DeserializeOwned,
+{
+    fn merge(&mut self, other: &Self) {
+        let empty = BTreeSet::new();
+        let keys: BTreeSet<T> = self
+            .elements
+            .keys()
+            .chain(other.elements.keys())
+            .cloned()
+            .collect();
+
+        for element in keys {
+            let dots = self.elements.get(&element).unwrap_or(&empty);
+            let other_dots = other.elements.get(&element).unwrap_or(&empty);
+
+            // Adds seen by both sides survive, as do adds one side hasn't observed yet. Adds
+            // that one side has observed but no longer holds have been removed.
+            let merged: BTreeSet<Dot> = dots
+                .intersection(other_dots)
+                .chain(dots.iter().filter(|d| !other.observed(d)))
+                .chain(other_dots.iter().filter(|d| !self.observed(d)))
+                .copied()
+                .collect();
+
+            if merged.is_empty() {
+                self.elements.remove(&element);
+            } else {
+                self.elements.insert(element, merged);
+            }
+        }
+
+        for (node_id, counter) in &other.clock {
+            let current = self.clock.entry(*node_id).or_default();
+            *current = (*current).max(*counter);
+        }
+    }
+}
+
+/// Maps are written as a list of entries, so keys don't have to serialise as strings.
+pub(crate) mod entries {
+    use serde::de::DeserializeOwned;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::BTreeMap;
+
+    pub fn serialize<K, V, S>(map: &BTreeMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: Serialize,
+        V: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(map.iter())
+    }
+
+    pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<BTreeMap<K, V>, D::Error>
+    where
+        K: Ord + DeserializeOwned,
+        V: DeserializeOwned,
+        D: Deserializer<'de>,
+    {
+        Vec::<(K, V)>::deserialize(deserializer).map(|entries| entries.into_iter().collect())
+    }
+}
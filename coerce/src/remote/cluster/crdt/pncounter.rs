@@ -0,0 +1,35 @@
+use crate::remote::cluster::crdt::gcounter::GCounter;
+use crate::remote::cluster::crdt::Crdt;
+use crate::remote::system::NodeId;
+
+/// A counter that can be incremented and decremented, made up of two `GCounter`s.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PNCounter {
+    increments: GCounter,
+    decrements: GCounter,
+}
+
+impl PNCounter {
+    pub fn new() -> PNCounter {
+        PNCounter::default()
+    }
+
+    pub fn increment(&mut self, node_id: NodeId, n: u64) {
+        self.increments.increment(node_id, n);
+    }
+
+    pub fn decrement(&mut self, node_id: NodeId, n: u64) {
+        self.decrements.increment(node_id, n);
+    }
+
+    pub fn value(&self) -> i64 {
+        self.increments.value() as i64 - self.decrements.value() as i64
+    }
+}
+
+impl Crdt for PNCounter {
+    fn merge(&mut self, other: &Self) {
+        self.increments.merge(&other.increments);
+        self.decrements.merge(&other.decrements);
+    }
+}
@@ -0,0 +1,282 @@
+use crate::actor::context::ActorContext;
+use crate::actor::message::Handler;
+use crate::actor::scheduler::timer::Timer;
+use crate::actor::{Actor, ActorId, ActorRef, IntoActorId};
+use crate::remote::cluster::crdt::message::{
+    DataEnvelope, Get, GossipTick, MergeData, ReadData, Update,
+};
+use crate::remote::cluster::crdt::{Crdt, DataChanged, DataErr, DataTopic};
+use crate::remote::cluster::node::NodeStatus;
+use crate::remote::stream::pubsub::PubSub;
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use crate::remote::RemoteActorRef;
+use rand::seq::SliceRandom;
+use std::any::Any;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Holds this node's replica of every key. Writes and reads that need other replicas are
+/// coordinated by `DistributedData`, so a replicator never waits on another replicator.
+pub struct Replicator {
+    node_id: NodeId,
+    entries: HashMap<String, DataEntry>,
+    gossip_interval: Duration,
+    gossip_timer: Option<Timer>,
+}
+
+enum DataEntry {
+    Typed(Box<dyn ReplicatedData>),
+    Raw(DataEnvelope),
+}
+
+trait ReplicatedData: 'static + Send + Sync {
+    fn merge_state(&mut self, state: &[u8]) -> Result<bool, DataErr>;
+
+    fn state(&self) -> Result<Vec<u8>, DataErr>;
+
+    fn type_name(&self) -> &'static str;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Crdt> ReplicatedData for T {
+    fn merge_state(&mut self, state: &[u8]) -> Result<bool, DataErr> {
+        let state: T = serde_json::from_slice(state).map_err(|_| DataErr::Deserialisation)?;
+        let previous = self.clone();
+        self.merge(&state);
+
+        Ok(*self != previous)
+    }
+
+    fn state(&self) -> Result<Vec<u8>, DataErr> {
+        serde_json::to_vec(self).map_err(|_| DataErr::Serialisation)
+    }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+pub fn replicator_actor_id(node_id: NodeId) -> ActorId {
+    format!("Replicator-{}", node_id).into_actor_id()
+}
+
+impl Replicator {
+    pub fn new(node_id: NodeId, gossip_interval: Duration) -> Replicator {
+        Replicator {
+            node_id,
+            entries: HashMap::new(),
+            gossip_interval,
+            gossip_timer: None,
+        }
+    }
+
+    fn typed_entry<T: Crdt>(&mut self, key: &str) -> Result<&mut T, DataErr> {
+        let entry = self
+            .entries
+            .entry(key.to_string())
+            .or_insert_with(|| DataEntry::Typed(Box::new(T::default())));
+
+        if let DataEntry::Raw(envelope) = entry {
+            *entry = DataEntry::Typed(Box::new(envelope.value::<T>()?));
+        }
+
+        match entry {
+            DataEntry::Typed(data) => data
+                .as_any_mut()
+                .downcast_mut::<T>()
+                .ok_or(DataErr::TypeMismatch),
+            DataEntry::Raw(_) => Err(DataErr::TypeMismatch),
+        }
+    }
+
+    fn envelope(&self, key: &str) -> Option<DataEnvelope> {
+        match self.entries.get(key)? {
+            DataEntry::Typed(data) => data.state().ok().map(|state| DataEnvelope {
+                key: key.to_string(),
+                type_name: data.type_name().to_string(),
+                states: vec![state],
+            }),
+            DataEntry::Raw(envelope) => Some(envelope.clone()),
+        }
+    }
+
+    fn merge_envelope(&mut self, envelope: DataEnvelope) -> Result<bool, DataErr> {
+        match self.entries.get_mut(&envelope.key) {
+            None => {
+                self.entries
+                    .insert(envelope.key.clone(), DataEntry::Raw(envelope));
+                Ok(true)
+            }
+
+            Some(DataEntry::Typed(data)) => {
+                if data.type_name() != envelope.type_name {
+                    return Err(DataErr::TypeMismatch);
+                }
+
+                let mut changed = false;
+                for state in &envelope.states {
+                    changed |= data.merge_state(state)?;
+                }
+
+                Ok(changed)
+            }
+
+            // Without the type, states can't be merged, only collected until the key is used
+            Some(DataEntry::Raw(raw)) => {
+                if raw.type_name != envelope.type_name {
+                    return Err(DataErr::TypeMismatch);
+                }
+
+                let mut changed = false;
+                for state in envelope.states {
+                    if !raw.states.contains(&state) {
+                        raw.states.push(state);
+                        changed = true;
+                    }
+                }
+
+                Ok(changed)
+            }
+        }
+    }
+
+    async fn publish_change(&self, key: &str, remote: &RemoteActorSystem) {
+        if let Some(envelope) = self.envelope(key) {
+            PubSub::publish_locally(DataTopic(key.to_string()), DataChanged(envelope), remote)
+                .await;
+        }
+    }
+}
+
+#[async_trait]
+impl Actor for Replicator {
+    async fn started(&mut self, ctx: &mut ActorContext) {
+        self.gossip_timer = Some(Timer::start(
+            self.actor_ref(ctx),
+            self.gossip_interval,
+            GossipTick,
+        ));
+    }
+
+    async fn stopped(&mut self, _ctx: &mut ActorContext) {
+        if let Some(timer) = self.gossip_timer.take() {
+            timer.stop();
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Crdt> Handler<Update<T>> for Replicator {
+    async fn handle(
+        &mut self,
+        message: Update<T>,
+        ctx: &mut ActorContext,
+    ) -> Result<(T, DataEnvelope), DataErr> {
+        let value = self.typed_entry::<T>(&message.key)?;
+        let previous = value.clone();
+
+        (message.modify)(value);
+
+        let value = value.clone();
+        let envelope = DataEnvelope::new(message.key, &value)?;
+        if value != previous {
+            self.publish_change(&envelope.key, ctx.system().remote())
+                .await;
+        }
+
+        Ok((value, envelope))
+    }
+}
+
+#[async_trait]
+impl<T: Crdt> Handler<Get<T>> for Replicator {
+    async fn handle(
+        &mut self,
+        message: Get<T>,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<T>, DataErr> {
+        if !self.entries.contains_key(&message.key) {
+            return Ok(None);
+        }
+
+        self.typed_entry::<T>(&message.key).map(|v| Some(v.clone()))
+    }
+}
+
+#[async_trait]
+impl Handler<MergeData> for Replicator {
+    async fn handle(&mut self, message: MergeData, ctx: &mut ActorContext) {
+        for envelope in message.0 {
+            let key = envelope.key.clone();
+            match self.merge_envelope(envelope) {
+                Ok(true) => self.publish_change(&key, ctx.system().remote()).await,
+                Ok(false) => {}
+                Err(e) => {
+                    warn!(
+                        "[node={}] failed to merge replicated data (key={}, err={:?})",
+                        self.node_id, &key, e
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<ReadData> for Replicator {
+    async fn handle(&mut self, message: ReadData, _ctx: &mut ActorContext) -> Option<DataEnvelope> {
+        self.envelope(&message.0)
+    }
+}
+
+#[async_trait]
+impl Handler<GossipTick> for Replicator {
+    async fn handle(&mut self, _message: GossipTick, ctx: &mut ActorContext) {
+        if self.entries.is_empty() {
+            return;
+        }
+
+        // Each tick, the full state is sent to a single random peer
+        let remote = ctx.system().remote_owned();
+        let peers: Vec<NodeId> = remote
+            .get_nodes()
+            .await
+            .into_iter()
+            .filter(|n| {
+                n.id != self.node_id
+                    && matches!(n.status, NodeStatus::Joining | NodeStatus::Healthy)
+                    && !remote.is_node_terminated(n.id)
+            })
+            .map(|n| n.id)
+            .collect();
+
+        let peer = match peers.choose(&mut rand::thread_rng()) {
+            Some(peer) => *peer,
+            None => return,
+        };
+
+        let entries: Vec<DataEnvelope> = self
+            .entries
+            .keys()
+            .filter_map(|key| self.envelope(key))
+            .collect();
+
+        trace!(
+            "[node={}] gossiping {} entries to node={}",
+            self.node_id,
+            entries.len(),
+            peer
+        );
+
+        let replicator: ActorRef<Replicator> =
+            RemoteActorRef::new(replicator_actor_id(peer), peer, remote).into();
+        tokio::spawn(async move {
+            let _ = replicator.notify(MergeData(entries)).await;
+        });
+    }
+}
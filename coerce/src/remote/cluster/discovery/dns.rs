@@ -1,57 +1,293 @@
-// use crate::remote::cluster::discovery::{ClusterSeed, ClusterSeedErr, DiscoveredWorker};
-//
-// use std::net::{IpAddr, SocketAddr};
-//
-// use std::str::FromStr;
-// use trust_dns_client::client::{AsyncClient, ClientHandle};
-//
-// use trust_dns_client::rr::{DNSClass, Name, RecordType};
-// use trust_dns_client::udp::UdpClientStream;
-// use trust_dns_proto::udp::UdpResponse;
-//
-// use tokio::net::UdpSocket;
-//
-// pub struct DnsClusterSeed {
-//     client: AsyncClient<UdpResponse>,
-//     seed_host: String,
-// }
-//
-// impl DnsClusterSeed {
-//     pub async fn new(upstream: SocketAddr, seed_host: String) -> DnsClusterSeed {
-//         let stream = UdpClientStream::<UdpSocket>::new(upstream);
-//         let (client, bg) = AsyncClient::connect(stream).await.unwrap();
-//
-//         tokio::spawn(bg);
-//
-//         DnsClusterSeed { client, seed_host }
-//     }
-//
-//     pub async fn all_a_records(&mut self, host: &str) -> Result<Vec<IpAddr>, ClusterSeedErr> {
-//         match self
-//             .client
-//             .query(Name::from_str(host).unwrap(), DNSClass::IN, RecordType::A)
-//             .await
-//         {
-//             Ok(res) => Ok(res
-//                 .answers()
-//                 .into_iter()
-//                 .filter_map(|a| a.rdata().to_ip_addr())
-//                 .collect()),
-//
-//             Err(e) => Err(ClusterSeedErr::Err(format!("{:?}", e))),
-//         }
-//     }
-// }
-//
-// #[async_trait]
-// impl ClusterSeed for DnsClusterSeed {
-//     async fn initial_workers(&mut self) -> Result<Vec<DiscoveredWorker>, ClusterSeedErr> {
-//         let seed_host = self.seed_host.clone();
-//         let records = self.all_a_records(&seed_host).await?;
-//
-//         Ok(records
-//             .into_iter()
-//             .map(DiscoveredWorker::from_host)
-//             .collect())
-//     }
-// }
+use crate::remote::cluster::discovery::{Discover, Seed};
+use crate::remote::cluster::node::{NodeStatus, RemoteNode, RemoteNodeState};
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use futures::future::join_all;
+use hickory_resolver::config::{
+    LookupIpStrategy, NameServerConfigGroup, ResolverConfig, ResolverOpts,
+};
+use hickory_resolver::error::ResolveErrorKind;
+use hickory_resolver::{Name, TokioAsyncResolver};
+use std::collections::{BTreeSet, HashSet};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::net::{IpAddr, SocketAddr};
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+
+const RESOLV_CONF: &str = "/etc/resolv.conf";
+
+/// Discovers seed nodes from DNS records, for clusters running behind service DNS. Records are
+/// resolved when the worker starts, and again every `refresh_interval`, any nodes that aren't
+/// already part of the cluster are identified and passed to `NodeDiscovery`.
+#[derive(Clone, Debug)]
+pub struct DnsClusterSeed {
+    upstream: SocketAddr,
+    query: DnsSeedQuery,
+    refresh_interval: Duration,
+    timeout: Duration,
+}
+
+#[derive(Clone, Debug)]
+pub enum DnsSeedQuery {
+    /// The A and AAAA records of `name`, every address is combined with `port`.
+    Host { name: String, port: u16 },
+
+    /// The SRV records of `name`, targets are resolved from their A and AAAA records. Targets
+    /// that fail to resolve are skipped.
+    Srv { name: String },
+}
+
+#[derive(Debug)]
+pub enum DnsSeedErr {
+    InvalidName(String),
+    NoNameserver,
+    Query(String),
+}
+
+impl Display for DnsSeedErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DnsSeedErr::InvalidName(name) => write!(f, "invalid DNS name: {}", name),
+            DnsSeedErr::NoNameserver => write!(f, "no nameserver found in {}", RESOLV_CONF),
+            DnsSeedErr::Query(e) => write!(f, "DNS query failed: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for DnsSeedErr {}
+
+impl DnsClusterSeed {
+    pub fn new(upstream: SocketAddr, query: DnsSeedQuery) -> DnsClusterSeed {
+        DnsClusterSeed {
+            upstream,
+            query,
+            refresh_interval: Duration::from_secs(30),
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    pub fn host(upstream: SocketAddr, name: impl ToString, port: u16) -> DnsClusterSeed {
+        Self::new(
+            upstream,
+            DnsSeedQuery::Host {
+                name: name.to_string(),
+                port,
+            },
+        )
+    }
+
+    pub fn srv(upstream: SocketAddr, name: impl ToString) -> DnsClusterSeed {
+        Self::new(
+            upstream,
+            DnsSeedQuery::Srv {
+                name: name.to_string(),
+            },
+        )
+    }
+
+    /// Uses the first nameserver from `/etc/resolv.conf`.
+    pub fn from_resolv_conf(query: DnsSeedQuery) -> Result<DnsClusterSeed, DnsSeedErr> {
+        let resolv_conf = std::fs::read_to_string(RESOLV_CONF).unwrap_or_default();
+        let upstream = resolv_conf
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("nameserver"))
+            .find_map(|addr| IpAddr::from_str(addr.trim()).ok())
+            .ok_or(DnsSeedErr::NoNameserver)?;
+
+        Ok(Self::new(SocketAddr::new(upstream, 53), query))
+    }
+
+    pub fn with_refresh_interval(mut self, refresh_interval: Duration) -> Self {
+        self.refresh_interval = refresh_interval;
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn refresh_interval(&self) -> Duration {
+        self.refresh_interval
+    }
+
+    /// Resolves the addresses of the seed nodes, as `ip:port`.
+    pub async fn resolve(&self) -> Result<Vec<String>, DnsSeedErr> {
+        let resolver = self.resolver();
+        let addrs: BTreeSet<SocketAddr> = match &self.query {
+            DnsSeedQuery::Host { name, port } => {
+                let name = parse_name(name)?;
+                resolve_host(&resolver, name)
+                    .await?
+                    .into_iter()
+                    .map(|ip| SocketAddr::new(ip, *port))
+                    .collect()
+            }
+
+            DnsSeedQuery::Srv { name } => resolve_srv(&resolver, parse_name(name)?).await?,
+        };
+
+        Ok(addrs.into_iter().map(|addr| addr.to_string()).collect())
+    }
+
+    /// Resolves the seed nodes and passes any that aren't yet part of the cluster to
+    /// `NodeDiscovery`, returns the number of new nodes.
+    pub async fn discover(&self, system: &RemoteActorSystem) -> Result<usize, DnsSeedErr> {
+        let addrs = self.resolve().await?;
+
+        let known_nodes: Vec<RemoteNodeState> = system
+            .get_nodes()
+            .await
+            .into_iter()
+            .filter(|n| n.status != NodeStatus::Terminated)
+            .collect();
+
+        // Node addresses may be host names, which are resolved so they can be compared with the
+        // addresses of the seed nodes.
+        let known_addrs: HashSet<String> =
+            join_all(known_nodes.iter().map(|n| self.resolve_node_addr(&n.addr)))
+                .await
+                .into_iter()
+                .flatten()
+                .collect();
+
+        let known_ids: HashSet<NodeId> = known_nodes.iter().map(|n| n.id).collect();
+
+        let nodes: Vec<RemoteNode> = join_all(
+            addrs
+                .into_iter()
+                .filter(|addr| !known_addrs.contains(addr))
+                .map(|addr| self.identify(addr, system)),
+        )
+        .await
+        .into_iter()
+        .flatten()
+        .filter(|node| node.id != system.node_id() && !known_ids.contains(&node.id))
+        .collect();
+
+        if nodes.is_empty() {
+            return Ok(0);
+        }
+
+        debug!(
+            "discovered {} nodes from DNS (query={:?})",
+            nodes.len(),
+            &self.query
+        );
+
+        let node_count = nodes.len();
+        let (tx, rx) = oneshot::channel();
+        let _ = system.node_discovery().notify(Discover {
+            seed: Seed::Nodes(nodes),
+            on_discovery_complete: Some(tx),
+        });
+
+        let _ = rx.await;
+        Ok(node_count)
+    }
+
+    /// A resolver that only queries `upstream`. Lookups aren't cached between calls, since a
+    /// new resolver is created for each.
+    fn resolver(&self) -> TokioAsyncResolver {
+        let nameservers = NameServerConfigGroup::from_ips_clear(
+            &[self.upstream.ip()],
+            self.upstream.port(),
+            true,
+        );
+
+        let mut opts = ResolverOpts::default();
+        opts.timeout = self.timeout;
+        opts.ip_strategy = LookupIpStrategy::Ipv4AndIpv6;
+        opts.use_hosts_file = false;
+
+        TokioAsyncResolver::tokio(ResolverConfig::from_parts(None, vec![], nameservers), opts)
+    }
+
+    async fn resolve_node_addr(&self, addr: &str) -> Vec<String> {
+        match tokio::time::timeout(self.timeout, tokio::net::lookup_host(addr)).await {
+            Ok(Ok(addrs)) => addrs.map(|addr| addr.to_string()).collect(),
+            _ => vec![addr.to_string()],
+        }
+    }
+
+    async fn identify(&self, addr: String, system: &RemoteActorSystem) -> Option<RemoteNode> {
+        let identify = async {
+            let client = system.get_remote_client(addr.clone()).await?;
+            client.identify().await.ok().flatten()
+        };
+
+        match tokio::time::timeout(self.timeout, identify).await {
+            Ok(Some(identity)) => Some(identity.node),
+            _ => {
+                debug!(
+                    "unable to identify node discovered from DNS (addr={})",
+                    &addr
+                );
+                None
+            }
+        }
+    }
+
+    /// Keeps discovering nodes every `refresh_interval`, until the actor system is shut down.
+    pub(crate) fn start_refresh(self, system: RemoteActorSystem) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(self.refresh_interval).await;
+                if system.actor_system().is_terminated() {
+                    break;
+                }
+
+                if let Err(e) = self.discover(&system).await {
+                    warn!("failed to refresh DNS seed nodes, err={}", e);
+                }
+            }
+        })
+    }
+}
+
+fn parse_name(name: &str) -> Result<Name, DnsSeedErr> {
+    Name::from_str(name).map_err(|_| DnsSeedErr::InvalidName(name.to_string()))
+}
+
+async fn resolve_host(
+    resolver: &TokioAsyncResolver,
+    name: Name,
+) -> Result<Vec<IpAddr>, DnsSeedErr> {
+    match resolver.lookup_ip(name).await {
+        Ok(lookup) => Ok(lookup.iter().collect()),
+        Err(e) if matches!(e.kind(), ResolveErrorKind::NoRecordsFound { .. }) => Ok(vec![]),
+        Err(e) => Err(DnsSeedErr::Query(e.to_string())),
+    }
+}
+
+async fn resolve_srv(
+    resolver: &TokioAsyncResolver,
+    name: Name,
+) -> Result<BTreeSet<SocketAddr>, DnsSeedErr> {
+    let lookup = match resolver.srv_lookup(name).await {
+        Ok(lookup) => lookup,
+        Err(e) if matches!(e.kind(), ResolveErrorKind::NoRecordsFound { .. }) => {
+            return Ok(BTreeSet::new())
+        }
+        Err(e) => return Err(DnsSeedErr::Query(e.to_string())),
+    };
+
+    let mut addrs = BTreeSet::new();
+    for srv in lookup.iter() {
+        match resolve_host(resolver, srv.target().clone()).await {
+            Ok(ips) => addrs.extend(ips.into_iter().map(|ip| SocketAddr::new(ip, srv.port()))),
+            Err(e) => {
+                warn!(
+                    "failed to resolve SRV target (target={}, port={}), err={}",
+                    srv.target(),
+                    srv.port(),
+                    e
+                );
+            }
+        }
+    }
+
+    Ok(addrs)
+}
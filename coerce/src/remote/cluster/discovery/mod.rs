@@ -1,7 +1,7 @@
 use crate::actor::context::ActorContext;
 use crate::actor::message::{Handler, Message};
 use crate::actor::Actor;
-use crate::remote::actor::message::SetRemote;
+use crate::remote::actor::message::{RemoveNodeClients, SetRemote};
 use crate::remote::cluster::node::{NodeIdentity, NodeStatus, RemoteNode};
 use crate::remote::net::client::RemoteClientRef;
 use crate::remote::stream::pubsub::PubSub;
@@ -14,6 +14,8 @@ use std::sync::Arc;
 use tokio::sync::oneshot::Sender;
 use uuid::Uuid;
 
+pub mod dns;
+
 #[derive(Default)]
 pub struct NodeDiscovery {
     discovering_nodes: HashSet<NodeId>,
@@ -72,8 +74,12 @@ impl Handler<Discover> for NodeDiscovery {
                     let mut discovered_nodes: HashMap<NodeId, Arc<NodeIdentity>> = HashMap::new();
                     discovered_nodes.insert(seed_node.node.id, seed_node.clone());
 
-                    self.discover_nodes(&remote, seed_node, &mut discovered_nodes)
-                        .await;
+                    // With SWIM membership, the rest of the cluster is learned through gossip
+                    // rather than by walking the peers of every node.
+                    if remote.config().swim_config().is_none() {
+                        self.discover_nodes(&remote, seed_node, &mut discovered_nodes)
+                            .await;
+                    }
 
                     if discovered_nodes.len() > 1 {
                         let nodes_to_discover: Vec<Arc<NodeIdentity>> =
@@ -182,6 +188,8 @@ impl Handler<Forget> for NodeDiscovery {
                     let system = system.clone();
                     let node = Arc::new(node.node.clone());
 
+                    let _ = system.client_registry().notify(RemoveNodeClients(node.id));
+
                     // TODO: if configured to do so, remove the node from the RemoteRegistry/RemoteNodeStore
 
                     let _ = tokio::spawn(async move {
@@ -0,0 +1,110 @@
+use crate::actor::message::{Handler, Message};
+use crate::actor::{ActorRef, IntoActor, LocalActorRef};
+use crate::remote::cluster::lease::coordinator::{lease_coordinator_actor_id, LeaseCoordinator};
+use crate::remote::cluster::lease::message::{AcquireLease, GetLeaseHolder, ReleaseLease};
+use crate::remote::cluster::lease::{LeaseErr, LeaseStore};
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use crate::remote::RemoteActorRef;
+use std::time::Duration;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Leases granted by the `LeaseCoordinator` on the cluster leader. Every node that uses the
+/// store must start it, so the coordinator is running wherever the leader ends up.
+///
+/// `max_ttl` is the longest TTL any lease will be acquired with, after a leader change, new
+/// leases aren't granted until `max_ttl` has passed.
+pub struct ClusterLeaseStore {
+    system: RemoteActorSystem,
+    coordinator: LocalActorRef<LeaseCoordinator>,
+}
+
+impl ClusterLeaseStore {
+    pub async fn start(system: RemoteActorSystem, max_ttl: Duration) -> ClusterLeaseStore {
+        let node_id = system.node_id();
+        let coordinator = LeaseCoordinator::new(node_id, max_ttl)
+            .into_actor(
+                Some(lease_coordinator_actor_id(node_id)),
+                system.actor_system(),
+            )
+            .await
+            .expect("create LeaseCoordinator");
+
+        ClusterLeaseStore {
+            system,
+            coordinator,
+        }
+    }
+
+    pub fn coordinator(&self) -> &LocalActorRef<LeaseCoordinator> {
+        &self.coordinator
+    }
+
+    async fn send<M: Message<Result = Result<T, LeaseErr>>, T>(
+        &self,
+        message: M,
+    ) -> Result<T, LeaseErr>
+    where
+        LeaseCoordinator: Handler<M>,
+    {
+        let leader = self.system.current_leader().ok_or(LeaseErr::Unavailable)?;
+        let coordinator = self.leader_coordinator(leader);
+
+        match tokio::time::timeout(REQUEST_TIMEOUT, coordinator.send(message)).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(_)) => Err(LeaseErr::Unavailable),
+            Err(_) => Err(LeaseErr::Timeout),
+        }
+    }
+
+    fn leader_coordinator(&self, leader: NodeId) -> ActorRef<LeaseCoordinator> {
+        if leader == self.system.node_id() {
+            self.coordinator.clone().into()
+        } else {
+            RemoteActorRef::new(
+                lease_coordinator_actor_id(leader),
+                leader,
+                self.system.clone(),
+            )
+            .into()
+        }
+    }
+}
+
+#[async_trait]
+impl LeaseStore for ClusterLeaseStore {
+    async fn acquire(&self, name: &str, owner: NodeId, ttl: Duration) -> Result<bool, LeaseErr> {
+        self.send(AcquireLease {
+            name: name.to_string(),
+            owner,
+            ttl,
+            renewal: false,
+        })
+        .await
+    }
+
+    async fn renew(&self, name: &str, owner: NodeId, ttl: Duration) -> Result<bool, LeaseErr> {
+        self.send(AcquireLease {
+            name: name.to_string(),
+            owner,
+            ttl,
+            renewal: true,
+        })
+        .await
+    }
+
+    async fn release(&self, name: &str, owner: NodeId) -> Result<bool, LeaseErr> {
+        self.send(ReleaseLease {
+            name: name.to_string(),
+            owner,
+        })
+        .await
+    }
+
+    async fn holder(&self, name: &str) -> Result<Option<NodeId>, LeaseErr> {
+        self.send(GetLeaseHolder {
+            name: name.to_string(),
+        })
+        .await
+    }
+}
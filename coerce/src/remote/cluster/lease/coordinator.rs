@@ -0,0 +1,162 @@
+use crate::actor::context::ActorContext;
+use crate::actor::message::Handler;
+use crate::actor::{Actor, ActorId, IntoActorId};
+use crate::remote::cluster::lease::message::{AcquireLease, GetLeaseHolder, ReleaseLease};
+use crate::remote::cluster::lease::LeaseErr;
+use crate::remote::stream::pubsub::{PubSub, Receive, Subscription};
+use crate::remote::stream::system::{ClusterEvent, SystemEvent, SystemTopic};
+use crate::remote::system::NodeId;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Runs on every node, but only grants leases while the node is the cluster leader.
+///
+/// Leases aren't replicated, so a new leader doesn't know which leases the previous leader
+/// granted. Until `max_ttl` has passed since the node became leader, it only grants leases to
+/// the node renewing them, by which point every lease the previous leader granted has either
+/// been renewed or expired.
+pub struct LeaseCoordinator {
+    node_id: NodeId,
+    max_ttl: Duration,
+    leader_since: Option<Instant>,
+    leases: HashMap<String, Lease>,
+    system_event_subscription: Option<Subscription>,
+}
+
+struct Lease {
+    holder: NodeId,
+    expires_at: Instant,
+}
+
+pub fn lease_coordinator_actor_id(node_id: NodeId) -> ActorId {
+    format!("LeaseCoordinator-{}", node_id).into_actor_id()
+}
+
+impl LeaseCoordinator {
+    pub fn new(node_id: NodeId, max_ttl: Duration) -> LeaseCoordinator {
+        LeaseCoordinator {
+            node_id,
+            max_ttl,
+            leader_since: None,
+            leases: HashMap::new(),
+            system_event_subscription: None,
+        }
+    }
+
+    fn holder(&self, name: &str) -> Option<NodeId> {
+        self.leases
+            .get(name)
+            .filter(|l| l.expires_at > Instant::now())
+            .map(|l| l.holder)
+    }
+
+    fn in_handover(&self) -> bool {
+        self.leader_since
+            .is_some_and(|since| since.elapsed() < self.max_ttl)
+    }
+
+    fn set_leader(&mut self, leader: Option<NodeId>) {
+        let is_leader = leader == Some(self.node_id);
+        if is_leader && self.leader_since.is_none() {
+            debug!("[node={}] granting leases as leader", self.node_id);
+            self.leader_since = Some(Instant::now());
+        } else if !is_leader {
+            self.leader_since = None;
+            self.leases.clear();
+        }
+    }
+}
+
+#[async_trait]
+impl Actor for LeaseCoordinator {
+    async fn started(&mut self, ctx: &mut ActorContext) {
+        self.set_leader(ctx.system().remote().current_leader());
+
+        self.system_event_subscription = Some(
+            PubSub::subscribe::<Self, SystemTopic>(SystemTopic, ctx)
+                .await
+                .unwrap(),
+        );
+    }
+}
+
+#[async_trait]
+impl Handler<AcquireLease> for LeaseCoordinator {
+    async fn handle(
+        &mut self,
+        message: AcquireLease,
+        _ctx: &mut ActorContext,
+    ) -> Result<bool, LeaseErr> {
+        if self.leader_since.is_none() {
+            return Err(LeaseErr::NotLeader);
+        }
+
+        let granted = match self.holder(&message.name) {
+            Some(holder) => holder == message.owner,
+            None => !self.in_handover() || message.renewal,
+        };
+
+        if granted {
+            self.leases.insert(
+                message.name,
+                Lease {
+                    holder: message.owner,
+                    expires_at: Instant::now() + message.ttl,
+                },
+            );
+        }
+
+        Ok(granted)
+    }
+}
+
+#[async_trait]
+impl Handler<ReleaseLease> for LeaseCoordinator {
+    async fn handle(
+        &mut self,
+        message: ReleaseLease,
+        _ctx: &mut ActorContext,
+    ) -> Result<bool, LeaseErr> {
+        if self.leader_since.is_none() {
+            return Err(LeaseErr::NotLeader);
+        }
+
+        if self.holder(&message.name) == Some(message.owner) {
+            self.leases.remove(&message.name);
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<GetLeaseHolder> for LeaseCoordinator {
+    async fn handle(
+        &mut self,
+        message: GetLeaseHolder,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<NodeId>, LeaseErr> {
+        if self.leader_since.is_none() {
+            return Err(LeaseErr::NotLeader);
+        }
+
+        Ok(self.holder(&message.name))
+    }
+}
+
+#[async_trait]
+impl Handler<Receive<SystemTopic>> for LeaseCoordinator {
+    async fn handle(&mut self, message: Receive<SystemTopic>, _ctx: &mut ActorContext) {
+        match message.0.as_ref() {
+            SystemEvent::Cluster(ClusterEvent::LeaderChanged(leader)) => {
+                self.set_leader(Some(*leader))
+            }
+            SystemEvent::Cluster(ClusterEvent::LeaderCleared) => self.set_leader(None),
+            SystemEvent::Cluster(ClusterEvent::NodeDowned(node_id)) if *node_id == self.node_id => {
+                self.set_leader(None)
+            }
+            _ => {}
+        }
+    }
+}
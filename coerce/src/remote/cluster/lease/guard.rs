@@ -0,0 +1,213 @@
+use crate::remote::cluster::lease::{LeaseErr, LeaseStoreRef};
+use crate::remote::system::NodeId;
+use parking_lot::Mutex;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+type ExpiryCallback = Box<dyn FnOnce() + Send>;
+
+/// A held lease. The lease is renewed every third of its TTL until the guard is released or
+/// dropped. The lease expires locally once the TTL has passed since the last successful renewal
+/// was sent, or as soon as the store reports that another node holds it, and the expiry callback
+/// is called.
+pub struct LeaseGuard {
+    state: Arc<GuardState>,
+    renewal: JoinHandle<()>,
+}
+
+struct GuardState {
+    name: String,
+    ttl: Duration,
+    owner: NodeId,
+    store: LeaseStoreRef,
+    held: Arc<Mutex<HashSet<String>>>,
+    expires_at: Mutex<Instant>,
+    finished: AtomicBool,
+    expired: watch::Sender<bool>,
+    on_expiry: Mutex<Option<ExpiryCallback>>,
+}
+
+impl LeaseGuard {
+    pub(crate) fn new(
+        name: String,
+        ttl: Duration,
+        expires_at: Instant,
+        owner: NodeId,
+        store: LeaseStoreRef,
+        held: Arc<Mutex<HashSet<String>>>,
+    ) -> LeaseGuard {
+        let (expired, _) = watch::channel(false);
+        let state = Arc::new(GuardState {
+            name,
+            ttl,
+            owner,
+            store,
+            held,
+            expires_at: Mutex::new(expires_at),
+            finished: AtomicBool::new(false),
+            expired,
+            on_expiry: Mutex::new(None),
+        });
+
+        let renewal = tokio::spawn(renew_lease(state.clone()));
+        LeaseGuard { state, renewal }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.state.name
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.state.ttl
+    }
+
+    pub fn is_held(&self) -> bool {
+        !*self.state.expired.borrow()
+    }
+
+    /// Calls `f` once the lease expires, or immediately if it already has.
+    pub fn on_expiry<F: 'static + FnOnce() + Send>(&self, f: F) {
+        if !self.is_held() {
+            f();
+            return;
+        }
+
+        *self.state.on_expiry.lock() = Some(Box::new(f));
+
+        // The lease may have expired while the callback was being set
+        if !self.is_held() {
+            if let Some(f) = self.state.on_expiry.lock().take() {
+                f();
+            }
+        }
+    }
+
+    /// Resolves once the lease has expired.
+    pub async fn expired(&self) {
+        let mut expired = self.state.expired.subscribe();
+        while !*expired.borrow_and_update() {
+            if expired.changed().await.is_err() {
+                return;
+            }
+        }
+    }
+
+    /// Renews the lease now, rather than waiting for the background renewal.
+    pub async fn renew(&self) -> Result<(), LeaseErr> {
+        if !self.is_held() {
+            return Err(LeaseErr::AlreadyHeld);
+        }
+
+        let state = &self.state;
+        let sent_at = Instant::now();
+        match state.store.renew(&state.name, state.owner, state.ttl).await {
+            Ok(true) => {
+                state.renewed(sent_at);
+                Ok(())
+            }
+            Ok(false) => {
+                state.expire();
+                Err(LeaseErr::AlreadyHeld)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Releases the lease, returns false if the lease had already expired.
+    pub async fn release(self) -> Result<bool, LeaseErr> {
+        self.renewal.abort();
+
+        let state = self.state.clone();
+        if !state.finish() {
+            return Ok(false);
+        }
+
+        state.store.release(&state.name, state.owner).await
+    }
+}
+
+impl Drop for LeaseGuard {
+    fn drop(&mut self) {
+        self.renewal.abort();
+
+        if self.state.finish() {
+            let state = self.state.clone();
+            if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+                runtime.spawn(async move {
+                    let _ = state.store.release(&state.name, state.owner).await;
+                });
+            }
+        }
+    }
+}
+
+impl GuardState {
+    /// Marks the guard as finished, returns true if it hadn't already finished.
+    fn finish(&self) -> bool {
+        let first = !self.finished.swap(true, Ordering::SeqCst);
+        if first {
+            self.held.lock().remove(&self.name);
+        }
+
+        first
+    }
+
+    fn renewed(&self, sent_at: Instant) {
+        let mut expires_at = self.expires_at.lock();
+        *expires_at = (*expires_at).max(sent_at + self.ttl);
+    }
+
+    fn expire(&self) {
+        if !self.finish() {
+            return;
+        }
+
+        warn!("lease expired (name={}, owner={})", &self.name, self.owner);
+
+        self.expired.send_replace(true);
+        if let Some(f) = self.on_expiry.lock().take() {
+            f();
+        }
+    }
+}
+
+async fn renew_lease(state: Arc<GuardState>) {
+    let interval = state.ttl / 3;
+
+    loop {
+        let expires_at = *state.expires_at.lock();
+        let renewal = tokio::time::timeout_at(expires_at, async {
+            tokio::time::sleep(interval).await;
+
+            let sent_at = Instant::now();
+            state
+                .store
+                .renew(&state.name, state.owner, state.ttl)
+                .await
+                .map(|renewed| (renewed, sent_at))
+        })
+        .await;
+
+        match renewal {
+            Ok(Ok((true, sent_at))) => state.renewed(sent_at),
+            Ok(Ok((false, _))) => break,
+            Ok(Err(e)) => {
+                warn!(
+                    "failed to renew lease (name={}, owner={}, err={})",
+                    &state.name, state.owner, e
+                );
+            }
+
+            // A manual renewal may have extended the lease while this one was in flight
+            Err(_) if *state.expires_at.lock() > Instant::now() => {}
+            Err(_) => break,
+        }
+    }
+
+    state.expire();
+}
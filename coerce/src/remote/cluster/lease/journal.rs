@@ -0,0 +1,122 @@
+use crate::persistent::journal::storage::{JournalEntry, JournalStorageRef};
+use crate::remote::cluster::lease::proto::lease as proto;
+use crate::remote::cluster::lease::{LeaseErr, LeaseStore};
+use crate::remote::system::NodeId;
+use chrono::Utc;
+use protobuf::Message;
+use std::time::Duration;
+
+const LEASE_RECORD: &str = "LeaseRecord";
+
+/// Leases recorded in a `JournalStorage` backend, as the latest snapshot of `lease-{name}`. Expiry
+/// uses wall-clock time, so the clocks of the nodes sharing the store need to be kept in sync.
+///
+/// Leases are written with `JournalStorage::write_snapshot_if`, conditional on the sequence of the
+/// record that was read, so when two nodes race for the same lease only one of them is granted it.
+/// The storage backend must support conditional writes, as the in-memory and Redis providers do.
+pub struct JournalLeaseStore {
+    storage: JournalStorageRef,
+}
+
+struct LeaseRecord {
+    sequence: i64,
+    holder: NodeId,
+    expires_at: i64,
+}
+
+impl JournalLeaseStore {
+    pub fn new(storage: JournalStorageRef) -> JournalLeaseStore {
+        JournalLeaseStore { storage }
+    }
+
+    async fn read(&self, name: &str) -> Result<Option<LeaseRecord>, LeaseErr> {
+        let entry = self
+            .storage
+            .read_latest_snapshot(&persistence_id(name))
+            .await
+            .map_err(|e| LeaseErr::Storage(e.to_string()))?;
+
+        match entry {
+            Some(entry) => {
+                let record = proto::LeaseRecord::parse_from_bytes(&entry.bytes)
+                    .map_err(|e| LeaseErr::Storage(e.to_string()))?;
+
+                Ok(Some(LeaseRecord {
+                    sequence: entry.sequence,
+                    holder: record.holder,
+                    expires_at: record.expires_at,
+                }))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Writes the record, as long as the latest record is still `sequence`.
+    async fn write(
+        &self,
+        name: &str,
+        sequence: i64,
+        holder: NodeId,
+        expires_at: i64,
+    ) -> Result<bool, LeaseErr> {
+        let bytes = proto::LeaseRecord {
+            holder,
+            expires_at,
+            ..Default::default()
+        }
+        .write_to_bytes()
+        .map_err(|e| LeaseErr::Storage(e.to_string()))?;
+
+        self.storage
+            .write_snapshot_if(
+                &persistence_id(name),
+                sequence,
+                JournalEntry {
+                    sequence: sequence + 1,
+                    payload_type: LEASE_RECORD.to_string(),
+                    bytes,
+                },
+            )
+            .await
+            .map_err(|e| LeaseErr::Storage(e.to_string()))
+    }
+
+    async fn current(&self, name: &str) -> Result<(i64, Option<NodeId>), LeaseErr> {
+        let now = Utc::now().timestamp_millis();
+        Ok(match self.read(name).await? {
+            Some(record) if record.expires_at > now => (record.sequence, Some(record.holder)),
+            Some(record) => (record.sequence, None),
+            None => (0, None),
+        })
+    }
+}
+
+fn persistence_id(name: &str) -> String {
+    format!("lease-{}", name)
+}
+
+#[async_trait]
+impl LeaseStore for JournalLeaseStore {
+    async fn acquire(&self, name: &str, owner: NodeId, ttl: Duration) -> Result<bool, LeaseErr> {
+        let (sequence, holder) = self.current(name).await?;
+        if holder.is_some_and(|holder| holder != owner) {
+            return Ok(false);
+        }
+
+        let expires_at = Utc::now().timestamp_millis() + ttl.as_millis() as i64;
+        self.write(name, sequence, owner, expires_at).await
+    }
+
+    async fn release(&self, name: &str, owner: NodeId) -> Result<bool, LeaseErr> {
+        let (sequence, holder) = self.current(name).await?;
+        if holder != Some(owner) {
+            return Ok(false);
+        }
+
+        self.write(name, sequence, owner, 0).await
+    }
+
+    async fn holder(&self, name: &str) -> Result<Option<NodeId>, LeaseErr> {
+        self.current(name).await.map(|(_, holder)| holder)
+    }
+}
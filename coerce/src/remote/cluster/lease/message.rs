@@ -0,0 +1,140 @@
+use crate::remote::cluster::lease::proto::lease as proto;
+use crate::remote::cluster::lease::LeaseErr;
+use crate::remote::system::NodeId;
+use coerce_macros::ProtoMessage;
+use std::time::Duration;
+
+/// Acquires or renews a lease, handled by the `LeaseCoordinator` on the leader.
+#[derive(ProtoMessage)]
+#[proto(proto::AcquireLease)]
+#[result("Result<bool, LeaseErr>", proto::LeaseResult)]
+pub struct AcquireLease {
+    pub name: String,
+    pub owner: NodeId,
+    pub ttl: Duration,
+    pub renewal: bool,
+}
+
+#[derive(ProtoMessage)]
+#[proto(proto::ReleaseLease)]
+#[result("Result<bool, LeaseErr>", proto::LeaseResult)]
+pub struct ReleaseLease {
+    pub name: String,
+    pub owner: NodeId,
+}
+
+#[derive(ProtoMessage)]
+#[proto(proto::GetLeaseHolder)]
+#[result("Result<Option<NodeId>, LeaseErr>", proto::LeaseHolder)]
+pub struct GetLeaseHolder {
+    pub name: String,
+}
+
+impl From<&AcquireLease> for proto::AcquireLease {
+    fn from(msg: &AcquireLease) -> Self {
+        proto::AcquireLease {
+            name: msg.name.clone(),
+            owner: msg.owner,
+            ttl_millis: msg.ttl.as_millis() as u64,
+            renewal: msg.renewal,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::AcquireLease> for AcquireLease {
+    fn from(msg: proto::AcquireLease) -> Self {
+        AcquireLease {
+            name: msg.name,
+            owner: msg.owner,
+            ttl: Duration::from_millis(msg.ttl_millis),
+            renewal: msg.renewal,
+        }
+    }
+}
+
+impl From<&ReleaseLease> for proto::ReleaseLease {
+    fn from(msg: &ReleaseLease) -> Self {
+        proto::ReleaseLease {
+            name: msg.name.clone(),
+            owner: msg.owner,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::ReleaseLease> for ReleaseLease {
+    fn from(msg: proto::ReleaseLease) -> Self {
+        ReleaseLease {
+            name: msg.name,
+            owner: msg.owner,
+        }
+    }
+}
+
+impl From<&GetLeaseHolder> for proto::GetLeaseHolder {
+    fn from(msg: &GetLeaseHolder) -> Self {
+        proto::GetLeaseHolder {
+            name: msg.name.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::GetLeaseHolder> for GetLeaseHolder {
+    fn from(msg: proto::GetLeaseHolder) -> Self {
+        GetLeaseHolder { name: msg.name }
+    }
+}
+
+impl From<proto::LeaseResult> for Result<bool, LeaseErr> {
+    fn from(res: proto::LeaseResult) -> Self {
+        if res.not_leader {
+            Err(LeaseErr::NotLeader)
+        } else {
+            Ok(res.granted)
+        }
+    }
+}
+
+// The `LeaseCoordinator` only fails with `LeaseErr::NotLeader`, so it's the only error sent back
+impl From<Result<bool, LeaseErr>> for proto::LeaseResult {
+    fn from(res: Result<bool, LeaseErr>) -> Self {
+        match res {
+            Ok(granted) => proto::LeaseResult {
+                granted,
+                ..Default::default()
+            },
+            Err(_) => proto::LeaseResult {
+                not_leader: true,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl From<proto::LeaseHolder> for Result<Option<NodeId>, LeaseErr> {
+    fn from(res: proto::LeaseHolder) -> Self {
+        if res.not_leader {
+            Err(LeaseErr::NotLeader)
+        } else {
+            Ok(if res.held { Some(res.holder) } else { None })
+        }
+    }
+}
+
+impl From<Result<Option<NodeId>, LeaseErr>> for proto::LeaseHolder {
+    fn from(res: Result<Option<NodeId>, LeaseErr>) -> Self {
+        match res {
+            Ok(holder) => proto::LeaseHolder {
+                held: holder.is_some(),
+                holder: holder.unwrap_or_default(),
+                ..Default::default()
+            },
+            Err(_) => proto::LeaseHolder {
+                not_leader: true,
+                ..Default::default()
+            },
+        }
+    }
+}
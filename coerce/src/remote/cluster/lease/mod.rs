@@ -0,0 +1,173 @@
+use crate::remote::cluster::lease::coordinator::LeaseCoordinator;
+use crate::remote::cluster::lease::message::{AcquireLease, GetLeaseHolder, ReleaseLease};
+use crate::remote::cluster::split_brain::SplitBrainLease;
+use crate::remote::system::builder::RemoteSystemConfigBuilder;
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use parking_lot::Mutex;
+use std::collections::HashSet;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+
+pub mod cluster;
+pub mod coordinator;
+pub mod guard;
+pub mod journal;
+pub mod message;
+pub mod proto;
+
+pub use cluster::ClusterLeaseStore;
+pub use guard::LeaseGuard;
+pub use journal::JournalLeaseStore;
+
+/// Where leases are recorded. Leases are held by nodes, a lease is available once it has been
+/// released, or once its TTL has passed without being renewed by the holder.
+#[async_trait]
+pub trait LeaseStore: 'static + Send + Sync {
+    /// Acquires the lease for `owner`, returns false if another node holds it.
+    async fn acquire(&self, name: &str, owner: NodeId, ttl: Duration) -> Result<bool, LeaseErr>;
+
+    /// Extends the lease held by `owner`, returns false if `owner` no longer holds it.
+    async fn renew(&self, name: &str, owner: NodeId, ttl: Duration) -> Result<bool, LeaseErr> {
+        self.acquire(name, owner, ttl).await
+    }
+
+    /// Releases the lease, returns false if `owner` didn't hold it.
+    async fn release(&self, name: &str, owner: NodeId) -> Result<bool, LeaseErr>;
+
+    /// The node currently holding the lease, if any.
+    async fn holder(&self, name: &str) -> Result<Option<NodeId>, LeaseErr>;
+}
+
+pub type LeaseStoreRef = Arc<dyn LeaseStore>;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum LeaseErr {
+    /// The lease is held by another node, or by another guard on this node.
+    AlreadyHeld,
+    NotLeader,
+    Unavailable,
+    Timeout,
+    Storage(String),
+}
+
+impl Display for LeaseErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            LeaseErr::AlreadyHeld => write!(f, "lease is already held"),
+            LeaseErr::NotLeader => {
+                write!(f, "lease request was sent to a node that isn't the leader")
+            }
+            LeaseErr::Unavailable => write!(f, "lease store is unavailable"),
+            LeaseErr::Timeout => write!(f, "lease request timed out"),
+            LeaseErr::Storage(e) => write!(f, "lease storage error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for LeaseErr {}
+
+/// Acquires leases on behalf of this node. A lease is held by at most one guard at a time, even
+/// on the node that holds it.
+#[derive(Clone)]
+pub struct Leases {
+    node_id: NodeId,
+    store: LeaseStoreRef,
+    held: Arc<Mutex<HashSet<String>>>,
+}
+
+impl Leases {
+    pub fn new(system: &RemoteActorSystem, store: LeaseStoreRef) -> Leases {
+        Leases {
+            node_id: system.node_id(),
+            store,
+            held: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+
+    pub fn store(&self) -> &LeaseStoreRef {
+        &self.store
+    }
+
+    /// Acquires the lease, which is renewed in the background until the guard is released or
+    /// dropped. Fails with `LeaseErr::AlreadyHeld` if the lease is held elsewhere.
+    pub async fn acquire(
+        &self,
+        name: impl ToString,
+        ttl: Duration,
+    ) -> Result<LeaseGuard, LeaseErr> {
+        let name = name.to_string();
+        if !self.held.lock().insert(name.clone()) {
+            return Err(LeaseErr::AlreadyHeld);
+        }
+
+        // The lease is held until `sent_at + ttl` at the latest, however long the store takes
+        let sent_at = Instant::now();
+        match self.store.acquire(&name, self.node_id, ttl).await {
+            Ok(true) => Ok(LeaseGuard::new(
+                name,
+                ttl,
+                sent_at + ttl,
+                self.node_id,
+                self.store.clone(),
+                self.held.clone(),
+            )),
+            Ok(false) => {
+                self.held.lock().remove(&name);
+                Err(LeaseErr::AlreadyHeld)
+            }
+            Err(e) => {
+                self.held.lock().remove(&name);
+                Err(e)
+            }
+        }
+    }
+
+    pub async fn holder(&self, name: &str) -> Result<Option<NodeId>, LeaseErr> {
+        self.store.holder(name).await
+    }
+}
+
+/// Uses a lease from a `LeaseStore` to decide split-brain partitions, via `DowningStrategy::Lease`.
+/// The store must stay reachable from both sides of a partition, so `ClusterLeaseStore` isn't
+/// suitable, the leader it relies on is only reachable from one side.
+pub struct StoreLease {
+    store: LeaseStoreRef,
+    name: String,
+    ttl: Duration,
+}
+
+impl StoreLease {
+    pub fn new(store: LeaseStoreRef, name: impl ToString, ttl: Duration) -> StoreLease {
+        StoreLease {
+            store,
+            name: name.to_string(),
+            ttl,
+        }
+    }
+}
+
+#[async_trait]
+impl SplitBrainLease for StoreLease {
+    async fn acquire(&self, owner: NodeId) -> bool {
+        match self.store.acquire(&self.name, owner, self.ttl).await {
+            Ok(acquired) => acquired,
+            Err(e) => {
+                warn!(
+                    "failed to acquire split-brain lease (name={}, err={})",
+                    &self.name, e
+                );
+                false
+            }
+        }
+    }
+}
+
+pub fn lease(builder: &mut RemoteSystemConfigBuilder) -> &mut RemoteSystemConfigBuilder {
+    builder
+        .with_control_handler::<LeaseCoordinator, AcquireLease>("LeaseCoordinator.AcquireLease")
+        .with_control_handler::<LeaseCoordinator, ReleaseLease>("LeaseCoordinator.ReleaseLease")
+        .with_control_handler::<LeaseCoordinator, GetLeaseHolder>("LeaseCoordinator.GetLeaseHolder")
+}
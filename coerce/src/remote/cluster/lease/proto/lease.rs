@@ -0,0 +1,952 @@
+// This file is generated by rust-protobuf 3.2.0. Do not edit
+// .proto file is parsed by protoc 3.21.9
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `lease.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_2_0;
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.lease.AcquireLease)
+pub struct AcquireLease {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.lease.AcquireLease.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.lease.AcquireLease.owner)
+    pub owner: u64,
+    // @@protoc_insertion_point(field:coerce.lease.AcquireLease.ttl_millis)
+    pub ttl_millis: u64,
+    // @@protoc_insertion_point(field:coerce.lease.AcquireLease.renewal)
+    pub renewal: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.lease.AcquireLease.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AcquireLease {
+    fn default() -> &'a AcquireLease {
+        <AcquireLease as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AcquireLease {
+    pub fn new() -> AcquireLease {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &AcquireLease| { &m.name },
+            |m: &mut AcquireLease| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "owner",
+            |m: &AcquireLease| { &m.owner },
+            |m: &mut AcquireLease| { &mut m.owner },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "ttl_millis",
+            |m: &AcquireLease| { &m.ttl_millis },
+            |m: &mut AcquireLease| { &mut m.ttl_millis },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "renewal",
+            |m: &AcquireLease| { &m.renewal },
+            |m: &mut AcquireLease| { &mut m.renewal },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AcquireLease>(
+            "AcquireLease",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AcquireLease {
+    const NAME: &'static str = "AcquireLease";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                16 => {
+                    self.owner = is.read_uint64()?;
+                },
+                24 => {
+                    self.ttl_millis = is.read_uint64()?;
+                },
+                32 => {
+                    self.renewal = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.owner != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.owner);
+        }
+        if self.ttl_millis != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.ttl_millis);
+        }
+        if self.renewal != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.owner != 0 {
+            os.write_uint64(2, self.owner)?;
+        }
+        if self.ttl_millis != 0 {
+            os.write_uint64(3, self.ttl_millis)?;
+        }
+        if self.renewal != false {
+            os.write_bool(4, self.renewal)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AcquireLease {
+        AcquireLease::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.owner = 0;
+        self.ttl_millis = 0;
+        self.renewal = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AcquireLease {
+        static instance: AcquireLease = AcquireLease {
+            name: ::std::string::String::new(),
+            owner: 0,
+            ttl_millis: 0,
+            renewal: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AcquireLease {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AcquireLease").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AcquireLease {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AcquireLease {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.lease.ReleaseLease)
+pub struct ReleaseLease {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.lease.ReleaseLease.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.lease.ReleaseLease.owner)
+    pub owner: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.lease.ReleaseLease.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ReleaseLease {
+    fn default() -> &'a ReleaseLease {
+        <ReleaseLease as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReleaseLease {
+    pub fn new() -> ReleaseLease {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &ReleaseLease| { &m.name },
+            |m: &mut ReleaseLease| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "owner",
+            |m: &ReleaseLease| { &m.owner },
+            |m: &mut ReleaseLease| { &mut m.owner },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ReleaseLease>(
+            "ReleaseLease",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ReleaseLease {
+    const NAME: &'static str = "ReleaseLease";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                16 => {
+                    self.owner = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.owner != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.owner);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.owner != 0 {
+            os.write_uint64(2, self.owner)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ReleaseLease {
+        ReleaseLease::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.owner = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ReleaseLease {
+        static instance: ReleaseLease = ReleaseLease {
+            name: ::std::string::String::new(),
+            owner: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ReleaseLease {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ReleaseLease").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ReleaseLease {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReleaseLease {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.lease.LeaseResult)
+pub struct LeaseResult {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.lease.LeaseResult.granted)
+    pub granted: bool,
+    // @@protoc_insertion_point(field:coerce.lease.LeaseResult.not_leader)
+    pub not_leader: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.lease.LeaseResult.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LeaseResult {
+    fn default() -> &'a LeaseResult {
+        <LeaseResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LeaseResult {
+    pub fn new() -> LeaseResult {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "granted",
+            |m: &LeaseResult| { &m.granted },
+            |m: &mut LeaseResult| { &mut m.granted },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "not_leader",
+            |m: &LeaseResult| { &m.not_leader },
+            |m: &mut LeaseResult| { &mut m.not_leader },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LeaseResult>(
+            "LeaseResult",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LeaseResult {
+    const NAME: &'static str = "LeaseResult";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.granted = is.read_bool()?;
+                },
+                16 => {
+                    self.not_leader = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.granted != false {
+            my_size += 1 + 1;
+        }
+        if self.not_leader != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.granted != false {
+            os.write_bool(1, self.granted)?;
+        }
+        if self.not_leader != false {
+            os.write_bool(2, self.not_leader)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LeaseResult {
+        LeaseResult::new()
+    }
+
+    fn clear(&mut self) {
+        self.granted = false;
+        self.not_leader = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LeaseResult {
+        static instance: LeaseResult = LeaseResult {
+            granted: false,
+            not_leader: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for LeaseResult {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LeaseResult").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LeaseResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LeaseResult {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.lease.GetLeaseHolder)
+pub struct GetLeaseHolder {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.lease.GetLeaseHolder.name)
+    pub name: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.lease.GetLeaseHolder.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetLeaseHolder {
+    fn default() -> &'a GetLeaseHolder {
+        <GetLeaseHolder as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetLeaseHolder {
+    pub fn new() -> GetLeaseHolder {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &GetLeaseHolder| { &m.name },
+            |m: &mut GetLeaseHolder| { &mut m.name },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetLeaseHolder>(
+            "GetLeaseHolder",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetLeaseHolder {
+    const NAME: &'static str = "GetLeaseHolder";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetLeaseHolder {
+        GetLeaseHolder::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetLeaseHolder {
+        static instance: GetLeaseHolder = GetLeaseHolder {
+            name: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetLeaseHolder {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetLeaseHolder").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetLeaseHolder {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetLeaseHolder {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.lease.LeaseHolder)
+pub struct LeaseHolder {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.lease.LeaseHolder.held)
+    pub held: bool,
+    // @@protoc_insertion_point(field:coerce.lease.LeaseHolder.holder)
+    pub holder: u64,
+    // @@protoc_insertion_point(field:coerce.lease.LeaseHolder.not_leader)
+    pub not_leader: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.lease.LeaseHolder.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LeaseHolder {
+    fn default() -> &'a LeaseHolder {
+        <LeaseHolder as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LeaseHolder {
+    pub fn new() -> LeaseHolder {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "held",
+            |m: &LeaseHolder| { &m.held },
+            |m: &mut LeaseHolder| { &mut m.held },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "holder",
+            |m: &LeaseHolder| { &m.holder },
+            |m: &mut LeaseHolder| { &mut m.holder },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "not_leader",
+            |m: &LeaseHolder| { &m.not_leader },
+            |m: &mut LeaseHolder| { &mut m.not_leader },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LeaseHolder>(
+            "LeaseHolder",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LeaseHolder {
+    const NAME: &'static str = "LeaseHolder";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.held = is.read_bool()?;
+                },
+                16 => {
+                    self.holder = is.read_uint64()?;
+                },
+                24 => {
+                    self.not_leader = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.held != false {
+            my_size += 1 + 1;
+        }
+        if self.holder != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.holder);
+        }
+        if self.not_leader != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.held != false {
+            os.write_bool(1, self.held)?;
+        }
+        if self.holder != 0 {
+            os.write_uint64(2, self.holder)?;
+        }
+        if self.not_leader != false {
+            os.write_bool(3, self.not_leader)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LeaseHolder {
+        LeaseHolder::new()
+    }
+
+    fn clear(&mut self) {
+        self.held = false;
+        self.holder = 0;
+        self.not_leader = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LeaseHolder {
+        static instance: LeaseHolder = LeaseHolder {
+            held: false,
+            holder: 0,
+            not_leader: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for LeaseHolder {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LeaseHolder").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LeaseHolder {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LeaseHolder {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.lease.LeaseRecord)
+pub struct LeaseRecord {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.lease.LeaseRecord.holder)
+    pub holder: u64,
+    // @@protoc_insertion_point(field:coerce.lease.LeaseRecord.expires_at)
+    pub expires_at: i64,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.lease.LeaseRecord.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LeaseRecord {
+    fn default() -> &'a LeaseRecord {
+        <LeaseRecord as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LeaseRecord {
+    pub fn new() -> LeaseRecord {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "holder",
+            |m: &LeaseRecord| { &m.holder },
+            |m: &mut LeaseRecord| { &mut m.holder },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "expires_at",
+            |m: &LeaseRecord| { &m.expires_at },
+            |m: &mut LeaseRecord| { &mut m.expires_at },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LeaseRecord>(
+            "LeaseRecord",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LeaseRecord {
+    const NAME: &'static str = "LeaseRecord";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.holder = is.read_uint64()?;
+                },
+                16 => {
+                    self.expires_at = is.read_int64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.holder != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.holder);
+        }
+        if self.expires_at != 0 {
+            my_size += ::protobuf::rt::int64_size(2, self.expires_at);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.holder != 0 {
+            os.write_uint64(1, self.holder)?;
+        }
+        if self.expires_at != 0 {
+            os.write_int64(2, self.expires_at)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LeaseRecord {
+        LeaseRecord::new()
+    }
+
+    fn clear(&mut self) {
+        self.holder = 0;
+        self.expires_at = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LeaseRecord {
+        static instance: LeaseRecord = LeaseRecord {
+            holder: 0,
+            expires_at: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for LeaseRecord {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LeaseRecord").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LeaseRecord {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LeaseRecord {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0blease.proto\x12\x0ccoerce.lease\"q\n\x0cAcquireLease\x12\x12\n\x04\
+    name\x18\x01\x20\x01(\tR\x04name\x12\x14\n\x05owner\x18\x02\x20\x01(\x04\
+    R\x05owner\x12\x1d\n\nttl_millis\x18\x03\x20\x01(\x04R\tttlMillis\x12\
+    \x18\n\x07renewal\x18\x04\x20\x01(\x08R\x07renewal\"8\n\x0cReleaseLease\
+    \x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x14\n\x05owner\x18\
+    \x02\x20\x01(\x04R\x05owner\"F\n\x0bLeaseResult\x12\x18\n\x07granted\x18\
+    \x01\x20\x01(\x08R\x07granted\x12\x1d\n\nnot_leader\x18\x02\x20\x01(\x08\
+    R\tnotLeader\"$\n\x0eGetLeaseHolder\x12\x12\n\x04name\x18\x01\x20\x01(\t\
+    R\x04name\"X\n\x0bLeaseHolder\x12\x12\n\x04held\x18\x01\x20\x01(\x08R\
+    \x04held\x12\x16\n\x06holder\x18\x02\x20\x01(\x04R\x06holder\x12\x1d\n\n\
+    not_leader\x18\x03\x20\x01(\x08R\tnotLeader\"D\n\x0bLeaseRecord\x12\x16\
+    \n\x06holder\x18\x01\x20\x01(\x04R\x06holder\x12\x1d\n\nexpires_at\x18\
+    \x02\x20\x01(\x03R\texpiresAtb\x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(0);
+            let mut messages = ::std::vec::Vec::with_capacity(6);
+            messages.push(AcquireLease::generated_message_descriptor_data());
+            messages.push(ReleaseLease::generated_message_descriptor_data());
+            messages.push(LeaseResult::generated_message_descriptor_data());
+            messages.push(GetLeaseHolder::generated_message_descriptor_data());
+            messages.push(LeaseHolder::generated_message_descriptor_data());
+            messages.push(LeaseRecord::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(0);
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
@@ -1,6 +1,11 @@
 pub mod builder;
 pub mod client;
+pub mod crdt;
 pub mod discovery;
+pub mod lease;
 pub mod node;
 pub mod sharding;
+pub mod singleton;
+pub mod split_brain;
+pub mod swim;
 pub mod workers;
@@ -17,6 +17,8 @@ pub struct RemoteNodeStore {
 pub enum NodeStatus {
     Joining,
     Healthy,
+    /// Leaving the cluster gracefully, no new work is placed on the node.
+    Leaving,
     Unhealthy,
     Terminated,
 }
@@ -36,6 +38,7 @@ pub struct RemoteNodeState {
     pub last_heartbeat: Option<DateTime<Utc>>,
     pub node_started_at: Option<DateTime<Utc>>,
     pub status: NodeStatus,
+    pub roles: Vec<String>,
 }
 
 #[derive(Hash, Debug, Clone)]
@@ -44,6 +47,7 @@ pub struct RemoteNode {
     pub addr: String,
     pub tag: String,
     pub node_started_at: Option<DateTime<Utc>>,
+    pub roles: Vec<String>,
 }
 
 #[derive(Clone)]
@@ -96,7 +100,7 @@ impl RemoteNodeStore {
     pub fn remove(&mut self, node_id: &NodeId) -> Option<RemoteNode> {
         self.nodes.remove(&node_id).and_then(|node| {
             self.table
-                .remove(&RemoteNode::new(node.id, node.addr, node.tag, None))
+                .remove(&RemoteNode::new(node.id, node.addr, node.tag, None).with_roles(node.roles))
         })
     }
 
@@ -114,12 +118,10 @@ impl RemoteNodeStore {
             .into_iter()
             .map(|n| {
                 let node = n.clone();
-                self.table.add(RemoteNode::new(
-                    n.id,
-                    n.addr,
-                    node.tag.clone(),
-                    node.node_started_at,
-                ));
+                self.table.add(
+                    RemoteNode::new(n.id, n.addr, node.tag.clone(), node.node_started_at)
+                        .with_roles(node.roles.clone()),
+                );
                 (node.id, node)
             })
             .collect();
@@ -136,6 +138,7 @@ impl RemoteNodeState {
         let addr = node.addr;
         let node_started_at = node.node_started_at;
         let tag = node.tag;
+        let roles = node.roles;
 
         Self {
             id,
@@ -145,8 +148,13 @@ impl RemoteNodeState {
             ping_latency: None,
             last_heartbeat: None,
             status: NodeStatus::Joining,
+            roles,
         }
     }
+
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role)
+    }
 }
 
 impl From<RemoteNodeState> for RemoteNode {
@@ -156,6 +164,7 @@ impl From<RemoteNodeState> for RemoteNode {
             addr: s.addr,
             tag: s.tag,
             node_started_at: s.node_started_at,
+            roles: s.roles,
         }
     }
 }
@@ -170,6 +179,7 @@ impl Default for RemoteNodeState {
             ping_latency: None,
             last_heartbeat: None,
             node_started_at: None,
+            roles: vec![],
         }
     }
 }
@@ -186,8 +196,18 @@ impl RemoteNode {
             addr,
             tag,
             node_started_at,
+            roles: vec![],
         }
     }
+
+    pub fn with_roles(mut self, roles: Vec<String>) -> RemoteNode {
+        self.roles = roles;
+        self
+    }
+
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role)
+    }
 }
 
 impl ToString for RemoteNode {
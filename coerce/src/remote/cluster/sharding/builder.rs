@@ -7,6 +7,8 @@ use std::marker::PhantomData;
 pub struct ShardingBuilder<A: ActorFactory> {
     shard_allocator: Option<Box<dyn ShardAllocator>>,
     shard_entity: Option<String>,
+    role: Option<String>,
+    coordinator_role: Option<String>,
     system: Option<RemoteActorSystem>,
     _a: PhantomData<A>,
 }
@@ -16,6 +18,8 @@ impl<A: ActorFactory> Sharding<A> {
         ShardingBuilder {
             shard_allocator: None,
             shard_entity: None,
+            role: None,
+            coordinator_role: None,
             system: Some(system),
             _a: PhantomData,
         }
@@ -33,6 +37,19 @@ impl<A: ActorFactory> ShardingBuilder<A> {
         self
     }
 
+    /// Shards are only allocated to nodes with the given role.
+    pub fn with_role<S: ToString>(&mut self, role: S) -> &mut Self {
+        self.role = Some(role.to_string());
+        self
+    }
+
+    /// Runs the shard coordinator on the oldest node with the given role, rather than on the
+    /// cluster leader.
+    pub fn with_coordinator_role<S: ToString>(&mut self, coordinator_role: S) -> &mut Self {
+        self.coordinator_role = Some(coordinator_role.to_string());
+        self
+    }
+
     pub async fn build(&mut self) -> Sharding<A> {
         Sharding::start(
             self.shard_entity
@@ -40,6 +57,8 @@ impl<A: ActorFactory> ShardingBuilder<A> {
                 .unwrap_or_else(|| A::Actor::type_name().to_string()),
             self.system.take().unwrap(),
             self.shard_allocator.take(),
+            self.role.take(),
+            self.coordinator_role.take(),
         )
         .await
     }
@@ -1,5 +1,5 @@
 use crate::actor::context::ActorContext;
-use crate::actor::message::{Envelope, Handler, Message, MessageUnwrapErr, MessageWrapErr};
+use crate::actor::message::{Envelope, Handler};
 use crate::actor::{ActorId, ActorRef};
 use crate::persistent::{PersistentActor, Recover};
 use crate::remote::cluster::sharding::coordinator::{ShardCoordinator, ShardHostState, ShardId};
@@ -8,13 +8,16 @@ use crate::remote::cluster::sharding::host::{
 };
 use crate::remote::cluster::sharding::proto::sharding as proto;
 use crate::remote::system::NodeId;
+use coerce_macros::ProtoMessage;
 use futures::future::join_all;
-use protobuf::Message as ProtoMessage;
 use std::collections::hash_map::{DefaultHasher, Entry, VacantEntry};
 
 use crate::remote::cluster::sharding::proto::sharding::allocate_shard_result;
 use std::hash::{Hash, Hasher};
 
+#[derive(ProtoMessage)]
+#[proto(proto::AllocateShard)]
+#[result(AllocateShardResult, proto::AllocateShardResult)]
 pub struct AllocateShard {
     pub shard_id: ShardId,
     pub rebalancing: bool,
@@ -46,6 +49,7 @@ impl ShardCoordinator {
         shard_id: ShardId,
         _ctx: &mut ActorContext,
     ) -> AllocateShardResult {
+        let required_role = self.required_role.as_deref();
         let shard_entry = self.shards.entry(shard_id);
 
         match shard_entry {
@@ -54,6 +58,7 @@ impl ShardCoordinator {
                 allocate(
                     shard_id,
                     self.hosts.values_mut().filter(|n| n.is_ready()).collect(),
+                    required_role,
                     vacant,
                 )
                 .await
@@ -73,6 +78,16 @@ impl Handler<AllocateShard> for ShardCoordinator {
             return AllocateShardResult::AlreadyAllocated(message.shard_id, *entry);
         }
 
+        // Shards handed off by a leaving node are allocated once the node has stopped them,
+        // hosts requesting them meanwhile are sent `ShardAllocated` when that happens.
+        if self.reallocating_shards.contains(&message.shard_id) {
+            if !message.rebalancing {
+                return AllocateShardResult::NotAllocated;
+            }
+
+            self.reallocating_shards.remove(&message.shard_id);
+        }
+
         if message.rebalancing {
             return self.allocate_shard(message.shard_id, ctx).await;
         }
@@ -99,6 +114,7 @@ impl Recover<AllocateShard> for ShardCoordinator {
 async fn allocate(
     shard_id: ShardId,
     mut hosts: Vec<&mut ShardHostState>,
+    required_role: Option<&str>,
     shard_entry: VacantEntry<'_, ShardId, NodeId>,
 ) -> AllocateShardResult {
     // TODO: weighted ordering - shards with more entities should have a higher weight, the more shards*
@@ -107,7 +123,8 @@ async fn allocate(
 
     debug!(target: "ShardCoordinator", "shard#{} allocating - available nodes={:#?}", shard_id, &hosts);
 
-    if let Some(host) = hosts.first_mut() {
+    // Hosts without the required role still receive the allocation, they just can't host it
+    if let Some(host) = hosts.iter_mut().find(|h| h.can_host(required_role)) {
         let node_id = host.node_id;
 
         trace!(target: "ShardCoordinator", "shard#{} allocated, target_node={}", shard_id, node_id);
@@ -184,62 +201,51 @@ pub async fn broadcast_reallocation(shard_id: ShardId, hosts: Vec<ActorRef<Shard
     trace!(target: "ShardCoordinator", "broadcast to all nodes complete");
 }
 
-impl Message for AllocateShard {
-    type Result = AllocateShardResult;
-
-    fn as_bytes(&self) -> Result<Vec<u8>, MessageWrapErr> {
+impl From<&AllocateShard> for proto::AllocateShard {
+    fn from(msg: &AllocateShard) -> Self {
         proto::AllocateShard {
-            shard_id: self.shard_id,
-            rebalancing: self.rebalancing,
+            shard_id: msg.shard_id,
+            rebalancing: msg.rebalancing,
             ..Default::default()
         }
-        .write_to_bytes()
-        .map_or_else(|_| Err(MessageWrapErr::SerializationErr), |b| Ok(b))
     }
+}
 
-    fn from_bytes(buffer: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
-        proto::AllocateShard::parse_from_bytes(&buffer).map_or_else(
-            |_| Err(MessageUnwrapErr::DeserializationErr),
-            |allocate_shard| {
-                Ok(AllocateShard {
-                    shard_id: allocate_shard.shard_id,
-                    rebalancing: allocate_shard.rebalancing,
-                })
-            },
-        )
+impl From<proto::AllocateShard> for AllocateShard {
+    fn from(msg: proto::AllocateShard) -> Self {
+        AllocateShard {
+            shard_id: msg.shard_id,
+            rebalancing: msg.rebalancing,
+        }
     }
+}
 
-    fn read_remote_result(buffer: Vec<u8>) -> Result<Self::Result, MessageUnwrapErr> {
-        let result = proto::AllocateShardResult::parse_from_bytes(&buffer);
-        let result = match result {
-            Ok(result) => match result.result_type.unwrap() {
-                allocate_shard_result::Type::ALLOCATED => {
-                    let allocation = result.allocation.unwrap();
-                    AllocateShardResult::Allocated(allocation.shard_id, allocation.node_id)
-                }
-                allocate_shard_result::Type::ALREADY_ALLOCATED => {
-                    let allocation = result.allocation.unwrap();
-                    AllocateShardResult::AlreadyAllocated(allocation.shard_id, allocation.node_id)
-                }
-                allocate_shard_result::Type::NOT_ALLOCATED => AllocateShardResult::NotAllocated,
-                allocate_shard_result::Type::ERR => {
-                    AllocateShardResult::Err(match result.err.unwrap() {
-                        allocate_shard_result::AllocateShardErr::PERSISTENCE => {
-                            AllocateShardErr::Persistence
-                        }
-                        allocate_shard_result::AllocateShardErr::UNKNOWN => {
-                            AllocateShardErr::Unknown
-                        }
-                    })
-                }
-            },
-            Err(_e) => return Err(MessageUnwrapErr::DeserializationErr),
-        };
-
-        Ok(result)
+impl From<proto::AllocateShardResult> for AllocateShardResult {
+    fn from(result: proto::AllocateShardResult) -> Self {
+        match result.result_type.unwrap() {
+            allocate_shard_result::Type::ALLOCATED => {
+                let allocation = result.allocation.unwrap();
+                AllocateShardResult::Allocated(allocation.shard_id, allocation.node_id)
+            }
+            allocate_shard_result::Type::ALREADY_ALLOCATED => {
+                let allocation = result.allocation.unwrap();
+                AllocateShardResult::AlreadyAllocated(allocation.shard_id, allocation.node_id)
+            }
+            allocate_shard_result::Type::NOT_ALLOCATED => AllocateShardResult::NotAllocated,
+            allocate_shard_result::Type::ERR => {
+                AllocateShardResult::Err(match result.err.unwrap() {
+                    allocate_shard_result::AllocateShardErr::PERSISTENCE => {
+                        AllocateShardErr::Persistence
+                    }
+                    allocate_shard_result::AllocateShardErr::UNKNOWN => AllocateShardErr::Unknown,
+                })
+            }
+        }
     }
+}
 
-    fn write_remote_result(res: Self::Result) -> Result<Vec<u8>, MessageWrapErr> {
+impl From<AllocateShardResult> for proto::AllocateShardResult {
+    fn from(res: AllocateShardResult) -> Self {
         let mut result: proto::AllocateShardResult = Default::default();
 
         match res {
@@ -277,8 +283,6 @@ impl Message for AllocateShard {
         }
 
         result
-            .write_to_bytes()
-            .map_err(|_e| MessageWrapErr::SerializationErr)
     }
 }
 
@@ -6,7 +6,9 @@ use crate::remote::cluster::sharding::coordinator::allocation::{
 };
 use crate::remote::cluster::sharding::coordinator::{ShardCoordinator, ShardHostStatus, ShardId};
 use crate::remote::cluster::sharding::host::{ShardHost, ShardStopped, StopShard};
+use crate::remote::cluster::sharding::proto::sharding as proto;
 use crate::remote::system::{NodeId, RemoteActorSystem};
+use coerce_macros::ProtoMessage;
 use futures::future::join_all;
 
 use std::mem;
@@ -21,6 +23,16 @@ pub enum Rebalance {
     All,
 }
 
+/// Sent by the shard host of a node that's leaving the cluster. The node's shards are marked as
+/// reallocating and the host is excluded from allocation, the shards are allocated elsewhere once
+/// the host has stopped them and sent `AllocateShard` with `rebalancing` set.
+#[derive(ProtoMessage)]
+#[proto(proto::HandoffNodeShards)]
+#[result("Vec<ShardId>", proto::NodeShardsHandedOff)]
+pub struct HandoffNodeShards {
+    pub node_id: NodeId,
+}
+
 #[async_trait]
 impl Handler<Rebalance> for ShardCoordinator {
     async fn handle(&mut self, message: Rebalance, ctx: &mut ActorContext) {
@@ -31,12 +43,21 @@ impl Handler<Rebalance> for ShardCoordinator {
             Rebalance::All => {
                 let self_ref = ctx.actor_ref();
 
+                let required_role = self.required_role.as_deref();
+                let eligible_hosts = self
+                    .hosts
+                    .values()
+                    .filter(|h| h.can_host(required_role))
+                    .count();
+
                 let total_shards = self.shards.len();
-                let fair_shard_count_per_node = total_shards / self.hosts.len();
+                let fair_shard_count_per_node = total_shards / eligible_hosts.max(1);
 
                 let mut shards_to_rebalance = vec![];
                 for (node_id, shard_host) in &self.hosts {
-                    if shard_host.shards.len() > fair_shard_count_per_node {
+                    if !shard_host.can_host(required_role) {
+                        shards_to_rebalance.extend(shard_host.shards.iter().copied());
+                    } else if shard_host.shards.len() > fair_shard_count_per_node {
                         let diff = shard_host.shards.len() - fair_shard_count_per_node;
                         let mut i = 0;
                         for shard in &shard_host.shards {
@@ -76,6 +97,50 @@ impl Handler<Rebalance> for ShardCoordinator {
     }
 }
 
+#[async_trait]
+impl Handler<HandoffNodeShards> for ShardCoordinator {
+    async fn handle(
+        &mut self,
+        message: HandoffNodeShards,
+        _ctx: &mut ActorContext,
+    ) -> Vec<ShardId> {
+        let node_id = message.node_id;
+        let shards = match self.hosts.get_mut(&node_id) {
+            None => return vec![],
+            Some(shard_host_state) => {
+                shard_host_state.status = ShardHostStatus::Unavailable;
+                mem::take(&mut shard_host_state.shards)
+            }
+        };
+
+        let hosts = self
+            .hosts
+            .values()
+            .map(|h| h.actor.clone())
+            .collect::<Vec<ActorRef<ShardHost>>>();
+
+        for shard in &shards {
+            self.shards.remove(shard);
+            self.reallocating_shards.insert(*shard);
+        }
+
+        join_all(
+            shards
+                .iter()
+                .map(|shard| broadcast_reallocation(*shard, hosts.clone())),
+        )
+        .await;
+
+        info!(
+            "node={} is leaving, handing off {} shards",
+            node_id,
+            shards.len()
+        );
+
+        shards.into_iter().collect()
+    }
+}
+
 impl ShardCoordinator {
     pub async fn rebalance_shards(
         &mut self,
@@ -174,3 +239,35 @@ impl ShardCoordinator {
 impl Message for Rebalance {
     type Result = ();
 }
+
+impl From<&HandoffNodeShards> for proto::HandoffNodeShards {
+    fn from(msg: &HandoffNodeShards) -> Self {
+        proto::HandoffNodeShards {
+            node_id: msg.node_id,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::HandoffNodeShards> for HandoffNodeShards {
+    fn from(msg: proto::HandoffNodeShards) -> Self {
+        HandoffNodeShards {
+            node_id: msg.node_id,
+        }
+    }
+}
+
+impl From<proto::NodeShardsHandedOff> for Vec<ShardId> {
+    fn from(res: proto::NodeShardsHandedOff) -> Self {
+        res.shard_ids
+    }
+}
+
+impl From<Vec<ShardId>> for proto::NodeShardsHandedOff {
+    fn from(shard_ids: Vec<ShardId>) -> Self {
+        proto::NodeShardsHandedOff {
+            shard_ids,
+            ..Default::default()
+        }
+    }
+}
@@ -43,6 +43,7 @@ impl Handler<NodeDiscovered> for ShardCoordinator {
                 vacant_entry.insert(ShardHostState {
                     node_id: new_node.id,
                     node_tag: new_node.tag.clone(),
+                    roles: new_node.roles.clone(),
                     shards: Default::default(),
                     actor: RemoteActorRef::<ShardHost>::new(
                         format!("ShardHost-{}-{}", &self.shard_entity, new_node.id).into_actor_id(),
@@ -30,6 +30,7 @@ pub type ShardId = u32;
 pub struct ShardHostState {
     pub node_id: NodeId,
     pub node_tag: String,
+    pub roles: Vec<String>,
     pub shards: HashSet<ShardId>,
     pub actor: ActorRef<ShardHost>,
     pub status: ShardHostStatus,
@@ -58,6 +59,7 @@ pub struct ShardCoordinator {
     scheduled_rebalance: Option<ScheduledRebalance>,
     self_node_id: Option<NodeId>,
     system_event_subscription: Option<Subscription>,
+    required_role: Option<String>,
 }
 
 type ScheduledRebalance = ScheduledNotify<ShardCoordinator, Rebalance>;
@@ -86,6 +88,7 @@ impl PersistentActor for ShardCoordinator {
         self.add_host(ShardHostState {
             node_id,
             node_tag,
+            roles: remote.node_roles().to_vec(),
             shards: Default::default(),
             actor: self.local_shard_host.clone().into(),
             status: ShardHostStatus::Ready,
@@ -111,6 +114,7 @@ impl PersistentActor for ShardCoordinator {
                 self.add_host(ShardHostState {
                     node_id: host.id,
                     node_tag: String::default(),
+                    roles: host.roles,
                     shards: HashSet::new(),
                     actor: RemoteActorRef::<ShardHost>::new(
                         format!("ShardHost-{}-{}", &self.shard_entity, host.id).into_actor_id(),
@@ -143,9 +147,16 @@ impl ShardCoordinator {
             scheduled_rebalance: None,
             self_node_id: None,
             system_event_subscription: None,
+            required_role: None,
         }
     }
 
+    /// Only allocates shards to hosts on nodes with the given role.
+    pub fn with_required_role(mut self, required_role: Option<String>) -> ShardCoordinator {
+        self.required_role = required_role;
+        self
+    }
+
     pub fn schedule_full_rebalance(&mut self, ctx: &ActorContext) {
         if let Some(scheduled_rebalance) = self.scheduled_rebalance.take() {
             scheduled_rebalance.cancel();
@@ -169,4 +180,12 @@ impl ShardHostState {
             _ => false,
         }
     }
+
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role)
+    }
+
+    pub fn can_host(&self, required_role: Option<&str>) -> bool {
+        required_role.is_none_or(|role| self.has_role(role))
+    }
 }
@@ -3,7 +3,9 @@ use crate::actor::message::Handler;
 use crate::actor::{Actor, IntoActor, LocalActorRef};
 use crate::remote::cluster::sharding::coordinator::discovery::{NodeDiscovered, NodeForgotten};
 use crate::remote::cluster::sharding::coordinator::ShardCoordinator;
-use crate::remote::cluster::sharding::host::{LeaderAllocated, ShardHost};
+use crate::remote::cluster::sharding::host::{
+    ForgetNodeShards, LeaderAllocated, ShardHost, StopAllShards,
+};
 use crate::remote::stream::pubsub::{PubSub, Receive, Subscription};
 use crate::remote::stream::system::{ClusterEvent, SystemEvent, SystemTopic};
 use crate::remote::system::NodeId;
@@ -11,10 +13,12 @@ use crate::remote::system::NodeId;
 pub struct CoordinatorSpawner {
     node_id: NodeId,
     shard_entity: String,
-    current_leader: Option<NodeId>,
     local_shard_host: LocalActorRef<ShardHost>,
     system_event_subscription: Option<Subscription>,
     coordinator: Option<LocalActorRef<ShardCoordinator>>,
+    downed: bool,
+    required_role: Option<String>,
+    coordinator_role: Option<String>,
 }
 
 const COORDINATOR_SPAWNER: &str = "ShardCoordinator-Spawner";
@@ -29,15 +33,28 @@ impl CoordinatorSpawner {
             node_id,
             shard_entity,
             local_shard_host,
-            current_leader: None,
             system_event_subscription: None,
             coordinator: None,
+            downed: false,
+            required_role: None,
+            coordinator_role: None,
         }
     }
 
+    pub fn with_roles(
+        mut self,
+        required_role: Option<String>,
+        coordinator_role: Option<String>,
+    ) -> CoordinatorSpawner {
+        self.required_role = required_role;
+        self.coordinator_role = coordinator_role;
+        self
+    }
+
     pub async fn start_coordinator(&mut self, ctx: &mut ActorContext) {
         let coordinator =
             ShardCoordinator::new(self.shard_entity.clone(), self.local_shard_host.clone())
+                .with_required_role(self.required_role.clone())
                 .into_actor(
                     Some(format!("ShardCoordinator-{}", &self.shard_entity)),
                     &ctx.system(),
@@ -74,23 +91,57 @@ impl CoordinatorSpawner {
             false
         }
     }
-}
 
-#[async_trait]
-impl Actor for CoordinatorSpawner {
-    async fn started(&mut self, ctx: &mut ActorContext) {
-        let remote = ctx.system().remote();
-        self.current_leader = remote.current_leader();
-        if let Some(current_leader) = self.current_leader {
-            if current_leader == remote.node_id() {
-                self.start_coordinator(ctx).await;
-            }
-        } else {
+    /// Starts or stops the local coordinator, depending on whether this node is the leader, or
+    /// the leader of the coordinator role if there is one. `ShardHost` locates the coordinator
+    /// using the same `role_leader`, so both sides agree on where it runs.
+    async fn update_coordinator(&mut self, ctx: &mut ActorContext) {
+        if self.downed {
+            return;
+        }
+
+        let coordinator_node = ctx
+            .system()
+            .remote()
+            .role_leader(self.coordinator_role.as_deref());
+
+        if coordinator_node != Some(self.node_id) && self.stop_coordinator().await {
             debug!(
                 target: COORDINATOR_SPAWNER,
-                "[node={}] no leader allocated", self.node_id
+                "[node={}] stopped coordinator, coordinator_node={:?}",
+                self.node_id,
+                coordinator_node
+            );
+        }
+
+        let coordinator_node = match coordinator_node {
+            Some(coordinator_node) => coordinator_node,
+            None => {
+                debug!(
+                    target: COORDINATOR_SPAWNER,
+                    "[node={}] no coordinator node available", self.node_id
+                );
+                return;
+            }
+        };
+
+        if coordinator_node == self.node_id && self.coordinator.is_none() {
+            self.start_coordinator(ctx).await;
+        }
+
+        if let Err(e) = self.local_shard_host.notify(LeaderAllocated) {
+            error!(target: COORDINATOR_SPAWNER,
+                "[node={}] failed to notify `LeaderAllocated` to local shard host (entity={}, err={})",
+                self.node_id, &self.shard_entity, e
             );
         }
+    }
+}
+
+#[async_trait]
+impl Actor for CoordinatorSpawner {
+    async fn started(&mut self, ctx: &mut ActorContext) {
+        self.update_coordinator(ctx).await;
 
         self.system_event_subscription = Some(
             PubSub::subscribe::<Self, SystemTopic>(SystemTopic, ctx)
@@ -120,30 +171,35 @@ impl Handler<Receive<SystemTopic>> for CoordinatorSpawner {
                     if let Some(coordinator) = &self.coordinator {
                         let _ = coordinator.notify(NodeForgotten(node.clone()));
                     }
+
+                    let _ = self.local_shard_host.notify(ForgetNodeShards(node.id));
                 }
 
-                ClusterEvent::LeaderChanged(leader_node_id) => {
-                    let leader_node_id = *leader_node_id;
-                    debug!(
-                        target: COORDINATOR_SPAWNER,
-                        "[node={}] Leader changed, leader_node_id={}", self.node_id, leader_node_id,
-                    );
-
-                    if leader_node_id == self.node_id && self.coordinator.is_none() {
-                        self.start_coordinator(ctx).await;
-                    } else if self.stop_coordinator().await {
-                        trace!(
+                ClusterEvent::NodeDowned(node_id) => {
+                    if *node_id == self.node_id {
+                        warn!(
                             target: COORDINATOR_SPAWNER,
-                            "[node={}] stopped coordinator",
-                            self.node_id
+                            "[node={}] node downed, no longer hosting shards (entity={})",
+                            self.node_id,
+                            &self.shard_entity
                         );
+
+                        self.downed = true;
+                        self.stop_coordinator().await;
+
+                        let _ = self.local_shard_host.notify(StopAllShards);
                     }
+                }
 
-                    if let Err(e) = self.local_shard_host.notify(LeaderAllocated) {
-                        error!(target: COORDINATOR_SPAWNER,
-                            "[node={}] failed to notify `LeaderAllocated` to local shard host (entity={}, err={})",
-                            self.node_id, &self.shard_entity, e
-                        );
+                ClusterEvent::LeaderChanged(_) | ClusterEvent::LeaderCleared => {
+                    if self.coordinator_role.is_none() {
+                        self.update_coordinator(ctx).await;
+                    }
+                }
+
+                ClusterEvent::RoleLeaderChanged(role, _) => {
+                    if self.coordinator_role.as_ref() == Some(role) {
+                        self.update_coordinator(ctx).await;
                     }
                 }
             },
@@ -1,11 +1,15 @@
 use crate::actor::context::ActorContext;
-use crate::actor::message::{Envelope, Handler, Message, MessageUnwrapErr, MessageWrapErr};
+use crate::actor::message::Handler;
 use crate::remote::cluster::sharding::coordinator::{ShardCoordinator, ShardHostStatus};
 use crate::remote::cluster::sharding::host::stats::RemoteShard;
 use crate::remote::cluster::sharding::proto::sharding as proto;
 use crate::remote::system::NodeId;
-use protobuf::{EnumOrUnknown, Message as ProtoMessage};
+use coerce_macros::ProtoMessage;
+use protobuf::EnumOrUnknown;
 
+#[derive(ProtoMessage)]
+#[proto(proto::GetShardingStats)]
+#[result(ShardingStats, proto::ShardingStats)]
 pub struct GetShardingStats;
 
 pub struct NodeStats {
@@ -48,58 +52,50 @@ impl Handler<GetShardingStats> for ShardCoordinator {
     }
 }
 
-impl Message for GetShardingStats {
-    type Result = ShardingStats;
-
-    fn as_bytes(&self) -> Result<Vec<u8>, MessageWrapErr> {
-        proto::GetShardingStats {
-            ..Default::default()
-        }
-        .write_to_bytes()
-        .map_err(|_| MessageWrapErr::SerializationErr)
+impl From<&GetShardingStats> for proto::GetShardingStats {
+    fn from(_: &GetShardingStats) -> Self {
+        proto::GetShardingStats::default()
     }
+}
 
-    fn from_bytes(bytes: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
-        proto::GetShardingStats::parse_from_bytes(&bytes).map_or_else(
-            |_| Err(MessageUnwrapErr::DeserializationErr),
-            |_m| Ok(GetShardingStats),
-        )
+impl From<proto::GetShardingStats> for GetShardingStats {
+    fn from(_: proto::GetShardingStats) -> Self {
+        GetShardingStats
     }
+}
 
-    fn read_remote_result(res: Vec<u8>) -> Result<Self::Result, MessageUnwrapErr> {
-        let proto_stats = proto::ShardingStats::parse_from_bytes(&res);
-        if let Ok(stats) = proto_stats {
-            Ok(ShardingStats {
-                entity_type: stats.entity_type,
-                total_shards: stats.total_shards,
-                shards: stats
-                    .shards
-                    .into_iter()
-                    .map(|s| RemoteShard {
-                        shard_id: s.shard_id,
-                        node_id: s.node_id,
-                    })
-                    .collect(),
-                nodes: stats
-                    .nodes
-                    .into_iter()
-                    .map(|n| NodeStats {
-                        node_id: n.node_id,
-                        shard_count: n.shard_count,
-                        status: n.status.unwrap().into(),
-                    })
-                    .collect(),
-            })
-        } else {
-            Err(MessageUnwrapErr::DeserializationErr)
+impl From<proto::ShardingStats> for ShardingStats {
+    fn from(stats: proto::ShardingStats) -> Self {
+        ShardingStats {
+            entity_type: stats.entity_type,
+            total_shards: stats.total_shards,
+            shards: stats
+                .shards
+                .into_iter()
+                .map(|s| RemoteShard {
+                    shard_id: s.shard_id,
+                    node_id: s.node_id,
+                })
+                .collect(),
+            nodes: stats
+                .nodes
+                .into_iter()
+                .map(|n| NodeStats {
+                    node_id: n.node_id,
+                    shard_count: n.shard_count,
+                    status: n.status.unwrap().into(),
+                })
+                .collect(),
         }
     }
+}
 
-    fn write_remote_result(res: ShardingStats) -> Result<Vec<u8>, MessageWrapErr> {
+impl From<ShardingStats> for proto::ShardingStats {
+    fn from(stats: ShardingStats) -> Self {
         proto::ShardingStats {
-            entity_type: res.entity_type,
-            total_shards: res.total_shards,
-            shards: res
+            entity_type: stats.entity_type,
+            total_shards: stats.total_shards,
+            shards: stats
                 .shards
                 .into_iter()
                 .map(|s| proto::RemoteShard {
@@ -108,7 +104,7 @@ impl Message for GetShardingStats {
                     ..Default::default()
                 })
                 .collect(),
-            nodes: res
+            nodes: stats
                 .nodes
                 .into_iter()
                 .map(|n| proto::NodeStats {
@@ -120,8 +116,6 @@ impl Message for GetShardingStats {
                 .collect(),
             ..Default::default()
         }
-        .write_to_bytes()
-        .map_or_else(|_| Err(MessageWrapErr::SerializationErr), |b| Ok(b))
     }
 }
 
@@ -0,0 +1,121 @@
+use crate::actor::context::ActorContext;
+use crate::actor::message::{Handler, Message};
+use crate::actor::{Actor, ActorRef, LocalActorRef};
+use crate::remote::cluster::sharding::coordinator::allocation::AllocateShard;
+use crate::remote::cluster::sharding::coordinator::balancing::HandoffNodeShards;
+use crate::remote::cluster::sharding::coordinator::{ShardCoordinator, ShardId};
+use crate::remote::cluster::sharding::host::{ShardHost, ShardState, ShardStopped};
+use crate::remote::cluster::sharding::shard::Shard;
+use crate::remote::system::NodeId;
+use futures::future::join_all;
+use tokio::sync::oneshot;
+
+/// Hands the shards hosted on this node off to the rest of the cluster, ahead of the node leaving.
+/// The hosted shards, and the entities within them, are stopped before they're allocated to
+/// other nodes. The number of shards that were handed off is sent via the enclosed channel.
+pub struct HandoffShards(pub oneshot::Sender<usize>);
+
+impl Message for HandoffShards {
+    type Result = ();
+}
+
+struct StopHandedOffShards(Vec<ShardId>);
+
+impl Message for StopHandedOffShards {
+    type Result = Vec<(ShardId, LocalActorRef<Shard>)>;
+}
+
+#[async_trait]
+impl Handler<HandoffShards> for ShardHost {
+    async fn handle(&mut self, message: HandoffShards, ctx: &mut ActorContext) {
+        let tx = message.0;
+        let coordinator = self.get_coordinator(ctx).await;
+        let node_id = ctx.system().remote().node_id();
+        let host = self.actor_ref(ctx);
+        let shard_entity = self.shard_entity.clone();
+
+        tokio::spawn(async move {
+            let handed_off = match coordinator {
+                Some(coordinator) => handoff(node_id, coordinator, host).await,
+                None => {
+                    warn!(
+                        "no coordinator available, unable to hand off shards (entity={})",
+                        &shard_entity
+                    );
+                    0
+                }
+            };
+
+            let _ = tx.send(handed_off);
+        });
+    }
+}
+
+#[async_trait]
+impl Handler<StopHandedOffShards> for ShardHost {
+    async fn handle(
+        &mut self,
+        message: StopHandedOffShards,
+        _ctx: &mut ActorContext,
+    ) -> Vec<(ShardId, LocalActorRef<Shard>)> {
+        // Shards that are still starting are stopped as soon as they're ready
+        self.stopped_hosting = true;
+
+        let mut stopping = vec![];
+        for shard_id in message.0 {
+            if let Some(ShardState::Ready(actor_ref)) = self.hosted_shards.get(&shard_id) {
+                stopping.push((shard_id, actor_ref.clone()));
+                self.hosted_shards.insert(shard_id, ShardState::Stopping);
+            }
+        }
+
+        stopping
+    }
+}
+
+async fn handoff(
+    node_id: NodeId,
+    coordinator: ActorRef<ShardCoordinator>,
+    host: LocalActorRef<ShardHost>,
+) -> usize {
+    let shards = match coordinator.send(HandoffNodeShards { node_id }).await {
+        Ok(shards) => shards,
+        Err(e) => {
+            error!(
+                "failed to hand off shards, coordinator={}, err={}",
+                &coordinator, e
+            );
+            return 0;
+        }
+    };
+
+    let stopping = host
+        .send(StopHandedOffShards(shards.clone()))
+        .await
+        .unwrap_or_default();
+
+    join_all(stopping.into_iter().map(|(shard_id, shard)| {
+        let host = host.clone();
+        async move {
+            let result = shard.stop().await;
+            let _ = host
+                .send(ShardStopped {
+                    shard_id,
+                    stopped_successfully: result.is_ok(),
+                })
+                .await;
+        }
+    }))
+    .await;
+
+    join_all(shards.iter().map(|shard_id| {
+        coordinator.send(AllocateShard {
+            shard_id: *shard_id,
+            rebalancing: true,
+        })
+    }))
+    .await;
+
+    info!("handed off {} shards (node_id={})", shards.len(), node_id);
+    shards.len()
+}
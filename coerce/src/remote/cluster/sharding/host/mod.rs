@@ -1,7 +1,5 @@
 use crate::actor::context::ActorContext;
-use crate::actor::message::{
-    Envelope, EnvelopeType, Handler, Message, MessageUnwrapErr, MessageWrapErr,
-};
+use crate::actor::message::{Envelope, EnvelopeType, Handler, Message};
 use crate::actor::{Actor, ActorId, ActorRef, IntoActor, IntoActorId, LocalActorRef, ToActorId};
 use crate::remote::cluster::sharding::coordinator::allocation::DefaultAllocator;
 use crate::remote::cluster::sharding::coordinator::{ShardCoordinator, ShardId};
@@ -11,7 +9,7 @@ use crate::remote::cluster::sharding::proto::sharding as proto;
 use crate::remote::cluster::sharding::shard::Shard;
 use crate::remote::system::NodeId;
 use crate::remote::RemoteActorRef;
-use protobuf::Message as ProtoMessage;
+use coerce_macros::ProtoMessage;
 
 use crate::remote::actor::BoxedActorHandler;
 use futures::FutureExt;
@@ -20,6 +18,7 @@ use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use uuid::Uuid;
 
+pub mod handoff;
 pub mod request;
 pub mod stats;
 
@@ -45,6 +44,8 @@ pub struct ShardHost {
     requests_pending_leader_allocation: VecDeque<EntityRequest>,
     requests_pending_shard_allocation: HashMap<ShardId, Vec<EntityRequest>>,
     allocator: Box<dyn ShardAllocator>,
+    stopped_hosting: bool,
+    coordinator_role: Option<String>,
 }
 
 pub trait ShardAllocator: 'static + Send + Sync {
@@ -76,22 +77,32 @@ impl ShardHost {
                 || Box::new(DefaultAllocator::default()) as Box<dyn ShardAllocator>,
                 |s| s,
             ),
+            stopped_hosting: false,
+            coordinator_role: None,
         }
     }
 
-    pub async fn get_coordinator(&self, ctx: &ActorContext) -> ActorRef<ShardCoordinator> {
+    /// Locates the coordinator on the oldest node with the given role, rather than on the leader.
+    pub fn with_coordinator_role(mut self, coordinator_role: Option<String>) -> ShardHost {
+        self.coordinator_role = coordinator_role;
+        self
+    }
+
+    pub async fn get_coordinator(&self, ctx: &ActorContext) -> Option<ActorRef<ShardCoordinator>> {
         let actor_id = format!("ShardCoordinator-{}", &self.shard_entity).into_actor_id();
         let remote = ctx.system().remote();
-        let leader = remote.current_leader();
-        if leader == Some(remote.node_id()) {
+        let coordinator_node = remote.role_leader(self.coordinator_role.as_deref())?;
+
+        if coordinator_node == remote.node_id() {
             ctx.system()
                 .get_tracked_actor::<ShardCoordinator>(actor_id)
                 .await
-                .expect("get local coordinator")
-                .into()
+                .map(|coordinator| coordinator.into())
         } else {
-            RemoteActorRef::<ShardCoordinator>::new(actor_id, leader.unwrap(), remote.clone())
-                .into()
+            Some(
+                RemoteActorRef::<ShardCoordinator>::new(actor_id, coordinator_node, remote.clone())
+                    .into(),
+            )
         }
     }
 }
@@ -99,39 +110,69 @@ impl ShardHost {
 pub struct GetCoordinator;
 
 impl Message for GetCoordinator {
-    type Result = ActorRef<ShardCoordinator>;
+    type Result = Option<ActorRef<ShardCoordinator>>;
 }
 
+#[derive(ProtoMessage)]
+#[proto(proto::ShardAllocated)]
 pub struct ShardAllocated(pub ShardId, pub NodeId);
 
+#[derive(ProtoMessage)]
+#[proto(proto::ShardReallocating)]
 pub struct ShardReallocating(pub ShardId);
 
+/// Forgets the shards allocated to a node that has left the cluster, the next request to one of
+/// those shards will ask the coordinator where it is now allocated.
+pub struct ForgetNodeShards(pub NodeId);
+
+impl Message for ForgetNodeShards {
+    type Result = ();
+}
+
+/// Stops every shard hosted on this node, shards that are still starting are stopped as soon as
+/// they're ready. Sent when this node has been downed by the split brain resolver.
+pub struct StopAllShards;
+
+impl Message for StopAllShards {
+    type Result = ();
+}
+
 pub struct ShardReady(pub ShardId, pub LocalActorRef<Shard>);
 
 impl Message for ShardReady {
     type Result = ();
 }
 
+#[derive(ProtoMessage)]
+#[proto(proto::ShardStopped)]
 pub struct ShardStopped {
     shard_id: ShardId,
     stopped_successfully: bool,
 }
 
+#[derive(ProtoMessage)]
+#[proto(proto::StopShard)]
 pub struct StopShard {
     pub shard_id: ShardId,
     pub origin_node_id: NodeId,
     pub request_id: Uuid,
 }
 
+#[derive(ProtoMessage)]
+#[proto(proto::StartEntity)]
 pub struct StartEntity {
     pub actor_id: ActorId,
     pub recipe: Arc<Vec<u8>>,
 }
 
+#[derive(ProtoMessage)]
+#[proto(proto::RemoveEntity)]
 pub struct RemoveEntity {
     pub actor_id: ActorId,
 }
 
+#[derive(ProtoMessage)]
+#[proto(proto::PassivateEntity)]
 pub struct PassivateEntity {
     pub actor_id: ActorId,
 }
@@ -157,7 +198,7 @@ impl Handler<GetCoordinator> for ShardHost {
         &mut self,
         _message: GetCoordinator,
         ctx: &mut ActorContext,
-    ) -> ActorRef<ShardCoordinator> {
+    ) -> Option<ActorRef<ShardCoordinator>> {
         self.get_coordinator(&ctx).await
     }
 }
@@ -263,6 +304,12 @@ impl Handler<ShardReady> for ShardHost {
 
         ctx.attach_child_ref(actor_ref.clone().into());
 
+        if self.stopped_hosting {
+            self.hosted_shards.insert(shard_id, ShardState::Stopping);
+            self.stop_shard(shard_id, actor_ref, ctx, None);
+            return;
+        }
+
         match shard_state {
             Some(ShardState::Starting {
                 request_buffer,
@@ -291,6 +338,53 @@ impl Handler<ShardReallocating> for ShardHost {
     }
 }
 
+#[async_trait]
+impl Handler<ForgetNodeShards> for ShardHost {
+    async fn handle(&mut self, message: ForgetNodeShards, _ctx: &mut ActorContext) {
+        let node_id = message.0;
+        self.remote_shards
+            .retain(|_, shard| shard.node_id() != Some(node_id));
+    }
+}
+
+#[async_trait]
+impl Handler<PassivateEntity> for ShardHost {
+    async fn handle(&mut self, message: PassivateEntity, _ctx: &mut ActorContext) {
+        let shard_id = self.allocator.allocate(&message.actor_id);
+        if let Some(ShardState::Ready(shard)) = self.hosted_shards.get(&shard_id) {
+            let _ = shard.notify(message);
+        } else if let Some(shard) = self.remote_shards.get(&shard_id) {
+            let shard = shard.clone();
+            tokio::spawn(async move { shard.notify(message).await });
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<StopAllShards> for ShardHost {
+    async fn handle(&mut self, _message: StopAllShards, ctx: &mut ActorContext) {
+        self.stopped_hosting = true;
+        self.remote_shards.clear();
+
+        let ready_shards: Vec<(ShardId, LocalActorRef<Shard>)> = self
+            .hosted_shards
+            .iter()
+            .filter_map(|(shard_id, state)| state.actor_ref().map(|s| (*shard_id, s)))
+            .collect();
+
+        info!(
+            "stopping {} hosted shards (entity={})",
+            ready_shards.len(),
+            &self.shard_entity
+        );
+
+        for (shard_id, actor_ref) in ready_shards {
+            self.hosted_shards.insert(shard_id, ShardState::Stopping);
+            self.stop_shard(shard_id, actor_ref, ctx, None);
+        }
+    }
+}
+
 #[async_trait]
 impl Handler<StopShard> for ShardHost {
     async fn handle(&mut self, message: StopShard, ctx: &mut ActorContext) {
@@ -376,218 +470,132 @@ pub fn shard_actor_id(shard_entity: &String, shard_id: ShardId) -> ActorId {
     format!("{}-Shard-{}", &shard_entity, shard_id).into_actor_id()
 }
 
-impl Message for ShardAllocated {
-    type Result = ();
-
-    fn as_bytes(&self) -> Result<Vec<u8>, MessageWrapErr> {
+impl From<&ShardAllocated> for proto::ShardAllocated {
+    fn from(msg: &ShardAllocated) -> Self {
         proto::ShardAllocated {
             shard: Some(proto::RemoteShard {
-                shard_id: self.0,
-                node_id: self.1,
+                shard_id: msg.0,
+                node_id: msg.1,
                 ..Default::default()
             })
             .into(),
             ..Default::default()
         }
-        .write_to_bytes()
-        .map_err(|_| MessageWrapErr::SerializationErr)
-    }
-
-    fn from_bytes(b: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
-        proto::ShardAllocated::parse_from_bytes(&b)
-            .map(|r| {
-                let shard = r.shard.unwrap();
-                Self(shard.shard_id, shard.node_id)
-            })
-            .map_err(|_e| MessageUnwrapErr::DeserializationErr)
-    }
-
-    fn read_remote_result(_: Vec<u8>) -> Result<Self::Result, MessageUnwrapErr> {
-        Ok(())
     }
+}
 
-    fn write_remote_result(_res: Self::Result) -> Result<Vec<u8>, MessageWrapErr> {
-        Ok(vec![])
+impl From<proto::ShardAllocated> for ShardAllocated {
+    fn from(msg: proto::ShardAllocated) -> Self {
+        let shard = msg.shard.unwrap();
+        ShardAllocated(shard.shard_id, shard.node_id)
     }
 }
 
-impl Message for ShardReallocating {
-    type Result = ();
-
-    fn as_bytes(&self) -> Result<Vec<u8>, MessageWrapErr> {
+impl From<&ShardReallocating> for proto::ShardReallocating {
+    fn from(msg: &ShardReallocating) -> Self {
         proto::ShardReallocating {
-            shard_id: self.0,
+            shard_id: msg.0,
             ..Default::default()
         }
-        .write_to_bytes()
-        .map_err(|_| MessageWrapErr::SerializationErr)
-    }
-
-    fn from_bytes(b: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
-        proto::StopShard::parse_from_bytes(&b)
-            .map(|r| Self(r.shard_id))
-            .map_err(|_e| MessageUnwrapErr::DeserializationErr)
-    }
-
-    fn read_remote_result(_: Vec<u8>) -> Result<Self::Result, MessageUnwrapErr> {
-        Ok(())
     }
+}
 
-    fn write_remote_result(_res: Self::Result) -> Result<Vec<u8>, MessageWrapErr> {
-        Ok(vec![])
+impl From<proto::ShardReallocating> for ShardReallocating {
+    fn from(msg: proto::ShardReallocating) -> Self {
+        ShardReallocating(msg.shard_id)
     }
 }
 
-impl Message for StopShard {
-    type Result = ();
-
-    fn as_bytes(&self) -> Result<Vec<u8>, MessageWrapErr> {
+impl From<&StopShard> for proto::StopShard {
+    fn from(msg: &StopShard) -> Self {
         proto::StopShard {
-            shard_id: self.shard_id,
-            request_id: self.request_id.to_string(),
-            origin_node_id: self.origin_node_id,
+            shard_id: msg.shard_id,
+            request_id: msg.request_id.to_string(),
+            origin_node_id: msg.origin_node_id,
             ..Default::default()
         }
-        .write_to_bytes()
-        .map_err(|_| MessageWrapErr::SerializationErr)
-    }
-
-    fn from_bytes(b: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
-        proto::StopShard::parse_from_bytes(&b)
-            .map(|r| Self {
-                shard_id: r.shard_id,
-                request_id: Uuid::parse_str(&r.request_id).unwrap(),
-                origin_node_id: r.origin_node_id,
-            })
-            .map_err(|_e| MessageUnwrapErr::DeserializationErr)
-    }
-
-    fn read_remote_result(_: Vec<u8>) -> Result<Self::Result, MessageUnwrapErr> {
-        Ok(())
     }
+}
 
-    fn write_remote_result(_res: Self::Result) -> Result<Vec<u8>, MessageWrapErr> {
-        Ok(vec![])
+impl From<proto::StopShard> for StopShard {
+    fn from(msg: proto::StopShard) -> Self {
+        StopShard {
+            shard_id: msg.shard_id,
+            request_id: Uuid::parse_str(&msg.request_id).unwrap(),
+            origin_node_id: msg.origin_node_id,
+        }
     }
 }
 
-impl Message for StartEntity {
-    type Result = ();
-
-    fn as_bytes(&self) -> Result<Vec<u8>, MessageWrapErr> {
+impl From<&StartEntity> for proto::StartEntity {
+    fn from(msg: &StartEntity) -> Self {
         proto::StartEntity {
-            actor_id: self.actor_id.to_string(),
-            recipe: self.recipe.as_ref().clone(),
+            actor_id: msg.actor_id.to_string(),
+            recipe: msg.recipe.as_ref().clone(),
             ..Default::default()
         }
-        .write_to_bytes()
-        .map_err(|_| MessageWrapErr::SerializationErr)
-    }
-
-    fn from_bytes(b: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
-        proto::StartEntity::parse_from_bytes(&b)
-            .map(|r| Self {
-                actor_id: r.actor_id.to_actor_id(),
-                recipe: Arc::new(r.recipe),
-            })
-            .map_err(|_e| MessageUnwrapErr::DeserializationErr)
-    }
-
-    fn read_remote_result(_: Vec<u8>) -> Result<Self::Result, MessageUnwrapErr> {
-        Ok(())
     }
+}
 
-    fn write_remote_result(_res: Self::Result) -> Result<Vec<u8>, MessageWrapErr> {
-        Ok(vec![])
+impl From<proto::StartEntity> for StartEntity {
+    fn from(msg: proto::StartEntity) -> Self {
+        StartEntity {
+            actor_id: msg.actor_id.to_actor_id(),
+            recipe: Arc::new(msg.recipe),
+        }
     }
 }
 
-impl Message for RemoveEntity {
-    type Result = ();
-
-    fn as_bytes(&self) -> Result<Vec<u8>, MessageWrapErr> {
+impl From<&RemoveEntity> for proto::RemoveEntity {
+    fn from(msg: &RemoveEntity) -> Self {
         proto::RemoveEntity {
-            actor_id: self.actor_id.to_string(),
+            actor_id: msg.actor_id.to_string(),
             ..Default::default()
         }
-        .write_to_bytes()
-        .map_err(|_| MessageWrapErr::SerializationErr)
-    }
-
-    fn from_bytes(b: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
-        proto::RemoveEntity::parse_from_bytes(&b)
-            .map(|r| Self {
-                actor_id: r.actor_id.into_actor_id(),
-            })
-            .map_err(|_e| MessageUnwrapErr::DeserializationErr)
-    }
-
-    fn read_remote_result(_: Vec<u8>) -> Result<Self::Result, MessageUnwrapErr> {
-        Ok(())
     }
+}
 
-    fn write_remote_result(_res: Self::Result) -> Result<Vec<u8>, MessageWrapErr> {
-        Ok(vec![])
+impl From<proto::RemoveEntity> for RemoveEntity {
+    fn from(msg: proto::RemoveEntity) -> Self {
+        RemoveEntity {
+            actor_id: msg.actor_id.into_actor_id(),
+        }
     }
 }
 
-impl Message for PassivateEntity {
-    type Result = ();
-
-    fn as_bytes(&self) -> Result<Vec<u8>, MessageWrapErr> {
+impl From<&PassivateEntity> for proto::PassivateEntity {
+    fn from(msg: &PassivateEntity) -> Self {
         proto::PassivateEntity {
-            actor_id: self.actor_id.to_string(),
+            actor_id: msg.actor_id.to_string(),
             ..Default::default()
         }
-        .write_to_bytes()
-        .map_err(|_| MessageWrapErr::SerializationErr)
-    }
-
-    fn from_bytes(b: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
-        proto::PassivateEntity::parse_from_bytes(&b)
-            .map(|r| Self {
-                actor_id: r.actor_id.into_actor_id(),
-            })
-            .map_err(|_e| MessageUnwrapErr::DeserializationErr)
-    }
-
-    fn read_remote_result(_: Vec<u8>) -> Result<Self::Result, MessageUnwrapErr> {
-        Ok(())
     }
+}
 
-    fn write_remote_result(_res: Self::Result) -> Result<Vec<u8>, MessageWrapErr> {
-        Ok(vec![])
+impl From<proto::PassivateEntity> for PassivateEntity {
+    fn from(msg: proto::PassivateEntity) -> Self {
+        PassivateEntity {
+            actor_id: msg.actor_id.into_actor_id(),
+        }
     }
 }
 
-impl Message for ShardStopped {
-    type Result = ();
-
-    fn as_bytes(&self) -> Result<Vec<u8>, MessageWrapErr> {
+impl From<&ShardStopped> for proto::ShardStopped {
+    fn from(msg: &ShardStopped) -> Self {
         proto::ShardStopped {
-            shard_id: self.shard_id,
-            is_successful: self.stopped_successfully,
+            shard_id: msg.shard_id,
+            is_successful: msg.stopped_successfully,
             ..Default::default()
         }
-        .write_to_bytes()
-        .map_err(|_| MessageWrapErr::SerializationErr)
-    }
-
-    fn from_bytes(b: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
-        proto::ShardStopped::parse_from_bytes(&b)
-            .map(|r| Self {
-                shard_id: r.shard_id,
-                stopped_successfully: r.is_successful,
-            })
-            .map_err(|_e| MessageUnwrapErr::DeserializationErr)
-    }
-
-    fn read_remote_result(_: Vec<u8>) -> Result<Self::Result, MessageUnwrapErr> {
-        Ok(())
     }
+}
 
-    fn write_remote_result(_res: Self::Result) -> Result<Vec<u8>, MessageWrapErr> {
-        Ok(vec![])
+impl From<proto::ShardStopped> for ShardStopped {
+    fn from(msg: proto::ShardStopped) -> Self {
+        ShardStopped {
+            shard_id: msg.shard_id,
+            stopped_successfully: msg.is_successful,
+        }
     }
 }
 
@@ -1,5 +1,5 @@
 use crate::actor::context::ActorContext;
-use crate::actor::message::{Envelope, Handler, Message, MessageUnwrapErr, MessageWrapErr};
+use crate::actor::message::{Bytes, Envelope, Handler, Message, MessageUnwrapErr, MessageWrapErr};
 use crate::actor::{Actor, ActorId, ActorRef, ActorRefErr, IntoActorId};
 use crate::remote::cluster::sharding::coordinator::allocation::{
     AllocateShard, AllocateShardResult,
@@ -20,16 +20,16 @@ use uuid::Uuid;
 pub struct EntityRequest {
     pub actor_id: ActorId,
     pub message_type: String,
-    pub message: Vec<u8>,
+    pub message: Bytes,
     pub recipe: Option<Arc<Vec<u8>>>,
-    pub result_channel: Option<Sender<Result<Vec<u8>, ActorRefErr>>>,
+    pub result_channel: Option<Sender<Result<Bytes, ActorRefErr>>>,
 }
 
 pub struct RemoteEntityRequest {
     pub request_id: Uuid,
     pub actor_id: ActorId,
     pub message_type: String,
-    pub message: Vec<u8>,
+    pub message: Bytes,
     pub recipe: Option<Vec<u8>>,
     pub origin_node: NodeId,
 }
@@ -82,9 +82,7 @@ impl Handler<EntityRequest> for ShardHost {
                 message,
                 ctx.system().remote_owned(),
             ));
-        } else if ctx.system().remote().current_leader().is_some() {
-            let leader = self.get_coordinator(&ctx).await;
-
+        } else if let Some(leader) = self.get_coordinator(&ctx).await {
             let buffered_requests = self.requests_pending_shard_allocation.entry(shard_id);
             let buffered_requests = buffered_requests.or_insert_with(|| vec![]);
             buffered_requests.push(message);
@@ -99,15 +97,21 @@ impl Handler<EntityRequest> for ShardHost {
                         rebalancing: false,
                     })
                     .await;
-                if let Ok(AllocateShardResult::Allocated(shard_id, node_id)) = allocation {
-                    let _ = host_ref.notify(ShardAllocated(shard_id, node_id));
+                match allocation {
+                    // Hosts that aren't members of the cluster (cluster clients) never receive the
+                    // allocation broadcast, so they rely on the coordinator's response instead
+                    Ok(AllocateShardResult::Allocated(shard_id, node_id))
+                    | Ok(AllocateShardResult::AlreadyAllocated(shard_id, node_id)) => {
+                        let _ = host_ref.notify(ShardAllocated(shard_id, node_id));
+                    }
+                    _ => {}
                 }
             });
         } else {
             self.requests_pending_leader_allocation.push_back(message);
 
             debug!(
-                "no leader allocated, buffering message (requests_pending_leader_allocation={})",
+                "no coordinator available, buffering message (requests_pending_leader_allocation={})",
                 self.requests_pending_leader_allocation.len()
             );
         }
@@ -218,8 +222,8 @@ impl Message for RemoteEntityRequest {
         .map_err(|_| MessageWrapErr::SerializationErr)
     }
 
-    fn from_bytes(buffer: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
-        proto::RemoteEntityRequest::parse_from_bytes(&buffer).map_or_else(
+    fn from_bytes(buffer: Bytes) -> Result<Self, MessageUnwrapErr> {
+        proto::RemoteEntityRequest::parse_from_tokio_bytes(&buffer).map_or_else(
             |_e| Err(MessageUnwrapErr::DeserializationErr),
             |proto| {
                 Ok(RemoteEntityRequest {
@@ -237,7 +241,7 @@ impl Message for RemoteEntityRequest {
         )
     }
 
-    fn read_remote_result(_buffer: Vec<u8>) -> Result<Self::Result, MessageUnwrapErr> {
+    fn read_remote_result(_buffer: Bytes) -> Result<Self::Result, MessageUnwrapErr> {
         Ok(())
     }
 
@@ -4,12 +4,13 @@ use crate::actor::{
 };
 
 use crate::remote::cluster::sharding::coordinator::allocation::AllocateShard;
+use crate::remote::cluster::sharding::coordinator::balancing::HandoffNodeShards;
 use crate::remote::cluster::sharding::coordinator::spawner::CoordinatorSpawner;
 use crate::remote::cluster::sharding::coordinator::stats::GetShardingStats;
 use crate::remote::cluster::sharding::coordinator::ShardCoordinator;
 use crate::remote::cluster::sharding::host::request::{EntityRequest, RemoteEntityRequest};
 use crate::remote::cluster::sharding::host::{
-    ShardAllocated, ShardAllocator, ShardHost, ShardReallocating, StopShard,
+    PassivateEntity, ShardAllocated, ShardAllocator, ShardHost, ShardReallocating, StopShard,
 };
 use crate::remote::cluster::sharding::shard::stats::GetShardStats;
 use crate::remote::cluster::sharding::shard::Shard;
@@ -50,6 +51,8 @@ impl<A: ActorFactory> Sharding<A> {
         shard_entity: String,
         system: RemoteActorSystem,
         allocator: Option<Box<dyn ShardAllocator>>,
+        role: Option<String>,
+        coordinator_role: Option<String>,
     ) -> Self {
         let coordinator_spawner_actor_id = Some(
             format!(
@@ -71,12 +74,16 @@ impl<A: ActorFactory> Sharding<A> {
         };
 
         let host = ShardHost::new(shard_entity.clone(), actor_handler, allocator)
+            .with_coordinator_role(coordinator_role.clone())
             .into_actor(host_actor_id, system.actor_system())
             .await
             .expect("create ShardHost actor");
 
+        system.register_shard_host(host.clone());
+
         let coordinator_spawner =
             CoordinatorSpawner::new(system.node_id(), shard_entity.clone(), host.clone())
+                .with_roles(role, coordinator_role)
                 .into_actor(coordinator_spawner_actor_id, system.actor_system())
                 .await
                 .expect("create ShardCoordinator spawner");
@@ -164,7 +171,7 @@ impl<A: Actor> Sharded<A> {
         let _res = self.sharding.notify_host(EntityRequest {
             actor_id,
             message_type,
-            message,
+            message: message.into(),
             recipe: self.recipe.clone(),
             result_channel: Some(tx),
         });
@@ -185,11 +192,17 @@ impl<A: Actor> Sharded<A> {
 
 pub fn sharding(builder: &mut RemoteSystemConfigBuilder) -> &mut RemoteSystemConfigBuilder {
     builder
-        .with_handler::<ShardCoordinator, AllocateShard>("ShardCoordinator.AllocateShard")
-        .with_handler::<ShardCoordinator, GetShardingStats>("ShardCoordinator.GetShardingStats")
-        .with_handler::<ShardHost, ShardAllocated>("ShardHost.ShardAllocated")
-        .with_handler::<ShardHost, ShardReallocating>("ShardHost.ShardReallocating")
-        .with_handler::<ShardHost, StopShard>("ShardHost.StopShard")
+        .with_control_handler::<ShardCoordinator, AllocateShard>("ShardCoordinator.AllocateShard")
+        .with_control_handler::<ShardCoordinator, GetShardingStats>(
+            "ShardCoordinator.GetShardingStats",
+        )
+        .with_control_handler::<ShardCoordinator, HandoffNodeShards>(
+            "ShardCoordinator.HandoffNodeShards",
+        )
+        .with_control_handler::<ShardHost, ShardAllocated>("ShardHost.ShardAllocated")
+        .with_control_handler::<ShardHost, ShardReallocating>("ShardHost.ShardReallocating")
+        .with_control_handler::<ShardHost, StopShard>("ShardHost.StopShard")
         .with_handler::<Shard, RemoteEntityRequest>("Shard.RemoteEntityRequest")
         .with_handler::<Shard, GetShardStats>("Shard.GetShardStats")
+        .with_handler::<Shard, PassivateEntity>("Shard.PassivateEntity")
 }
@@ -1155,7 +1155,7 @@ pub struct RemoteEntityRequest {
     // @@protoc_insertion_point(field:coerce.sharding.RemoteEntityRequest.message_type)
     pub message_type: ::std::string::String,
     // @@protoc_insertion_point(field:coerce.sharding.RemoteEntityRequest.message)
-    pub message: ::std::vec::Vec<u8>,
+    pub message: ::bytes::Bytes,
     // @@protoc_insertion_point(field:coerce.sharding.RemoteEntityRequest.recipe)
     pub recipe: ::protobuf::MessageField<remote_entity_request::Recipe>,
     // @@protoc_insertion_point(field:coerce.sharding.RemoteEntityRequest.origin_node)
@@ -1237,7 +1237,7 @@ impl ::protobuf::Message for RemoteEntityRequest {
                     self.message_type = is.read_string()?;
                 },
                 34 => {
-                    self.message = is.read_bytes()?;
+                    self.message = is.read_tokio_bytes()?;
                 },
                 42 => {
                     ::protobuf::rt::read_singular_message_into_field(is, &mut self.recipe)?;
@@ -1331,7 +1331,7 @@ impl ::protobuf::Message for RemoteEntityRequest {
             request_id: ::std::string::String::new(),
             actor_id: ::std::string::String::new(),
             message_type: ::std::string::String::new(),
-            message: ::std::vec::Vec::new(),
+            message: ::bytes::Bytes::new(),
             recipe: ::protobuf::MessageField::none(),
             origin_node: 0,
             special_fields: ::protobuf::SpecialFields::new(),
@@ -2886,6 +2886,253 @@ impl ::protobuf::reflect::ProtobufValue for ShardStats {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.sharding.HandoffNodeShards)
+pub struct HandoffNodeShards {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.sharding.HandoffNodeShards.node_id)
+    pub node_id: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.sharding.HandoffNodeShards.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a HandoffNodeShards {
+    fn default() -> &'a HandoffNodeShards {
+        <HandoffNodeShards as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HandoffNodeShards {
+    pub fn new() -> HandoffNodeShards {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "node_id",
+            |m: &HandoffNodeShards| { &m.node_id },
+            |m: &mut HandoffNodeShards| { &mut m.node_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<HandoffNodeShards>(
+            "HandoffNodeShards",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for HandoffNodeShards {
+    const NAME: &'static str = "HandoffNodeShards";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.node_id = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.node_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.node_id != 0 {
+            os.write_uint64(1, self.node_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> HandoffNodeShards {
+        HandoffNodeShards::new()
+    }
+
+    fn clear(&mut self) {
+        self.node_id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static HandoffNodeShards {
+        static instance: HandoffNodeShards = HandoffNodeShards {
+            node_id: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for HandoffNodeShards {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("HandoffNodeShards").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for HandoffNodeShards {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HandoffNodeShards {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.sharding.NodeShardsHandedOff)
+pub struct NodeShardsHandedOff {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.sharding.NodeShardsHandedOff.shard_ids)
+    pub shard_ids: ::std::vec::Vec<u32>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.sharding.NodeShardsHandedOff.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a NodeShardsHandedOff {
+    fn default() -> &'a NodeShardsHandedOff {
+        <NodeShardsHandedOff as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl NodeShardsHandedOff {
+    pub fn new() -> NodeShardsHandedOff {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "shard_ids",
+            |m: &NodeShardsHandedOff| { &m.shard_ids },
+            |m: &mut NodeShardsHandedOff| { &mut m.shard_ids },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<NodeShardsHandedOff>(
+            "NodeShardsHandedOff",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for NodeShardsHandedOff {
+    const NAME: &'static str = "NodeShardsHandedOff";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    is.read_repeated_packed_uint32_into(&mut self.shard_ids)?;
+                },
+                8 => {
+                    self.shard_ids.push(is.read_uint32()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.shard_ids {
+            my_size += ::protobuf::rt::uint32_size(1, *value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.shard_ids {
+            os.write_uint32(1, *v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> NodeShardsHandedOff {
+        NodeShardsHandedOff::new()
+    }
+
+    fn clear(&mut self) {
+        self.shard_ids.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static NodeShardsHandedOff {
+        static instance: NodeShardsHandedOff = NodeShardsHandedOff {
+            shard_ids: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for NodeShardsHandedOff {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("NodeShardsHandedOff").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for NodeShardsHandedOff {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NodeShardsHandedOff {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
 // @@protoc_insertion_point(enum:coerce.sharding.EntityState)
 pub enum EntityState {
@@ -3053,11 +3300,13 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     hards\x120\n\x05nodes\x18\x04\x20\x03(\x0b2\x1a.coerce.sharding.NodeStat\
     sR\x05nodes\"\x0f\n\rGetShardStats\"\\\n\nShardStats\x12\x19\n\x08shard_\
     id\x18\x01\x20\x01(\rR\x07shardId\x12\x17\n\x07node_id\x18\x02\x20\x01(\
-    \x04R\x06nodeId\x12\x1a\n\x08entities\x18\x03\x20\x03(\tR\x08entities*3\
-    \n\x0bEntityState\x12\x08\n\x04IDLE\x10\0\x12\n\n\x06ACTIVE\x10\x01\x12\
-    \x0e\n\nPASSIVATED\x10\x02*H\n\x0fShardHostStatus\x12\x0b\n\x07UNKNOWN\
-    \x10\0\x12\x0c\n\x08STARTING\x10\x01\x12\t\n\x05READY\x10\x02\x12\x0f\n\
-    \x0bUNAVAILABLE\x10\x03b\x06proto3\
+    \x04R\x06nodeId\x12\x1a\n\x08entities\x18\x03\x20\x03(\tR\x08entities\",\
+    \n\x11HandoffNodeShards\x12\x17\n\x07node_id\x18\x01\x20\x01(\x04R\x06no\
+    deId\"2\n\x13NodeShardsHandedOff\x12\x1b\n\tshard_ids\x18\x01\x20\x03(\r\
+    R\x08shardIds*3\n\x0bEntityState\x12\x08\n\x04IDLE\x10\0\x12\n\n\x06ACTI\
+    VE\x10\x01\x12\x0e\n\nPASSIVATED\x10\x02*H\n\x0fShardHostStatus\x12\x0b\
+    \n\x07UNKNOWN\x10\0\x12\x0c\n\x08STARTING\x10\x01\x12\t\n\x05READY\x10\
+    \x02\x12\x0f\n\x0bUNAVAILABLE\x10\x03b\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -3075,7 +3324,7 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
     file_descriptor.get(|| {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(0);
-            let mut messages = ::std::vec::Vec::with_capacity(19);
+            let mut messages = ::std::vec::Vec::with_capacity(21);
             messages.push(AllocateShard::generated_message_descriptor_data());
             messages.push(RemoteShard::generated_message_descriptor_data());
             messages.push(ShardAllocated::generated_message_descriptor_data());
@@ -3093,6 +3342,8 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(ShardingStats::generated_message_descriptor_data());
             messages.push(GetShardStats::generated_message_descriptor_data());
             messages.push(ShardStats::generated_message_descriptor_data());
+            messages.push(HandoffNodeShards::generated_message_descriptor_data());
+            messages.push(NodeShardsHandedOff::generated_message_descriptor_data());
             messages.push(remote_entity_request::Recipe::generated_message_descriptor_data());
             messages.push(shard_state_snapshot::Entity::generated_message_descriptor_data());
             let mut enums = ::std::vec::Vec::with_capacity(4);
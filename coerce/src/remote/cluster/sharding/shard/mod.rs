@@ -1,5 +1,5 @@
-use crate::actor::context::ActorContext;
-use crate::actor::message::{Envelope, Handler, MessageUnwrapErr, MessageWrapErr};
+use crate::actor::context::{ActorContext, StopReason};
+use crate::actor::message::{Bytes, Envelope, Handler, MessageUnwrapErr, MessageWrapErr};
 use crate::actor::{ActorId, ActorRefErr, BoxedActorRef, CoreActorRef, IntoActorId};
 use crate::persistent::journal::snapshot::Snapshot;
 use crate::persistent::journal::types::JournalTypes;
@@ -262,7 +262,7 @@ impl Handler<EntityRequest> for Shard {
                 let message = message.message;
                 tokio::spawn(async move {
                     handler
-                        .handle_direct(&actor_ref, &message, result_channel)
+                        .handle_direct(&actor_ref, message, result_channel)
                         .await;
                 });
             }
@@ -356,6 +356,35 @@ impl Handler<RemoteEntityRequest> for Shard {
     }
 }
 
+#[async_trait]
+impl Handler<PassivateEntity> for Shard {
+    async fn handle(&mut self, message: PassivateEntity, ctx: &mut ActorContext) {
+        let actor_ref = match self.entities.get(&message.actor_id) {
+            Some(entity) => match entity.status.get_actor_ref() {
+                Some(actor_ref) => actor_ref,
+                None => return,
+            },
+            None => return,
+        };
+
+        if self.persistent_entities {
+            let _persist_res = self.persist(&message, ctx).await;
+        }
+
+        debug!(
+            "passivating entity, actor_id={}, shard_id={}",
+            &message.actor_id, self.shard_id
+        );
+
+        let _ = actor_ref.stop_with_reason(StopReason::Passivated).await;
+
+        // Passivated entities are started again, from their original recipe, on the next request
+        if let Some(entity) = self.entities.get_mut(&message.actor_id) {
+            entity.status = EntityState::Passivated;
+        }
+    }
+}
+
 impl Shard {
     async fn save_snapshot(&self, ctx: &mut ActorContext) -> Result<(), PersistErr> {
         let node_id = ctx.system().remote().node_id();
@@ -442,11 +471,11 @@ impl Snapshot for ShardStateSnapshot {
 
         proto.write_to_bytes().map_or_else(
             |_e| Err(MessageWrapErr::SerializationErr),
-            |s| Ok(Envelope::Remote(s)),
+            |s| Ok(Envelope::Remote(s.into())),
         )
     }
 
-    fn from_remote_envelope(b: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
+    fn from_remote_envelope(b: Bytes) -> Result<Self, MessageUnwrapErr> {
         let proto = proto::ShardStateSnapshot::parse_from_bytes(&b);
 
         proto.map_or_else(
@@ -1,15 +1,17 @@
 use crate::actor::context::ActorContext;
-use crate::actor::message::{Envelope, Handler, Message, MessageUnwrapErr, MessageWrapErr};
+use crate::actor::message::Handler;
 
 use crate::remote::cluster::sharding::coordinator::ShardId;
 
 use crate::remote::cluster::sharding::proto::sharding as proto;
 use crate::remote::cluster::sharding::shard::Shard;
 use crate::remote::system::NodeId;
-use protobuf::Message as ProtoMessage;
+use coerce_macros::ProtoMessage;
 use std::collections::HashSet;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, ProtoMessage)]
+#[proto(proto::GetShardStats)]
+#[result(ShardStats, proto::ShardStats)]
 pub struct GetShardStats;
 
 #[derive(Serialize, Deserialize)]
@@ -33,42 +35,35 @@ impl Handler<GetShardStats> for Shard {
     }
 }
 
-impl Message for GetShardStats {
-    type Result = ShardStats;
-
-    fn as_bytes(&self) -> Result<Vec<u8>, MessageWrapErr> {
-        proto::GetShardStats {
-            ..Default::default()
-        }
-        .write_to_bytes()
-        .map_or_else(|_e| Err(MessageWrapErr::SerializationErr), |m| Ok(m))
+impl From<&GetShardStats> for proto::GetShardStats {
+    fn from(_: &GetShardStats) -> Self {
+        proto::GetShardStats::default()
     }
+}
 
-    fn from_bytes(_: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
-        Ok(Self)
+impl From<proto::GetShardStats> for GetShardStats {
+    fn from(_: proto::GetShardStats) -> Self {
+        GetShardStats
     }
+}
 
-    fn read_remote_result(res: Vec<u8>) -> Result<Self::Result, MessageUnwrapErr> {
-        proto::ShardStats::parse_from_bytes(&res).map_or_else(
-            |_| Err(MessageUnwrapErr::DeserializationErr),
-            |s| {
-                Ok(ShardStats {
-                    shard_id: s.shard_id,
-                    node_id: s.node_id,
-                    entities: s.entities.into_iter().map(|e| e.to_string()).collect(),
-                })
-            },
-        )
+impl From<proto::ShardStats> for ShardStats {
+    fn from(stats: proto::ShardStats) -> Self {
+        ShardStats {
+            shard_id: stats.shard_id,
+            node_id: stats.node_id,
+            entities: stats.entities.into_iter().collect(),
+        }
     }
+}
 
-    fn write_remote_result(res: ShardStats) -> Result<Vec<u8>, MessageWrapErr> {
+impl From<ShardStats> for proto::ShardStats {
+    fn from(stats: ShardStats) -> Self {
         proto::ShardStats {
-            shard_id: res.shard_id,
-            node_id: res.node_id,
-            entities: res.entities.into_iter().map(|e| e.to_string()).collect(),
+            shard_id: stats.shard_id,
+            node_id: stats.node_id,
+            entities: stats.entities.into_iter().collect(),
             ..Default::default()
         }
-        .write_to_bytes()
-        .map_or_else(|_| Err(MessageWrapErr::SerializationErr), |b| Ok(b))
     }
 }
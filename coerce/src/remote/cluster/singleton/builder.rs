@@ -0,0 +1,56 @@
+use crate::actor::ActorFactory;
+use crate::remote::cluster::singleton::proxy::SingletonProxyConfig;
+use crate::remote::cluster::singleton::ClusterSingleton;
+use crate::remote::system::RemoteActorSystem;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+pub struct ClusterSingletonBuilder<F: ActorFactory> {
+    role: Option<String>,
+    proxy_config: SingletonProxyConfig,
+    system: Option<RemoteActorSystem>,
+    _f: PhantomData<F>,
+}
+
+impl<F: ActorFactory> ClusterSingleton<F> {
+    pub fn builder(system: RemoteActorSystem) -> ClusterSingletonBuilder<F> {
+        ClusterSingletonBuilder {
+            role: None,
+            proxy_config: SingletonProxyConfig::default(),
+            system: Some(system),
+            _f: PhantomData,
+        }
+    }
+}
+
+impl<F: ActorFactory> ClusterSingletonBuilder<F> {
+    /// Runs the singleton on the oldest node with the given role, rather than on the leader.
+    pub fn with_role<S: ToString>(&mut self, role: S) -> &mut Self {
+        self.role = Some(role.to_string());
+        self
+    }
+
+    /// Limits the number of messages buffered while the singleton is being handed over.
+    pub fn with_max_buffered_messages(&mut self, max_buffered_messages: usize) -> &mut Self {
+        self.proxy_config.max_buffered_messages = max_buffered_messages;
+        self
+    }
+
+    /// How long a message is buffered for while the singleton is being handed over, before
+    /// failing with `ActorRefErr::Timeout`.
+    pub fn with_buffer_timeout(&mut self, buffer_timeout: Duration) -> &mut Self {
+        self.proxy_config.buffer_timeout = buffer_timeout;
+        self
+    }
+
+    pub async fn build(&mut self, name: impl ToString, recipe: F::Recipe) -> ClusterSingleton<F> {
+        ClusterSingleton::start(
+            name,
+            recipe,
+            self.system.take().unwrap(),
+            self.role.take(),
+            self.proxy_config.clone(),
+        )
+        .await
+    }
+}
@@ -0,0 +1,337 @@
+use crate::actor::context::ActorContext;
+use crate::actor::message::Handler;
+use crate::actor::{Actor, ActorId, ActorRef, BoxedActorRef, CoreActorRef, IntoActorId};
+use crate::remote::actor::BoxedActorHandler;
+use crate::remote::cluster::singleton::message::{
+    LocateSingleton, SingletonLocated, SingletonStarted, StartSingleton, StopSingleton,
+};
+use crate::remote::stream::pubsub::{PubSub, Receive, Subscription};
+use crate::remote::stream::system::{ClusterEvent, SystemEvent, SystemTopic};
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use crate::remote::RemoteActorRef;
+use std::mem;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+
+const SINGLETON_MANAGER: &str = "ClusterSingleton-Manager";
+
+/// How long to wait for the previous node to stop the singleton, before starting it anyway.
+const HANDOVER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Runs on every node, starts the singleton when this node is the leader (or the oldest node
+/// with the singleton's role) and stops it when that's no longer the case.
+pub struct SingletonManager {
+    name: String,
+    node_id: NodeId,
+    role: Option<String>,
+    handler: BoxedActorHandler,
+    recipe: Arc<Vec<u8>>,
+    target_node: Option<NodeId>,
+    instance: Option<BoxedActorRef>,
+    location: watch::Sender<Option<NodeId>>,
+    system_event_subscription: Option<Subscription>,
+    downed: bool,
+}
+
+pub fn singleton_actor_id(name: &str) -> ActorId {
+    format!("ClusterSingleton-{}", name).into_actor_id()
+}
+
+pub fn singleton_manager_actor_id(name: &str, node_id: NodeId) -> ActorId {
+    format!("ClusterSingleton-{}-Manager-{}", name, node_id).into_actor_id()
+}
+
+impl SingletonManager {
+    pub fn new(
+        name: String,
+        node_id: NodeId,
+        role: Option<String>,
+        handler: BoxedActorHandler,
+        recipe: Arc<Vec<u8>>,
+        location: watch::Sender<Option<NodeId>>,
+    ) -> SingletonManager {
+        SingletonManager {
+            name,
+            node_id,
+            role,
+            handler,
+            recipe,
+            target_node: None,
+            instance: None,
+            location,
+            system_event_subscription: None,
+            downed: false,
+        }
+    }
+
+    fn manager_ref(&self, node_id: NodeId, remote: &RemoteActorSystem) -> ActorRef<Self> {
+        RemoteActorRef::<Self>::new(
+            singleton_manager_actor_id(&self.name, node_id),
+            node_id,
+            remote.clone(),
+        )
+        .into()
+    }
+
+    /// Works out which node should be running the singleton, starting or stopping the local
+    /// instance if that has changed.
+    async fn update_target(&mut self, ctx: &mut ActorContext) {
+        if self.downed {
+            return;
+        }
+
+        let remote = ctx.system().remote_owned();
+        let target_node = remote.role_leader(self.role.as_deref());
+
+        if target_node == self.target_node {
+            return;
+        }
+
+        let previous_node = mem::replace(&mut self.target_node, target_node);
+        self.location.send_replace(None);
+
+        debug!(
+            target: SINGLETON_MANAGER,
+            "[node={}] singleton={} moving from node={:?} to node={:?}",
+            self.node_id,
+            &self.name,
+            previous_node,
+            target_node
+        );
+
+        match target_node {
+            Some(target_node) if target_node == self.node_id => {
+                let previous_node =
+                    previous_node.filter(|n| *n != self.node_id && !remote.is_node_terminated(*n));
+
+                match previous_node {
+                    Some(previous_node) => {
+                        let previous_manager = self.manager_ref(previous_node, &remote);
+                        let self_ref = self.actor_ref(ctx);
+                        let source_node_id = self.node_id;
+
+                        tokio::spawn(async move {
+                            let stopped = tokio::time::timeout(
+                                HANDOVER_TIMEOUT,
+                                previous_manager.send(StopSingleton { source_node_id }),
+                            )
+                            .await;
+
+                            if !matches!(stopped, Ok(Ok(_))) {
+                                warn!(
+                                    target: SINGLETON_MANAGER,
+                                    "[node={}] previous singleton node={} didn't confirm it stopped, starting anyway",
+                                    source_node_id,
+                                    previous_node
+                                );
+                            }
+
+                            let _ = self_ref.notify(StartSingleton);
+                        });
+                    }
+
+                    None => self.start_singleton(ctx).await,
+                }
+            }
+
+            target_node => {
+                self.stop_singleton().await;
+
+                if let Some(target_node) = target_node {
+                    let target_manager = self.manager_ref(target_node, &remote);
+                    let self_ref = self.actor_ref(ctx);
+
+                    tokio::spawn(async move {
+                        if let Ok(true) = target_manager.send(LocateSingleton).await {
+                            let _ = self_ref.notify(SingletonLocated(target_node));
+                        }
+                    });
+                }
+            }
+        }
+    }
+
+    async fn start_singleton(&mut self, ctx: &mut ActorContext) {
+        if self.instance.is_some() || self.downed || self.target_node != Some(self.node_id) {
+            return;
+        }
+
+        let instance = self
+            .handler
+            .create(
+                Some(singleton_actor_id(&self.name)),
+                &self.recipe,
+                None,
+                Some(ctx.system()),
+            )
+            .await;
+
+        match instance {
+            Ok(instance) => {
+                info!(
+                    target: SINGLETON_MANAGER,
+                    "[node={}] started singleton={}", self.node_id, &self.name
+                );
+
+                self.instance = Some(instance);
+                self.location.send_replace(Some(self.node_id));
+
+                let remote = ctx.system().remote_owned();
+                let managers: Vec<ActorRef<Self>> = remote
+                    .get_nodes()
+                    .await
+                    .into_iter()
+                    .filter(|n| n.id != self.node_id && !remote.is_node_terminated(n.id))
+                    .map(|n| self.manager_ref(n.id, &remote))
+                    .collect();
+
+                let node_id = self.node_id;
+                tokio::spawn(async move {
+                    for manager in managers {
+                        let _ = manager.notify(SingletonStarted { node_id }).await;
+                    }
+                });
+            }
+            Err(e) => {
+                error!(
+                    target: SINGLETON_MANAGER,
+                    "[node={}] failed to start singleton={}, err={}", self.node_id, &self.name, e
+                );
+            }
+        }
+    }
+
+    async fn stop_singleton(&mut self) -> bool {
+        if let Some(instance) = self.instance.take() {
+            let _ = instance.stop().await;
+
+            info!(
+                target: SINGLETON_MANAGER,
+                "[node={}] stopped singleton={}", self.node_id, &self.name
+            );
+
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[async_trait]
+impl Actor for SingletonManager {
+    async fn started(&mut self, ctx: &mut ActorContext) {
+        self.update_target(ctx).await;
+
+        self.system_event_subscription = Some(
+            PubSub::subscribe::<Self, SystemTopic>(SystemTopic, ctx)
+                .await
+                .unwrap(),
+        );
+    }
+
+    async fn stopped(&mut self, _ctx: &mut ActorContext) {
+        self.stop_singleton().await;
+    }
+}
+
+#[async_trait]
+impl Handler<StartSingleton> for SingletonManager {
+    async fn handle(&mut self, _message: StartSingleton, ctx: &mut ActorContext) {
+        self.start_singleton(ctx).await;
+    }
+}
+
+#[async_trait]
+impl Handler<StopSingleton> for SingletonManager {
+    async fn handle(&mut self, message: StopSingleton, _ctx: &mut ActorContext) -> bool {
+        debug!(
+            target: SINGLETON_MANAGER,
+            "[node={}] node={} is taking over singleton={}",
+            self.node_id,
+            message.source_node_id,
+            &self.name
+        );
+
+        let was_running = self.stop_singleton().await;
+        if *self.location.borrow() == Some(self.node_id) {
+            self.location.send_replace(None);
+        }
+
+        was_running
+    }
+}
+
+#[async_trait]
+impl Handler<LocateSingleton> for SingletonManager {
+    async fn handle(&mut self, _message: LocateSingleton, _ctx: &mut ActorContext) -> bool {
+        self.instance.is_some()
+    }
+}
+
+#[async_trait]
+impl Handler<SingletonStarted> for SingletonManager {
+    async fn handle(&mut self, message: SingletonStarted, ctx: &mut ActorContext) {
+        self.handle(SingletonLocated(message.node_id), ctx).await;
+    }
+}
+
+#[async_trait]
+impl Handler<SingletonLocated> for SingletonManager {
+    async fn handle(&mut self, message: SingletonLocated, _ctx: &mut ActorContext) {
+        // Started notifications from a node this node doesn't consider the target are ignored,
+        // the location is asked for again once this node's view of the cluster catches up.
+        let node_id = message.0;
+        if node_id != self.node_id && self.target_node == Some(node_id) {
+            self.location.send_replace(Some(node_id));
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<Receive<SystemTopic>> for SingletonManager {
+    async fn handle(&mut self, message: Receive<SystemTopic>, ctx: &mut ActorContext) {
+        match message.0.as_ref() {
+            SystemEvent::Cluster(event) => match event {
+                ClusterEvent::NodeAdded(node) => {
+                    if self.instance.is_some() {
+                        let manager = self.manager_ref(node.id, ctx.system().remote());
+                        let node_id = self.node_id;
+                        tokio::spawn(async move {
+                            let _ = manager.notify(SingletonStarted { node_id }).await;
+                        });
+                    }
+                }
+
+                ClusterEvent::NodeRemoved(_) => {}
+
+                ClusterEvent::NodeDowned(node_id) => {
+                    if *node_id == self.node_id {
+                        warn!(
+                            target: SINGLETON_MANAGER,
+                            "[node={}] node downed, no longer running singleton={}",
+                            self.node_id,
+                            &self.name
+                        );
+
+                        self.downed = true;
+                        self.stop_singleton().await;
+                        self.location.send_replace(None);
+                    }
+                }
+
+                ClusterEvent::LeaderChanged(_) | ClusterEvent::LeaderCleared => {
+                    if self.role.is_none() {
+                        self.update_target(ctx).await;
+                    }
+                }
+
+                ClusterEvent::RoleLeaderChanged(role, _) => {
+                    if self.role.as_ref() == Some(role) {
+                        self.update_target(ctx).await;
+                    }
+                }
+            },
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::actor::message::Message;
+use crate::remote::cluster::singleton::proto::singleton as proto;
+use crate::remote::system::NodeId;
+use coerce_macros::ProtoMessage;
+
+/// Broadcast by the manager that started the singleton, to the managers on every other node.
+#[derive(ProtoMessage)]
+#[proto(proto::SingletonStarted)]
+pub struct SingletonStarted {
+    pub node_id: NodeId,
+}
+
+/// Sent by the manager taking over the singleton to the manager of the node that was previously
+/// running it. The singleton is only started once the previous instance has stopped.
+#[derive(ProtoMessage)]
+#[proto(proto::StopSingleton)]
+#[result(bool, proto::SingletonStopped)]
+pub struct StopSingleton {
+    pub source_node_id: NodeId,
+}
+
+/// Asks a manager whether it's running the singleton.
+#[derive(ProtoMessage)]
+#[proto(proto::LocateSingleton)]
+#[result(bool, proto::SingletonLocation)]
+pub struct LocateSingleton;
+
+pub(crate) struct StartSingleton;
+
+pub(crate) struct SingletonLocated(pub NodeId);
+
+impl Message for StartSingleton {
+    type Result = ();
+}
+
+impl Message for SingletonLocated {
+    type Result = ();
+}
+
+impl From<&SingletonStarted> for proto::SingletonStarted {
+    fn from(msg: &SingletonStarted) -> Self {
+        proto::SingletonStarted {
+            node_id: msg.node_id,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::SingletonStarted> for SingletonStarted {
+    fn from(msg: proto::SingletonStarted) -> Self {
+        SingletonStarted {
+            node_id: msg.node_id,
+        }
+    }
+}
+
+impl From<&StopSingleton> for proto::StopSingleton {
+    fn from(msg: &StopSingleton) -> Self {
+        proto::StopSingleton {
+            source_node_id: msg.source_node_id,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::StopSingleton> for StopSingleton {
+    fn from(msg: proto::StopSingleton) -> Self {
+        StopSingleton {
+            source_node_id: msg.source_node_id,
+        }
+    }
+}
+
+impl From<proto::SingletonStopped> for bool {
+    fn from(res: proto::SingletonStopped) -> Self {
+        res.was_running
+    }
+}
+
+impl From<bool> for proto::SingletonStopped {
+    fn from(was_running: bool) -> Self {
+        proto::SingletonStopped {
+            was_running,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&LocateSingleton> for proto::LocateSingleton {
+    fn from(_: &LocateSingleton) -> Self {
+        proto::LocateSingleton::default()
+    }
+}
+
+impl From<proto::LocateSingleton> for LocateSingleton {
+    fn from(_: proto::LocateSingleton) -> Self {
+        LocateSingleton
+    }
+}
+
+impl From<proto::SingletonLocation> for bool {
+    fn from(res: proto::SingletonLocation) -> Self {
+        res.running
+    }
+}
+
+impl From<bool> for proto::SingletonLocation {
+    fn from(running: bool) -> Self {
+        proto::SingletonLocation {
+            running,
+            ..Default::default()
+        }
+    }
+}
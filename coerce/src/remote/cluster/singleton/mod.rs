@@ -0,0 +1,143 @@
+use crate::actor::message::{Handler, Message};
+use crate::actor::{Actor, ActorFactory, ActorRecipe, ActorRefErr, IntoActor, LocalActorRef};
+use crate::remote::cluster::singleton::manager::{singleton_manager_actor_id, SingletonManager};
+use crate::remote::cluster::singleton::message::{
+    LocateSingleton, SingletonStarted, StopSingleton,
+};
+use crate::remote::cluster::singleton::proxy::{
+    singleton_proxy_actor_id, SingletonProxy, SingletonProxyConfig, SingletonRef,
+};
+use crate::remote::system::builder::RemoteSystemConfigBuilder;
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use std::marker::PhantomData;
+use std::sync::Arc;
+use tokio::sync::watch;
+
+pub mod builder;
+pub mod manager;
+pub mod message;
+pub mod proto;
+pub mod proxy;
+
+/// A single instance of an actor, running on the cluster leader, or on the oldest node with the
+/// singleton's role. The singleton is handed over to another node when that changes, messages
+/// sent while it's moving are held by the [`SingletonProxy`] until the new instance has started.
+///
+/// Every node that wants to send messages to the singleton, or that could end up running it,
+/// starts the `ClusterSingleton` with the same name.
+#[derive(Clone)]
+pub struct ClusterSingleton<F: ActorFactory> {
+    core: Arc<SingletonCore>,
+    proxy: SingletonRef<F::Actor>,
+    _f: PhantomData<F>,
+}
+
+struct SingletonCore {
+    name: String,
+    manager: LocalActorRef<SingletonManager>,
+    location: watch::Receiver<Option<NodeId>>,
+    system: RemoteActorSystem,
+}
+
+impl<F: ActorFactory> ClusterSingleton<F> {
+    pub async fn start(
+        name: impl ToString,
+        recipe: F::Recipe,
+        system: RemoteActorSystem,
+        role: Option<String>,
+        proxy_config: SingletonProxyConfig,
+    ) -> Self {
+        let name = name.to_string();
+        let actor_handler = match system.config().actor_handler(F::Actor::type_name()) {
+            None => panic!("failed to initialise singleton={}, factory not found for type={}, please register it via RemoteActorSystemBuilder", &name, F::Actor::type_name()),
+            Some(handler) => handler,
+        };
+
+        let recipe = Arc::new(recipe.write_to_bytes().expect("serialize singleton recipe"));
+        let (location_tx, location) = watch::channel(None);
+
+        let manager = SingletonManager::new(
+            name.clone(),
+            system.node_id(),
+            role,
+            actor_handler,
+            recipe,
+            location_tx,
+        )
+        .into_actor(
+            Some(singleton_manager_actor_id(&name, system.node_id())),
+            system.actor_system(),
+        )
+        .await
+        .expect("create ClusterSingleton manager");
+
+        let proxy = SingletonProxy::<F::Actor>::new(
+            name.clone(),
+            system.clone(),
+            location.clone(),
+            proxy_config,
+        )
+        .into_actor(Some(singleton_proxy_actor_id(&name)), system.actor_system())
+        .await
+        .expect("create ClusterSingleton proxy");
+
+        Self {
+            core: Arc::new(SingletonCore {
+                name,
+                manager,
+                location,
+                system,
+            }),
+            proxy: SingletonRef::new(proxy),
+            _f: PhantomData,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.core.name
+    }
+
+    pub fn system(&self) -> &RemoteActorSystem {
+        &self.core.system
+    }
+
+    pub fn manager(&self) -> &LocalActorRef<SingletonManager> {
+        &self.core.manager
+    }
+
+    /// The node running the singleton, `None` while it's being handed over.
+    pub fn current_node(&self) -> Option<NodeId> {
+        *self.core.location.borrow()
+    }
+
+    /// A reference to the singleton, messages sent through it are buffered by the
+    /// [`SingletonProxy`] while the singleton is moving between nodes.
+    pub fn actor_ref(&self) -> &SingletonRef<F::Actor> {
+        &self.proxy
+    }
+
+    pub async fn send<M: Message>(&self, message: M) -> Result<M::Result, ActorRefErr>
+    where
+        F::Actor: Handler<M>,
+    {
+        self.proxy.send(message).await
+    }
+
+    pub async fn notify<M: Message>(&self, message: M) -> Result<(), ActorRefErr>
+    where
+        F::Actor: Handler<M>,
+    {
+        self.proxy.notify(message).await
+    }
+}
+
+pub fn singleton(builder: &mut RemoteSystemConfigBuilder) -> &mut RemoteSystemConfigBuilder {
+    builder
+        .with_control_handler::<SingletonManager, SingletonStarted>(
+            "SingletonManager.SingletonStarted",
+        )
+        .with_control_handler::<SingletonManager, StopSingleton>("SingletonManager.StopSingleton")
+        .with_control_handler::<SingletonManager, LocateSingleton>(
+            "SingletonManager.LocateSingleton",
+        )
+}
@@ -0,0 +1,659 @@
+// This file is generated by rust-protobuf 3.2.0. Do not edit
+// .proto file is parsed by protoc 3.21.9
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `singleton.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_2_0;
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.singleton.SingletonStarted)
+pub struct SingletonStarted {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.singleton.SingletonStarted.node_id)
+    pub node_id: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.singleton.SingletonStarted.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SingletonStarted {
+    fn default() -> &'a SingletonStarted {
+        <SingletonStarted as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SingletonStarted {
+    pub fn new() -> SingletonStarted {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "node_id",
+            |m: &SingletonStarted| { &m.node_id },
+            |m: &mut SingletonStarted| { &mut m.node_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SingletonStarted>(
+            "SingletonStarted",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SingletonStarted {
+    const NAME: &'static str = "SingletonStarted";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.node_id = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.node_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.node_id != 0 {
+            os.write_uint64(1, self.node_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SingletonStarted {
+        SingletonStarted::new()
+    }
+
+    fn clear(&mut self) {
+        self.node_id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SingletonStarted {
+        static instance: SingletonStarted = SingletonStarted {
+            node_id: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SingletonStarted {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SingletonStarted").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SingletonStarted {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SingletonStarted {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.singleton.StopSingleton)
+pub struct StopSingleton {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.singleton.StopSingleton.source_node_id)
+    pub source_node_id: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.singleton.StopSingleton.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a StopSingleton {
+    fn default() -> &'a StopSingleton {
+        <StopSingleton as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StopSingleton {
+    pub fn new() -> StopSingleton {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "source_node_id",
+            |m: &StopSingleton| { &m.source_node_id },
+            |m: &mut StopSingleton| { &mut m.source_node_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<StopSingleton>(
+            "StopSingleton",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for StopSingleton {
+    const NAME: &'static str = "StopSingleton";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.source_node_id = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.source_node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.source_node_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.source_node_id != 0 {
+            os.write_uint64(1, self.source_node_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> StopSingleton {
+        StopSingleton::new()
+    }
+
+    fn clear(&mut self) {
+        self.source_node_id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static StopSingleton {
+        static instance: StopSingleton = StopSingleton {
+            source_node_id: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for StopSingleton {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("StopSingleton").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for StopSingleton {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StopSingleton {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.singleton.SingletonStopped)
+pub struct SingletonStopped {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.singleton.SingletonStopped.was_running)
+    pub was_running: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.singleton.SingletonStopped.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SingletonStopped {
+    fn default() -> &'a SingletonStopped {
+        <SingletonStopped as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SingletonStopped {
+    pub fn new() -> SingletonStopped {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "was_running",
+            |m: &SingletonStopped| { &m.was_running },
+            |m: &mut SingletonStopped| { &mut m.was_running },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SingletonStopped>(
+            "SingletonStopped",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SingletonStopped {
+    const NAME: &'static str = "SingletonStopped";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.was_running = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.was_running != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.was_running != false {
+            os.write_bool(1, self.was_running)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SingletonStopped {
+        SingletonStopped::new()
+    }
+
+    fn clear(&mut self) {
+        self.was_running = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SingletonStopped {
+        static instance: SingletonStopped = SingletonStopped {
+            was_running: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SingletonStopped {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SingletonStopped").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SingletonStopped {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SingletonStopped {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.singleton.LocateSingleton)
+pub struct LocateSingleton {
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.singleton.LocateSingleton.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LocateSingleton {
+    fn default() -> &'a LocateSingleton {
+        <LocateSingleton as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LocateSingleton {
+    pub fn new() -> LocateSingleton {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LocateSingleton>(
+            "LocateSingleton",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LocateSingleton {
+    const NAME: &'static str = "LocateSingleton";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LocateSingleton {
+        LocateSingleton::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LocateSingleton {
+        static instance: LocateSingleton = LocateSingleton {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for LocateSingleton {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LocateSingleton").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LocateSingleton {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LocateSingleton {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.singleton.SingletonLocation)
+pub struct SingletonLocation {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.singleton.SingletonLocation.running)
+    pub running: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.singleton.SingletonLocation.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SingletonLocation {
+    fn default() -> &'a SingletonLocation {
+        <SingletonLocation as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SingletonLocation {
+    pub fn new() -> SingletonLocation {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "running",
+            |m: &SingletonLocation| { &m.running },
+            |m: &mut SingletonLocation| { &mut m.running },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SingletonLocation>(
+            "SingletonLocation",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SingletonLocation {
+    const NAME: &'static str = "SingletonLocation";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.running = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.running != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.running != false {
+            os.write_bool(1, self.running)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SingletonLocation {
+        SingletonLocation::new()
+    }
+
+    fn clear(&mut self) {
+        self.running = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SingletonLocation {
+        static instance: SingletonLocation = SingletonLocation {
+            running: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SingletonLocation {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SingletonLocation").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SingletonLocation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SingletonLocation {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0fsingleton.proto\x12\x10coerce.singleton\"+\n\x10SingletonStarted\
+    \x12\x17\n\x07node_id\x18\x01\x20\x01(\x04R\x06nodeId\"5\n\rStopSingleto\
+    n\x12$\n\x0esource_node_id\x18\x01\x20\x01(\x04R\x0csourceNodeId\"3\n\
+    \x10SingletonStopped\x12\x1f\n\x0bwas_running\x18\x01\x20\x01(\x08R\nwas\
+    Running\"\x11\n\x0fLocateSingleton\"-\n\x11SingletonLocation\x12\x18\n\
+    \x07running\x18\x01\x20\x01(\x08R\x07runningb\x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(0);
+            let mut messages = ::std::vec::Vec::with_capacity(5);
+            messages.push(SingletonStarted::generated_message_descriptor_data());
+            messages.push(StopSingleton::generated_message_descriptor_data());
+            messages.push(SingletonStopped::generated_message_descriptor_data());
+            messages.push(LocateSingleton::generated_message_descriptor_data());
+            messages.push(SingletonLocation::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(0);
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
@@ -0,0 +1,360 @@
+use crate::actor::context::ActorContext;
+use crate::actor::message::{Handler, Message};
+use crate::actor::{
+    Actor, ActorId, ActorRef, ActorRefErr, IntoActorId, LocalActorRef, ScheduledNotify,
+};
+use crate::remote::cluster::singleton::manager::singleton_actor_id;
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use crate::remote::RemoteActorRef;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use tokio::sync::{oneshot, watch};
+
+const SINGLETON_PROXY: &str = "ClusterSingleton-Proxy";
+
+/// Limits applied to the messages a [`SingletonProxy`] holds while the singleton is being
+/// handed over to another node.
+#[derive(Clone, Debug)]
+pub struct SingletonProxyConfig {
+    /// Messages sent once this many are buffered fail with `ActorRefErr::ActorUnavailable`.
+    pub max_buffered_messages: usize,
+
+    /// Buffered messages fail with `ActorRefErr::Timeout` if the singleton hasn't started within
+    /// this long.
+    pub buffer_timeout: Duration,
+}
+
+impl Default for SingletonProxyConfig {
+    fn default() -> Self {
+        Self {
+            max_buffered_messages: 1_000,
+            buffer_timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Forwards messages to the singleton, wherever it's running. Messages sent while the singleton
+/// is being handed over are buffered, in order, until the new instance has started.
+pub struct SingletonProxy<A: Actor> {
+    name: String,
+    system: RemoteActorSystem,
+    location: watch::Receiver<Option<NodeId>>,
+    config: SingletonProxyConfig,
+    state: ProxyState<A>,
+    expiry: Option<ScheduledNotify<Self, ExpireBuffered>>,
+}
+
+enum ProxyState<A: Actor> {
+    Buffered {
+        requests: VecDeque<BufferedRequest<A>>,
+    },
+    Active {
+        actor_ref: ActorRef<A>,
+    },
+}
+
+struct BufferedRequest<A: Actor> {
+    request: Box<dyn ProxyRequest<A>>,
+    buffered_at: Instant,
+}
+
+/// A handle used to send messages to a singleton through its proxy.
+pub struct SingletonRef<A: Actor> {
+    proxy: LocalActorRef<SingletonProxy<A>>,
+}
+
+pub fn singleton_proxy_actor_id(name: &str) -> ActorId {
+    format!("ClusterSingleton-{}-Proxy", name).into_actor_id()
+}
+
+impl<A: Actor> SingletonProxy<A> {
+    pub fn new(
+        name: String,
+        system: RemoteActorSystem,
+        location: watch::Receiver<Option<NodeId>>,
+        config: SingletonProxyConfig,
+    ) -> SingletonProxy<A> {
+        SingletonProxy {
+            name,
+            system,
+            location,
+            config,
+            state: ProxyState::Buffered {
+                requests: VecDeque::new(),
+            },
+            expiry: None,
+        }
+    }
+
+    async fn resolve(&self, node_id: NodeId) -> Option<ActorRef<A>> {
+        let actor_id = singleton_actor_id(&self.name);
+        if node_id == self.system.node_id() {
+            self.system
+                .actor_system()
+                .get_tracked_actor::<A>(actor_id)
+                .await
+                .map(|actor| actor.into())
+        } else {
+            Some(RemoteActorRef::<A>::new(actor_id, node_id, self.system.clone()).into())
+        }
+    }
+
+    fn schedule_expiry(&mut self, ctx: &ActorContext) {
+        let oldest = match &self.state {
+            ProxyState::Buffered { requests } => requests.front().map(|r| r.buffered_at),
+            ProxyState::Active { .. } => None,
+        };
+
+        let expiry = oldest.map(|buffered_at| {
+            let delay = self
+                .config
+                .buffer_timeout
+                .saturating_sub(buffered_at.elapsed());
+            self.actor_ref(ctx).scheduled_notify(ExpireBuffered, delay)
+        });
+
+        if let Some(previous) = std::mem::replace(&mut self.expiry, expiry) {
+            previous.cancel();
+        }
+    }
+}
+
+#[async_trait]
+impl<A: Actor> Actor for SingletonProxy<A> {
+    async fn started(&mut self, ctx: &mut ActorContext) {
+        let mut location = self.location.clone();
+        let self_ref = self.actor_ref(ctx);
+
+        tokio::spawn(async move {
+            loop {
+                let node_id = *location.borrow_and_update();
+                if self_ref.notify(SingletonMoved(node_id)).is_err()
+                    || location.changed().await.is_err()
+                {
+                    break;
+                }
+            }
+        });
+    }
+
+    async fn stopped(&mut self, _ctx: &mut ActorContext) {
+        if let ProxyState::Buffered { requests } = &mut self.state {
+            for buffered in requests.drain(..) {
+                buffered.request.fail(ActorRefErr::ActorUnavailable);
+            }
+        }
+    }
+}
+
+pub(crate) struct Deliver<M: Message> {
+    message: M,
+    result_channel: ResultChannel<M>,
+}
+
+enum ResultChannel<M: Message> {
+    Send(oneshot::Sender<Result<M::Result, ActorRefErr>>),
+    Notify(oneshot::Sender<Result<(), ActorRefErr>>),
+}
+
+pub(crate) struct SingletonMoved(Option<NodeId>);
+
+pub(crate) struct ExpireBuffered;
+
+impl<M: Message> Deliver<M> {
+    fn fail(self, error: ActorRefErr) {
+        match self.result_channel {
+            ResultChannel::Send(result_channel) => {
+                let _ = result_channel.send(Err(error));
+            }
+
+            ResultChannel::Notify(result_channel) => {
+                let _ = result_channel.send(Err(error));
+            }
+        }
+    }
+}
+
+impl<M: Message> Message for Deliver<M> {
+    type Result = ();
+}
+
+impl Message for SingletonMoved {
+    type Result = ();
+}
+
+impl Message for ExpireBuffered {
+    type Result = ();
+}
+
+#[async_trait]
+trait ProxyRequest<A: Actor>: 'static + Send + Sync {
+    async fn deliver(self: Box<Self>, actor_ref: &ActorRef<A>);
+
+    fn fail(self: Box<Self>, error: ActorRefErr);
+}
+
+#[async_trait]
+impl<A: Actor, M: Message> ProxyRequest<A> for Deliver<M>
+where
+    A: Handler<M>,
+{
+    async fn deliver(self: Box<Self>, actor_ref: &ActorRef<A>) {
+        match self.result_channel {
+            ResultChannel::Send(result_channel) => {
+                let actor_ref = actor_ref.clone();
+                let message = self.message;
+
+                tokio::spawn(async move {
+                    let _ = result_channel.send(actor_ref.send(message).await);
+                });
+            }
+
+            ResultChannel::Notify(result_channel) => {
+                let _ = result_channel.send(actor_ref.notify(self.message).await);
+            }
+        }
+    }
+
+    fn fail(self: Box<Self>, error: ActorRefErr) {
+        Deliver::fail(*self, error)
+    }
+}
+
+#[async_trait]
+impl<A: Actor, M: Message> Handler<Deliver<M>> for SingletonProxy<A>
+where
+    A: Handler<M>,
+{
+    async fn handle(&mut self, message: Deliver<M>, ctx: &mut ActorContext) {
+        let requests = match &mut self.state {
+            ProxyState::Active { actor_ref } => return Box::new(message).deliver(actor_ref).await,
+            ProxyState::Buffered { requests } => requests,
+        };
+
+        if requests.len() >= self.config.max_buffered_messages {
+            warn!(
+                target: SINGLETON_PROXY,
+                "singleton={} unavailable and buffer full (buffered_messages={}), rejecting message",
+                &self.name,
+                requests.len()
+            );
+
+            return message.fail(ActorRefErr::ActorUnavailable);
+        }
+
+        requests.push_back(BufferedRequest {
+            request: Box::new(message),
+            buffered_at: Instant::now(),
+        });
+
+        if requests.len() == 1 {
+            self.schedule_expiry(ctx);
+        }
+    }
+}
+
+#[async_trait]
+impl<A: Actor> Handler<SingletonMoved> for SingletonProxy<A> {
+    async fn handle(&mut self, message: SingletonMoved, ctx: &mut ActorContext) {
+        let actor_ref = match message.0 {
+            Some(node_id) => self.resolve(node_id).await,
+            None => None,
+        };
+
+        match (actor_ref, &mut self.state) {
+            (Some(actor_ref), ProxyState::Buffered { requests }) => {
+                debug!(
+                    target: SINGLETON_PROXY,
+                    "singleton={} available, delivering {} buffered messages",
+                    &self.name,
+                    requests.len()
+                );
+
+                for buffered in requests.drain(..) {
+                    buffered.request.deliver(&actor_ref).await;
+                }
+
+                self.state = ProxyState::Active { actor_ref };
+            }
+
+            (Some(actor_ref), ProxyState::Active { .. }) => {
+                self.state = ProxyState::Active { actor_ref };
+            }
+
+            (None, ProxyState::Active { .. }) => {
+                self.state = ProxyState::Buffered {
+                    requests: VecDeque::new(),
+                };
+            }
+
+            (None, ProxyState::Buffered { .. }) => {}
+        }
+
+        self.schedule_expiry(ctx);
+    }
+}
+
+#[async_trait]
+impl<A: Actor> Handler<ExpireBuffered> for SingletonProxy<A> {
+    async fn handle(&mut self, _message: ExpireBuffered, ctx: &mut ActorContext) {
+        if let ProxyState::Buffered { requests } = &mut self.state {
+            while let Some(buffered) = requests.front() {
+                if buffered.buffered_at.elapsed() < self.config.buffer_timeout {
+                    break;
+                }
+
+                let buffered = requests.pop_front().unwrap();
+                buffered.request.fail(ActorRefErr::Timeout {
+                    time_taken_millis: buffered.buffered_at.elapsed().as_millis() as u64,
+                });
+            }
+        }
+
+        self.schedule_expiry(ctx);
+    }
+}
+
+impl<A: Actor> SingletonRef<A> {
+    pub fn new(proxy: LocalActorRef<SingletonProxy<A>>) -> SingletonRef<A> {
+        SingletonRef { proxy }
+    }
+
+    pub async fn send<M: Message>(&self, message: M) -> Result<M::Result, ActorRefErr>
+    where
+        A: Handler<M>,
+    {
+        let (tx, rx) = oneshot::channel();
+        self.proxy.notify(Deliver {
+            message,
+            result_channel: ResultChannel::Send(tx),
+        })?;
+
+        rx.await.map_err(|_| ActorRefErr::ResultChannelClosed)?
+    }
+
+    /// Resolves once the message has been forwarded to the singleton.
+    pub async fn notify<M: Message>(&self, message: M) -> Result<(), ActorRefErr>
+    where
+        A: Handler<M>,
+    {
+        let (tx, rx) = oneshot::channel();
+        self.proxy.notify(Deliver {
+            message,
+            result_channel: ResultChannel::Notify(tx),
+        })?;
+
+        rx.await.map_err(|_| ActorRefErr::ResultChannelClosed)?
+    }
+
+    pub fn proxy(&self) -> &LocalActorRef<SingletonProxy<A>> {
+        &self.proxy
+    }
+}
+
+impl<A: Actor> Clone for SingletonRef<A> {
+    fn clone(&self) -> Self {
+        SingletonRef {
+            proxy: self.proxy.clone(),
+        }
+    }
+}
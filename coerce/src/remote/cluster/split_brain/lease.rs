@@ -0,0 +1,53 @@
+use crate::remote::system::NodeId;
+use parking_lot::Mutex;
+use std::time::{Duration, Instant};
+
+/// A lease shared by every node in the cluster, backed by something that stays reachable from
+/// both sides of a partition (a database, Kubernetes API etc.). Used by `DowningStrategy::Lease`.
+#[async_trait]
+pub trait SplitBrainLease: 'static + Send + Sync {
+    /// Attempts to acquire the lease on behalf of `owner`, returns true if `owner` now holds the
+    /// lease. Acquiring a lease that is already held by `owner` should succeed.
+    async fn acquire(&self, owner: NodeId) -> bool;
+}
+
+/// An in-process lease, only useful when every node shares the same process (tests, mostly).
+pub struct MemoryLease {
+    ttl: Duration,
+    holder: Mutex<Option<(NodeId, Instant)>>,
+}
+
+impl MemoryLease {
+    pub fn new(ttl: Duration) -> MemoryLease {
+        MemoryLease {
+            ttl,
+            holder: Mutex::new(None),
+        }
+    }
+
+    pub fn holder(&self) -> Option<NodeId> {
+        self.holder
+            .lock()
+            .filter(|(_, acquired_at)| acquired_at.elapsed() < self.ttl)
+            .map(|(owner, _)| owner)
+    }
+}
+
+#[async_trait]
+impl SplitBrainLease for MemoryLease {
+    async fn acquire(&self, owner: NodeId) -> bool {
+        let mut holder = self.holder.lock();
+        let available = match *holder {
+            Some((current_owner, acquired_at)) => {
+                current_owner == owner || acquired_at.elapsed() >= self.ttl
+            }
+            None => true,
+        };
+
+        if available {
+            *holder = Some((owner, Instant::now()));
+        }
+
+        available
+    }
+}
@@ -0,0 +1,251 @@
+use crate::remote::cluster::node::{NodeStatus, RemoteNodeState};
+use crate::remote::system::NodeId;
+use chrono::{DateTime, Utc, MIN_DATETIME};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+pub mod lease;
+
+pub use lease::{MemoryLease, SplitBrainLease};
+
+/// Decides which side of a network partition survives. Without a resolver, each side of a
+/// partition marks the other as terminated and elects its own leader, so the cluster ends up
+/// with two leaders (and two shard coordinators).
+///
+/// When members become unreachable, the resolver waits for the set of unreachable members to stop
+/// changing for `stable_after`, then applies the `strategy`. Nodes on the surviving side down the
+/// unreachable members, nodes on the losing side down themselves and apply the `action`.
+/// Leader changes are held back while a decision is pending.
+#[derive(Clone, Debug)]
+pub struct SplitBrainResolverConfig {
+    pub strategy: DowningStrategy,
+
+    /// What a node on the losing side of a partition does once it has been downed.
+    pub action: DowningAction,
+
+    /// How long the set of unreachable members must be unchanged before a decision is made.
+    pub stable_after: Duration,
+}
+
+#[derive(Clone)]
+pub enum DowningStrategy {
+    /// The side with the most reachable members survives. When both sides are the same size,
+    /// the side containing the member with the lowest node id survives.
+    KeepMajority,
+
+    /// The side containing the oldest member survives. With `down_if_alone`, if the oldest member
+    /// is on its own, it is downed and the other side survives instead.
+    KeepOldest { down_if_alone: bool },
+
+    /// A side survives if it has at least `quorum_size` reachable members, sides without enough
+    /// members are downed. `quorum_size` should be more than half of the expected cluster size.
+    StaticQuorum { quorum_size: usize },
+
+    /// The side that acquires the lease survives, the lease is acquired on behalf of the member
+    /// with the lowest node id on each side.
+    Lease { lease: Arc<dyn SplitBrainLease> },
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DowningAction {
+    /// Shuts down the actor system.
+    Shutdown,
+
+    /// Stops all local shards and shard coordinators, the node stays up but takes no further
+    /// part in leader election.
+    StopHostingShards,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Resolution {
+    /// Every member is reachable.
+    Stable,
+
+    /// There are unreachable members but no decision has been made yet.
+    Pending,
+
+    /// This node is on the surviving side, the unreachable members have been downed.
+    DownUnreachable(Vec<NodeId>),
+
+    /// This node is on the losing side and has been downed.
+    DownSelf,
+}
+
+pub struct SplitBrainResolver {
+    config: SplitBrainResolverConfig,
+    members: HashMap<NodeId, Option<DateTime<Utc>>>,
+    downed_nodes: HashSet<NodeId>,
+    unreachable: BTreeSet<NodeId>,
+    unreachable_since: Option<Instant>,
+    downed: bool,
+}
+
+impl SplitBrainResolver {
+    pub fn new(config: SplitBrainResolverConfig) -> SplitBrainResolver {
+        SplitBrainResolver {
+            config,
+            members: HashMap::new(),
+            downed_nodes: HashSet::new(),
+            unreachable: BTreeSet::new(),
+            unreachable_since: None,
+            downed: false,
+        }
+    }
+
+    pub fn config(&self) -> &SplitBrainResolverConfig {
+        &self.config
+    }
+
+    pub fn is_downed(&self) -> bool {
+        self.downed
+    }
+
+    pub fn is_node_downed(&self, node_id: NodeId) -> bool {
+        self.downed_nodes.contains(&node_id)
+    }
+
+    pub async fn resolve(&mut self, current_node: NodeId, nodes: &[RemoteNodeState]) -> Resolution {
+        if self.downed {
+            return Resolution::DownSelf;
+        }
+
+        let mut reachable = BTreeSet::new();
+        let mut unreachable = BTreeSet::new();
+
+        for node in nodes {
+            if self.downed_nodes.contains(&node.id) {
+                continue;
+            }
+
+            if node.id != current_node && node.status == NodeStatus::Leaving {
+                // Nodes leaving gracefully are no longer members, so they aren't unreachable
+                self.members.remove(&node.id);
+                continue;
+            }
+
+            if node.id == current_node || node.status == NodeStatus::Healthy {
+                self.members.insert(node.id, node.node_started_at);
+                reachable.insert(node.id);
+            } else if node.status != NodeStatus::Joining && self.members.contains_key(&node.id) {
+                // Joining nodes aren't members until they've been seen healthy at least once
+                unreachable.insert(node.id);
+            }
+        }
+
+        if unreachable.is_empty() {
+            self.unreachable.clear();
+            self.unreachable_since = None;
+            return Resolution::Stable;
+        }
+
+        if unreachable != self.unreachable {
+            info!(
+                "[node={}] unreachable members changed to {:?}, reachable members: {:?}",
+                current_node, &unreachable, &reachable
+            );
+
+            self.unreachable = unreachable;
+            self.unreachable_since = Some(Instant::now());
+            return Resolution::Pending;
+        }
+
+        let stable = self
+            .unreachable_since
+            .is_some_and(|since| since.elapsed() >= self.config.stable_after);
+
+        if !stable {
+            return Resolution::Pending;
+        }
+
+        if self.keep_reachable(&reachable).await {
+            let downed: Vec<NodeId> = self.unreachable.iter().copied().collect();
+            warn!(
+                "[node={}] split brain resolved using {:?}, downing unreachable members: {:?}",
+                current_node, &self.config.strategy, &downed
+            );
+
+            for node_id in &downed {
+                self.members.remove(node_id);
+                self.downed_nodes.insert(*node_id);
+            }
+
+            self.unreachable.clear();
+            self.unreachable_since = None;
+            Resolution::DownUnreachable(downed)
+        } else {
+            error!(
+                "[node={}] split brain resolved using {:?}, this node is on the losing side (reachable={:?}, unreachable={:?})",
+                current_node, &self.config.strategy, &reachable, &self.unreachable
+            );
+
+            self.downed = true;
+            Resolution::DownSelf
+        }
+    }
+
+    async fn keep_reachable(&self, reachable: &BTreeSet<NodeId>) -> bool {
+        let unreachable = &self.unreachable;
+
+        match &self.config.strategy {
+            DowningStrategy::KeepMajority => {
+                if reachable.len() != unreachable.len() {
+                    reachable.len() > unreachable.len()
+                } else {
+                    reachable.iter().next() < unreachable.iter().next()
+                }
+            }
+
+            DowningStrategy::KeepOldest { down_if_alone } => {
+                let oldest = self
+                    .members
+                    .iter()
+                    .filter(|(id, _)| reachable.contains(id) || unreachable.contains(id))
+                    .min_by_key(|(id, started_at)| (started_at.unwrap_or(MIN_DATETIME), **id))
+                    .map(|(id, _)| *id);
+
+                match oldest {
+                    Some(oldest) if reachable.contains(&oldest) => {
+                        !(*down_if_alone && reachable.len() == 1 && unreachable.len() > 1)
+                    }
+                    _ => *down_if_alone && unreachable.len() == 1 && reachable.len() > 1,
+                }
+            }
+
+            DowningStrategy::StaticQuorum { quorum_size } => reachable.len() >= *quorum_size,
+
+            DowningStrategy::Lease { lease } => match reachable.iter().next() {
+                Some(owner) => lease.acquire(*owner).await,
+                None => false,
+            },
+        }
+    }
+}
+
+impl fmt::Debug for DowningStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DowningStrategy::KeepMajority => write!(f, "KeepMajority"),
+            DowningStrategy::KeepOldest { down_if_alone } => f
+                .debug_struct("KeepOldest")
+                .field("down_if_alone", down_if_alone)
+                .finish(),
+            DowningStrategy::StaticQuorum { quorum_size } => f
+                .debug_struct("StaticQuorum")
+                .field("quorum_size", quorum_size)
+                .finish(),
+            DowningStrategy::Lease { .. } => write!(f, "Lease"),
+        }
+    }
+}
+
+impl Default for SplitBrainResolverConfig {
+    fn default() -> Self {
+        Self {
+            strategy: DowningStrategy::KeepMajority,
+            action: DowningAction::Shutdown,
+            stable_after: Duration::from_secs(20),
+        }
+    }
+}
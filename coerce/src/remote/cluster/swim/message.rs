@@ -0,0 +1,181 @@
+use crate::actor::message::Message;
+use crate::actor::scheduler::timer::TimerTick;
+use crate::remote::cluster::node::RemoteNode;
+use crate::remote::cluster::swim::proto::swim as proto;
+use crate::remote::cluster::swim::{Member, MemberStatus};
+use crate::remote::net::message::{datetime_to_timestamp, timestamp_to_datetime};
+use crate::remote::system::NodeId;
+use coerce_macros::ProtoMessage;
+
+/// Probes a member directly, the member responds with an `Ack`.
+#[derive(ProtoMessage)]
+#[proto(proto::Ping)]
+pub struct Ping {
+    pub sequence: u64,
+    pub source_node_id: NodeId,
+    pub updates: Vec<Member>,
+}
+
+/// Asks a member to probe `target_node_id` on behalf of the source, used when the source
+/// couldn't reach the target directly. The `Ack` from the target is forwarded to the source.
+#[derive(ProtoMessage)]
+#[proto(proto::PingReq)]
+pub struct PingReq {
+    pub sequence: u64,
+    pub source_node_id: NodeId,
+    pub target_node_id: NodeId,
+    pub updates: Vec<Member>,
+}
+
+#[derive(ProtoMessage)]
+#[proto(proto::Ack)]
+pub struct Ack {
+    pub sequence: u64,
+    pub source_node_id: NodeId,
+    pub updates: Vec<Member>,
+}
+
+#[derive(Clone)]
+pub struct ProbeTick;
+
+pub struct ProbeTimeout(pub u64);
+
+pub struct GetMembers;
+
+impl Message for ProbeTick {
+    type Result = ();
+}
+
+impl TimerTick for ProbeTick {}
+
+impl Message for ProbeTimeout {
+    type Result = ();
+}
+
+impl Message for GetMembers {
+    type Result = Vec<Member>;
+}
+
+impl From<&Ping> for proto::Ping {
+    fn from(msg: &Ping) -> Self {
+        proto::Ping {
+            sequence: msg.sequence,
+            source_node_id: msg.source_node_id,
+            updates: msg.updates.iter().map(|m| m.into()).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::Ping> for Ping {
+    fn from(msg: proto::Ping) -> Self {
+        Ping {
+            sequence: msg.sequence,
+            source_node_id: msg.source_node_id,
+            updates: msg.updates.into_iter().map(|m| m.into()).collect(),
+        }
+    }
+}
+
+impl From<&PingReq> for proto::PingReq {
+    fn from(msg: &PingReq) -> Self {
+        proto::PingReq {
+            sequence: msg.sequence,
+            source_node_id: msg.source_node_id,
+            target_node_id: msg.target_node_id,
+            updates: msg.updates.iter().map(|m| m.into()).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::PingReq> for PingReq {
+    fn from(msg: proto::PingReq) -> Self {
+        PingReq {
+            sequence: msg.sequence,
+            source_node_id: msg.source_node_id,
+            target_node_id: msg.target_node_id,
+            updates: msg.updates.into_iter().map(|m| m.into()).collect(),
+        }
+    }
+}
+
+impl From<&Ack> for proto::Ack {
+    fn from(msg: &Ack) -> Self {
+        proto::Ack {
+            sequence: msg.sequence,
+            source_node_id: msg.source_node_id,
+            updates: msg.updates.iter().map(|m| m.into()).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::Ack> for Ack {
+    fn from(msg: proto::Ack) -> Self {
+        Ack {
+            sequence: msg.sequence,
+            source_node_id: msg.source_node_id,
+            updates: msg.updates.into_iter().map(|m| m.into()).collect(),
+        }
+    }
+}
+
+impl From<&Member> for proto::MemberUpdate {
+    fn from(member: &Member) -> Self {
+        proto::MemberUpdate {
+            node_id: member.node.id,
+            addr: member.node.addr.clone(),
+            tag: member.node.tag.clone(),
+            node_started_at: member
+                .node
+                .node_started_at
+                .as_ref()
+                .map(datetime_to_timestamp)
+                .into(),
+            status: proto::MemberStatus::from(member.status).into(),
+            incarnation: member.incarnation,
+            roles: member.node.roles.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::MemberUpdate> for Member {
+    fn from(update: proto::MemberUpdate) -> Self {
+        Member {
+            node: RemoteNode::new(
+                update.node_id,
+                update.addr,
+                update.tag,
+                update
+                    .node_started_at
+                    .into_option()
+                    .map(timestamp_to_datetime),
+            )
+            .with_roles(update.roles),
+            status: update.status.enum_value_or_default().into(),
+            incarnation: update.incarnation,
+        }
+    }
+}
+
+impl From<MemberStatus> for proto::MemberStatus {
+    fn from(status: MemberStatus) -> Self {
+        match status {
+            MemberStatus::Alive => Self::Alive,
+            MemberStatus::Suspect => Self::Suspect,
+            MemberStatus::Dead => Self::Dead,
+        }
+    }
+}
+
+impl From<proto::MemberStatus> for MemberStatus {
+    fn from(status: proto::MemberStatus) -> Self {
+        match status {
+            proto::MemberStatus::Alive => Self::Alive,
+            proto::MemberStatus::Suspect => Self::Suspect,
+            proto::MemberStatus::Dead => Self::Dead,
+        }
+    }
+}
@@ -0,0 +1,605 @@
+use crate::actor::context::ActorContext;
+use crate::actor::message::{Handler, Message};
+use crate::actor::scheduler::timer::Timer;
+use crate::actor::{Actor, ActorId, ActorRef, IntoActor, IntoActorId, LocalActorRef};
+use crate::remote::actor::message::NodeTerminated;
+use crate::remote::cluster::node::{NodeStatus, RemoteNode};
+use crate::remote::cluster::swim::message::{
+    Ack, GetMembers, Ping, PingReq, ProbeTick, ProbeTimeout,
+};
+use crate::remote::stream::pubsub::PubSub;
+use crate::remote::stream::system::{ClusterEvent, SystemEvent, SystemTopic};
+use crate::remote::system::builder::RemoteSystemConfigBuilder;
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use crate::remote::RemoteActorRef;
+use chrono::Utc;
+use rand::seq::SliceRandom;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+pub mod message;
+pub mod proto;
+
+pub fn swim_actor_id(node_id: NodeId) -> ActorId {
+    format!("SwimMembership-{}", node_id).into_actor_id()
+}
+
+/// Failure detection and membership dissemination based on the SWIM protocol. Each protocol
+/// period, a node probes a single member rather than every node in the cluster. Members that
+/// don't respond are probed indirectly through other members before being suspected, and
+/// suspected members that don't refute the suspicion in time are declared dead. Membership
+/// changes are piggybacked on the probes.
+#[derive(Clone, Debug)]
+pub struct SwimConfig {
+    /// How often a member is probed.
+    pub probe_interval: Duration,
+
+    /// How long to wait for a probed member to respond before asking other members to probe it.
+    pub probe_timeout: Duration,
+
+    /// How many members are asked to probe a member that didn't respond to a direct probe.
+    pub indirect_probes: usize,
+
+    /// How long a member can be suspected before it is declared dead.
+    pub suspicion_timeout: Duration,
+
+    /// The maximum number of membership updates piggybacked on each message.
+    pub max_gossip_updates: usize,
+
+    /// Each membership update is gossiped `retransmit_multiplier * log2(members + 1)` times.
+    pub retransmit_multiplier: usize,
+}
+
+impl Default for SwimConfig {
+    fn default() -> Self {
+        Self {
+            probe_interval: Duration::from_secs(1),
+            probe_timeout: Duration::from_millis(500),
+            indirect_probes: 3,
+            suspicion_timeout: Duration::from_secs(5),
+            max_gossip_updates: 8,
+            retransmit_multiplier: 3,
+        }
+    }
+}
+
+pub(crate) fn swim(builder: &mut RemoteSystemConfigBuilder) -> &mut RemoteSystemConfigBuilder {
+    builder
+        .with_control_handler::<Swim, Ping>("Swim.Ping")
+        .with_control_handler::<Swim, PingReq>("Swim.PingReq")
+        .with_control_handler::<Swim, Ack>("Swim.Ack")
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MemberStatus {
+    Alive,
+    Suspect,
+    Dead,
+}
+
+#[derive(Debug, Clone)]
+pub struct Member {
+    pub node: RemoteNode,
+    pub status: MemberStatus,
+    pub incarnation: u64,
+}
+
+pub struct Swim {
+    system: RemoteActorSystem,
+    config: SwimConfig,
+    node: RemoteNode,
+    incarnation: u64,
+    members: HashMap<NodeId, Member>,
+    suspicions: HashMap<NodeId, Instant>,
+    gossip: HashMap<NodeId, Gossip>,
+    probe_targets: Vec<NodeId>,
+    probes: HashMap<u64, Probe>,
+    forwarded_probes: HashMap<u64, ForwardedProbe>,
+    next_sequence: u64,
+    probe_timer: Option<Timer>,
+}
+
+struct Gossip {
+    update: Member,
+    transmissions: usize,
+}
+
+struct Probe {
+    target: NodeId,
+    indirect: bool,
+}
+
+struct ForwardedProbe {
+    source_node_id: NodeId,
+    sequence: u64,
+    forwarded_at: Instant,
+}
+
+impl Swim {
+    pub async fn start(
+        system: RemoteActorSystem,
+        node: RemoteNode,
+        config: SwimConfig,
+    ) -> LocalActorRef<Swim> {
+        let actor_system = system.actor_system().clone();
+        let actor_id = swim_actor_id(node.id);
+
+        Swim {
+            system,
+            config,
+            node,
+            incarnation: 0,
+            members: HashMap::new(),
+            suspicions: HashMap::new(),
+            gossip: HashMap::new(),
+            probe_targets: vec![],
+            probes: HashMap::new(),
+            forwarded_probes: HashMap::new(),
+            next_sequence: 0,
+            probe_timer: None,
+        }
+        .into_actor(Some(actor_id), &actor_system)
+        .await
+        .expect("create Swim actor")
+    }
+
+    fn member(&self) -> Member {
+        Member {
+            node: self.node.clone(),
+            status: MemberStatus::Alive,
+            incarnation: self.incarnation,
+        }
+    }
+
+    fn next_sequence(&mut self) -> u64 {
+        self.next_sequence += 1;
+        self.next_sequence
+    }
+
+    async fn send<M: 'static + Message<Result = ()> + Sync + Send>(
+        &self,
+        node_id: NodeId,
+        message: M,
+    ) where
+        Swim: Handler<M>,
+    {
+        let actor_ref: ActorRef<Swim> =
+            RemoteActorRef::new(swim_actor_id(node_id), node_id, self.system.clone()).into();
+
+        if let Err(e) = actor_ref.notify(message).await {
+            debug!(
+                "unable to send message to node_id={}, error: {}",
+                node_id, e
+            );
+        }
+    }
+
+    /// Adds any nodes that were registered outside of gossip, for example by node discovery.
+    async fn sync_members(&mut self) {
+        for node in self.system.get_nodes().await {
+            if node.id == self.node.id
+                || node.status == NodeStatus::Terminated
+                || self.members.contains_key(&node.id)
+            {
+                continue;
+            }
+
+            let member = Member {
+                node: RemoteNode::new(node.id, node.addr, node.tag, node.node_started_at)
+                    .with_roles(node.roles),
+                status: MemberStatus::Alive,
+                incarnation: 0,
+            };
+
+            debug!(
+                "[node={}] member joined (id={}, addr={})",
+                self.node.id, member.node.id, &member.node.addr
+            );
+
+            self.update_node_status(member.node.id, NodeStatus::Healthy)
+                .await;
+
+            self.members.insert(member.node.id, member.clone());
+            self.gossip(member);
+        }
+    }
+
+    async fn apply(&mut self, update: Member) {
+        if update.node.id == self.node.id {
+            if update.status != MemberStatus::Alive && update.incarnation >= self.incarnation {
+                // This node's own state is piggybacked on every message, so the refutation
+                // spreads at least as quickly as the suspicion did.
+                self.incarnation = update.incarnation + 1;
+
+                info!(
+                    "[node={}] refuting {:?} (incarnation={})",
+                    self.node.id, update.status, self.incarnation
+                );
+            }
+
+            return;
+        }
+
+        let previous = self.members.get(&update.node.id);
+        let accept = match (previous, update.status) {
+            (None, status) => status == MemberStatus::Alive,
+            (Some(previous), MemberStatus::Alive) => update.incarnation > previous.incarnation,
+            (Some(previous), MemberStatus::Suspect) => match previous.status {
+                MemberStatus::Alive => update.incarnation >= previous.incarnation,
+                MemberStatus::Suspect => update.incarnation > previous.incarnation,
+                MemberStatus::Dead => false,
+            },
+            (Some(previous), MemberStatus::Dead) => {
+                previous.status != MemberStatus::Dead && update.incarnation >= previous.incarnation
+            }
+        };
+
+        if !accept {
+            return;
+        }
+
+        let previous_status = previous.map(|m| m.status);
+        let node_id = update.node.id;
+        match update.status {
+            MemberStatus::Alive => {
+                self.suspicions.remove(&node_id);
+
+                if previous_status.is_none() || previous_status == Some(MemberStatus::Dead) {
+                    self.member_added(&update.node).await;
+                } else {
+                    self.update_node_status(node_id, NodeStatus::Healthy).await;
+                }
+            }
+
+            MemberStatus::Suspect => {
+                warn!(
+                    "[node={}] suspecting member (id={}, incarnation={})",
+                    self.node.id, node_id, update.incarnation
+                );
+
+                self.suspicions.insert(node_id, Instant::now());
+                self.update_node_status(node_id, NodeStatus::Unhealthy)
+                    .await;
+            }
+
+            MemberStatus::Dead => {
+                self.suspicions.remove(&node_id);
+                self.member_removed(&update.node).await;
+            }
+        }
+
+        self.members.insert(node_id, update.clone());
+        self.gossip(update);
+    }
+
+    async fn apply_all(&mut self, updates: Vec<Member>) {
+        for update in updates {
+            self.apply(update).await;
+        }
+    }
+
+    async fn member_added(&self, node: &RemoteNode) {
+        info!(
+            "[node={}] member added (id={}, addr={}, tag={})",
+            self.node.id, node.id, &node.addr, &node.tag
+        );
+
+        self.system.register_node(node.clone()).await;
+        self.update_node_status(node.id, NodeStatus::Healthy).await;
+
+        if self
+            .system
+            .get_remote_client(node.addr.clone())
+            .await
+            .is_none()
+        {
+            warn!("unable to create client for member (addr={})", &node.addr);
+        }
+
+        PubSub::publish_locally(
+            SystemTopic,
+            SystemEvent::Cluster(ClusterEvent::NodeAdded(Arc::new(node.clone()))),
+            &self.system,
+        )
+        .await;
+    }
+
+    async fn member_removed(&self, node: &RemoteNode) {
+        error!(
+            "[node={}] member declared dead (id={}, addr={}, tag={})",
+            self.node.id, node.id, &node.addr, &node.tag
+        );
+
+        let _ = self.system.heartbeat().send(NodeTerminated(node.id)).await;
+
+        PubSub::publish_locally(
+            SystemTopic,
+            SystemEvent::Cluster(ClusterEvent::NodeRemoved(Arc::new(node.clone()))),
+            &self.system,
+        )
+        .await;
+    }
+
+    async fn update_node_status(&self, node_id: NodeId, status: NodeStatus) {
+        let node = self
+            .system
+            .get_nodes()
+            .await
+            .into_iter()
+            .find(|n| n.id == node_id);
+
+        if let Some(mut node) = node {
+            if status == NodeStatus::Healthy {
+                node.last_heartbeat = Some(Utc::now());
+            }
+
+            node.status = status;
+            self.system.update_nodes(vec![node]).await;
+        }
+    }
+
+    async fn set_member_status(&mut self, node_id: NodeId, status: MemberStatus) {
+        if let Some(member) = self.members.get(&node_id) {
+            let update = Member {
+                status,
+                ..member.clone()
+            };
+
+            self.apply(update).await;
+        }
+    }
+
+    fn gossip(&mut self, update: Member) {
+        let transmissions = self.config.retransmit_multiplier
+            * ((self.members.len() + 1) as f64).log2().ceil().max(1.0) as usize;
+
+        self.gossip.insert(
+            update.node.id,
+            Gossip {
+                update,
+                transmissions,
+            },
+        );
+    }
+
+    /// The updates piggybacked on the next message, always starting with this node so that
+    /// members learn about each other as soon as they are probed.
+    fn take_gossip(&mut self) -> Vec<Member> {
+        let mut updates = vec![self.member()];
+
+        let mut gossip: Vec<&mut Gossip> = self.gossip.values_mut().collect();
+        gossip.sort_by_key(|g| std::cmp::Reverse(g.transmissions));
+
+        for gossip in gossip.into_iter().take(self.config.max_gossip_updates) {
+            gossip.transmissions -= 1;
+            updates.push(gossip.update.clone());
+        }
+
+        self.gossip.retain(|_, gossip| gossip.transmissions > 0);
+        updates
+    }
+
+    fn next_probe_target(&mut self) -> Option<NodeId> {
+        if self.probe_targets.is_empty() {
+            // Members are probed in a random order, each member once per round
+            self.probe_targets = self
+                .members
+                .values()
+                .filter(|m| m.status != MemberStatus::Dead)
+                .map(|m| m.node.id)
+                .collect();
+
+            self.probe_targets.shuffle(&mut rand::thread_rng());
+        }
+
+        while let Some(node_id) = self.probe_targets.pop() {
+            if self
+                .members
+                .get(&node_id)
+                .is_some_and(|m| m.status != MemberStatus::Dead)
+            {
+                return Some(node_id);
+            }
+        }
+
+        None
+    }
+}
+
+#[async_trait]
+impl Actor for Swim {
+    async fn started(&mut self, ctx: &mut ActorContext) {
+        debug!(
+            "[node={}] starting SWIM membership (probe_interval={}ms)",
+            self.node.id,
+            self.config.probe_interval.as_millis()
+        );
+
+        self.probe_timer = Some(Timer::start(
+            self.actor_ref(ctx),
+            self.config.probe_interval,
+            ProbeTick,
+        ));
+    }
+
+    async fn stopped(&mut self, _ctx: &mut ActorContext) {
+        if let Some(probe_timer) = self.probe_timer.take() {
+            probe_timer.stop();
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<ProbeTick> for Swim {
+    async fn handle(&mut self, _message: ProbeTick, ctx: &mut ActorContext) {
+        self.sync_members().await;
+
+        // Probes from the last protocol period that weren't acknowledged, directly or indirectly
+        let failed_probes: Vec<NodeId> = self.probes.drain().map(|(_, p)| p.target).collect();
+        for node_id in failed_probes {
+            if self
+                .members
+                .get(&node_id)
+                .map_or(false, |m| m.status == MemberStatus::Alive)
+            {
+                self.set_member_status(node_id, MemberStatus::Suspect).await;
+            }
+        }
+
+        let suspicion_timeout = self.config.suspicion_timeout;
+        let dead_members: Vec<NodeId> = self
+            .suspicions
+            .iter()
+            .filter(|(_, suspected_at)| suspected_at.elapsed() >= suspicion_timeout)
+            .map(|(node_id, _)| *node_id)
+            .collect();
+
+        for node_id in dead_members {
+            self.set_member_status(node_id, MemberStatus::Dead).await;
+        }
+
+        let probe_interval = self.config.probe_interval;
+        self.forwarded_probes
+            .retain(|_, probe| probe.forwarded_at.elapsed() < probe_interval);
+
+        if let Some(target) = self.next_probe_target() {
+            let sequence = self.next_sequence();
+            self.probes.insert(
+                sequence,
+                Probe {
+                    target,
+                    indirect: false,
+                },
+            );
+
+            trace!(
+                "[node={}] probing node_id={} (sequence={})",
+                self.node.id,
+                target,
+                sequence
+            );
+
+            let ping = Ping {
+                sequence,
+                source_node_id: self.node.id,
+                updates: self.take_gossip(),
+            };
+
+            self.send(target, ping).await;
+
+            let _ = self
+                .actor_ref(ctx)
+                .scheduled_notify(ProbeTimeout(sequence), self.config.probe_timeout);
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<ProbeTimeout> for Swim {
+    async fn handle(&mut self, message: ProbeTimeout, _ctx: &mut ActorContext) {
+        let sequence = message.0;
+        let target = match self.probes.get_mut(&sequence) {
+            Some(probe) if !probe.indirect => {
+                probe.indirect = true;
+                probe.target
+            }
+            _ => return,
+        };
+
+        let mut members: Vec<NodeId> = self
+            .members
+            .values()
+            .filter(|m| m.status == MemberStatus::Alive && m.node.id != target)
+            .map(|m| m.node.id)
+            .collect();
+
+        members.shuffle(&mut rand::thread_rng());
+
+        debug!(
+            "[node={}] node_id={} did not respond to probe, probing indirectly via {} members",
+            self.node.id,
+            target,
+            members.len().min(self.config.indirect_probes)
+        );
+
+        for node_id in members.into_iter().take(self.config.indirect_probes) {
+            let ping_req = PingReq {
+                sequence,
+                source_node_id: self.node.id,
+                target_node_id: target,
+                updates: self.take_gossip(),
+            };
+
+            self.send(node_id, ping_req).await;
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<Ping> for Swim {
+    async fn handle(&mut self, message: Ping, _ctx: &mut ActorContext) {
+        self.apply_all(message.updates).await;
+
+        let ack = Ack {
+            sequence: message.sequence,
+            source_node_id: self.node.id,
+            updates: self.take_gossip(),
+        };
+
+        self.send(message.source_node_id, ack).await;
+    }
+}
+
+#[async_trait]
+impl Handler<PingReq> for Swim {
+    async fn handle(&mut self, message: PingReq, _ctx: &mut ActorContext) {
+        self.apply_all(message.updates).await;
+
+        let sequence = self.next_sequence();
+        self.forwarded_probes.insert(
+            sequence,
+            ForwardedProbe {
+                source_node_id: message.source_node_id,
+                sequence: message.sequence,
+                forwarded_at: Instant::now(),
+            },
+        );
+
+        let ping = Ping {
+            sequence,
+            source_node_id: self.node.id,
+            updates: self.take_gossip(),
+        };
+
+        self.send(message.target_node_id, ping).await;
+    }
+}
+
+#[async_trait]
+impl Handler<Ack> for Swim {
+    async fn handle(&mut self, message: Ack, _ctx: &mut ActorContext) {
+        self.apply_all(message.updates).await;
+
+        if self.probes.remove(&message.sequence).is_some() {
+            return;
+        }
+
+        if let Some(probe) = self.forwarded_probes.remove(&message.sequence) {
+            let ack = Ack {
+                sequence: probe.sequence,
+                source_node_id: self.node.id,
+                updates: self.take_gossip(),
+            };
+
+            self.send(probe.source_node_id, ack).await;
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<GetMembers> for Swim {
+    async fn handle(&mut self, _message: GetMembers, _ctx: &mut ActorContext) -> Vec<Member> {
+        self.members.values().cloned().collect()
+    }
+}
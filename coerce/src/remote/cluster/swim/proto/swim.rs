@@ -0,0 +1,866 @@
+// This file is generated by rust-protobuf 3.2.0. Do not edit
+// .proto file is parsed by protoc 3.21.9
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `swim.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_2_0;
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.swim.MemberUpdate)
+pub struct MemberUpdate {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.swim.MemberUpdate.node_id)
+    pub node_id: u64,
+    // @@protoc_insertion_point(field:coerce.swim.MemberUpdate.addr)
+    pub addr: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.swim.MemberUpdate.tag)
+    pub tag: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.swim.MemberUpdate.node_started_at)
+    pub node_started_at: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
+    // @@protoc_insertion_point(field:coerce.swim.MemberUpdate.status)
+    pub status: ::protobuf::EnumOrUnknown<MemberStatus>,
+    // @@protoc_insertion_point(field:coerce.swim.MemberUpdate.incarnation)
+    pub incarnation: u64,
+    // @@protoc_insertion_point(field:coerce.swim.MemberUpdate.roles)
+    pub roles: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.swim.MemberUpdate.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MemberUpdate {
+    fn default() -> &'a MemberUpdate {
+        <MemberUpdate as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MemberUpdate {
+    pub fn new() -> MemberUpdate {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "node_id",
+            |m: &MemberUpdate| { &m.node_id },
+            |m: &mut MemberUpdate| { &mut m.node_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "addr",
+            |m: &MemberUpdate| { &m.addr },
+            |m: &mut MemberUpdate| { &mut m.addr },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tag",
+            |m: &MemberUpdate| { &m.tag },
+            |m: &mut MemberUpdate| { &mut m.tag },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
+            "node_started_at",
+            |m: &MemberUpdate| { &m.node_started_at },
+            |m: &mut MemberUpdate| { &mut m.node_started_at },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status",
+            |m: &MemberUpdate| { &m.status },
+            |m: &mut MemberUpdate| { &mut m.status },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "incarnation",
+            |m: &MemberUpdate| { &m.incarnation },
+            |m: &mut MemberUpdate| { &mut m.incarnation },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "roles",
+            |m: &MemberUpdate| { &m.roles },
+            |m: &mut MemberUpdate| { &mut m.roles },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MemberUpdate>(
+            "MemberUpdate",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MemberUpdate {
+    const NAME: &'static str = "MemberUpdate";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.node_id = is.read_uint64()?;
+                },
+                18 => {
+                    self.addr = is.read_string()?;
+                },
+                26 => {
+                    self.tag = is.read_string()?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.node_started_at)?;
+                },
+                40 => {
+                    self.status = is.read_enum_or_unknown()?;
+                },
+                48 => {
+                    self.incarnation = is.read_uint64()?;
+                },
+                58 => {
+                    self.roles.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.node_id);
+        }
+        if !self.addr.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.addr);
+        }
+        if !self.tag.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.tag);
+        }
+        if let Some(v) = self.node_started_at.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(MemberStatus::Alive) {
+            my_size += ::protobuf::rt::int32_size(5, self.status.value());
+        }
+        if self.incarnation != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.incarnation);
+        }
+        for value in &self.roles {
+            my_size += ::protobuf::rt::string_size(7, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.node_id != 0 {
+            os.write_uint64(1, self.node_id)?;
+        }
+        if !self.addr.is_empty() {
+            os.write_string(2, &self.addr)?;
+        }
+        if !self.tag.is_empty() {
+            os.write_string(3, &self.tag)?;
+        }
+        if let Some(v) = self.node_started_at.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(MemberStatus::Alive) {
+            os.write_enum(5, ::protobuf::EnumOrUnknown::value(&self.status))?;
+        }
+        if self.incarnation != 0 {
+            os.write_uint64(6, self.incarnation)?;
+        }
+        for v in &self.roles {
+            os.write_string(7, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MemberUpdate {
+        MemberUpdate::new()
+    }
+
+    fn clear(&mut self) {
+        self.node_id = 0;
+        self.addr.clear();
+        self.tag.clear();
+        self.node_started_at.clear();
+        self.status = ::protobuf::EnumOrUnknown::new(MemberStatus::Alive);
+        self.incarnation = 0;
+        self.roles.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MemberUpdate {
+        static instance: MemberUpdate = MemberUpdate {
+            node_id: 0,
+            addr: ::std::string::String::new(),
+            tag: ::std::string::String::new(),
+            node_started_at: ::protobuf::MessageField::none(),
+            status: ::protobuf::EnumOrUnknown::from_i32(0),
+            incarnation: 0,
+            roles: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MemberUpdate {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MemberUpdate").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MemberUpdate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MemberUpdate {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.swim.Ping)
+pub struct Ping {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.swim.Ping.sequence)
+    pub sequence: u64,
+    // @@protoc_insertion_point(field:coerce.swim.Ping.source_node_id)
+    pub source_node_id: u64,
+    // @@protoc_insertion_point(field:coerce.swim.Ping.updates)
+    pub updates: ::std::vec::Vec<MemberUpdate>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.swim.Ping.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Ping {
+    fn default() -> &'a Ping {
+        <Ping as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Ping {
+    pub fn new() -> Ping {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sequence",
+            |m: &Ping| { &m.sequence },
+            |m: &mut Ping| { &mut m.sequence },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "source_node_id",
+            |m: &Ping| { &m.source_node_id },
+            |m: &mut Ping| { &mut m.source_node_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "updates",
+            |m: &Ping| { &m.updates },
+            |m: &mut Ping| { &mut m.updates },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Ping>(
+            "Ping",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Ping {
+    const NAME: &'static str = "Ping";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.sequence = is.read_uint64()?;
+                },
+                16 => {
+                    self.source_node_id = is.read_uint64()?;
+                },
+                26 => {
+                    self.updates.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.sequence != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.sequence);
+        }
+        if self.source_node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.source_node_id);
+        }
+        for value in &self.updates {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.sequence != 0 {
+            os.write_uint64(1, self.sequence)?;
+        }
+        if self.source_node_id != 0 {
+            os.write_uint64(2, self.source_node_id)?;
+        }
+        for v in &self.updates {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Ping {
+        Ping::new()
+    }
+
+    fn clear(&mut self) {
+        self.sequence = 0;
+        self.source_node_id = 0;
+        self.updates.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Ping {
+        static instance: Ping = Ping {
+            sequence: 0,
+            source_node_id: 0,
+            updates: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Ping {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Ping").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Ping {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Ping {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.swim.PingReq)
+pub struct PingReq {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.swim.PingReq.sequence)
+    pub sequence: u64,
+    // @@protoc_insertion_point(field:coerce.swim.PingReq.source_node_id)
+    pub source_node_id: u64,
+    // @@protoc_insertion_point(field:coerce.swim.PingReq.target_node_id)
+    pub target_node_id: u64,
+    // @@protoc_insertion_point(field:coerce.swim.PingReq.updates)
+    pub updates: ::std::vec::Vec<MemberUpdate>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.swim.PingReq.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PingReq {
+    fn default() -> &'a PingReq {
+        <PingReq as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PingReq {
+    pub fn new() -> PingReq {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sequence",
+            |m: &PingReq| { &m.sequence },
+            |m: &mut PingReq| { &mut m.sequence },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "source_node_id",
+            |m: &PingReq| { &m.source_node_id },
+            |m: &mut PingReq| { &mut m.source_node_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "target_node_id",
+            |m: &PingReq| { &m.target_node_id },
+            |m: &mut PingReq| { &mut m.target_node_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "updates",
+            |m: &PingReq| { &m.updates },
+            |m: &mut PingReq| { &mut m.updates },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PingReq>(
+            "PingReq",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PingReq {
+    const NAME: &'static str = "PingReq";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.sequence = is.read_uint64()?;
+                },
+                16 => {
+                    self.source_node_id = is.read_uint64()?;
+                },
+                24 => {
+                    self.target_node_id = is.read_uint64()?;
+                },
+                34 => {
+                    self.updates.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.sequence != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.sequence);
+        }
+        if self.source_node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.source_node_id);
+        }
+        if self.target_node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.target_node_id);
+        }
+        for value in &self.updates {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.sequence != 0 {
+            os.write_uint64(1, self.sequence)?;
+        }
+        if self.source_node_id != 0 {
+            os.write_uint64(2, self.source_node_id)?;
+        }
+        if self.target_node_id != 0 {
+            os.write_uint64(3, self.target_node_id)?;
+        }
+        for v in &self.updates {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PingReq {
+        PingReq::new()
+    }
+
+    fn clear(&mut self) {
+        self.sequence = 0;
+        self.source_node_id = 0;
+        self.target_node_id = 0;
+        self.updates.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PingReq {
+        static instance: PingReq = PingReq {
+            sequence: 0,
+            source_node_id: 0,
+            target_node_id: 0,
+            updates: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PingReq {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PingReq").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PingReq {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PingReq {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.swim.Ack)
+pub struct Ack {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.swim.Ack.sequence)
+    pub sequence: u64,
+    // @@protoc_insertion_point(field:coerce.swim.Ack.source_node_id)
+    pub source_node_id: u64,
+    // @@protoc_insertion_point(field:coerce.swim.Ack.updates)
+    pub updates: ::std::vec::Vec<MemberUpdate>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.swim.Ack.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Ack {
+    fn default() -> &'a Ack {
+        <Ack as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Ack {
+    pub fn new() -> Ack {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sequence",
+            |m: &Ack| { &m.sequence },
+            |m: &mut Ack| { &mut m.sequence },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "source_node_id",
+            |m: &Ack| { &m.source_node_id },
+            |m: &mut Ack| { &mut m.source_node_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "updates",
+            |m: &Ack| { &m.updates },
+            |m: &mut Ack| { &mut m.updates },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Ack>(
+            "Ack",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Ack {
+    const NAME: &'static str = "Ack";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.sequence = is.read_uint64()?;
+                },
+                16 => {
+                    self.source_node_id = is.read_uint64()?;
+                },
+                26 => {
+                    self.updates.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.sequence != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.sequence);
+        }
+        if self.source_node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.source_node_id);
+        }
+        for value in &self.updates {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.sequence != 0 {
+            os.write_uint64(1, self.sequence)?;
+        }
+        if self.source_node_id != 0 {
+            os.write_uint64(2, self.source_node_id)?;
+        }
+        for v in &self.updates {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Ack {
+        Ack::new()
+    }
+
+    fn clear(&mut self) {
+        self.sequence = 0;
+        self.source_node_id = 0;
+        self.updates.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Ack {
+        static instance: Ack = Ack {
+            sequence: 0,
+            source_node_id: 0,
+            updates: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Ack {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Ack").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Ack {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Ack {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:coerce.swim.MemberStatus)
+pub enum MemberStatus {
+    // @@protoc_insertion_point(enum_value:coerce.swim.MemberStatus.Alive)
+    Alive = 0,
+    // @@protoc_insertion_point(enum_value:coerce.swim.MemberStatus.Suspect)
+    Suspect = 1,
+    // @@protoc_insertion_point(enum_value:coerce.swim.MemberStatus.Dead)
+    Dead = 2,
+}
+
+impl ::protobuf::Enum for MemberStatus {
+    const NAME: &'static str = "MemberStatus";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<MemberStatus> {
+        match value {
+            0 => ::std::option::Option::Some(MemberStatus::Alive),
+            1 => ::std::option::Option::Some(MemberStatus::Suspect),
+            2 => ::std::option::Option::Some(MemberStatus::Dead),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [MemberStatus] = &[
+        MemberStatus::Alive,
+        MemberStatus::Suspect,
+        MemberStatus::Dead,
+    ];
+}
+
+impl ::protobuf::EnumFull for MemberStatus {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("MemberStatus").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for MemberStatus {
+    fn default() -> Self {
+        MemberStatus::Alive
+    }
+}
+
+impl MemberStatus {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<MemberStatus>("MemberStatus")
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\nswim.proto\x12\x0bcoerce.swim\x1a\x1fgoogle/protobuf/timestamp.proto\
+    \"\xfc\x01\n\x0cMemberUpdate\x12\x17\n\x07node_id\x18\x01\x20\x01(\x04R\
+    \x06nodeId\x12\x12\n\x04addr\x18\x02\x20\x01(\tR\x04addr\x12\x10\n\x03ta\
+    g\x18\x03\x20\x01(\tR\x03tag\x12B\n\x0fnode_started_at\x18\x04\x20\x01(\
+    \x0b2\x1a.google.protobuf.TimestampR\rnodeStartedAt\x121\n\x06status\x18\
+    \x05\x20\x01(\x0e2\x19.coerce.swim.MemberStatusR\x06status\x12\x20\n\x0b\
+    incarnation\x18\x06\x20\x01(\x04R\x0bincarnation\x12\x14\n\x05roles\x18\
+    \x07\x20\x03(\tR\x05roles\"}\n\x04Ping\x12\x1a\n\x08sequence\x18\x01\x20\
+    \x01(\x04R\x08sequence\x12$\n\x0esource_node_id\x18\x02\x20\x01(\x04R\
+    \x0csourceNodeId\x123\n\x07updates\x18\x03\x20\x03(\x0b2\x19.coerce.swim\
+    .MemberUpdateR\x07updates\"\xa6\x01\n\x07PingReq\x12\x1a\n\x08sequence\
+    \x18\x01\x20\x01(\x04R\x08sequence\x12$\n\x0esource_node_id\x18\x02\x20\
+    \x01(\x04R\x0csourceNodeId\x12$\n\x0etarget_node_id\x18\x03\x20\x01(\x04\
+    R\x0ctargetNodeId\x123\n\x07updates\x18\x04\x20\x03(\x0b2\x19.coerce.swi\
+    m.MemberUpdateR\x07updates\"|\n\x03Ack\x12\x1a\n\x08sequence\x18\x01\x20\
+    \x01(\x04R\x08sequence\x12$\n\x0esource_node_id\x18\x02\x20\x01(\x04R\
+    \x0csourceNodeId\x123\n\x07updates\x18\x03\x20\x03(\x0b2\x19.coerce.swim\
+    .MemberUpdateR\x07updates*0\n\x0cMemberStatus\x12\t\n\x05Alive\x10\0\x12\
+    \x0b\n\x07Suspect\x10\x01\x12\x08\n\x04Dead\x10\x02b\x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(1);
+            deps.push(::protobuf::well_known_types::timestamp::file_descriptor().clone());
+            let mut messages = ::std::vec::Vec::with_capacity(4);
+            messages.push(MemberUpdate::generated_message_descriptor_data());
+            messages.push(Ping::generated_message_descriptor_data());
+            messages.push(PingReq::generated_message_descriptor_data());
+            messages.push(Ack::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(1);
+            enums.push(MemberStatus::generated_enum_descriptor_data());
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
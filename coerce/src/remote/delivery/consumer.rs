@@ -0,0 +1,140 @@
+use crate::actor::context::ActorContext;
+use crate::actor::message::Handler;
+use crate::actor::{Actor, ActorId, ActorRef, IntoActorId};
+use crate::remote::delivery::producer::ProducerController;
+use crate::remote::delivery::{Delivery, DeliveryAck, SequenceNr};
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use crate::remote::RemoteActorRef;
+use std::collections::HashMap;
+
+/// Receives messages from one or more `ProducerController`s and passes each of them
+/// to the consumer actor once, in the order they were produced.
+pub struct ConsumerController {
+    consumer: ActorId,
+    producers: HashMap<String, ProducerState>,
+}
+
+struct ProducerState {
+    session_id: String,
+    delivered_sequence: SequenceNr,
+}
+
+impl ConsumerController {
+    pub fn new(consumer: impl IntoActorId) -> ConsumerController {
+        ConsumerController {
+            consumer: consumer.into_actor_id(),
+            producers: HashMap::new(),
+        }
+    }
+}
+
+impl Actor for ConsumerController {}
+
+#[async_trait]
+impl Handler<Delivery> for ConsumerController {
+    async fn handle(&mut self, message: Delivery, ctx: &mut ActorContext) {
+        let remote = ctx.system().remote_owned();
+        let state = self
+            .producers
+            .entry(message.producer_id.clone())
+            .or_insert_with(|| ProducerState {
+                session_id: String::new(),
+                delivered_sequence: 0,
+            });
+
+        if state.session_id != message.session_id {
+            // Either the first message from this producer, or the producer was restarted without
+            // its unconfirmed messages, anything it has already confirmed won't be sent again.
+            debug!(
+                "new producer session (producer_id={}, session_id={}, confirmed_sequence={})",
+                &message.producer_id, &message.session_id, message.confirmed_sequence
+            );
+
+            state.session_id = message.session_id.clone();
+            state.delivered_sequence = message.confirmed_sequence;
+        }
+
+        if message.sequence > state.delivered_sequence + 1 {
+            // An earlier message was lost, wait for the producer to redeliver it
+            trace!(
+                "out of order delivery (producer_id={}, sequence={}, expected={}), discarding",
+                &message.producer_id,
+                message.sequence,
+                state.delivered_sequence + 1
+            );
+
+            return;
+        }
+
+        if message.sequence == state.delivered_sequence + 1 {
+            let result = remote
+                .handle_message(
+                    &message.message_type,
+                    self.consumer.clone(),
+                    message.message.into(),
+                )
+                .await;
+
+            if let Err(e) = result {
+                warn!(
+                    "unable to deliver message (producer_id={}, sequence={}, consumer={}), error: {}",
+                    &message.producer_id, message.sequence, &self.consumer, e
+                );
+
+                return;
+            }
+
+            state.delivered_sequence = message.sequence;
+        } else {
+            trace!(
+                "duplicate delivery (producer_id={}, sequence={}), discarding",
+                &message.producer_id,
+                message.sequence
+            );
+        }
+
+        let ack = DeliveryAck {
+            producer_id: message.producer_id,
+            session_id: message.session_id,
+            confirmed_sequence: state.delivered_sequence,
+        };
+
+        confirm(
+            message.producer_actor_id,
+            message.producer_node_id,
+            ack,
+            &remote,
+        )
+        .await;
+    }
+}
+
+async fn confirm(
+    producer_actor_id: ActorId,
+    producer_node_id: NodeId,
+    ack: DeliveryAck,
+    remote: &RemoteActorSystem,
+) {
+    let producer: ActorRef<ProducerController> = if producer_node_id == remote.node_id() {
+        match remote
+            .actor_system()
+            .get_tracked_actor::<ProducerController>(producer_actor_id.clone())
+            .await
+        {
+            Some(producer) => producer.into(),
+            None => {
+                warn!(
+                    "producer (actor_id={}) not found, unable to confirm delivery",
+                    &producer_actor_id
+                );
+                return;
+            }
+        }
+    } else {
+        RemoteActorRef::new(producer_actor_id, producer_node_id, remote.clone()).into()
+    };
+
+    if let Err(e) = producer.notify(ack).await {
+        debug!("unable to confirm delivery, error: {}", e);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::actor::{ActorId, IntoActorId};
+use crate::remote::delivery::consumer::ConsumerController;
+use crate::remote::delivery::producer::ProducerController;
+use crate::remote::system::builder::RemoteSystemConfigBuilder;
+use crate::remote::system::NodeId;
+use coerce_macros::ProtoMessage;
+
+pub mod consumer;
+pub mod producer;
+pub mod proto;
+
+pub type SequenceNr = u64;
+
+/// A message sent from a producer to a consumer.
+#[derive(ProtoMessage)]
+#[proto(proto::delivery::Delivery)]
+pub struct Delivery {
+    pub producer_id: String,
+    pub producer_actor_id: ActorId,
+    pub producer_node_id: NodeId,
+    pub session_id: String,
+    pub sequence: SequenceNr,
+    pub confirmed_sequence: SequenceNr,
+    pub message_type: String,
+    pub message: Vec<u8>,
+}
+
+/// Confirms every message up to and including `confirmed_sequence` has been handled by the consumer.
+#[derive(ProtoMessage)]
+#[proto(proto::delivery::DeliveryAck)]
+pub struct DeliveryAck {
+    pub producer_id: String,
+    pub session_id: String,
+    pub confirmed_sequence: SequenceNr,
+}
+
+pub fn delivery(builder: &mut RemoteSystemConfigBuilder) -> &mut RemoteSystemConfigBuilder {
+    builder
+        .with_handler::<ConsumerController, Delivery>("ConsumerController.Delivery")
+        .with_handler::<ProducerController, DeliveryAck>("ProducerController.DeliveryAck")
+}
+
+impl From<&Delivery> for proto::delivery::Delivery {
+    fn from(msg: &Delivery) -> Self {
+        proto::delivery::Delivery {
+            producer_id: msg.producer_id.clone(),
+            producer_actor_id: msg.producer_actor_id.to_string(),
+            producer_node_id: msg.producer_node_id,
+            session_id: msg.session_id.clone(),
+            sequence: msg.sequence,
+            confirmed_sequence: msg.confirmed_sequence,
+            message_type: msg.message_type.clone(),
+            message: msg.message.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::delivery::Delivery> for Delivery {
+    fn from(msg: proto::delivery::Delivery) -> Self {
+        Delivery {
+            producer_id: msg.producer_id,
+            producer_actor_id: msg.producer_actor_id.into_actor_id(),
+            producer_node_id: msg.producer_node_id,
+            session_id: msg.session_id,
+            sequence: msg.sequence,
+            confirmed_sequence: msg.confirmed_sequence,
+            message_type: msg.message_type,
+            message: msg.message,
+        }
+    }
+}
+
+impl From<&DeliveryAck> for proto::delivery::DeliveryAck {
+    fn from(msg: &DeliveryAck) -> Self {
+        proto::delivery::DeliveryAck {
+            producer_id: msg.producer_id.clone(),
+            session_id: msg.session_id.clone(),
+            confirmed_sequence: msg.confirmed_sequence,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::delivery::DeliveryAck> for DeliveryAck {
+    fn from(msg: proto::delivery::DeliveryAck) -> Self {
+        DeliveryAck {
+            producer_id: msg.producer_id,
+            session_id: msg.session_id,
+            confirmed_sequence: msg.confirmed_sequence,
+        }
+    }
+}
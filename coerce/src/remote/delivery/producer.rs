@@ -0,0 +1,518 @@
+use crate::actor::context::ActorContext;
+use crate::actor::message::{Handler, Message, MessageWrapErr};
+use crate::actor::scheduler::timer::{Timer, TimerTick};
+use crate::actor::{Actor, ActorRef};
+use crate::persistent::journal::storage::{JournalEntry, JournalStorageRef};
+use crate::persistent::journal::PersistErr;
+use crate::remote::delivery::consumer::ConsumerController;
+use crate::remote::delivery::proto::delivery as proto;
+use crate::remote::delivery::{Delivery, DeliveryAck, SequenceNr};
+use crate::remote::stream::pubsub::{PubSub, Receive, Subscription};
+use crate::remote::stream::system::{ClusterEvent, SystemEvent, SystemTopic};
+use crate::remote::system::RemoteActorSystem;
+use protobuf::Message as ProtoMessage;
+use std::any::TypeId;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+#[derive(Clone, Debug)]
+pub struct ProducerConfig {
+    /// How long to wait for a message to be confirmed before it is redelivered.
+    pub redelivery_interval: Duration,
+
+    /// Persists unconfirmed messages using the actor system's journal storage,
+    /// so they are redelivered if the producer is restarted.
+    pub persistent: bool,
+}
+
+impl Default for ProducerConfig {
+    fn default() -> Self {
+        Self {
+            redelivery_interval: Duration::from_secs(1),
+            persistent: false,
+        }
+    }
+}
+
+/// Sends messages to a `ConsumerController` with at-least-once delivery, keeping each message
+/// until the consumer confirms it has been handled.
+pub struct ProducerController {
+    producer_id: String,
+    consumer: ActorRef<ConsumerController>,
+    config: ProducerConfig,
+    session_id: String,
+    next_sequence: SequenceNr,
+    confirmed_sequence: SequenceNr,
+    unconfirmed: BTreeMap<SequenceNr, UnconfirmedDelivery>,
+    journal: Option<ProducerJournal>,
+    redelivery_timer: Option<Timer>,
+    system_event_subscription: Option<Subscription>,
+}
+
+struct UnconfirmedDelivery {
+    message_type: String,
+    message: Vec<u8>,
+    last_sent: Option<Instant>,
+}
+
+struct ProducerJournal {
+    persistence_id: String,
+    storage: JournalStorageRef,
+    sequence: i64,
+}
+
+impl ProducerController {
+    pub fn new(
+        producer_id: impl ToString,
+        consumer: ActorRef<ConsumerController>,
+        config: ProducerConfig,
+    ) -> ProducerController {
+        ProducerController {
+            producer_id: producer_id.to_string(),
+            consumer,
+            config,
+            session_id: Uuid::new_v4().to_string(),
+            next_sequence: 1,
+            confirmed_sequence: 0,
+            unconfirmed: BTreeMap::new(),
+            journal: None,
+            redelivery_timer: None,
+            system_event_subscription: None,
+        }
+    }
+}
+
+pub struct Deliver {
+    message_type: String,
+    message: Vec<u8>,
+}
+
+impl Deliver {
+    pub fn new<A: Handler<M>, M: Message>(
+        message: M,
+        system: &RemoteActorSystem,
+    ) -> Result<Deliver, DeliveryErr> {
+        let message_type =
+            system
+                .config()
+                .handler_name::<A, M>()
+                .ok_or_else(|| DeliveryErr::NotSupported {
+                    message_type: M::type_name().to_string(),
+                    actor_type: A::type_name().to_string(),
+                })?;
+
+        let message = message.as_bytes().map_err(DeliveryErr::Serialisation)?;
+        Ok(Deliver {
+            message_type,
+            message,
+        })
+    }
+}
+
+impl Message for Deliver {
+    type Result = Result<SequenceNr, DeliveryErr>;
+}
+
+pub struct UnconfirmedCount;
+
+impl Message for UnconfirmedCount {
+    type Result = usize;
+}
+
+#[derive(Clone)]
+struct RedeliveryTick;
+
+impl Message for RedeliveryTick {
+    type Result = ();
+}
+
+impl TimerTick for RedeliveryTick {}
+
+#[derive(Debug)]
+pub enum DeliveryErr {
+    NotSupported {
+        message_type: String,
+        actor_type: String,
+    },
+    Serialisation(MessageWrapErr),
+    Persistence(PersistErr),
+}
+
+impl Display for DeliveryErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeliveryErr::NotSupported {
+                message_type,
+                actor_type,
+            } => write!(
+                f,
+                "no remote handler registered for message={}, actor_type={}",
+                message_type, actor_type
+            ),
+            DeliveryErr::Serialisation(e) => write!(f, "serialisation error ({})", e),
+            DeliveryErr::Persistence(e) => write!(f, "persistence error {}", e),
+        }
+    }
+}
+
+impl Error for DeliveryErr {}
+
+#[async_trait]
+impl Actor for ProducerController {
+    async fn started(&mut self, ctx: &mut ActorContext) {
+        if self.config.persistent {
+            if let Err(e) = self.recover(ctx).await {
+                error!(
+                    "unable to recover unconfirmed messages (producer_id={}), error: {}",
+                    &self.producer_id, e
+                );
+            }
+        }
+
+        self.redelivery_timer = Some(Timer::start(
+            self.actor_ref(ctx),
+            self.config.redelivery_interval,
+            RedeliveryTick,
+        ));
+
+        if let Ok(subscription) = PubSub::subscribe::<Self, SystemTopic>(SystemTopic, ctx).await {
+            self.system_event_subscription = Some(subscription);
+        }
+
+        self.redeliver(true, ctx).await;
+    }
+
+    async fn stopped(&mut self, _ctx: &mut ActorContext) {
+        if let Some(redelivery_timer) = self.redelivery_timer.take() {
+            redelivery_timer.stop();
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<Deliver> for ProducerController {
+    async fn handle(
+        &mut self,
+        message: Deliver,
+        ctx: &mut ActorContext,
+    ) -> Result<SequenceNr, DeliveryErr> {
+        let sequence = self.next_sequence;
+        if let Some(journal) = &mut self.journal {
+            let entry = proto::UnconfirmedDelivery {
+                sequence,
+                message_type: message.message_type.clone(),
+                message: message.message.clone(),
+                ..Default::default()
+            };
+
+            journal
+                .persist("UnconfirmedDelivery", &entry)
+                .await
+                .map_err(DeliveryErr::Persistence)?;
+        }
+
+        self.next_sequence += 1;
+        self.unconfirmed.insert(
+            sequence,
+            UnconfirmedDelivery {
+                message_type: message.message_type,
+                message: message.message,
+                last_sent: None,
+            },
+        );
+
+        self.send(sequence, ctx).await;
+        Ok(sequence)
+    }
+}
+
+#[async_trait]
+impl Handler<DeliveryAck> for ProducerController {
+    async fn handle(&mut self, message: DeliveryAck, _ctx: &mut ActorContext) {
+        if message.session_id != self.session_id
+            || message.confirmed_sequence <= self.confirmed_sequence
+        {
+            return;
+        }
+
+        let confirmed_sequence = message.confirmed_sequence.min(self.next_sequence - 1);
+        self.confirm(confirmed_sequence);
+
+        if let Some(journal) = &mut self.journal {
+            let entry = proto::DeliveryConfirmed {
+                confirmed_sequence,
+                ..Default::default()
+            };
+
+            let mut result = journal.persist("DeliveryConfirmed", &entry).await;
+            if result.is_ok() && self.unconfirmed.is_empty() {
+                // Nothing left to recover, later recoveries can start from here
+                result = journal
+                    .snapshot(&self.session_id, self.next_sequence, confirmed_sequence)
+                    .await;
+            }
+
+            if let Err(e) = result {
+                warn!(
+                    "unable to persist confirmation (producer_id={}, confirmed_sequence={}), error: {}",
+                    &self.producer_id, confirmed_sequence, e
+                );
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<RedeliveryTick> for ProducerController {
+    async fn handle(&mut self, _: RedeliveryTick, ctx: &mut ActorContext) {
+        self.redeliver(false, ctx).await;
+    }
+}
+
+#[async_trait]
+impl Handler<Receive<SystemTopic>> for ProducerController {
+    async fn handle(&mut self, event: Receive<SystemTopic>, ctx: &mut ActorContext) {
+        let consumer_node_added = matches!(
+            event.0.as_ref(),
+            SystemEvent::Cluster(ClusterEvent::NodeAdded(node)) if self.consumer.node_id() == Some(node.id)
+        );
+
+        if consumer_node_added && !self.unconfirmed.is_empty() {
+            debug!(
+                "consumer node joined, redelivering {} unconfirmed message(s) (producer_id={})",
+                self.unconfirmed.len(),
+                &self.producer_id
+            );
+
+            self.redeliver(true, ctx).await;
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<UnconfirmedCount> for ProducerController {
+    async fn handle(&mut self, _: UnconfirmedCount, _ctx: &mut ActorContext) -> usize {
+        self.unconfirmed.len()
+    }
+}
+
+impl ProducerController {
+    async fn send(&mut self, sequence: SequenceNr, ctx: &ActorContext) {
+        let unconfirmed = match self.unconfirmed.get_mut(&sequence) {
+            Some(unconfirmed) => unconfirmed,
+            None => return,
+        };
+
+        let delivery = Delivery {
+            producer_id: self.producer_id.clone(),
+            producer_actor_id: ctx.id().clone(),
+            producer_node_id: ctx.system().remote().node_id(),
+            session_id: self.session_id.clone(),
+            sequence,
+            confirmed_sequence: self.confirmed_sequence,
+            message_type: unconfirmed.message_type.clone(),
+            message: unconfirmed.message.clone(),
+        };
+
+        unconfirmed.last_sent = Some(Instant::now());
+        if let Err(e) = self.consumer.notify(delivery).await {
+            trace!(
+                "unable to deliver message (producer_id={}, sequence={}), error: {}",
+                &self.producer_id,
+                sequence,
+                e
+            );
+        }
+    }
+
+    async fn redeliver(&mut self, all: bool, ctx: &ActorContext) {
+        let redelivery_interval = self.config.redelivery_interval;
+        let pending: Vec<SequenceNr> = self
+            .unconfirmed
+            .iter()
+            .filter(|(_, unconfirmed)| {
+                all || unconfirmed
+                    .last_sent
+                    .is_none_or(|last_sent| last_sent.elapsed() >= redelivery_interval)
+            })
+            .map(|(sequence, _)| *sequence)
+            .collect();
+
+        if !pending.is_empty() {
+            trace!(
+                "redelivering {} message(s) (producer_id={})",
+                pending.len(),
+                &self.producer_id
+            );
+        }
+
+        for sequence in pending {
+            self.send(sequence, ctx).await;
+        }
+    }
+
+    fn confirm(&mut self, confirmed_sequence: SequenceNr) {
+        self.unconfirmed = self.unconfirmed.split_off(&(confirmed_sequence + 1));
+        self.confirmed_sequence = confirmed_sequence;
+    }
+
+    async fn recover(&mut self, ctx: &ActorContext) -> Result<(), PersistErr> {
+        let storage = ctx.system().persistence().and_then(|persistence| {
+            persistence
+                .provider(TypeId::of::<ProducerController>())
+                .journal_storage()
+        });
+
+        let storage = match storage {
+            Some(storage) => storage,
+            None => {
+                warn!(
+                    "no journal storage configured, unconfirmed messages will not be persisted (producer_id={})",
+                    &self.producer_id
+                );
+                return Ok(());
+            }
+        };
+
+        let persistence_id = format!("ProducerController-{}", &self.producer_id);
+        let snapshot = storage.read_latest_snapshot(&persistence_id).await?;
+
+        let mut journal = ProducerJournal {
+            persistence_id,
+            storage,
+            sequence: 0,
+        };
+
+        match snapshot {
+            Some(snapshot) => {
+                let snapshot = proto::ProducerSnapshot::parse_from_bytes(&snapshot.bytes)
+                    .map_err(|e| PersistErr::Storage(e.into()))?;
+
+                self.session_id = snapshot.session_id;
+                self.next_sequence = snapshot.next_sequence;
+                self.confirmed_sequence = snapshot.confirmed_sequence;
+                journal.sequence = snapshot.journal_sequence;
+            }
+            None => {
+                // Persist the session so the consumer continues to de-duplicate after a restart
+                journal
+                    .snapshot(
+                        &self.session_id,
+                        self.next_sequence,
+                        self.confirmed_sequence,
+                    )
+                    .await?;
+            }
+        }
+
+        let entries = journal
+            .storage
+            .read_latest_messages(&journal.persistence_id, journal.sequence)
+            .await?
+            .unwrap_or_default();
+
+        for entry in entries {
+            journal.sequence = entry.sequence;
+            match entry.payload_type.as_str() {
+                "UnconfirmedDelivery" => {
+                    let delivery = proto::UnconfirmedDelivery::parse_from_bytes(&entry.bytes)
+                        .map_err(|e| PersistErr::Storage(e.into()))?;
+
+                    self.next_sequence = self.next_sequence.max(delivery.sequence + 1);
+                    if delivery.sequence > self.confirmed_sequence {
+                        self.unconfirmed.insert(
+                            delivery.sequence,
+                            UnconfirmedDelivery {
+                                message_type: delivery.message_type,
+                                message: delivery.message,
+                                last_sent: None,
+                            },
+                        );
+                    }
+                }
+                "DeliveryConfirmed" => {
+                    let confirmed = proto::DeliveryConfirmed::parse_from_bytes(&entry.bytes)
+                        .map_err(|e| PersistErr::Storage(e.into()))?;
+
+                    self.confirm(confirmed.confirmed_sequence);
+                }
+                payload_type => {
+                    warn!(
+                        "unknown journal entry (producer_id={}, payload_type={})",
+                        &self.producer_id, payload_type
+                    );
+                }
+            }
+        }
+
+        debug!(
+            "recovered producer (producer_id={}, unconfirmed={}, next_sequence={})",
+            &self.producer_id,
+            self.unconfirmed.len(),
+            self.next_sequence
+        );
+
+        self.journal = Some(journal);
+        Ok(())
+    }
+}
+
+impl ProducerJournal {
+    async fn persist<M: ProtoMessage>(
+        &mut self,
+        payload_type: &str,
+        entry: &M,
+    ) -> Result<(), PersistErr> {
+        let bytes = entry
+            .write_to_bytes()
+            .map_err(|e| PersistErr::Storage(e.into()))?;
+
+        let sequence = self.sequence + 1;
+        self.storage
+            .write_message(
+                &self.persistence_id,
+                JournalEntry {
+                    sequence,
+                    payload_type: payload_type.to_string(),
+                    bytes,
+                },
+            )
+            .await?;
+
+        self.sequence = sequence;
+        Ok(())
+    }
+
+    async fn snapshot(
+        &mut self,
+        session_id: &str,
+        next_sequence: SequenceNr,
+        confirmed_sequence: SequenceNr,
+    ) -> Result<(), PersistErr> {
+        let bytes = proto::ProducerSnapshot {
+            session_id: session_id.to_string(),
+            next_sequence,
+            confirmed_sequence,
+            journal_sequence: self.sequence,
+            ..Default::default()
+        }
+        .write_to_bytes()
+        .map_err(|e| PersistErr::Storage(e.into()))?;
+
+        self.storage
+            .write_snapshot(
+                &self.persistence_id,
+                JournalEntry {
+                    sequence: self.sequence,
+                    payload_type: "ProducerSnapshot".to_string(),
+                    bytes,
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,943 @@
+// This file is generated by rust-protobuf 3.2.0. Do not edit
+// .proto file is parsed by protoc 3.21.9
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `delivery.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_2_0;
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.delivery.Delivery)
+pub struct Delivery {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.delivery.Delivery.producer_id)
+    pub producer_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.delivery.Delivery.producer_actor_id)
+    pub producer_actor_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.delivery.Delivery.producer_node_id)
+    pub producer_node_id: u64,
+    // @@protoc_insertion_point(field:coerce.delivery.Delivery.session_id)
+    pub session_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.delivery.Delivery.sequence)
+    pub sequence: u64,
+    // @@protoc_insertion_point(field:coerce.delivery.Delivery.confirmed_sequence)
+    pub confirmed_sequence: u64,
+    // @@protoc_insertion_point(field:coerce.delivery.Delivery.message_type)
+    pub message_type: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.delivery.Delivery.message)
+    pub message: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.delivery.Delivery.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Delivery {
+    fn default() -> &'a Delivery {
+        <Delivery as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Delivery {
+    pub fn new() -> Delivery {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(8);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "producer_id",
+            |m: &Delivery| { &m.producer_id },
+            |m: &mut Delivery| { &mut m.producer_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "producer_actor_id",
+            |m: &Delivery| { &m.producer_actor_id },
+            |m: &mut Delivery| { &mut m.producer_actor_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "producer_node_id",
+            |m: &Delivery| { &m.producer_node_id },
+            |m: &mut Delivery| { &mut m.producer_node_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "session_id",
+            |m: &Delivery| { &m.session_id },
+            |m: &mut Delivery| { &mut m.session_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sequence",
+            |m: &Delivery| { &m.sequence },
+            |m: &mut Delivery| { &mut m.sequence },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "confirmed_sequence",
+            |m: &Delivery| { &m.confirmed_sequence },
+            |m: &mut Delivery| { &mut m.confirmed_sequence },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message_type",
+            |m: &Delivery| { &m.message_type },
+            |m: &mut Delivery| { &mut m.message_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message",
+            |m: &Delivery| { &m.message },
+            |m: &mut Delivery| { &mut m.message },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Delivery>(
+            "Delivery",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Delivery {
+    const NAME: &'static str = "Delivery";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.producer_id = is.read_string()?;
+                },
+                18 => {
+                    self.producer_actor_id = is.read_string()?;
+                },
+                24 => {
+                    self.producer_node_id = is.read_uint64()?;
+                },
+                34 => {
+                    self.session_id = is.read_string()?;
+                },
+                40 => {
+                    self.sequence = is.read_uint64()?;
+                },
+                48 => {
+                    self.confirmed_sequence = is.read_uint64()?;
+                },
+                58 => {
+                    self.message_type = is.read_string()?;
+                },
+                66 => {
+                    self.message = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.producer_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.producer_id);
+        }
+        if !self.producer_actor_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.producer_actor_id);
+        }
+        if self.producer_node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.producer_node_id);
+        }
+        if !self.session_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.session_id);
+        }
+        if self.sequence != 0 {
+            my_size += ::protobuf::rt::uint64_size(5, self.sequence);
+        }
+        if self.confirmed_sequence != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.confirmed_sequence);
+        }
+        if !self.message_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.message_type);
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(8, &self.message);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.producer_id.is_empty() {
+            os.write_string(1, &self.producer_id)?;
+        }
+        if !self.producer_actor_id.is_empty() {
+            os.write_string(2, &self.producer_actor_id)?;
+        }
+        if self.producer_node_id != 0 {
+            os.write_uint64(3, self.producer_node_id)?;
+        }
+        if !self.session_id.is_empty() {
+            os.write_string(4, &self.session_id)?;
+        }
+        if self.sequence != 0 {
+            os.write_uint64(5, self.sequence)?;
+        }
+        if self.confirmed_sequence != 0 {
+            os.write_uint64(6, self.confirmed_sequence)?;
+        }
+        if !self.message_type.is_empty() {
+            os.write_string(7, &self.message_type)?;
+        }
+        if !self.message.is_empty() {
+            os.write_bytes(8, &self.message)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Delivery {
+        Delivery::new()
+    }
+
+    fn clear(&mut self) {
+        self.producer_id.clear();
+        self.producer_actor_id.clear();
+        self.producer_node_id = 0;
+        self.session_id.clear();
+        self.sequence = 0;
+        self.confirmed_sequence = 0;
+        self.message_type.clear();
+        self.message.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Delivery {
+        static instance: Delivery = Delivery {
+            producer_id: ::std::string::String::new(),
+            producer_actor_id: ::std::string::String::new(),
+            producer_node_id: 0,
+            session_id: ::std::string::String::new(),
+            sequence: 0,
+            confirmed_sequence: 0,
+            message_type: ::std::string::String::new(),
+            message: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Delivery {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Delivery").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Delivery {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Delivery {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.delivery.DeliveryAck)
+pub struct DeliveryAck {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.delivery.DeliveryAck.producer_id)
+    pub producer_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.delivery.DeliveryAck.session_id)
+    pub session_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.delivery.DeliveryAck.confirmed_sequence)
+    pub confirmed_sequence: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.delivery.DeliveryAck.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DeliveryAck {
+    fn default() -> &'a DeliveryAck {
+        <DeliveryAck as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeliveryAck {
+    pub fn new() -> DeliveryAck {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "producer_id",
+            |m: &DeliveryAck| { &m.producer_id },
+            |m: &mut DeliveryAck| { &mut m.producer_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "session_id",
+            |m: &DeliveryAck| { &m.session_id },
+            |m: &mut DeliveryAck| { &mut m.session_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "confirmed_sequence",
+            |m: &DeliveryAck| { &m.confirmed_sequence },
+            |m: &mut DeliveryAck| { &mut m.confirmed_sequence },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeliveryAck>(
+            "DeliveryAck",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DeliveryAck {
+    const NAME: &'static str = "DeliveryAck";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.producer_id = is.read_string()?;
+                },
+                18 => {
+                    self.session_id = is.read_string()?;
+                },
+                24 => {
+                    self.confirmed_sequence = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.producer_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.producer_id);
+        }
+        if !self.session_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.session_id);
+        }
+        if self.confirmed_sequence != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.confirmed_sequence);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.producer_id.is_empty() {
+            os.write_string(1, &self.producer_id)?;
+        }
+        if !self.session_id.is_empty() {
+            os.write_string(2, &self.session_id)?;
+        }
+        if self.confirmed_sequence != 0 {
+            os.write_uint64(3, self.confirmed_sequence)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DeliveryAck {
+        DeliveryAck::new()
+    }
+
+    fn clear(&mut self) {
+        self.producer_id.clear();
+        self.session_id.clear();
+        self.confirmed_sequence = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DeliveryAck {
+        static instance: DeliveryAck = DeliveryAck {
+            producer_id: ::std::string::String::new(),
+            session_id: ::std::string::String::new(),
+            confirmed_sequence: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DeliveryAck {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeliveryAck").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DeliveryAck {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeliveryAck {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.delivery.UnconfirmedDelivery)
+pub struct UnconfirmedDelivery {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.delivery.UnconfirmedDelivery.sequence)
+    pub sequence: u64,
+    // @@protoc_insertion_point(field:coerce.delivery.UnconfirmedDelivery.message_type)
+    pub message_type: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.delivery.UnconfirmedDelivery.message)
+    pub message: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.delivery.UnconfirmedDelivery.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a UnconfirmedDelivery {
+    fn default() -> &'a UnconfirmedDelivery {
+        <UnconfirmedDelivery as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UnconfirmedDelivery {
+    pub fn new() -> UnconfirmedDelivery {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sequence",
+            |m: &UnconfirmedDelivery| { &m.sequence },
+            |m: &mut UnconfirmedDelivery| { &mut m.sequence },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message_type",
+            |m: &UnconfirmedDelivery| { &m.message_type },
+            |m: &mut UnconfirmedDelivery| { &mut m.message_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message",
+            |m: &UnconfirmedDelivery| { &m.message },
+            |m: &mut UnconfirmedDelivery| { &mut m.message },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<UnconfirmedDelivery>(
+            "UnconfirmedDelivery",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for UnconfirmedDelivery {
+    const NAME: &'static str = "UnconfirmedDelivery";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.sequence = is.read_uint64()?;
+                },
+                18 => {
+                    self.message_type = is.read_string()?;
+                },
+                26 => {
+                    self.message = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.sequence != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.sequence);
+        }
+        if !self.message_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.message_type);
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.message);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.sequence != 0 {
+            os.write_uint64(1, self.sequence)?;
+        }
+        if !self.message_type.is_empty() {
+            os.write_string(2, &self.message_type)?;
+        }
+        if !self.message.is_empty() {
+            os.write_bytes(3, &self.message)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> UnconfirmedDelivery {
+        UnconfirmedDelivery::new()
+    }
+
+    fn clear(&mut self) {
+        self.sequence = 0;
+        self.message_type.clear();
+        self.message.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static UnconfirmedDelivery {
+        static instance: UnconfirmedDelivery = UnconfirmedDelivery {
+            sequence: 0,
+            message_type: ::std::string::String::new(),
+            message: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for UnconfirmedDelivery {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("UnconfirmedDelivery").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for UnconfirmedDelivery {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UnconfirmedDelivery {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.delivery.DeliveryConfirmed)
+pub struct DeliveryConfirmed {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.delivery.DeliveryConfirmed.confirmed_sequence)
+    pub confirmed_sequence: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.delivery.DeliveryConfirmed.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DeliveryConfirmed {
+    fn default() -> &'a DeliveryConfirmed {
+        <DeliveryConfirmed as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeliveryConfirmed {
+    pub fn new() -> DeliveryConfirmed {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "confirmed_sequence",
+            |m: &DeliveryConfirmed| { &m.confirmed_sequence },
+            |m: &mut DeliveryConfirmed| { &mut m.confirmed_sequence },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeliveryConfirmed>(
+            "DeliveryConfirmed",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DeliveryConfirmed {
+    const NAME: &'static str = "DeliveryConfirmed";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.confirmed_sequence = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.confirmed_sequence != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.confirmed_sequence);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.confirmed_sequence != 0 {
+            os.write_uint64(1, self.confirmed_sequence)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DeliveryConfirmed {
+        DeliveryConfirmed::new()
+    }
+
+    fn clear(&mut self) {
+        self.confirmed_sequence = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DeliveryConfirmed {
+        static instance: DeliveryConfirmed = DeliveryConfirmed {
+            confirmed_sequence: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DeliveryConfirmed {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeliveryConfirmed").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DeliveryConfirmed {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeliveryConfirmed {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.delivery.ProducerSnapshot)
+pub struct ProducerSnapshot {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.delivery.ProducerSnapshot.session_id)
+    pub session_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.delivery.ProducerSnapshot.next_sequence)
+    pub next_sequence: u64,
+    // @@protoc_insertion_point(field:coerce.delivery.ProducerSnapshot.confirmed_sequence)
+    pub confirmed_sequence: u64,
+    // @@protoc_insertion_point(field:coerce.delivery.ProducerSnapshot.journal_sequence)
+    pub journal_sequence: i64,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.delivery.ProducerSnapshot.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ProducerSnapshot {
+    fn default() -> &'a ProducerSnapshot {
+        <ProducerSnapshot as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ProducerSnapshot {
+    pub fn new() -> ProducerSnapshot {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "session_id",
+            |m: &ProducerSnapshot| { &m.session_id },
+            |m: &mut ProducerSnapshot| { &mut m.session_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "next_sequence",
+            |m: &ProducerSnapshot| { &m.next_sequence },
+            |m: &mut ProducerSnapshot| { &mut m.next_sequence },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "confirmed_sequence",
+            |m: &ProducerSnapshot| { &m.confirmed_sequence },
+            |m: &mut ProducerSnapshot| { &mut m.confirmed_sequence },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "journal_sequence",
+            |m: &ProducerSnapshot| { &m.journal_sequence },
+            |m: &mut ProducerSnapshot| { &mut m.journal_sequence },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ProducerSnapshot>(
+            "ProducerSnapshot",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ProducerSnapshot {
+    const NAME: &'static str = "ProducerSnapshot";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.session_id = is.read_string()?;
+                },
+                16 => {
+                    self.next_sequence = is.read_uint64()?;
+                },
+                24 => {
+                    self.confirmed_sequence = is.read_uint64()?;
+                },
+                32 => {
+                    self.journal_sequence = is.read_int64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.session_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.session_id);
+        }
+        if self.next_sequence != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.next_sequence);
+        }
+        if self.confirmed_sequence != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.confirmed_sequence);
+        }
+        if self.journal_sequence != 0 {
+            my_size += ::protobuf::rt::int64_size(4, self.journal_sequence);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.session_id.is_empty() {
+            os.write_string(1, &self.session_id)?;
+        }
+        if self.next_sequence != 0 {
+            os.write_uint64(2, self.next_sequence)?;
+        }
+        if self.confirmed_sequence != 0 {
+            os.write_uint64(3, self.confirmed_sequence)?;
+        }
+        if self.journal_sequence != 0 {
+            os.write_int64(4, self.journal_sequence)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ProducerSnapshot {
+        ProducerSnapshot::new()
+    }
+
+    fn clear(&mut self) {
+        self.session_id.clear();
+        self.next_sequence = 0;
+        self.confirmed_sequence = 0;
+        self.journal_sequence = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ProducerSnapshot {
+        static instance: ProducerSnapshot = ProducerSnapshot {
+            session_id: ::std::string::String::new(),
+            next_sequence: 0,
+            confirmed_sequence: 0,
+            journal_sequence: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ProducerSnapshot {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ProducerSnapshot").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ProducerSnapshot {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ProducerSnapshot {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0edelivery.proto\x12\x0fcoerce.delivery\"\xa8\x02\n\x08Delivery\x12\
+    \x1f\n\x0bproducer_id\x18\x01\x20\x01(\tR\nproducerId\x12*\n\x11producer\
+    _actor_id\x18\x02\x20\x01(\tR\x0fproducerActorId\x12(\n\x10producer_node\
+    _id\x18\x03\x20\x01(\x04R\x0eproducerNodeId\x12\x1d\n\nsession_id\x18\
+    \x04\x20\x01(\tR\tsessionId\x12\x1a\n\x08sequence\x18\x05\x20\x01(\x04R\
+    \x08sequence\x12-\n\x12confirmed_sequence\x18\x06\x20\x01(\x04R\x11confi\
+    rmedSequence\x12!\n\x0cmessage_type\x18\x07\x20\x01(\tR\x0bmessageType\
+    \x12\x18\n\x07message\x18\x08\x20\x01(\x0cR\x07message\"|\n\x0bDeliveryA\
+    ck\x12\x1f\n\x0bproducer_id\x18\x01\x20\x01(\tR\nproducerId\x12\x1d\n\ns\
+    ession_id\x18\x02\x20\x01(\tR\tsessionId\x12-\n\x12confirmed_sequence\
+    \x18\x03\x20\x01(\x04R\x11confirmedSequence\"n\n\x13UnconfirmedDelivery\
+    \x12\x1a\n\x08sequence\x18\x01\x20\x01(\x04R\x08sequence\x12!\n\x0cmessa\
+    ge_type\x18\x02\x20\x01(\tR\x0bmessageType\x12\x18\n\x07message\x18\x03\
+    \x20\x01(\x0cR\x07message\"B\n\x11DeliveryConfirmed\x12-\n\x12confirmed_\
+    sequence\x18\x01\x20\x01(\x04R\x11confirmedSequence\"\xb0\x01\n\x10Produ\
+    cerSnapshot\x12\x1d\n\nsession_id\x18\x01\x20\x01(\tR\tsessionId\x12#\n\
+    \rnext_sequence\x18\x02\x20\x01(\x04R\x0cnextSequence\x12-\n\x12confirme\
+    d_sequence\x18\x03\x20\x01(\x04R\x11confirmedSequence\x12)\n\x10journal_\
+    sequence\x18\x04\x20\x01(\x03R\x0fjournalSequenceb\x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(0);
+            let mut messages = ::std::vec::Vec::with_capacity(5);
+            messages.push(Delivery::generated_message_descriptor_data());
+            messages.push(DeliveryAck::generated_message_descriptor_data());
+            messages.push(UnconfirmedDelivery::generated_message_descriptor_data());
+            messages.push(DeliveryConfirmed::generated_message_descriptor_data());
+            messages.push(ProducerSnapshot::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(0);
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
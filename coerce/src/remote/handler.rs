@@ -1,4 +1,4 @@
-use crate::actor::message::{Envelope, Handler, Message};
+use crate::actor::message::{Bytes, Envelope, Handler, Message};
 use crate::actor::scheduler::ActorType::Tracked;
 use crate::actor::system::ActorSystem;
 use crate::actor::{
@@ -6,6 +6,7 @@ use crate::actor::{
     CoreActorRef, LocalActorRef,
 };
 use crate::remote::actor::{BoxedActorHandler, BoxedMessageHandler};
+use crate::remote::system::builder::RemoteSystemConfigBuilder;
 
 use crate::actor::context::ActorContext;
 
@@ -39,16 +40,16 @@ pub trait ActorMessageHandler: Any {
     async fn handle_attempt(
         &self,
         actor: ActorId,
-        buffer: &[u8],
-        res: Sender<Result<Vec<u8>, ActorRefErr>>,
+        buffer: Bytes,
+        res: Sender<Result<Bytes, ActorRefErr>>,
         attempt: usize,
     );
 
     async fn handle_direct(
         &self,
         actor: &BoxedActorRef,
-        buffer: &[u8],
-        res: Option<Sender<Result<Vec<u8>, ActorRefErr>>>,
+        buffer: Bytes,
+        res: Option<Sender<Result<Bytes, ActorRefErr>>>,
     );
 
     fn new_boxed(&self) -> BoxedMessageHandler;
@@ -242,20 +243,20 @@ where
     async fn handle_attempt(
         &self,
         actor_id: ActorId,
-        buffer: &[u8],
-        res: Sender<Result<Vec<u8>, ActorRefErr>>,
+        buffer: Bytes,
+        res: Sender<Result<Bytes, ActorRefErr>>,
         attempt: usize,
     ) {
         let actor = get_actor_ref::<A>(&self.system, &actor_id).await;
         if let Some(actor) = actor {
-            let envelope = M::from_envelope(Envelope::Remote(buffer.to_vec()));
+            let envelope = M::from_envelope(Envelope::Remote(buffer.clone()));
             match envelope {
                 Ok(m) => {
                     let result = actor.send(m).await;
                     if let Ok(result) = result {
                         match M::write_remote_result(result) {
                             Ok(buffer) => {
-                                let send_res = res.send(Ok(buffer));
+                                let send_res = res.send(Ok(buffer.into()));
                                 if let Err(_) = send_res {
                                     error!(target: "RemoteHandler", "failed to send result back to sender");
                                 }
@@ -303,12 +304,12 @@ where
     async fn handle_direct(
         &self,
         actor: &BoxedActorRef,
-        buffer: &[u8],
-        res: Option<Sender<Result<Vec<u8>, ActorRefErr>>>,
+        buffer: Bytes,
+        res: Option<Sender<Result<Bytes, ActorRefErr>>>,
     ) {
         let actor_type = actor.actor_type();
         let actor = actor.as_actor::<A>();
-        let envelope = M::from_envelope(Envelope::Remote(buffer.to_vec()));
+        let envelope = M::from_envelope(Envelope::Remote(buffer));
 
         match (actor, envelope) {
             (Some(actor), Ok(message)) => match res {
@@ -319,7 +320,7 @@ where
                         .map(|result| M::write_remote_result(result));
                     match result {
                         Ok(Ok(result)) => {
-                            if res.send(Ok(result)).is_err() {
+                            if res.send(Ok(result.into())).is_err() {
                                 error!(target: "RemoteHandler", "failed to send message")
                             } else {
                                 trace!(target: "RemoteHandler", "handled message (actor_type={}, message_type={})", &actor_type, M::type_name());
@@ -386,3 +387,42 @@ where
         Err(e) => error!(target: "RemoteHandler", "failed to encode message result - {}", e),
     }
 }
+
+#[doc(hidden)]
+pub use inventory;
+
+pub struct RemoteHandlerRegistration {
+    pub identifier: &'static str,
+    register: fn(&mut RemoteSystemConfigBuilder, &'static str),
+}
+
+impl RemoteHandlerRegistration {
+    pub const fn new<A, M>(identifier: &'static str) -> RemoteHandlerRegistration
+    where
+        A: Handler<M>,
+        M: Message,
+    {
+        RemoteHandlerRegistration {
+            identifier,
+            register: register_handler::<A, M>,
+        }
+    }
+
+    pub fn register(&self, config: &mut RemoteSystemConfigBuilder) {
+        (self.register)(config, self.identifier)
+    }
+}
+
+fn register_handler<A, M>(config: &mut RemoteSystemConfigBuilder, identifier: &'static str)
+where
+    A: Handler<M>,
+    M: Message,
+{
+    config.with_handler::<A, M>(identifier);
+}
+
+inventory::collect!(RemoteHandlerRegistration);
+
+pub fn registered_handlers() -> impl Iterator<Item = &'static RemoteHandlerRegistration> {
+    inventory::iter::<RemoteHandlerRegistration>.into_iter()
+}
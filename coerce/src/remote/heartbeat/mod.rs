@@ -3,18 +3,20 @@ use crate::actor::message::{Handler, Message};
 use crate::actor::scheduler::timer::{Timer, TimerTick};
 use crate::actor::system::ActorSystem;
 use crate::actor::{Actor, IntoActor, LocalActorRef};
-use crate::remote::actor::message::{NodeTerminated, SetRemote};
+use crate::remote::actor::message::{NodeLeaving, NodeTerminated, SetRemote};
+use crate::remote::cluster::discovery::Forget;
 use crate::remote::cluster::node::{NodeStatus, RemoteNodeState};
+use crate::remote::cluster::split_brain::{DowningAction, Resolution, SplitBrainResolver};
 use crate::remote::net::proto::network::PongEvent;
 use crate::remote::stream::pubsub::PubSub;
-use crate::remote::stream::system::ClusterEvent::LeaderChanged;
-use crate::remote::stream::system::{SystemEvent, SystemTopic};
+use crate::remote::stream::system::ClusterEvent::{LeaderChanged, NodeDowned, RoleLeaderChanged};
+use crate::remote::stream::system::{ClusterEvent, SystemEvent, SystemTopic};
 use crate::remote::system::{NodeId, RemoteActorSystem};
 use chrono::{DateTime, Utc, MIN_DATETIME};
 
 use futures::FutureExt;
 use std::cmp::Ordering;
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use std::ops::Add;
 
@@ -27,6 +29,8 @@ pub struct Heartbeat {
     last_heartbeat: Option<DateTime<Utc>>,
     node_pings: HashMap<NodeId, NodePing>,
     on_next_leader_changed: VecDeque<Sender<NodeId>>,
+    split_brain_resolver: Option<SplitBrainResolver>,
+    leaving_nodes: HashSet<NodeId>,
 }
 
 pub struct HeartbeatConfig {
@@ -44,6 +48,8 @@ impl Heartbeat {
             last_heartbeat: None,
             node_pings: HashMap::new(),
             on_next_leader_changed: VecDeque::new(),
+            split_brain_resolver: None,
+            leaving_nodes: HashSet::new(),
         }
         .into_actor(Some(format!("heartbeat-{}", &node_tag)), sys)
         .await
@@ -68,6 +74,11 @@ impl Handler<SetRemote> for Heartbeat {
             HeartbeatTick,
         ));
 
+        self.split_brain_resolver = system
+            .config()
+            .split_brain_resolver()
+            .map(|config| SplitBrainResolver::new(config.clone()));
+
         self.system = Some(system);
         let _ = self.actor_ref(ctx).notify(HeartbeatTick);
     }
@@ -119,6 +130,20 @@ impl Message for NodePing {
 
 pub struct OnLeaderChanged(pub Sender<NodeId>);
 
+/// Sets the cluster leader when leader election is handled by Raft rather than `Heartbeat`.
+pub struct LeaderElected(pub NodeId);
+
+impl Message for LeaderElected {
+    type Result = ();
+}
+
+/// Clears the cluster leader when the Raft leader steps down or is no longer known.
+pub struct LeaderCleared;
+
+impl Message for LeaderCleared {
+    type Result = ();
+}
+
 impl Actor for Heartbeat {}
 
 impl Message for OnLeaderChanged {
@@ -145,6 +170,16 @@ impl Handler<NodeTerminated> for Heartbeat {
     }
 }
 
+#[async_trait]
+impl Handler<NodeLeaving> for Heartbeat {
+    async fn handle(&mut self, message: NodeLeaving, ctx: &mut ActorContext) {
+        if self.leaving_nodes.insert(message.0) {
+            info!(target: "Heartbeat", "node_id={} is leaving the cluster", message.0);
+            self.handle(HeartbeatTick, ctx).await;
+        }
+    }
+}
+
 #[async_trait]
 impl Handler<OnLeaderChanged> for Heartbeat {
     async fn handle(&mut self, message: OnLeaderChanged, _ctx: &mut ActorContext) {
@@ -152,6 +187,31 @@ impl Handler<OnLeaderChanged> for Heartbeat {
     }
 }
 
+#[async_trait]
+impl Handler<LeaderElected> for Heartbeat {
+    async fn handle(&mut self, message: LeaderElected, _ctx: &mut ActorContext) {
+        let current_leader = match &self.system {
+            Some(system) => system.current_leader(),
+            None => return,
+        };
+
+        if current_leader != Some(message.0) {
+            self.update_leader(message.0);
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<LeaderCleared> for Heartbeat {
+    async fn handle(&mut self, _message: LeaderCleared, _ctx: &mut ActorContext) {
+        if let Some(system) = &self.system {
+            if system.clear_leader().is_some() {
+                publish_locally(system, ClusterEvent::LeaderCleared);
+            }
+        }
+    }
+}
+
 #[async_trait]
 impl Handler<HeartbeatTick> for Heartbeat {
     async fn handle(&mut self, _msg: HeartbeatTick, _ctx: &mut ActorContext) {
@@ -171,19 +231,31 @@ impl Handler<HeartbeatTick> for Heartbeat {
               .count()
         );
 
+        // With SWIM membership, the statuses of other nodes are maintained by the `Swim` actor
+        let swim_enabled = system.config().swim_config().is_some();
+
         let mut new_leader_id = None;
         let mut updates = vec![];
 
         for node in nodes {
             if node.id == current_node {
                 let mut node = node;
-                node.status = NodeStatus::Healthy;
+                node.status = if system.is_leaving() {
+                    NodeStatus::Leaving
+                } else {
+                    NodeStatus::Healthy
+                };
                 node.last_heartbeat = Some(Utc::now());
                 updates.push(node);
 
                 continue;
             }
 
+            if swim_enabled {
+                updates.push(node);
+                continue;
+            }
+
             let node_id = node.id;
             updates.push(update_node(
                 current_node,
@@ -193,6 +265,55 @@ impl Handler<HeartbeatTick> for Heartbeat {
             ));
         }
 
+        // Nodes that announced they're leaving stay `Leaving` until they can no longer be reached
+        for node in updates.iter_mut() {
+            if node.id == current_node || !self.leaving_nodes.contains(&node.id) {
+                continue;
+            }
+
+            node.status = match node.status {
+                NodeStatus::Unhealthy | NodeStatus::Terminated => {
+                    self.leaving_nodes.remove(&node.id);
+                    NodeStatus::Terminated
+                }
+                _ => NodeStatus::Leaving,
+            };
+        }
+
+        // When Raft elects the cluster leader, `Heartbeat` only tracks node health and role
+        // leaders. Leader changes are held back until the split brain resolver has decided
+        // which side of a partition survives, and stop altogether once this node has been downed.
+        let raft_leader = system
+            .config()
+            .raft_config()
+            .is_some_and(|raft| raft.cluster_leader);
+        let mut held_back = false;
+        if let Some(resolver) = self.split_brain_resolver.as_mut() {
+            let was_downed = resolver.is_downed();
+            match resolver.resolve(current_node, &updates).await {
+                Resolution::Stable => {}
+                Resolution::Pending => held_back = true,
+                Resolution::DownUnreachable(node_ids) => {
+                    for node in updates.iter().filter(|n| node_ids.contains(&n.id)) {
+                        let _ = system.node_discovery().notify(Forget(node.addr.clone()));
+                        publish_locally(system, NodeDowned(node.id));
+                    }
+                }
+                Resolution::DownSelf => {
+                    held_back = true;
+                    if !was_downed {
+                        down_self(system, resolver.config().action);
+                    }
+                }
+            }
+
+            for node in updates.iter_mut() {
+                if resolver.is_node_downed(node.id) {
+                    node.status = NodeStatus::Terminated;
+                }
+            }
+        }
+
         trace!(
             "current_node = {}, nodes: {:?}, heartbeat took {} ms",
             current_node,
@@ -210,8 +331,32 @@ impl Handler<HeartbeatTick> for Heartbeat {
             }
         });
 
-        if self.last_heartbeat.is_some() {
-            let oldest_healthy_node = updates.iter().filter(|n| n.status.is_healthy()).next();
+        if self.last_heartbeat.is_some() && !held_back {
+            let mut role_leaders = HashMap::new();
+            for node in updates.iter().filter(|n| n.status.is_healthy()) {
+                for role in &node.roles {
+                    role_leaders.entry(role.clone()).or_insert(node.id);
+                }
+            }
+
+            for (role, leader) in system.update_role_leaders(role_leaders) {
+                debug!(
+                    "[node={}] leader of role={}: {:?}",
+                    system.node_id(),
+                    &role,
+                    leader
+                );
+
+                publish_locally(system, RoleLeaderChanged(role, leader));
+            }
+        }
+
+        if self.last_heartbeat.is_some() && !held_back && !raft_leader {
+            let leader_role = system.config().leader_role();
+            let oldest_healthy_node = updates
+                .iter()
+                .filter(|n| n.status.is_healthy())
+                .find(|n| leader_role.is_none_or(|role| n.has_role(role)));
 
             match oldest_healthy_node {
                 None => {}
@@ -230,6 +375,10 @@ impl Handler<HeartbeatTick> for Heartbeat {
             }
         }
 
+        if swim_enabled {
+            updates.retain(|n| n.id == current_node || n.status == NodeStatus::Leaving);
+        }
+
         system.update_nodes(updates).await;
         self.last_heartbeat = Some(Utc::now());
 
@@ -244,15 +393,7 @@ impl Heartbeat {
         let system = self.system.as_ref().unwrap();
         system.update_leader(node_id);
 
-        let sys = system.clone();
-        tokio::spawn(async move {
-            let _ = PubSub::publish_locally(
-                SystemTopic,
-                SystemEvent::Cluster(LeaderChanged(node_id)),
-                &sys,
-            )
-            .await;
-        });
+        publish_locally(system, LeaderChanged(node_id));
 
         while let Some(on_leader_changed_cb) = self.on_next_leader_changed.pop_front() {
             let _ = on_leader_changed_cb.send(node_id);
@@ -260,6 +401,30 @@ impl Heartbeat {
     }
 }
 
+fn publish_locally(system: &RemoteActorSystem, event: ClusterEvent) {
+    let sys = system.clone();
+    tokio::spawn(async move {
+        let _ = PubSub::publish_locally(SystemTopic, SystemEvent::Cluster(event), &sys).await;
+    });
+}
+
+fn down_self(system: &RemoteActorSystem, action: DowningAction) {
+    let node_id = system.node_id();
+    match action {
+        DowningAction::Shutdown => {
+            warn!("[node={}] node downed, shutting down", node_id);
+
+            let sys = system.actor_system().clone();
+            tokio::spawn(async move { sys.shutdown().await });
+        }
+
+        DowningAction::StopHostingShards => {
+            warn!("[node={}] node downed, stopping hosted shards", node_id);
+            publish_locally(system, NodeDowned(node_id));
+        }
+    }
+}
+
 fn update_node(
     node_id: NodeId,
     mut node: RemoteNodeState,
@@ -2,6 +2,7 @@ pub mod actor;
 pub mod actor_ref;
 pub mod api;
 pub mod cluster;
+pub mod delivery;
 pub mod handler;
 pub mod heartbeat;
 pub mod net;
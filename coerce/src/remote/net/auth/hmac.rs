@@ -0,0 +1,67 @@
+use crate::remote::net::auth::{AuthChallenge, AuthErr, Authenticator};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Shared-secret challenge-response authentication. Nodes prove they know the secret by signing the
+/// server's challenge, along with their node id and tag, using HMAC-SHA256.
+///
+/// Secrets can be rotated without downtime by first adding the new secret as an accepted secret on
+/// every node, then switching the signing secret, and finally removing the old secret.
+pub struct HmacAuthenticator {
+    secret: Vec<u8>,
+    accepted_secrets: Vec<Vec<u8>>,
+}
+
+impl HmacAuthenticator {
+    pub fn new(secret: impl Into<Vec<u8>>) -> HmacAuthenticator {
+        HmacAuthenticator {
+            secret: secret.into(),
+            accepted_secrets: vec![],
+        }
+    }
+
+    /// Accept credentials signed with `secret`, in addition to the secret used to sign credentials.
+    pub fn accept_secret(mut self, secret: impl Into<Vec<u8>>) -> Self {
+        self.accepted_secrets.push(secret.into());
+        self
+    }
+
+    fn mac(secret: &[u8], challenge: &AuthChallenge) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(secret).expect("hmac accepts keys of any length");
+        mac.update(challenge.challenge);
+        mac.update(&challenge.node_id.to_be_bytes());
+        mac.update(challenge.node_tag.as_bytes());
+        mac
+    }
+}
+
+impl Authenticator for HmacAuthenticator {
+    fn credentials(&self, challenge: &AuthChallenge) -> Result<Vec<u8>, AuthErr> {
+        Ok(Self::mac(&self.secret, challenge)
+            .finalize()
+            .into_bytes()
+            .to_vec())
+    }
+
+    fn verify(&self, challenge: &AuthChallenge, credentials: &[u8]) -> Result<(), AuthErr> {
+        if credentials.is_empty() {
+            return Err(AuthErr::MissingCredentials);
+        }
+
+        let verified = std::iter::once(&self.secret)
+            .chain(self.accepted_secrets.iter())
+            .any(|secret| {
+                Self::mac(secret, challenge)
+                    .verify_slice(credentials)
+                    .is_ok()
+            });
+
+        if verified {
+            Ok(())
+        } else {
+            Err(AuthErr::InvalidCredentials)
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use crate::remote::net::auth::{AuthChallenge, AuthErr, Authenticator};
+use chrono::Utc;
+use jsonwebtoken::errors::ErrorKind;
+use jsonwebtoken::{decode, decode_header, encode, Header, Validation};
+use std::collections::HashMap;
+use std::time::Duration;
+
+pub use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+
+const DEFAULT_TOKEN_TTL: Duration = Duration::from_secs(30);
+
+/// JWT based challenge-response authentication. Connecting nodes issue a short-lived token, signed
+/// with their encoding key, with the node id as the subject and the server's challenge as the nonce.
+///
+/// Keys can be rotated by assigning each key an id (`kid`) and registering the decoding keys of
+/// all currently valid keys via [`JwtAuthenticator::with_decoding_key`].
+pub struct JwtAuthenticator {
+    algorithm: Algorithm,
+    encoding_key: EncodingKey,
+    key_id: Option<String>,
+    decoding_key: DecodingKey,
+    decoding_keys: HashMap<String, DecodingKey>,
+    issuer: Option<String>,
+    token_ttl: Duration,
+}
+
+#[derive(Serialize, Deserialize)]
+struct NodeClaims {
+    sub: String,
+    node_tag: String,
+    nonce: String,
+    iat: i64,
+    exp: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    iss: Option<String>,
+}
+
+impl JwtAuthenticator {
+    pub fn new(
+        algorithm: Algorithm,
+        encoding_key: EncodingKey,
+        decoding_key: DecodingKey,
+    ) -> JwtAuthenticator {
+        JwtAuthenticator {
+            algorithm,
+            encoding_key,
+            decoding_key,
+            key_id: None,
+            decoding_keys: HashMap::new(),
+            issuer: None,
+            token_ttl: DEFAULT_TOKEN_TTL,
+        }
+    }
+
+    /// Uses a shared secret to both sign and verify tokens (HS256).
+    pub fn from_secret(secret: &[u8]) -> JwtAuthenticator {
+        Self::new(
+            Algorithm::HS256,
+            EncodingKey::from_secret(secret),
+            DecodingKey::from_secret(secret),
+        )
+    }
+
+    /// Sets the `kid` header of issued tokens.
+    pub fn with_key_id(mut self, key_id: impl ToString) -> Self {
+        self.key_id = Some(key_id.to_string());
+        self
+    }
+
+    /// Accept tokens with the `kid` header of `key_id`, verified using `decoding_key`.
+    pub fn with_decoding_key(mut self, key_id: impl ToString, decoding_key: DecodingKey) -> Self {
+        self.decoding_keys.insert(key_id.to_string(), decoding_key);
+        self
+    }
+
+    /// Sets the `iss` claim of issued tokens, and rejects tokens from any other issuer.
+    pub fn with_issuer(mut self, issuer: impl ToString) -> Self {
+        self.issuer = Some(issuer.to_string());
+        self
+    }
+
+    pub fn with_token_ttl(mut self, token_ttl: Duration) -> Self {
+        self.token_ttl = token_ttl;
+        self
+    }
+
+    fn decoding_key(&self, credentials: &str) -> Result<&DecodingKey, AuthErr> {
+        let header = decode_header(credentials).map_err(|_| AuthErr::InvalidCredentials)?;
+        match header.kid {
+            Some(kid) => self
+                .decoding_keys
+                .get(&kid)
+                .or_else(|| (self.key_id.as_ref() == Some(&kid)).then_some(&self.decoding_key))
+                .ok_or(AuthErr::InvalidCredentials),
+            None => Ok(&self.decoding_key),
+        }
+    }
+}
+
+impl Authenticator for JwtAuthenticator {
+    fn credentials(&self, challenge: &AuthChallenge) -> Result<Vec<u8>, AuthErr> {
+        let now = Utc::now().timestamp();
+        let claims = NodeClaims {
+            sub: challenge.node_id.to_string(),
+            node_tag: challenge.node_tag.to_string(),
+            nonce: to_hex(challenge.challenge),
+            iat: now,
+            exp: now + self.token_ttl.as_secs() as i64,
+            iss: self.issuer.clone(),
+        };
+
+        let mut header = Header::new(self.algorithm);
+        header.kid = self.key_id.clone();
+
+        encode(&header, &claims, &self.encoding_key)
+            .map(String::into_bytes)
+            .map_err(|e| AuthErr::Other(e.to_string()))
+    }
+
+    fn verify(&self, challenge: &AuthChallenge, credentials: &[u8]) -> Result<(), AuthErr> {
+        if credentials.is_empty() {
+            return Err(AuthErr::MissingCredentials);
+        }
+
+        let credentials =
+            std::str::from_utf8(credentials).map_err(|_| AuthErr::InvalidCredentials)?;
+
+        let mut validation = Validation::new(self.algorithm);
+        validation.set_required_spec_claims(&["exp", "sub"]);
+        if let Some(issuer) = &self.issuer {
+            validation.set_issuer(&[issuer]);
+        }
+
+        let claims =
+            decode::<NodeClaims>(credentials, self.decoding_key(credentials)?, &validation)
+                .map_err(|e| match e.kind() {
+                    ErrorKind::ExpiredSignature => AuthErr::CredentialsExpired,
+                    _ => AuthErr::InvalidCredentials,
+                })?
+                .claims;
+
+        if claims.nonce != to_hex(challenge.challenge) {
+            return Err(AuthErr::InvalidCredentials);
+        }
+
+        if claims.sub != challenge.node_id.to_string() || claims.node_tag != challenge.node_tag {
+            return Err(AuthErr::NodeMismatch);
+        }
+
+        Ok(())
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
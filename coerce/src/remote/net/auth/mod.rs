@@ -0,0 +1,71 @@
+use crate::remote::system::NodeId;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+pub mod hmac;
+pub mod jwt;
+
+pub use self::hmac::HmacAuthenticator;
+pub use self::jwt::JwtAuthenticator;
+
+pub type AuthenticatorRef = Arc<dyn Authenticator>;
+
+/// Authenticates nodes connecting to the cluster. When a connection is accepted, the server sends a
+/// random challenge along with its `NodeIdentity`. The connecting node responds with an `Identify`
+/// event, containing the credentials produced by [`Authenticator::credentials`], which the server checks
+/// with [`Authenticator::verify`] before any other requests from the connection are processed.
+pub trait Authenticator: 'static + Send + Sync {
+    fn credentials(&self, challenge: &AuthChallenge) -> Result<Vec<u8>, AuthErr>;
+
+    fn verify(&self, challenge: &AuthChallenge, credentials: &[u8]) -> Result<(), AuthErr>;
+}
+
+pub struct AuthChallenge<'a> {
+    pub node_id: NodeId,
+    pub node_tag: &'a str,
+    pub challenge: &'a [u8],
+}
+
+#[derive(Debug)]
+pub enum AuthErr {
+    MissingCredentials,
+    InvalidCredentials,
+    CredentialsExpired,
+    NodeMismatch,
+    Unauthenticated,
+    Other(String),
+}
+
+impl AuthErr {
+    pub fn as_label(&self) -> &'static str {
+        match self {
+            AuthErr::MissingCredentials => "missing_credentials",
+            AuthErr::InvalidCredentials => "invalid_credentials",
+            AuthErr::CredentialsExpired => "credentials_expired",
+            AuthErr::NodeMismatch => "node_mismatch",
+            AuthErr::Unauthenticated => "unauthenticated",
+            AuthErr::Other(_) => "other",
+        }
+    }
+}
+
+impl Display for AuthErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthErr::MissingCredentials => write!(f, "no credentials provided"),
+            AuthErr::InvalidCredentials => write!(f, "invalid credentials"),
+            AuthErr::CredentialsExpired => write!(f, "credentials expired"),
+            AuthErr::NodeMismatch => write!(f, "credentials were issued to a different node"),
+            AuthErr::Unauthenticated => write!(f, "request received before authentication"),
+            AuthErr::Other(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for AuthErr {}
+
+pub(crate) fn new_challenge() -> Vec<u8> {
+    let mut challenge = vec![0u8; 32];
+    rand::Rng::fill(&mut rand::thread_rng(), challenge.as_mut_slice());
+    challenge
+}
@@ -0,0 +1,127 @@
+use crate::actor::ActorRefErr;
+use crate::remote::actor::RemoteResponse;
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use std::collections::VecDeque;
+use uuid::Uuid;
+
+/// Limits applied to the messages buffered by each connection to a remote node
+/// while it is disconnected.
+#[derive(Clone, Debug)]
+pub struct WriteBufferConfig {
+    pub max_messages: usize,
+    pub max_bytes: usize,
+    pub overflow_policy: BufferOverflowPolicy,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BufferOverflowPolicy {
+    /// New messages are rejected once the buffer is full.
+    RejectNewest,
+
+    /// The oldest buffered messages are discarded to make room for new messages.
+    DropOldest,
+}
+
+impl Default for WriteBufferConfig {
+    fn default() -> Self {
+        Self {
+            max_messages: 10_000,
+            max_bytes: 64 * 1024 * 1024,
+            overflow_policy: BufferOverflowPolicy::RejectNewest,
+        }
+    }
+}
+
+pub struct BufferedWrite {
+    pub bytes: Vec<u8>,
+    pub request_id: Option<Uuid>,
+}
+
+pub struct WriteBuffer {
+    config: WriteBufferConfig,
+    writes: VecDeque<BufferedWrite>,
+    bytes_total: usize,
+}
+
+impl WriteBuffer {
+    pub fn new(config: WriteBufferConfig) -> WriteBuffer {
+        WriteBuffer {
+            config,
+            writes: VecDeque::new(),
+            bytes_total: 0,
+        }
+    }
+
+    /// Buffers the write, returning any writes that were rejected or dropped
+    /// as a result of the buffer being full.
+    pub fn push(&mut self, write: BufferedWrite) -> Vec<BufferedWrite> {
+        if write.bytes.len() > self.config.max_bytes || self.config.max_messages == 0 {
+            return vec![write];
+        }
+
+        let mut overflow = vec![];
+        while self.is_full(write.bytes.len()) {
+            match self.config.overflow_policy {
+                BufferOverflowPolicy::RejectNewest => return vec![write],
+                BufferOverflowPolicy::DropOldest => {
+                    overflow.extend(self.pop_front());
+                }
+            }
+        }
+
+        self.push_back(write);
+        overflow
+    }
+
+    pub fn pop_front(&mut self) -> Option<BufferedWrite> {
+        let write = self.writes.pop_front()?;
+        self.bytes_total -= write.bytes.len();
+        Some(write)
+    }
+
+    /// Returns a write that could not be flushed to the front of the buffer, regardless of limits.
+    pub fn push_front(&mut self, write: BufferedWrite) {
+        self.bytes_total += write.bytes.len();
+        self.writes.push_front(write);
+    }
+
+    pub fn drain(&mut self) -> Vec<BufferedWrite> {
+        self.bytes_total = 0;
+        self.writes.drain(..).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.writes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.writes.is_empty()
+    }
+
+    pub fn bytes_total(&self) -> usize {
+        self.bytes_total
+    }
+
+    fn push_back(&mut self, write: BufferedWrite) {
+        self.bytes_total += write.bytes.len();
+        self.writes.push_back(write);
+    }
+
+    fn is_full(&self, additional_bytes: usize) -> bool {
+        self.writes.len() >= self.config.max_messages
+            || self.bytes_total + additional_bytes > self.config.max_bytes
+    }
+}
+
+/// Fails the request awaiting a response to `write` (if any) with `ActorRefErr::NodeUnavailable`.
+pub fn fail_write(write: &BufferedWrite, node_id: NodeId, system: &RemoteActorSystem) {
+    if let Some(request_id) = write.request_id {
+        fail_request(request_id, node_id, system);
+    }
+}
+
+pub fn fail_request(request_id: Uuid, node_id: NodeId, system: &RemoteActorSystem) {
+    if let Some(res_tx) = system.pop_request(request_id) {
+        let _ = res_tx.send(RemoteResponse::Err(ActorRefErr::NodeUnavailable(node_id)));
+    }
+}
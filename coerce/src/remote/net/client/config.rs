@@ -0,0 +1,79 @@
+use rand::Rng;
+use std::time::Duration;
+
+/// Connection settings used by each `RemoteClient`.
+#[derive(Clone, Debug)]
+pub struct RemoteClientConfig {
+    /// How long to wait for a connection (including TLS and the exchange of node identities)
+    /// to be established before it is considered failed.
+    pub connect_timeout: Duration,
+
+    /// How long to wait for a client to identify the node it's connected to.
+    pub identify_timeout: Duration,
+
+    pub handshake_timeout: Duration,
+
+    pub handshake_max_attempts: usize,
+
+    /// Delay before the first reconnection attempt, the delay grows by `reconnect_multiplier`
+    /// with each failed attempt, up to `reconnect_max_delay`.
+    pub reconnect_initial_delay: Duration,
+
+    pub reconnect_max_delay: Duration,
+
+    pub reconnect_multiplier: f64,
+
+    /// Randomises each reconnect delay by up to this fraction (between `0.0` and `1.0`) in either
+    /// direction, so that nodes don't all reconnect at once.
+    pub reconnect_jitter: f64,
+
+    /// The number of consecutive failed connection attempts before the node is marked as
+    /// unreachable and no further attempts are made, until the node is discovered again.
+    /// Clients retry indefinitely when `None`.
+    pub max_reconnect_attempts: Option<usize>,
+
+    /// Enables TCP keepalive on connections, probes are sent after the connection
+    /// has been idle for this long.
+    pub keepalive: Option<Duration>,
+}
+
+impl RemoteClientConfig {
+    /// The delay before the next reconnection attempt, after `attempts` failed attempts.
+    pub fn reconnect_delay(&self, attempts: usize) -> Duration {
+        let exponent = attempts.saturating_sub(1).min(i32::MAX as usize) as i32;
+        let delay = self.reconnect_initial_delay.as_secs_f64()
+            * self.reconnect_multiplier.max(1.0).powi(exponent);
+
+        let delay = delay.min(self.reconnect_max_delay.as_secs_f64());
+        let jitter = self.reconnect_jitter.clamp(0.0, 1.0);
+        let delay = if jitter > 0.0 {
+            delay * (1.0 + rand::thread_rng().gen_range(-jitter..=jitter))
+        } else {
+            delay
+        };
+
+        Duration::from_secs_f64(delay.max(0.0))
+    }
+
+    pub fn is_unreachable(&self, attempts: usize) -> bool {
+        self.max_reconnect_attempts
+            .is_some_and(|max_attempts| attempts >= max_attempts)
+    }
+}
+
+impl Default for RemoteClientConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Duration::from_secs(5),
+            identify_timeout: Duration::from_secs(1),
+            handshake_timeout: Duration::from_secs(3),
+            handshake_max_attempts: 5,
+            reconnect_initial_delay: Duration::from_secs(1),
+            reconnect_max_delay: Duration::from_secs(30),
+            reconnect_multiplier: 2.0,
+            reconnect_jitter: 0.2,
+            max_reconnect_attempts: None,
+            keepalive: None,
+        }
+    }
+}
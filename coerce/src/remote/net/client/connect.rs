@@ -1,31 +1,37 @@
 use crate::actor::context::ActorContext;
 use crate::actor::message::{Handler, Message};
 use crate::actor::scheduler::timer::Timer;
-use crate::actor::{Actor, LocalActorRef};
+use crate::actor::{Actor, ActorRefErr, LocalActorRef};
 use crate::remote::actor::message::ClientConnected;
+use crate::remote::actor::RemoteResponse;
 use crate::remote::cluster::discovery::{Discover, Seed};
-use crate::remote::cluster::node::RemoteNode;
+use crate::remote::cluster::node::{NodeIdentity, RemoteNode};
+use crate::remote::net::auth::AuthChallenge;
 use crate::remote::net::client::ping::PingTick;
 use crate::remote::net::client::receive::{ClientMessageReceiver, HandshakeAcknowledge};
 use crate::remote::net::client::send::write_bytes;
 use crate::remote::net::client::{
-    BeginHandshake, ClientState, ConnectionState, HandshakeAckCallback, HandshakeStatus,
-    RemoteClient,
+    BeginHandshake, ClientState, ClientType, ConnectionState, HandshakeAckCallback,
+    HandshakeStatus, RemoteClient,
 };
 use crate::remote::net::codec::NetworkCodec;
 use crate::remote::net::message::{datetime_to_timestamp, SessionEvent};
+use crate::remote::net::metrics::NetworkMetrics;
 use crate::remote::net::proto::network as proto;
+use crate::remote::net::protocol::{ProtocolFeature, LEGACY_PROTOCOL_VERSION};
+use crate::remote::net::stream::{NetworkStream, NetworkStreamWriter};
 use crate::remote::net::{receive_loop, StreamData};
+use crate::remote::system::RemoteActorSystem;
+use chrono::Utc;
 use std::io::Error;
 use std::net::SocketAddr;
 use std::str::FromStr;
 
 use protobuf::EnumOrUnknown;
 use std::time::Duration;
-use tokio::net::TcpStream;
 use tokio::sync::oneshot;
 use tokio::sync::oneshot::Sender;
-use tokio_util::codec::{FramedRead, FramedWrite};
+use uuid::Uuid;
 
 pub struct Connect;
 
@@ -40,18 +46,22 @@ impl RemoteClient {
         // let span = tracing::trace_span!("RemoteClient::connect", address = self.addr.as_str());
         //
         // let _enter = span.enter();
-        let stream = TcpStream::connect(&self.addr).await;
-        if stream.is_err() {
-            let error = stream.unwrap_err();
-            error!("connection to {} failed, error: {}", &self.addr, error);
-            return None;
-        }
-
-        let stream = stream.unwrap();
-        let (read, writer) = tokio::io::split(stream);
+        let connect_timeout = self.config.connect_timeout;
+        let stream = match tokio::time::timeout(connect_timeout, self.open_stream(ctx)).await {
+            Ok(Some(stream)) => stream,
+            Ok(None) => return None,
+            Err(_) => {
+                error!(
+                    "connection to {} timed out after {}ms",
+                    &self.addr,
+                    connect_timeout.as_millis()
+                );
+                return None;
+            }
+        };
 
-        let reader = FramedRead::new(read, NetworkCodec);
-        let write = FramedWrite::new(writer, NetworkCodec);
+        let codec = NetworkCodec::new(ctx.system().remote().config().max_frame_size());
+        let (reader, mut write) = stream.into_framed(codec);
 
         let (identity_tx, identity_rx) = oneshot::channel();
 
@@ -62,32 +72,191 @@ impl RemoteClient {
             ClientMessageReceiver::new(self.actor_ref(ctx), identity_tx, self.addr.clone()),
         ));
 
-        self.ping_timer = Some(Timer::start_immediately(
-            self.actor_ref(ctx),
-            ctx.system().remote().config().heartbeat_config().interval,
-            PingTick,
-        ));
+        // With SWIM membership, nodes are probed by the `Swim` actor rather than every connection
+        let swim_enabled = ctx.system().remote().config().swim_config().is_some();
+        if self.is_control_lane() && !swim_enabled {
+            self.ping_timer = Some(Timer::start_immediately(
+                self.actor_ref(ctx),
+                ctx.system().remote().config().heartbeat_config().interval,
+                PingTick,
+            ));
+        }
 
-        let identity = match identity_rx.await {
-            Ok(identity) => identity,
-            Err(_) => {
-                warn!("no identity received (addr={})", &self.addr);
+        let (identity, auth_challenge, peer_protocol) =
+            match tokio::time::timeout(connect_timeout, identity_rx).await {
+                Ok(Ok(identity)) => identity,
+                Ok(Err(_)) => {
+                    warn!("no identity received (addr={})", &self.addr);
+                    return None;
+                }
+                Err(_) => {
+                    warn!(
+                        "no identity received within {}ms (addr={})",
+                        connect_timeout.as_millis(),
+                        &self.addr
+                    );
+
+                    receive_task.abort();
+                    return None;
+                }
+            };
+
+        let protocol = match remote.config().protocol().negotiate(&peer_protocol) {
+            Ok(protocol) => protocol,
+            Err(e) => {
+                error!(
+                    "unable to connect to node (addr={}, id={}), error: {}",
+                    &self.addr, identity.node.id, e
+                );
+
+                receive_task.abort();
                 return None;
             }
         };
 
+        // Legacy nodes don't expect an `Identify` unless they require authentication
+        if protocol.version() > LEGACY_PROTOCOL_VERSION || !auth_challenge.is_empty() {
+            if let Err(e) = identify(&remote, &auth_challenge, &mut write, connect_timeout).await {
+                error!(
+                    "identifying with node (addr={}, id={}) failed, error: {}",
+                    &self.addr, identity.node.id, e
+                );
+
+                receive_task.abort();
+                return None;
+            }
+        }
+
+        write
+            .encoder_mut()
+            .set_compression(protocol.supports(ProtocolFeature::Lz4Compression));
+
         Some(ConnectionState {
             identity,
             handshake: HandshakeStatus::None,
             write,
             receive_task,
+            protocol,
         })
     }
+
+    async fn open_stream(&self, ctx: &ActorContext) -> Option<NetworkStream> {
+        let remote = ctx.system().remote();
+        let stream = match remote.config().transport().connect(&self.addr).await {
+            Ok(stream) => stream,
+            Err(error) => {
+                error!("connection to {} failed, error: {}", &self.addr, error);
+                return None;
+            }
+        };
+
+        if let Some(keepalive) = self.config.keepalive {
+            if let Err(error) = stream.set_keepalive(keepalive) {
+                warn!(
+                    "unable to enable keepalive on connection to {}, error: {}",
+                    &self.addr, error
+                );
+            }
+        }
+
+        match remote.config().tls() {
+            Some(tls) => match tls.connect(&self.addr, stream).await {
+                Ok(stream) => Some(stream.into()),
+                Err(error) => {
+                    error!("TLS handshake with {} failed, error: {}", &self.addr, error);
+                    None
+                }
+            },
+            None => Some(stream),
+        }
+    }
+
+    /// Stops reconnecting to a node that has failed to accept too many consecutive
+    /// connection attempts, until the node is discovered again.
+    fn mark_unreachable(&mut self, connection_attempts: usize, ctx: &ActorContext) {
+        warn!(
+            "node (addr={}) is unreachable after {} connection attempt(s), no further attempts will be made",
+            &self.addr, connection_attempts
+        );
+
+        NetworkMetrics::incr_nodes_unreachable(&self.addr);
+
+        self.state = Some(ClientState::Quarantined {
+            since: Utc::now(),
+            connection_attempts,
+        });
+
+        self.fail_buffered_writes(self.node_id.unwrap_or_default(), ctx);
+    }
 }
 
-pub struct Disconnected;
+async fn identify(
+    remote: &RemoteActorSystem,
+    challenge: &[u8],
+    write: &mut NetworkStreamWriter,
+    timeout: Duration,
+) -> Result<(), ActorRefErr> {
+    let authenticator = remote
+        .config()
+        .authenticator()
+        .filter(|_| !challenge.is_empty());
+
+    let token = match authenticator {
+        Some(authenticator) => authenticator
+            .credentials(&AuthChallenge {
+                node_id: remote.node_id(),
+                node_tag: remote.node_tag(),
+                challenge,
+            })
+            .map_err(|e| {
+                error!("unable to create authentication credentials, error: {}", e);
+                ActorRefErr::AuthenticationFailed
+            })?,
+        None => vec![],
+    };
+
+    let message_id = Uuid::new_v4();
+    let (res_tx, res_rx) = oneshot::channel();
+    remote.push_request(message_id, res_tx);
+
+    let identify = SessionEvent::Identify(proto::IdentifyEvent {
+        source_node_id: remote.node_id(),
+        source_node_tag: remote.node_tag().to_string(),
+        token,
+        message_id: message_id.to_string(),
+        protocol: Some((&remote.config().protocol()).into()).into(),
+        ..Default::default()
+    });
+
+    if write_bytes(&identify.write_to_bytes().unwrap(), write)
+        .await
+        .is_err()
+    {
+        remote.pop_request(message_id);
+        return Err(ActorRefErr::ActorUnavailable);
+    }
 
-const RECONNECT_DELAY: Duration = Duration::from_millis(1000);
+    match tokio::time::timeout(timeout, res_rx).await {
+        Ok(Ok(RemoteResponse::Ok(_))) => Ok(()),
+        Ok(Ok(RemoteResponse::Err(e))) => Err(e),
+        Ok(Err(_)) => Err(ActorRefErr::ResultChannelClosed),
+        Err(_) => {
+            remote.pop_request(message_id);
+            Err(ActorRefErr::Timeout {
+                time_taken_millis: timeout.as_millis() as u64,
+            })
+        }
+    }
+}
+
+/// The nodes known to the peer at the time of the handshake, including the peer itself.
+fn handshake_nodes(identity: &NodeIdentity, ack: &HandshakeAcknowledge) -> Vec<RemoteNode> {
+    let mut nodes = vec![identity.node.clone()];
+    nodes.extend(ack.known_nodes.iter().cloned());
+    nodes
+}
+
+pub struct Disconnected;
 
 #[async_trait]
 impl Handler<Connect> for RemoteClient {
@@ -103,17 +272,20 @@ impl Handler<Connect> for RemoteClient {
         }
 
         if let Some(connection_state) = self.connect(message, ctx).await {
-            let client_actor_ref = self.actor_ref(ctx);
-            let _ = ctx
-                .system()
-                .remote()
-                .client_registry()
-                .send(ClientConnected {
-                    addr: connection_state.identity.node.addr.clone(),
-                    remote_node_id: connection_state.identity.node.id,
-                    client_actor_ref,
-                })
-                .await;
+            if self.is_control_lane() {
+                let client_actor_ref = self.actor_ref(ctx);
+                let _ = ctx
+                    .system()
+                    .remote()
+                    .client_registry()
+                    .send(ClientConnected {
+                        addr: connection_state.identity.node.addr.clone(),
+                        client_addr: self.addr.clone(),
+                        remote_node_id: connection_state.identity.node.id,
+                        client_actor_ref,
+                    })
+                    .await;
+            }
 
             while let Some(callback) = self.on_identified_callbacks.pop() {
                 let _ = callback.send(Some(connection_state.identity.clone()));
@@ -122,12 +294,32 @@ impl Handler<Connect> for RemoteClient {
             self.node_id = Some(connection_state.identity.node.id);
             self.state = Some(ClientState::Connected(connection_state));
 
-            debug!("RemoteClient connected to node (addr={})", &self.addr);
+            debug!(
+                "RemoteClient connected to node (addr={}, lane={})",
+                &self.addr, self.lane
+            );
 
-            let _ = ctx.system().remote().node_discovery().notify(Discover {
-                seed: Seed::Addr(self.addr.clone()),
-                on_discovery_complete: None,
-            });
+            if self.is_control_lane() {
+                match self.client_type {
+                    ClientType::Worker => {
+                        let _ = ctx.system().remote().node_discovery().notify(Discover {
+                            seed: Seed::Addr(self.addr.clone()),
+                            on_discovery_complete: None,
+                        });
+                    }
+
+                    // Cluster clients manage their own view of the cluster (see `RemoteClusterClient`),
+                    // but the node needs a handshake to know where to send the results of requests
+                    ClientType::Client => {
+                        let (tx, _) = oneshot::channel();
+                        let _ = self.actor_ref(ctx).notify(BeginHandshake {
+                            request_id: Uuid::new_v4(),
+                            seed_nodes: vec![],
+                            on_handshake_complete: tx,
+                        });
+                    }
+                }
+            }
 
             self.flush_buffered_writes().await;
         } else {
@@ -154,8 +346,11 @@ impl Handler<BeginHandshake> for RemoteClient {
         };
 
         match &connection.handshake {
-            &HandshakeStatus::Acknowledged(_) => {
-                let _ = message.on_handshake_complete.send(());
+            // Cluster clients handshake each time they want an up-to-date view of the cluster
+            HandshakeStatus::Acknowledged(ack) if self.client_type == ClientType::Worker => {
+                let _ = message
+                    .on_handshake_complete
+                    .send(handshake_nodes(&connection.identity, ack));
             }
 
             &HandshakeStatus::Pending => {
@@ -202,6 +397,7 @@ impl Handler<BeginHandshake> for RemoteClient {
                                     .as_ref()
                                     .map(datetime_to_timestamp)
                                     .into(),
+                                roles: node.roles,
                                 ..proto::RemoteNode::default()
                             })
                             .collect(),
@@ -239,6 +435,7 @@ impl Handler<HandshakeAcknowledge> for RemoteClient {
 
         match &mut self.state {
             Some(ClientState::Connected(state)) => {
+                let nodes = handshake_nodes(&state.identity, &message);
                 state.handshake = HandshakeStatus::Acknowledged(message);
 
                 while let Some(callback) = self.on_handshake_ack_callbacks.pop() {
@@ -247,7 +444,7 @@ impl Handler<HandshakeAcknowledge> for RemoteClient {
                         callback.request_id, &self.addr
                     );
 
-                    let _ = callback.callback.send(());
+                    let _ = callback.callback.send(nodes.clone());
                 }
             }
             _ => {
@@ -260,57 +457,53 @@ impl Handler<HandshakeAcknowledge> for RemoteClient {
 #[async_trait]
 impl Handler<Disconnected> for RemoteClient {
     async fn handle(&mut self, _msg: Disconnected, ctx: &mut ActorContext) {
-        if let Some(true) = self.state.as_ref().map(|n| n.is_connected()) {
+        let connection_attempts = match self.state.take().unwrap() {
+            ClientState::Idle {
+                connection_attempts,
+            } => connection_attempts + 1,
+
+            state @ ClientState::Quarantined { .. } => {
+                // The node has already been marked as unreachable, there's nothing to reconnect to.
+                self.state = Some(state);
+                return;
+            }
+
+            ClientState::Connected(mut state) => {
+                state.disconnected().await;
+                0
+            }
+        };
+
+        if self.config.is_unreachable(connection_attempts) {
+            self.mark_unreachable(connection_attempts, ctx);
+            return;
+        }
+
+        let reconnect_delay = self.config.reconnect_delay(connection_attempts.max(1));
+        if connection_attempts == 0 {
             warn!(
                 "RemoteClient disconnected from node (addr={}), attempting re-connection in {}ms",
                 &self.addr,
-                RECONNECT_DELAY.as_millis()
+                reconnect_delay.as_millis()
             );
         } else {
             warn!(
-                "failed to connect to node (addr={}) retrying in {}ms",
+                "failed to connect to node (addr={}, attempts={}) retrying in {}ms",
                 &self.addr,
-                RECONNECT_DELAY.as_millis()
+                connection_attempts,
+                reconnect_delay.as_millis()
             );
         }
 
-        let state = match self.state.take().unwrap() {
-            ClientState::Idle {
-                connection_attempts,
-            } => {
-                let connection_attempts = connection_attempts + 1;
-
-                ClientState::Idle {
-                    connection_attempts,
-                }
-            }
-
-            ClientState::Quarantined {
-                since,
-                connection_attempts,
-            } => {
-                let connection_attempts = connection_attempts + 1;
-
-                ClientState::Quarantined {
-                    since,
-                    connection_attempts,
-                }
-            }
-
-            ClientState::Connected(mut state) => {
-                state.disconnected().await;
-
-                ClientState::Idle {
-                    connection_attempts: 1,
-                }
-            }
-        };
+        self.state = Some(ClientState::Idle {
+            connection_attempts,
+        });
 
-        self.state = Some(state);
+        NetworkMetrics::incr_reconnect_attempts(&self.addr);
 
         let self_ref = self.actor_ref(ctx);
         tokio::spawn(async move {
-            tokio::time::sleep(RECONNECT_DELAY).await;
+            tokio::time::sleep(reconnect_delay).await;
             let _res = self_ref.send(Connect).await;
         });
     }
@@ -0,0 +1,89 @@
+use crate::actor::LocalActorRef;
+use crate::remote::actor::RemoteSystemConfig;
+use crate::remote::net::client::RemoteClient;
+use crate::remote::net::message::SessionEvent;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+pub const CONTROL_LANE: usize = 0;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TrafficClass {
+    /// Heartbeats, handshakes, actor registry lookups, and messages to handlers registered with
+    /// `RemoteSystemConfigBuilder::with_control_handler` (membership, raft, sharding, singletons,
+    /// replication and leases).
+    Control,
+
+    /// User traffic, routed by a key (the target actor id, or the stream topic) so that
+    /// all messages with the same key are written to the same connection, preserving order.
+    /// RPC results and errors are routed by their request id, each is matched to its request
+    /// by id so they don't need to share a connection with anything else.
+    Message(u64),
+}
+
+/// The connections to a single node, there is always a control lane and zero or more
+/// message lanes. When there are no message lanes, all traffic is written to the control lane.
+#[derive(Clone)]
+pub struct ClientLanes {
+    control: LocalActorRef<RemoteClient>,
+    messages: Vec<LocalActorRef<RemoteClient>>,
+}
+
+impl ClientLanes {
+    pub fn new(
+        control: LocalActorRef<RemoteClient>,
+        messages: Vec<LocalActorRef<RemoteClient>>,
+    ) -> ClientLanes {
+        ClientLanes { control, messages }
+    }
+
+    pub fn control(&self) -> &LocalActorRef<RemoteClient> {
+        &self.control
+    }
+
+    pub fn route(
+        &self,
+        event: &SessionEvent,
+        config: &RemoteSystemConfig,
+    ) -> &LocalActorRef<RemoteClient> {
+        match event.traffic_class(config) {
+            TrafficClass::Message(key) if !self.messages.is_empty() => {
+                &self.messages[(key % self.messages.len() as u64) as usize]
+            }
+            _ => &self.control,
+        }
+    }
+
+    pub fn all(&self) -> impl Iterator<Item = &LocalActorRef<RemoteClient>> {
+        std::iter::once(&self.control).chain(self.messages.iter())
+    }
+
+    pub fn lane_count(&self) -> usize {
+        1 + self.messages.len()
+    }
+}
+
+impl SessionEvent {
+    pub fn traffic_class(&self, config: &RemoteSystemConfig) -> TrafficClass {
+        match self {
+            SessionEvent::NotifyActor(request) => {
+                if config.is_control_handler(&request.handler_type) {
+                    TrafficClass::Control
+                } else {
+                    TrafficClass::Message(lane_key(&request.actor_id))
+                }
+            }
+            SessionEvent::CreateActor(create) => TrafficClass::Message(lane_key(&create.actor_id)),
+            SessionEvent::StreamPublish(publish) => TrafficClass::Message(lane_key(&publish.topic)),
+            SessionEvent::Result(result) => TrafficClass::Message(lane_key(&result.message_id)),
+            SessionEvent::Err(err) => TrafficClass::Message(lane_key(&err.message_id)),
+            _ => TrafficClass::Control,
+        }
+    }
+}
+
+fn lane_key(key: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
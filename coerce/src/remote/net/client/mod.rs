@@ -1,15 +1,11 @@
 use chrono::{DateTime, Utc};
 use futures::SinkExt;
-use std::collections::VecDeque;
 use std::time::{Duration, Instant};
-use tokio::io::WriteHalf;
-use tokio::net::TcpStream;
 use tokio::sync::oneshot;
 
 use tokio::sync::oneshot::{Receiver, Sender};
 use tokio::task::JoinHandle;
 
-use tokio_util::codec::FramedWrite;
 use uuid::Uuid;
 
 use crate::actor::context::ActorContext;
@@ -18,29 +14,37 @@ use crate::actor::scheduler::timer::Timer;
 
 use crate::actor::{Actor, ActorRefErr, IntoActor, LocalActorRef};
 use crate::remote::cluster::node::{NodeIdentity, RemoteNode};
+use crate::remote::net::client::buffer::WriteBuffer;
+use crate::remote::net::client::config::RemoteClientConfig;
 use crate::remote::net::client::connect::Connect;
+use crate::remote::net::client::lanes::CONTROL_LANE;
 use crate::remote::net::client::receive::HandshakeAcknowledge;
 use crate::remote::net::client::send::write_bytes;
-use crate::remote::net::codec::NetworkCodec;
 use crate::remote::net::message::SessionEvent;
 use crate::remote::net::proto::network as proto;
 use crate::remote::net::proto::network::PingEvent;
+use crate::remote::net::protocol::NegotiatedProtocol;
+use crate::remote::net::stream::NetworkStreamWriter;
 use crate::remote::net::StreamData;
 use crate::remote::system::{NodeId, RemoteActorSystem};
 
+pub mod buffer;
+pub mod config;
 pub mod connect;
+pub mod lanes;
 pub mod ping;
 pub mod receive;
 pub mod send;
 
 pub struct RemoteClient {
     addr: String,
+    lane: usize,
     node_id: Option<NodeId>,
     client_type: ClientType,
+    config: RemoteClientConfig,
     state: Option<ClientState>,
     stop: Option<oneshot::Sender<bool>>,
-    write_buffer_bytes_total: usize,
-    write_buffer: VecDeque<Vec<u8>>,
+    write_buffer: WriteBuffer,
     on_identified_callbacks: Vec<Sender<Option<NodeIdentity>>>,
     on_handshake_ack_callbacks: Vec<HandshakeAckCallback>,
     ping_timer: Option<Timer>,
@@ -48,20 +52,27 @@ pub struct RemoteClient {
 
 struct HandshakeAckCallback {
     request_id: Uuid,
-    callback: Sender<()>,
+    callback: Sender<Vec<RemoteNode>>,
 }
 
 pub struct RemoteClientRef {
     client: LocalActorRef<RemoteClient>,
+    config: RemoteClientConfig,
 }
 
 impl RemoteClient {
     pub async fn new(
         addr: String,
+        lane: usize,
         system: RemoteActorSystem,
         client_type: ClientType,
     ) -> LocalActorRef<Self> {
-        let actor_id = Some(format!("RemoteClient-{}", &addr));
+        let actor_id = Some(if lane == CONTROL_LANE {
+            format!("RemoteClient-{}", &addr)
+        } else {
+            format!("RemoteClient-{}-lane-{}", &addr, lane)
+        });
+
         debug!(
             "Creating RemoteClient (actor_id={})",
             actor_id.as_ref().unwrap()
@@ -69,14 +80,15 @@ impl RemoteClient {
 
         RemoteClient {
             addr,
+            lane,
             client_type,
+            config: system.config().client_config().clone(),
             node_id: None,
             stop: None,
             state: Some(ClientState::Idle {
                 connection_attempts: 0,
             }),
-            write_buffer: VecDeque::new(),
-            write_buffer_bytes_total: 0,
+            write_buffer: WriteBuffer::new(system.config().write_buffer().clone()),
             on_identified_callbacks: vec![],
             on_handshake_ack_callbacks: vec![],
             ping_timer: None,
@@ -86,6 +98,11 @@ impl RemoteClient {
         .unwrap()
     }
 
+    /// Only the control lane sends heartbeats and takes part in node discovery.
+    pub fn is_control_lane(&self) -> bool {
+        self.lane == CONTROL_LANE
+    }
+
     pub fn close(&mut self) -> bool {
         if let Some(stop) = self.stop.take() {
             stop.send(true).is_ok()
@@ -105,11 +122,22 @@ impl Message for Identify {
 
 #[async_trait]
 impl Handler<Identify> for RemoteClient {
-    async fn handle(&mut self, message: Identify, _ctx: &mut ActorContext) {
+    async fn handle(&mut self, message: Identify, ctx: &mut ActorContext) {
         match &self.state {
             Some(ClientState::Connected(state)) => {
                 let _ = message.callback.send(Some(state.identity.clone()));
             }
+            Some(ClientState::Quarantined { .. }) => {
+                // The node was marked as unreachable but has been discovered again, try once more
+                debug!("reconnecting to unreachable node (addr={})", &self.addr);
+
+                self.state = Some(ClientState::Idle {
+                    connection_attempts: 0,
+                });
+
+                self.on_identified_callbacks.push(message.callback);
+                let _ = self.actor_ref(ctx).notify(Connect);
+            }
             _ => {
                 self.on_identified_callbacks.push(message.callback);
             }
@@ -117,20 +145,17 @@ impl Handler<Identify> for RemoteClient {
     }
 }
 
-const REMOTE_CLIENT_HANDSHAKE_TIMEOUT: Duration =
-    Duration::from_secs(3 /*TODO: pull this from config*/);
-const REMOTE_CLIENT_HANDSHAKE_MAX_ATTEMPTS: usize = 5;
-
 impl RemoteClientRef {
-    pub async fn identify(&self) -> Result<Option<NodeIdentity>, ActorRefErr> {
-        const REMOTE_CLIENT_IDENTIFY_TIMEOUT: Duration =
-            Duration::from_secs(1 /*TODO: pull this from config*/);
+    pub fn new(client: LocalActorRef<RemoteClient>, config: RemoteClientConfig) -> Self {
+        Self { client, config }
+    }
 
+    pub async fn identify(&self) -> Result<Option<NodeIdentity>, ActorRefErr> {
         let (tx, rx) = oneshot::channel();
         if let Err(e) = self.client.notify(Identify { callback: tx }) {
             Err(e)
         } else {
-            await_timeout(REMOTE_CLIENT_IDENTIFY_TIMEOUT, rx).await
+            await_timeout(self.config.identify_timeout, rx).await
         }
     }
 
@@ -139,8 +164,19 @@ impl RemoteClientRef {
         request_id: Uuid,
         seed_nodes: Vec<RemoteNode>,
     ) -> Result<(), ActorRefErr> {
+        self.handshake_nodes(request_id, seed_nodes)
+            .await
+            .map(|_| ())
+    }
+
+    /// Handshakes with the node, returning the nodes it knows about, including itself.
+    pub async fn handshake_nodes(
+        &self,
+        request_id: Uuid,
+        seed_nodes: Vec<RemoteNode>,
+    ) -> Result<Vec<RemoteNode>, ActorRefErr> {
         let start = Instant::now();
-        for _attempt in 0..REMOTE_CLIENT_HANDSHAKE_MAX_ATTEMPTS {
+        for _attempt in 0..self.config.handshake_max_attempts {
             match self.handshake_attempt(request_id, seed_nodes.clone()).await {
                 Err(ActorRefErr::Timeout { .. }) => {
                     warn!(
@@ -162,7 +198,7 @@ impl RemoteClientRef {
         &self,
         request_id: Uuid,
         seed_nodes: Vec<RemoteNode>,
-    ) -> Result<(), ActorRefErr> {
+    ) -> Result<Vec<RemoteNode>, ActorRefErr> {
         let (tx, rx) = oneshot::channel();
 
         if let Err(e) = self.client.notify(BeginHandshake {
@@ -172,7 +208,7 @@ impl RemoteClientRef {
         }) {
             Err(e)
         } else {
-            await_timeout(REMOTE_CLIENT_HANDSHAKE_TIMEOUT, rx).await
+            await_timeout(self.config.handshake_timeout, rx).await
         }
     }
 }
@@ -190,12 +226,6 @@ async fn await_timeout<T>(timeout: Duration, rx: Receiver<T>) -> Result<T, Actor
     }
 }
 
-impl From<LocalActorRef<RemoteClient>> for RemoteClientRef {
-    fn from(client: LocalActorRef<RemoteClient>) -> Self {
-        Self { client }
-    }
-}
-
 #[async_trait]
 impl Actor for RemoteClient {
     async fn started(&mut self, ctx: &mut ActorContext) {
@@ -208,7 +238,7 @@ impl Actor for RemoteClient {
             Some(state) => match state {
                 ClientState::Idle { .. } => {}
                 ClientState::Connected(connection) => {
-                    if ctx.system().is_terminated() {
+                    if ctx.system().is_terminated() && self.lane == CONTROL_LANE {
                         debug!(
                             "system shutdown, notifying node(addr={}, id={:?})",
                             &self.addr, &self.node_id
@@ -257,8 +287,9 @@ pub enum ClientState {
 pub struct ConnectionState {
     identity: NodeIdentity,
     handshake: HandshakeStatus,
-    write: FramedWrite<WriteHalf<TcpStream>, NetworkCodec>,
+    write: NetworkStreamWriter,
     receive_task: JoinHandle<()>,
+    protocol: NegotiatedProtocol,
 }
 
 pub enum HandshakeStatus {
@@ -273,7 +304,7 @@ pub enum RemoteClientErr {
     StreamErr(tokio::io::Error),
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum ClientType {
     Client,
     Worker,
@@ -282,7 +313,7 @@ pub enum ClientType {
 pub struct BeginHandshake {
     request_id: Uuid,
     seed_nodes: Vec<RemoteNode>,
-    on_handshake_complete: Sender<()>,
+    on_handshake_complete: Sender<Vec<RemoteNode>>,
 }
 
 impl Message for BeginHandshake {
@@ -291,6 +322,10 @@ impl Message for BeginHandshake {
 
 impl ConnectionState {
     pub async fn disconnected(&mut self) {}
+
+    pub fn protocol(&self) -> &NegotiatedProtocol {
+        &self.protocol
+    }
 }
 
 impl ClientState {
@@ -69,6 +69,7 @@ impl Handler<PingTick> for RemoteClient {
         let ping_event = SessionEvent::Ping(PingEvent {
             message_id: message_id.to_string(),
             node_id: remote.node_id(),
+            leaving: remote.is_leaving(),
             ..PingEvent::default()
         });
 
@@ -2,13 +2,14 @@ use crate::actor::message::Message;
 use std::io::Error;
 use std::net::SocketAddr;
 
-use crate::actor::LocalActorRef;
+use crate::actor::{ActorRefErr, LocalActorRef};
 use crate::remote::actor::{RemoteResponse, SystemCapabilities};
 use crate::remote::cluster::node::{NodeIdentity, RemoteNode};
 use crate::remote::net::client::connect::Disconnected;
 use crate::remote::net::client::RemoteClient;
 use crate::remote::net::message::{timestamp_to_datetime, ClientEvent};
 use crate::remote::net::proto::network::PongEvent;
+use crate::remote::net::protocol::ProtocolVersion;
 use crate::remote::net::{proto, StreamReceiver};
 use crate::remote::system::{NodeId, RemoteActorSystem};
 use chrono::{DateTime, Utc};
@@ -21,7 +22,7 @@ use uuid::Uuid;
 
 pub struct ClientMessageReceiver {
     actor_ref: LocalActorRef<RemoteClient>,
-    identity_sender: Option<Sender<NodeIdentity>>,
+    identity_sender: Option<Sender<(NodeIdentity, Vec<u8>, ProtocolVersion)>>,
     should_close: bool,
     addr: String,
 }
@@ -29,7 +30,7 @@ pub struct ClientMessageReceiver {
 impl ClientMessageReceiver {
     pub fn new(
         actor_ref: LocalActorRef<RemoteClient>,
-        identity_sender: Sender<NodeIdentity>,
+        identity_sender: Sender<(NodeIdentity, Vec<u8>, ProtocolVersion)>,
         addr: String,
     ) -> ClientMessageReceiver {
         let identity_sender = Some(identity_sender);
@@ -61,7 +62,7 @@ impl StreamReceiver for ClientMessageReceiver {
         match msg {
             ClientEvent::Identity(identity) => {
                 if let Some(identity_sender) = self.identity_sender.take() {
-                    let _ = identity_sender.send(NodeIdentity {
+                    let node_identity = NodeIdentity {
                         node: (&identity).into(),
                         peers: identity.peers.into_iter().map(|n| n.into()).collect(),
                         capabilities: identity
@@ -71,7 +72,11 @@ impl StreamReceiver for ClientMessageReceiver {
                                 messages: capabilities.messages.to_vec(),
                             })
                             .unwrap_or_else(|| SystemCapabilities::default()),
-                    });
+                    };
+
+                    let protocol = ProtocolVersion::from(identity.protocol.into_option());
+                    let _ =
+                        identity_sender.send((node_identity, identity.auth_challenge, protocol));
                 } else {
                     debug!("received `Identity` but the client was already identified");
                 }
@@ -107,7 +112,10 @@ impl StreamReceiver for ClientMessageReceiver {
                 }
             }
             ClientEvent::Result(res) => {
-                match sys.pop_request(Uuid::from_str(&res.message_id).unwrap()) {
+                match Uuid::from_str(&res.message_id)
+                    .ok()
+                    .and_then(|id| sys.pop_request(id))
+                {
                     Some(res_tx) => {
                         let _ = res_tx.send(RemoteResponse::Ok(res.result));
                     }
@@ -121,9 +129,17 @@ impl StreamReceiver for ClientMessageReceiver {
             }
             ClientEvent::Err(e) => {
                 info!("received client error!");
-                match sys.pop_request(Uuid::from_str(&e.message_id).unwrap()) {
+                let error: ActorRefErr = e.error.unwrap_or_default().into();
+                if let ActorRefErr::AuthenticationFailed = &error {
+                    error!(target: "RemoteClient", "connection rejected by node (addr={}) - {}", &self.addr, &error);
+                }
+
+                match Uuid::from_str(&e.message_id)
+                    .ok()
+                    .and_then(|id| sys.pop_request(id))
+                {
                     Some(res_tx) => {
-                        let _ = res_tx.send(RemoteResponse::Err(e.error.unwrap().into()));
+                        let _ = res_tx.send(RemoteResponse::Err(error));
                     }
                     None => {
                         //                                          :P
@@ -133,7 +149,10 @@ impl StreamReceiver for ClientMessageReceiver {
             }
             ClientEvent::Ping(_ping) => {}
             ClientEvent::Pong(pong) => {
-                match sys.pop_request(Uuid::from_str(&pong.message_id).unwrap()) {
+                match Uuid::from_str(&pong.message_id)
+                    .ok()
+                    .and_then(|id| sys.pop_request(id))
+                {
                     Some(res_tx) => {
                         let _ = res_tx.send(RemoteResponse::Ok(
                             PongEvent {
@@ -141,7 +160,8 @@ impl StreamReceiver for ClientMessageReceiver {
                                 ..Default::default()
                             }
                             .write_to_bytes()
-                            .expect("serialised pong"),
+                            .expect("serialised pong")
+                            .into(),
                         ));
                     }
                     None => {
@@ -159,7 +179,12 @@ impl StreamReceiver for ClientMessageReceiver {
     }
 
     fn on_deserialisation_failed(&mut self) {
-        warn!("message serialisation failed (addr={})", &self.addr);
+        warn!(
+            "message serialisation failed, closing connection (addr={})",
+            &self.addr
+        );
+
+        self.should_close = true;
     }
 
     fn on_stream_lost(&mut self, error: Error) {
@@ -185,6 +210,7 @@ impl From<proto::network::RemoteNode> for RemoteNode {
             addr: n.addr,
             tag: n.tag,
             node_started_at: n.node_started_at.into_option().map(timestamp_to_datetime),
+            roles: n.roles,
         }
     }
 }
@@ -200,6 +226,7 @@ impl From<&proto::network::NodeIdentity> for RemoteNode {
                 .clone()
                 .into_option()
                 .map(timestamp_to_datetime),
+            roles: n.roles.clone(),
         }
     }
 }
@@ -1,13 +1,13 @@
 use crate::actor::context::ActorContext;
 use crate::actor::message::{Handler, Message};
+use crate::remote::actor::message::FailBufferedWrites;
+use crate::remote::net::client::buffer::{fail_write, BufferedWrite};
 use crate::remote::net::client::connect::Disconnected;
 use crate::remote::net::client::{ClientState, ConnectionState, RemoteClient, RemoteClientErr};
-use crate::remote::net::codec::NetworkCodec;
+use crate::remote::net::stream::NetworkStreamWriter;
 use crate::remote::net::StreamData;
+use crate::remote::system::NodeId;
 use futures::SinkExt;
-use tokio::io::WriteHalf;
-use tokio::net::TcpStream;
-use tokio_util::codec::FramedWrite;
 
 pub struct Write<M: StreamData>(pub M);
 
@@ -52,12 +52,12 @@ impl RemoteClient {
             &self.addr
         );
 
-        while let Some(buffered_message) = self.write_buffer.pop_front() {
-            let len = buffered_message.len();
-            if let Ok(()) = write_bytes(&buffered_message, &mut connection_state.write).await {
-                self.write_buffer_bytes_total -= len;
-            } else {
-                self.write_buffer.push_front(buffered_message);
+        while let Some(buffered_write) = self.write_buffer.pop_front() {
+            if write_bytes(&buffered_write.bytes, &mut connection_state.write)
+                .await
+                .is_err()
+            {
+                self.write_buffer.push_front(buffered_write);
 
                 // write failed, no point trying again - break and reconnect/retry later
                 break;
@@ -65,9 +65,45 @@ impl RemoteClient {
         }
     }
 
-    pub fn buffer_message(&mut self, message_bytes: Vec<u8>) {
-        self.write_buffer_bytes_total += message_bytes.len();
-        self.write_buffer.push_back(message_bytes);
+    pub fn buffer_message(&mut self, write: BufferedWrite, ctx: &ActorContext) {
+        let overflow = self.write_buffer.push(write);
+        if overflow.is_empty() {
+            return;
+        }
+
+        warn!(
+            "write buffer for addr={} is full (buffered={}, buffered_bytes={}), {} message(s) dropped",
+            &self.addr,
+            self.write_buffer.len(),
+            self.write_buffer.bytes_total(),
+            overflow.len()
+        );
+
+        let remote = ctx.system().remote();
+        let node_id = self.node_id.unwrap_or_default();
+        for write in &overflow {
+            fail_write(write, node_id, remote);
+        }
+    }
+
+    /// Fails any buffered requests, called once the node has been declared terminated.
+    pub fn fail_buffered_writes(&mut self, node_id: NodeId, ctx: &ActorContext) {
+        let writes = self.write_buffer.drain();
+        if writes.is_empty() {
+            return;
+        }
+
+        debug!(
+            "node_id={} (addr={}) is unavailable, failing {} buffered message(s)",
+            node_id,
+            &self.addr,
+            writes.len()
+        );
+
+        let remote = ctx.system().remote();
+        for write in &writes {
+            fail_write(write, node_id, remote);
+        }
     }
 
     pub async fn write<M: StreamData>(
@@ -82,7 +118,26 @@ impl RemoteClient {
             let mut buffer_message = None;
 
             let stream_write_error = match &mut self.state.as_mut().unwrap() {
-                ClientState::Idle { .. } | ClientState::Quarantined { .. } => {
+                ClientState::Quarantined { .. } => {
+                    debug!(
+                        "attempt to write to unreachable node (addr={}), message dropped",
+                        &self.addr
+                    );
+
+                    let write = BufferedWrite {
+                        bytes,
+                        request_id: message.request_id(),
+                    };
+
+                    fail_write(
+                        &write,
+                        self.node_id.unwrap_or_default(),
+                        ctx.system().remote(),
+                    );
+                    false
+                }
+
+                ClientState::Idle { .. } => {
                     buffer_message = Some(bytes);
 
                     debug!("attempt to write to addr={} but no connection is established, buffering message (total_buffered={})",
@@ -114,8 +169,13 @@ impl RemoteClient {
                 }
             };
 
-            if let Some(message_bytes) = buffer_message {
-                self.buffer_message(message_bytes);
+            if let Some(bytes) = buffer_message {
+                let write = BufferedWrite {
+                    bytes,
+                    request_id: message.request_id(),
+                };
+
+                self.buffer_message(write, ctx);
             }
 
             if stream_write_error {
@@ -130,9 +190,16 @@ impl RemoteClient {
     }
 }
 
+#[async_trait]
+impl Handler<FailBufferedWrites> for RemoteClient {
+    async fn handle(&mut self, message: FailBufferedWrites, ctx: &mut ActorContext) {
+        self.fail_buffered_writes(message.0, ctx);
+    }
+}
+
 pub(crate) async fn write_bytes(
     bytes: &Vec<u8>,
-    writer: &mut FramedWrite<WriteHalf<TcpStream>, NetworkCodec>,
+    writer: &mut NetworkStreamWriter,
 ) -> Result<(), RemoteClientErr> {
     match writer.send(bytes).await {
         Ok(()) => Ok(()),
@@ -1,12 +1,96 @@
-use bytes::{Buf, BufMut, BytesMut};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
 
 use byteorder::{ByteOrder, LittleEndian};
-use std::io::Error;
+use std::io::{Error, ErrorKind};
 use tokio_util::codec::{Decoder, Encoder};
 
-pub struct NetworkCodec;
+pub const DEFAULT_MAX_FRAME_SIZE: usize = 64 * 1024 * 1024;
 
-// TODO: change the codec to use the `bytes` structs
+const FRAME_HEADER_LEN: usize = 4;
+
+// Set on the event id of compressed frames, followed by the uncompressed length and the LZ4 block
+const COMPRESSED_FLAG: u8 = 0x80;
+
+const COMPRESSION_THRESHOLD: usize = 1024;
+
+/// The most that's reserved up front for a partially received frame, so a peer can't make the
+/// codec allocate `max_frame_size` bytes just by sending a frame header.
+const MAX_FRAME_RESERVE: usize = 64 * 1024;
+
+#[derive(Clone, Copy, Debug)]
+pub struct NetworkCodec {
+    max_frame_size: usize,
+    compression: bool,
+}
+
+impl NetworkCodec {
+    pub fn new(max_frame_size: usize) -> NetworkCodec {
+        NetworkCodec {
+            max_frame_size: max_frame_size.min(i32::MAX as usize),
+            compression: false,
+        }
+    }
+
+    pub fn max_frame_size(&self) -> usize {
+        self.max_frame_size
+    }
+
+    /// Compress outgoing frames, should only be enabled once the peer has advertised
+    /// support for `ProtocolFeature::Lz4Compression`. Compressed frames are always accepted.
+    pub fn set_compression(&mut self, compression: bool) {
+        self.compression = compression;
+    }
+
+    fn compress(item: &[u8]) -> Option<Vec<u8>> {
+        let compressed = lz4_flex::compress(&item[1..]);
+        if compressed.len() + 5 >= item.len() {
+            return None;
+        }
+
+        let mut frame = Vec::with_capacity(compressed.len() + 5);
+        frame.push(item[0] | COMPRESSED_FLAG);
+        frame.extend_from_slice(&((item.len() - 1) as u32).to_le_bytes());
+        frame.extend_from_slice(&compressed);
+        Some(frame)
+    }
+
+    fn decompress(&self, frame: Bytes) -> Result<Bytes, Error> {
+        if frame.len() < 5 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "invalid compressed frame",
+            ));
+        }
+
+        let len = LittleEndian::read_u32(&frame[1..5]) as usize;
+        if len >= self.max_frame_size {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "decompressed frame length {} exceeds the max frame size ({} bytes)",
+                    len, self.max_frame_size
+                ),
+            ));
+        }
+
+        let mut decompressed = BytesMut::zeroed(len + 1);
+        decompressed[0] = frame[0] & !COMPRESSED_FLAG;
+
+        match lz4_flex::block::decompress_into(&frame[5..], &mut decompressed[1..]) {
+            Ok(n) if n == len => Ok(decompressed.freeze()),
+            _ => Err(Error::new(
+                ErrorKind::InvalidData,
+                "invalid compressed frame",
+            )),
+        }
+    }
+}
+
+impl Default for NetworkCodec {
+    fn default() -> Self {
+        NetworkCodec::new(DEFAULT_MAX_FRAME_SIZE)
+    }
+}
 
 impl Encoder<&Vec<u8>> for NetworkCodec {
     type Error = Error;
@@ -14,7 +98,26 @@ impl Encoder<&Vec<u8>> for NetworkCodec {
     fn encode(&mut self, item: &Vec<u8>, dst: &mut BytesMut) -> Result<(), Error> {
         trace!(target: "NetworkCodec", "encoding msg");
 
-        dst.reserve(4 + item.len());
+        if item.len() > self.max_frame_size {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "frame of {} bytes exceeds the max frame size ({} bytes)",
+                    item.len(),
+                    self.max_frame_size
+                ),
+            ));
+        }
+
+        let compressed = if self.compression && item.len() >= COMPRESSION_THRESHOLD {
+            Self::compress(item)
+        } else {
+            None
+        };
+
+        let item = compressed.as_ref().unwrap_or(item);
+
+        dst.reserve(FRAME_HEADER_LEN + item.len());
         dst.put_i32_le(item.len() as i32);
         dst.put_slice(item);
 
@@ -23,23 +126,44 @@ impl Encoder<&Vec<u8>> for NetworkCodec {
 }
 
 impl Decoder for NetworkCodec {
-    type Item = Vec<u8>;
+    type Item = Bytes;
     type Error = Error;
 
-    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Vec<u8>>, Error> {
-        if src.is_empty() || src.remaining() <= 4 {
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Bytes>, Error> {
+        if src.remaining() < FRAME_HEADER_LEN {
             return Ok(None);
         }
 
         trace!(target: "NetworkCodec", "decoding message");
 
-        let len = LittleEndian::read_i32(src.as_ref()) as usize;
-        if (src.remaining() - 4) < len {
+        let len = LittleEndian::read_i32(src.as_ref());
+        if len < 0 || len as usize > self.max_frame_size {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "invalid frame length {} (max frame size={} bytes)",
+                    len, self.max_frame_size
+                ),
+            ));
+        }
+
+        let len = len as usize;
+        if src.remaining() - FRAME_HEADER_LEN < len {
+            let needed = FRAME_HEADER_LEN + len - src.remaining();
+            src.reserve(needed.min(MAX_FRAME_RESERVE));
             return Ok(None);
         }
 
-        src.advance(4);
-        let buf = src.split_to(len);
-        Ok(Some(buf.to_vec()))
+        src.advance(FRAME_HEADER_LEN);
+
+        let frame = src.split_to(len).freeze();
+        if frame
+            .first()
+            .is_some_and(|event| event & COMPRESSED_FLAG != 0)
+        {
+            self.decompress(frame).map(Some)
+        } else {
+            Ok(Some(frame))
+        }
     }
 }
@@ -2,10 +2,11 @@ use crate::actor::message::{MessageUnwrapErr, MessageWrapErr};
 use crate::actor::{ActorRefErr, ToActorId};
 use crate::remote::net::proto::network::{
     ActorAddress, ClientErr, ClientHandshake, ClientResult, CreateActorEvent, Event,
-    FindActorEvent, IdentifyEvent, MessageRequest, NodeIdentity, PingEvent, PongEvent, RaftRequest,
+    FindActorEvent, IdentifyEvent, MessageRequest, NodeIdentity, PingEvent, PongEvent,
     SessionHandshake, StreamPublishEvent,
 };
 use crate::remote::net::{proto, StreamData};
+use bytes::Bytes;
 use chrono::{DateTime, NaiveDateTime, Utc};
 use protobuf::{Enum, Error, Message};
 use std::fmt::{Debug, Display, Formatter};
@@ -34,7 +35,6 @@ pub enum SessionEvent {
     StreamPublish(Arc<StreamPublishEvent>),
     Result(ClientResult),
     Err(ClientErr),
-    Raft(RaftRequest),
 }
 
 #[derive(Debug)]
@@ -50,26 +50,26 @@ impl Display for ClientError {
 }
 
 impl StreamData for ClientEvent {
-    fn read_from_bytes(data: Vec<u8>) -> Option<Self> {
-        match data.split_first() {
-            Some((event, message)) => match Event::from_i32(*event as i32) {
+    fn read_from_bytes(data: Bytes) -> Option<Self> {
+        match split_event(data) {
+            Some((event, message)) => match Event::from_i32(event as i32) {
                 Some(Event::Identity) => Some(ClientEvent::Identity(
-                    NodeIdentity::parse_from_bytes(message).unwrap(),
+                    NodeIdentity::parse_from_tokio_bytes(&message).ok()?,
                 )),
                 Some(Event::Handshake) => Some(ClientEvent::Handshake(
-                    ClientHandshake::parse_from_bytes(message).unwrap(),
+                    ClientHandshake::parse_from_tokio_bytes(&message).ok()?,
                 )),
                 Some(Event::Result) => Some(ClientEvent::Result(
-                    ClientResult::parse_from_bytes(message).unwrap(),
+                    ClientResult::parse_from_tokio_bytes(&message).ok()?,
                 )),
                 Some(Event::Err) => Some(ClientEvent::Err(
-                    ClientErr::parse_from_bytes(message).unwrap(),
+                    ClientErr::parse_from_tokio_bytes(&message).ok()?,
                 )),
                 Some(Event::Ping) => Some(ClientEvent::Ping(
-                    PingEvent::parse_from_bytes(message).unwrap(),
+                    PingEvent::parse_from_tokio_bytes(&message).ok()?,
                 )),
                 Some(Event::Pong) => Some(ClientEvent::Pong(
-                    PongEvent::parse_from_bytes(message).unwrap(),
+                    PongEvent::parse_from_tokio_bytes(&message).ok()?,
                 )),
                 _ => None,
             },
@@ -92,41 +92,41 @@ impl StreamData for ClientEvent {
 }
 
 impl StreamData for SessionEvent {
-    fn read_from_bytes(data: Vec<u8>) -> Option<Self> {
-        match data.split_first() {
-            Some((event, message)) => match Event::from_i32(*event as i32) {
+    fn read_from_bytes(data: Bytes) -> Option<Self> {
+        match split_event(data) {
+            Some((event, message)) => match Event::from_i32(event as i32) {
                 Some(Event::Identify) => Some(SessionEvent::Identify(
-                    IdentifyEvent::parse_from_bytes(message).unwrap(),
+                    IdentifyEvent::parse_from_tokio_bytes(&message).ok()?,
                 )),
                 Some(Event::Handshake) => Some(SessionEvent::Handshake(
-                    SessionHandshake::parse_from_bytes(message).unwrap(),
+                    SessionHandshake::parse_from_tokio_bytes(&message).ok()?,
                 )),
                 Some(Event::Ping) => Some(SessionEvent::Ping(
-                    PingEvent::parse_from_bytes(message).unwrap(),
+                    PingEvent::parse_from_tokio_bytes(&message).ok()?,
                 )),
                 Some(Event::Pong) => Some(SessionEvent::Pong(
-                    PongEvent::parse_from_bytes(message).unwrap(),
+                    PongEvent::parse_from_tokio_bytes(&message).ok()?,
                 )),
                 Some(Event::CreateActor) => Some(SessionEvent::CreateActor(
-                    CreateActorEvent::parse_from_bytes(message).unwrap(),
+                    CreateActorEvent::parse_from_tokio_bytes(&message).ok()?,
                 )),
                 Some(Event::FindActor) => Some(SessionEvent::FindActor(
-                    FindActorEvent::parse_from_bytes(message).unwrap(),
+                    FindActorEvent::parse_from_tokio_bytes(&message).ok()?,
                 )),
                 Some(Event::NotifyActor) => Some(SessionEvent::NotifyActor(
-                    MessageRequest::parse_from_bytes(message).unwrap(),
+                    MessageRequest::parse_from_tokio_bytes(&message).ok()?,
                 )),
                 Some(Event::RegisterActor) => Some(SessionEvent::RegisterActor(
-                    ActorAddress::parse_from_bytes(message).unwrap(),
+                    ActorAddress::parse_from_tokio_bytes(&message).ok()?,
                 )),
                 Some(Event::StreamPublish) => Some(SessionEvent::StreamPublish(Arc::new(
-                    StreamPublishEvent::parse_from_bytes(message).unwrap(),
+                    StreamPublishEvent::parse_from_tokio_bytes(&message).ok()?,
                 ))),
                 Some(Event::Result) => Some(SessionEvent::Result(
-                    ClientResult::parse_from_bytes(message).unwrap(),
+                    ClientResult::parse_from_tokio_bytes(&message).ok()?,
                 )),
                 Some(Event::Err) => Some(SessionEvent::Err(
-                    ClientErr::parse_from_bytes(message).unwrap(),
+                    ClientErr::parse_from_tokio_bytes(&message).ok()?,
                 )),
                 _ => None,
             },
@@ -147,11 +147,28 @@ impl StreamData for SessionEvent {
             SessionEvent::Result(e) => (Event::Result, e.write_to_bytes()),
             SessionEvent::Identify(e) => (Event::Identify, e.write_to_bytes()),
             SessionEvent::Err(e) => (Event::Err, e.write_to_bytes()),
-            _ => return None,
         };
 
         write_event(event_id, message)
     }
+
+    fn request_id(&self) -> Option<Uuid> {
+        match self {
+            SessionEvent::NotifyActor(request) if request.requires_response => {
+                Uuid::parse_str(&request.message_id).ok()
+            }
+            _ => None,
+        }
+    }
+}
+
+fn split_event(mut data: Bytes) -> Option<(u8, Bytes)> {
+    if data.is_empty() {
+        None
+    } else {
+        let message = data.split_off(1);
+        Some((data[0], message))
+    }
 }
 
 fn write_event(event_id: Event, message: Result<Vec<u8>, Error>) -> Option<Vec<u8>> {
@@ -239,6 +256,12 @@ impl From<ActorRefErr> for proto::network::ActorRefErr {
                 ErrorType::NotSupported
             }
             ActorRefErr::NotImplemented => ErrorType::NotImplemented,
+            ActorRefErr::AuthenticationFailed => ErrorType::AuthenticationFailed,
+            ActorRefErr::IncompatibleProtocol => ErrorType::IncompatibleProtocol,
+            ActorRefErr::NodeUnavailable(node_id) => {
+                error.node_id = node_id;
+                ErrorType::NodeUnavailable
+            }
         }
         .into();
 
@@ -252,24 +275,24 @@ impl From<proto::network::ActorRefErr> for ActorRefErr {
         use proto::network::MessageUnwrapErr as ProtoUnwrapErr;
         use proto::network::MessageWrapErr as ProtoWrapErr;
 
-        match err.type_.unwrap() {
+        match err.type_.enum_value_or_default() {
             ErrorType::ActorUnavailable => ActorRefErr::ActorUnavailable,
             ErrorType::NotFound => ActorRefErr::NotFound(err.actor_id.to_actor_id()),
             ErrorType::AlreadyExists => ActorRefErr::AlreadyExists(err.actor_id.to_actor_id()),
             ErrorType::Serialisation => {
-                ActorRefErr::Serialisation(match err.serialization_error.unwrap() {
+                ActorRefErr::Serialisation(match err.serialization_error.enum_value_or_default() {
                     ProtoWrapErr::WrapUnsupported => MessageWrapErr::NotTransmittable,
                     ProtoWrapErr::SerializationErr => MessageWrapErr::SerializationErr,
                     ProtoWrapErr::UnknownWrapErr => MessageWrapErr::Unknown,
                 })
             }
-            ErrorType::Deserialisation => {
-                ActorRefErr::Deserialisation(match err.deserialization_error.unwrap() {
+            ErrorType::Deserialisation => ActorRefErr::Deserialisation(
+                match err.deserialization_error.enum_value_or_default() {
                     ProtoUnwrapErr::UnwrapUnsupported => MessageUnwrapErr::NotTransmittable,
                     ProtoUnwrapErr::DeserializationErr => MessageUnwrapErr::DeserializationErr,
                     ProtoUnwrapErr::UnknownUnwrapErr => MessageUnwrapErr::Unknown,
-                })
-            }
+                },
+            ),
             ErrorType::Timeout => ActorRefErr::Timeout {
                 time_taken_millis: err.time_taken_millis,
             },
@@ -283,6 +306,9 @@ impl From<proto::network::ActorRefErr> for ActorRefErr {
                 actor_type: err.actor_type,
             },
             ErrorType::NotImplemented => ActorRefErr::NotImplemented,
+            ErrorType::AuthenticationFailed => ActorRefErr::AuthenticationFailed,
+            ErrorType::IncompatibleProtocol => ActorRefErr::IncompatibleProtocol,
+            ErrorType::NodeUnavailable => ActorRefErr::NodeUnavailable(err.node_id),
         }
     }
 }
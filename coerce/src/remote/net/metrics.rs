@@ -0,0 +1,33 @@
+use crate::remote::net::auth::AuthErr;
+
+pub const METRIC_NETWORK_AUTH_FAILURES_TOTAL: &str = "coerce_network_auth_failures_total";
+pub const METRIC_NETWORK_RECONNECT_ATTEMPTS_TOTAL: &str = "coerce_network_reconnect_attempts_total";
+pub const METRIC_NETWORK_NODES_UNREACHABLE_TOTAL: &str = "coerce_network_nodes_unreachable_total";
+
+pub const LABEL_REASON: &str = "reason";
+pub const LABEL_ADDR: &str = "addr";
+
+pub struct NetworkMetrics;
+
+impl NetworkMetrics {
+    #[inline]
+    pub fn incr_auth_failures(error: &AuthErr) {
+        increment_counter!(METRIC_NETWORK_AUTH_FAILURES_TOTAL,
+            LABEL_REASON => error.as_label(),
+        );
+    }
+
+    #[inline]
+    pub fn incr_reconnect_attempts(addr: &str) {
+        increment_counter!(METRIC_NETWORK_RECONNECT_ATTEMPTS_TOTAL,
+            LABEL_ADDR => addr.to_string(),
+        );
+    }
+
+    #[inline]
+    pub fn incr_nodes_unreachable(addr: &str) {
+        increment_counter!(METRIC_NETWORK_NODES_UNREACHABLE_TOTAL,
+            LABEL_ADDR => addr.to_string(),
+        );
+    }
+}
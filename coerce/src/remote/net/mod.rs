@@ -8,20 +8,34 @@ use std::pin::Pin;
 use std::task::{Context, Poll};
 
 use crate::remote::net::codec::NetworkCodec;
+use bytes::Bytes;
 use futures::StreamExt;
 use protobuf::Message;
 use tokio_util::codec::FramedRead;
+use uuid::Uuid;
 
+pub mod auth;
 pub mod client;
 pub mod codec;
 pub mod message;
+pub mod metrics;
 pub mod proto;
+pub mod protocol;
 pub mod server;
+pub mod stream;
+pub mod tls;
+pub mod transport;
 
 pub trait StreamData: 'static + Send + Sync + Sized {
-    fn read_from_bytes(data: Vec<u8>) -> Option<Self>;
+    fn read_from_bytes(data: Bytes) -> Option<Self>;
 
     fn write_to_bytes(&self) -> Option<Vec<u8>>;
+
+    /// The id of the request awaiting a response, used to fail the request
+    /// if the message can't be delivered.
+    fn request_id(&self) -> Option<Uuid> {
+        None
+    }
 }
 
 #[async_trait]
@@ -59,14 +73,14 @@ impl<S: tokio::io::AsyncRead> tokio_stream::Stream for StreamReceiverFuture<S>
 where
     S: Unpin,
 {
-    type Item = Option<Vec<u8>>;
+    type Item = Option<Bytes>;
 
-    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Option<Vec<u8>>>> {
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Option<Bytes>>> {
         if let Poll::Ready(Ok(true)) = Pin::new(&mut self.stop_rx).poll(cx) {
             return Poll::Ready(None);
         }
 
-        let result: Option<Result<Vec<u8>, Error>> =
+        let result: Option<Result<Bytes, Error>> =
             futures::ready!(Pin::new(&mut self.stream).poll_next(cx));
 
         Poll::Ready(match result {
@@ -100,6 +114,9 @@ pub async fn receive_loop<R: StreamReceiver, S: tokio::io::AsyncRead + Unpin>(
                 None => {
                     // TODO: either pass the buffer into here or more context, this is pretty useless at the moment..
                     receiver.on_deserialisation_failed();
+                    if receiver.should_close() {
+                        break;
+                    }
                 }
             },
             Err(e) => {
@@ -37,6 +37,8 @@ pub struct RemoteNode {
     pub node_started_at: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
     // @@protoc_insertion_point(field:coerce.network.RemoteNode.tag)
     pub tag: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.RemoteNode.roles)
+    pub roles: ::std::vec::Vec<::std::string::String>,
     // special fields
     // @@protoc_insertion_point(special_field:coerce.network.RemoteNode.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -54,7 +56,7 @@ impl RemoteNode {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "node_id",
@@ -76,6 +78,11 @@ impl RemoteNode {
             |m: &RemoteNode| { &m.tag },
             |m: &mut RemoteNode| { &mut m.tag },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "roles",
+            |m: &RemoteNode| { &m.roles },
+            |m: &mut RemoteNode| { &mut m.roles },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RemoteNode>(
             "RemoteNode",
             fields,
@@ -106,6 +113,9 @@ impl ::protobuf::Message for RemoteNode {
                 34 => {
                     self.tag = is.read_string()?;
                 },
+                42 => {
+                    self.roles.push(is.read_string()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -131,6 +141,9 @@ impl ::protobuf::Message for RemoteNode {
         if !self.tag.is_empty() {
             my_size += ::protobuf::rt::string_size(4, &self.tag);
         }
+        for value in &self.roles {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -149,6 +162,9 @@ impl ::protobuf::Message for RemoteNode {
         if !self.tag.is_empty() {
             os.write_string(4, &self.tag)?;
         }
+        for v in &self.roles {
+            os.write_string(5, &v)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -170,6 +186,7 @@ impl ::protobuf::Message for RemoteNode {
         self.addr.clear();
         self.node_started_at.clear();
         self.tag.clear();
+        self.roles.clear();
         self.special_fields.clear();
     }
 
@@ -179,6 +196,7 @@ impl ::protobuf::Message for RemoteNode {
             addr: ::std::string::String::new(),
             node_started_at: ::protobuf::MessageField::none(),
             tag: ::std::string::String::new(),
+            roles: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -211,7 +229,11 @@ pub struct IdentifyEvent {
     // @@protoc_insertion_point(field:coerce.network.IdentifyEvent.source_node_tag)
     pub source_node_tag: ::std::string::String,
     // @@protoc_insertion_point(field:coerce.network.IdentifyEvent.token)
-    pub token: ::std::string::String,
+    pub token: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:coerce.network.IdentifyEvent.message_id)
+    pub message_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.IdentifyEvent.protocol)
+    pub protocol: ::protobuf::MessageField<ProtocolVersion>,
     // special fields
     // @@protoc_insertion_point(special_field:coerce.network.IdentifyEvent.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -229,7 +251,7 @@ impl IdentifyEvent {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "source_node_id",
@@ -246,6 +268,16 @@ impl IdentifyEvent {
             |m: &IdentifyEvent| { &m.token },
             |m: &mut IdentifyEvent| { &mut m.token },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message_id",
+            |m: &IdentifyEvent| { &m.message_id },
+            |m: &mut IdentifyEvent| { &mut m.message_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ProtocolVersion>(
+            "protocol",
+            |m: &IdentifyEvent| { &m.protocol },
+            |m: &mut IdentifyEvent| { &mut m.protocol },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<IdentifyEvent>(
             "IdentifyEvent",
             fields,
@@ -271,7 +303,13 @@ impl ::protobuf::Message for IdentifyEvent {
                     self.source_node_tag = is.read_string()?;
                 },
                 26 => {
-                    self.token = is.read_string()?;
+                    self.token = is.read_bytes()?;
+                },
+                34 => {
+                    self.message_id = is.read_string()?;
+                },
+                42 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.protocol)?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -292,7 +330,14 @@ impl ::protobuf::Message for IdentifyEvent {
             my_size += ::protobuf::rt::string_size(2, &self.source_node_tag);
         }
         if !self.token.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.token);
+            my_size += ::protobuf::rt::bytes_size(3, &self.token);
+        }
+        if !self.message_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.message_id);
+        }
+        if let Some(v) = self.protocol.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -307,7 +352,13 @@ impl ::protobuf::Message for IdentifyEvent {
             os.write_string(2, &self.source_node_tag)?;
         }
         if !self.token.is_empty() {
-            os.write_string(3, &self.token)?;
+            os.write_bytes(3, &self.token)?;
+        }
+        if !self.message_id.is_empty() {
+            os.write_string(4, &self.message_id)?;
+        }
+        if let Some(v) = self.protocol.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -329,6 +380,8 @@ impl ::protobuf::Message for IdentifyEvent {
         self.source_node_id = 0;
         self.source_node_tag.clear();
         self.token.clear();
+        self.message_id.clear();
+        self.protocol.clear();
         self.special_fields.clear();
     }
 
@@ -336,7 +389,9 @@ impl ::protobuf::Message for IdentifyEvent {
         static instance: IdentifyEvent = IdentifyEvent {
             source_node_id: 0,
             source_node_tag: ::std::string::String::new(),
-            token: ::std::string::String::new(),
+            token: ::std::vec::Vec::new(),
+            message_id: ::std::string::String::new(),
+            protocol: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -360,6 +415,164 @@ impl ::protobuf::reflect::ProtobufValue for IdentifyEvent {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.ProtocolVersion)
+pub struct ProtocolVersion {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.ProtocolVersion.version)
+    pub version: u32,
+    // @@protoc_insertion_point(field:coerce.network.ProtocolVersion.min_version)
+    pub min_version: u32,
+    // @@protoc_insertion_point(field:coerce.network.ProtocolVersion.features)
+    pub features: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.ProtocolVersion.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ProtocolVersion {
+    fn default() -> &'a ProtocolVersion {
+        <ProtocolVersion as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ProtocolVersion {
+    pub fn new() -> ProtocolVersion {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &ProtocolVersion| { &m.version },
+            |m: &mut ProtocolVersion| { &mut m.version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "min_version",
+            |m: &ProtocolVersion| { &m.min_version },
+            |m: &mut ProtocolVersion| { &mut m.min_version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "features",
+            |m: &ProtocolVersion| { &m.features },
+            |m: &mut ProtocolVersion| { &mut m.features },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ProtocolVersion>(
+            "ProtocolVersion",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ProtocolVersion {
+    const NAME: &'static str = "ProtocolVersion";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.version = is.read_uint32()?;
+                },
+                16 => {
+                    self.min_version = is.read_uint32()?;
+                },
+                26 => {
+                    self.features.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.version != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.version);
+        }
+        if self.min_version != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.min_version);
+        }
+        for value in &self.features {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.version != 0 {
+            os.write_uint32(1, self.version)?;
+        }
+        if self.min_version != 0 {
+            os.write_uint32(2, self.min_version)?;
+        }
+        for v in &self.features {
+            os.write_string(3, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ProtocolVersion {
+        ProtocolVersion::new()
+    }
+
+    fn clear(&mut self) {
+        self.version = 0;
+        self.min_version = 0;
+        self.features.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ProtocolVersion {
+        static instance: ProtocolVersion = ProtocolVersion {
+            version: 0,
+            min_version: 0,
+            features: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ProtocolVersion {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ProtocolVersion").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ProtocolVersion {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ProtocolVersion {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:coerce.network.NodeIdentity)
 pub struct NodeIdentity {
@@ -380,6 +593,12 @@ pub struct NodeIdentity {
     pub peers: ::std::vec::Vec<RemoteNode>,
     // @@protoc_insertion_point(field:coerce.network.NodeIdentity.capabilities)
     pub capabilities: ::protobuf::MessageField<SystemCapabilities>,
+    // @@protoc_insertion_point(field:coerce.network.NodeIdentity.auth_challenge)
+    pub auth_challenge: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:coerce.network.NodeIdentity.protocol)
+    pub protocol: ::protobuf::MessageField<ProtocolVersion>,
+    // @@protoc_insertion_point(field:coerce.network.NodeIdentity.roles)
+    pub roles: ::std::vec::Vec<::std::string::String>,
     // special fields
     // @@protoc_insertion_point(special_field:coerce.network.NodeIdentity.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -397,7 +616,7 @@ impl NodeIdentity {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(8);
+        let mut fields = ::std::vec::Vec::with_capacity(11);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "node_id",
@@ -439,6 +658,21 @@ impl NodeIdentity {
             |m: &NodeIdentity| { &m.capabilities },
             |m: &mut NodeIdentity| { &mut m.capabilities },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "auth_challenge",
+            |m: &NodeIdentity| { &m.auth_challenge },
+            |m: &mut NodeIdentity| { &mut m.auth_challenge },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ProtocolVersion>(
+            "protocol",
+            |m: &NodeIdentity| { &m.protocol },
+            |m: &mut NodeIdentity| { &mut m.protocol },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "roles",
+            |m: &NodeIdentity| { &m.roles },
+            |m: &mut NodeIdentity| { &mut m.roles },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<NodeIdentity>(
             "NodeIdentity",
             fields,
@@ -481,6 +715,15 @@ impl ::protobuf::Message for NodeIdentity {
                 66 => {
                     ::protobuf::rt::read_singular_message_into_field(is, &mut self.capabilities)?;
                 },
+                74 => {
+                    self.auth_challenge = is.read_bytes()?;
+                },
+                82 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.protocol)?;
+                },
+                90 => {
+                    self.roles.push(is.read_string()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -520,6 +763,16 @@ impl ::protobuf::Message for NodeIdentity {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
+        if !self.auth_challenge.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(9, &self.auth_challenge);
+        }
+        if let Some(v) = self.protocol.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.roles {
+            my_size += ::protobuf::rt::string_size(11, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -550,6 +803,15 @@ impl ::protobuf::Message for NodeIdentity {
         if let Some(v) = self.capabilities.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(8, v, os)?;
         }
+        if !self.auth_challenge.is_empty() {
+            os.write_bytes(9, &self.auth_challenge)?;
+        }
+        if let Some(v) = self.protocol.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
+        }
+        for v in &self.roles {
+            os.write_string(11, &v)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -575,6 +837,9 @@ impl ::protobuf::Message for NodeIdentity {
         self.node_started_at.clear();
         self.peers.clear();
         self.capabilities.clear();
+        self.auth_challenge.clear();
+        self.protocol.clear();
+        self.roles.clear();
         self.special_fields.clear();
     }
 
@@ -588,6 +853,9 @@ impl ::protobuf::Message for NodeIdentity {
             node_started_at: ::protobuf::MessageField::none(),
             peers: ::std::vec::Vec::new(),
             capabilities: ::protobuf::MessageField::none(),
+            auth_challenge: ::std::vec::Vec::new(),
+            protocol: ::protobuf::MessageField::none(),
+            roles: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -954,7 +1222,7 @@ pub struct ClientResult {
     // @@protoc_insertion_point(field:coerce.network.ClientResult.message_id)
     pub message_id: ::std::string::String,
     // @@protoc_insertion_point(field:coerce.network.ClientResult.result)
-    pub result: ::std::vec::Vec<u8>,
+    pub result: ::bytes::Bytes,
     // @@protoc_insertion_point(field:coerce.network.ClientResult.trace_id)
     pub trace_id: ::std::string::String,
     // special fields
@@ -1013,7 +1281,7 @@ impl ::protobuf::Message for ClientResult {
                     self.message_id = is.read_string()?;
                 },
                 18 => {
-                    self.result = is.read_bytes()?;
+                    self.result = is.read_tokio_bytes()?;
                 },
                 26 => {
                     self.trace_id = is.read_string()?;
@@ -1080,7 +1348,7 @@ impl ::protobuf::Message for ClientResult {
     fn default_instance() -> &'static ClientResult {
         static instance: ClientResult = ClientResult {
             message_id: ::std::string::String::new(),
-            result: ::std::vec::Vec::new(),
+            result: ::bytes::Bytes::new(),
             trace_id: ::std::string::String::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
@@ -1276,6 +1544,8 @@ pub struct PingEvent {
     pub node_id: u64,
     // @@protoc_insertion_point(field:coerce.network.PingEvent.system_terminated)
     pub system_terminated: bool,
+    // @@protoc_insertion_point(field:coerce.network.PingEvent.leaving)
+    pub leaving: bool,
     // special fields
     // @@protoc_insertion_point(special_field:coerce.network.PingEvent.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -1293,7 +1563,7 @@ impl PingEvent {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "message_id",
@@ -1315,6 +1585,11 @@ impl PingEvent {
             |m: &PingEvent| { &m.system_terminated },
             |m: &mut PingEvent| { &mut m.system_terminated },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "leaving",
+            |m: &PingEvent| { &m.leaving },
+            |m: &mut PingEvent| { &mut m.leaving },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PingEvent>(
             "PingEvent",
             fields,
@@ -1345,6 +1620,9 @@ impl ::protobuf::Message for PingEvent {
                 32 => {
                     self.system_terminated = is.read_bool()?;
                 },
+                40 => {
+                    self.leaving = is.read_bool()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1369,6 +1647,9 @@ impl ::protobuf::Message for PingEvent {
         if self.system_terminated != false {
             my_size += 1 + 1;
         }
+        if self.leaving != false {
+            my_size += 1 + 1;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -1387,6 +1668,9 @@ impl ::protobuf::Message for PingEvent {
         if self.system_terminated != false {
             os.write_bool(4, self.system_terminated)?;
         }
+        if self.leaving != false {
+            os.write_bool(5, self.leaving)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1408,6 +1692,7 @@ impl ::protobuf::Message for PingEvent {
         self.trace_id.clear();
         self.node_id = 0;
         self.system_terminated = false;
+        self.leaving = false;
         self.special_fields.clear();
     }
 
@@ -1417,6 +1702,7 @@ impl ::protobuf::Message for PingEvent {
             trace_id: ::std::string::String::new(),
             node_id: 0,
             system_terminated: false,
+            leaving: false,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -2102,7 +2388,7 @@ pub struct MessageRequest {
     // @@protoc_insertion_point(field:coerce.network.MessageRequest.actor_id)
     pub actor_id: ::std::string::String,
     // @@protoc_insertion_point(field:coerce.network.MessageRequest.message)
-    pub message: ::std::vec::Vec<u8>,
+    pub message: ::bytes::Bytes,
     // @@protoc_insertion_point(field:coerce.network.MessageRequest.trace_id)
     pub trace_id: ::std::string::String,
     // @@protoc_insertion_point(field:coerce.network.MessageRequest.requires_response)
@@ -2191,7 +2477,7 @@ impl ::protobuf::Message for MessageRequest {
                     self.actor_id = is.read_string()?;
                 },
                 34 => {
-                    self.message = is.read_bytes()?;
+                    self.message = is.read_tokio_bytes()?;
                 },
                 42 => {
                     self.trace_id = is.read_string()?;
@@ -2294,7 +2580,7 @@ impl ::protobuf::Message for MessageRequest {
             message_id: ::std::string::String::new(),
             handler_type: ::std::string::String::new(),
             actor_id: ::std::string::String::new(),
-            message: ::std::vec::Vec::new(),
+            message: ::bytes::Bytes::new(),
             trace_id: ::std::string::String::new(),
             requires_response: false,
             origin_node_id: 0,
@@ -2543,7 +2829,7 @@ pub struct StreamPublishEvent {
     // @@protoc_insertion_point(field:coerce.network.StreamPublishEvent.key)
     pub key: ::std::string::String,
     // @@protoc_insertion_point(field:coerce.network.StreamPublishEvent.message)
-    pub message: ::std::vec::Vec<u8>,
+    pub message: ::bytes::Bytes,
     // @@protoc_insertion_point(field:coerce.network.StreamPublishEvent.trace_id)
     pub trace_id: ::std::string::String,
     // special fields
@@ -2610,7 +2896,7 @@ impl ::protobuf::Message for StreamPublishEvent {
                     self.key = is.read_string()?;
                 },
                 26 => {
-                    self.message = is.read_bytes()?;
+                    self.message = is.read_tokio_bytes()?;
                 },
                 34 => {
                     self.trace_id = is.read_string()?;
@@ -2685,7 +2971,7 @@ impl ::protobuf::Message for StreamPublishEvent {
         static instance: StreamPublishEvent = StreamPublishEvent {
             topic: ::std::string::String::new(),
             key: ::std::string::String::new(),
-            message: ::std::vec::Vec::new(),
+            message: ::bytes::Bytes::new(),
             trace_id: ::std::string::String::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
@@ -3132,164 +3418,6 @@ impl ::protobuf::reflect::ProtobufValue for LeaderChangedEvent {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-#[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:coerce.network.RaftRequest)
-pub struct RaftRequest {
-    // message fields
-    // @@protoc_insertion_point(field:coerce.network.RaftRequest.message_id)
-    pub message_id: ::std::string::String,
-    // @@protoc_insertion_point(field:coerce.network.RaftRequest.request_type)
-    pub request_type: u32,
-    // @@protoc_insertion_point(field:coerce.network.RaftRequest.payload)
-    pub payload: ::std::vec::Vec<u8>,
-    // special fields
-    // @@protoc_insertion_point(special_field:coerce.network.RaftRequest.special_fields)
-    pub special_fields: ::protobuf::SpecialFields,
-}
-
-impl<'a> ::std::default::Default for &'a RaftRequest {
-    fn default() -> &'a RaftRequest {
-        <RaftRequest as ::protobuf::Message>::default_instance()
-    }
-}
-
-impl RaftRequest {
-    pub fn new() -> RaftRequest {
-        ::std::default::Default::default()
-    }
-
-    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
-        let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "message_id",
-            |m: &RaftRequest| { &m.message_id },
-            |m: &mut RaftRequest| { &mut m.message_id },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "request_type",
-            |m: &RaftRequest| { &m.request_type },
-            |m: &mut RaftRequest| { &mut m.request_type },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "payload",
-            |m: &RaftRequest| { &m.payload },
-            |m: &mut RaftRequest| { &mut m.payload },
-        ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftRequest>(
-            "RaftRequest",
-            fields,
-            oneofs,
-        )
-    }
-}
-
-impl ::protobuf::Message for RaftRequest {
-    const NAME: &'static str = "RaftRequest";
-
-    fn is_initialized(&self) -> bool {
-        true
-    }
-
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
-        while let Some(tag) = is.read_raw_tag_or_eof()? {
-            match tag {
-                10 => {
-                    self.message_id = is.read_string()?;
-                },
-                16 => {
-                    self.request_type = is.read_uint32()?;
-                },
-                26 => {
-                    self.payload = is.read_bytes()?;
-                },
-                tag => {
-                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
-                },
-            };
-        }
-        ::std::result::Result::Ok(())
-    }
-
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u64 {
-        let mut my_size = 0;
-        if !self.message_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.message_id);
-        }
-        if self.request_type != 0 {
-            my_size += ::protobuf::rt::uint32_size(2, self.request_type);
-        }
-        if !self.payload.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(3, &self.payload);
-        }
-        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
-        self.special_fields.cached_size().set(my_size as u32);
-        my_size
-    }
-
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.message_id.is_empty() {
-            os.write_string(1, &self.message_id)?;
-        }
-        if self.request_type != 0 {
-            os.write_uint32(2, self.request_type)?;
-        }
-        if !self.payload.is_empty() {
-            os.write_bytes(3, &self.payload)?;
-        }
-        os.write_unknown_fields(self.special_fields.unknown_fields())?;
-        ::std::result::Result::Ok(())
-    }
-
-    fn special_fields(&self) -> &::protobuf::SpecialFields {
-        &self.special_fields
-    }
-
-    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
-        &mut self.special_fields
-    }
-
-    fn new() -> RaftRequest {
-        RaftRequest::new()
-    }
-
-    fn clear(&mut self) {
-        self.message_id.clear();
-        self.request_type = 0;
-        self.payload.clear();
-        self.special_fields.clear();
-    }
-
-    fn default_instance() -> &'static RaftRequest {
-        static instance: RaftRequest = RaftRequest {
-            message_id: ::std::string::String::new(),
-            request_type: 0,
-            payload: ::std::vec::Vec::new(),
-            special_fields: ::protobuf::SpecialFields::new(),
-        };
-        &instance
-    }
-}
-
-impl ::protobuf::MessageFull for RaftRequest {
-    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
-        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftRequest").unwrap()).clone()
-    }
-}
-
-impl ::std::fmt::Display for RaftRequest {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
-    }
-}
-
-impl ::protobuf::reflect::ProtobufValue for RaftRequest {
-    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
-}
-
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:coerce.network.ActorRefErr)
 pub struct ActorRefErr {
@@ -3308,6 +3436,8 @@ pub struct ActorRefErr {
     pub serialization_error: ::protobuf::EnumOrUnknown<MessageWrapErr>,
     // @@protoc_insertion_point(field:coerce.network.ActorRefErr.deserialization_error)
     pub deserialization_error: ::protobuf::EnumOrUnknown<MessageUnwrapErr>,
+    // @@protoc_insertion_point(field:coerce.network.ActorRefErr.node_id)
+    pub node_id: u64,
     // special fields
     // @@protoc_insertion_point(special_field:coerce.network.ActorRefErr.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -3325,7 +3455,7 @@ impl ActorRefErr {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut fields = ::std::vec::Vec::with_capacity(8);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "type",
@@ -3362,6 +3492,11 @@ impl ActorRefErr {
             |m: &ActorRefErr| { &m.deserialization_error },
             |m: &mut ActorRefErr| { &mut m.deserialization_error },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "node_id",
+            |m: &ActorRefErr| { &m.node_id },
+            |m: &mut ActorRefErr| { &mut m.node_id },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ActorRefErr>(
             "ActorRefErr",
             fields,
@@ -3401,6 +3536,9 @@ impl ::protobuf::Message for ActorRefErr {
                 56 => {
                     self.deserialization_error = is.read_enum_or_unknown()?;
                 },
+                64 => {
+                    self.node_id = is.read_uint64()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -3434,6 +3572,9 @@ impl ::protobuf::Message for ActorRefErr {
         if self.deserialization_error != ::protobuf::EnumOrUnknown::new(MessageUnwrapErr::UnknownUnwrapErr) {
             my_size += ::protobuf::rt::int32_size(7, self.deserialization_error.value());
         }
+        if self.node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(8, self.node_id);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -3461,6 +3602,9 @@ impl ::protobuf::Message for ActorRefErr {
         if self.deserialization_error != ::protobuf::EnumOrUnknown::new(MessageUnwrapErr::UnknownUnwrapErr) {
             os.write_enum(7, ::protobuf::EnumOrUnknown::value(&self.deserialization_error))?;
         }
+        if self.node_id != 0 {
+            os.write_uint64(8, self.node_id)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3485,6 +3629,7 @@ impl ::protobuf::Message for ActorRefErr {
         self.time_taken_millis = 0;
         self.serialization_error = ::protobuf::EnumOrUnknown::new(MessageWrapErr::UnknownWrapErr);
         self.deserialization_error = ::protobuf::EnumOrUnknown::new(MessageUnwrapErr::UnknownUnwrapErr);
+        self.node_id = 0;
         self.special_fields.clear();
     }
 
@@ -3497,6 +3642,7 @@ impl ::protobuf::Message for ActorRefErr {
             time_taken_millis: 0,
             serialization_error: ::protobuf::EnumOrUnknown::from_i32(0),
             deserialization_error: ::protobuf::EnumOrUnknown::from_i32(0),
+            node_id: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -3549,6 +3695,12 @@ pub mod actor_ref_err {
         NotSupported = 10,
         // @@protoc_insertion_point(enum_value:coerce.network.ActorRefErr.ErrorType.NotImplemented)
         NotImplemented = 11,
+        // @@protoc_insertion_point(enum_value:coerce.network.ActorRefErr.ErrorType.AuthenticationFailed)
+        AuthenticationFailed = 12,
+        // @@protoc_insertion_point(enum_value:coerce.network.ActorRefErr.ErrorType.IncompatibleProtocol)
+        IncompatibleProtocol = 13,
+        // @@protoc_insertion_point(enum_value:coerce.network.ActorRefErr.ErrorType.NodeUnavailable)
+        NodeUnavailable = 14,
     }
 
     impl ::protobuf::Enum for ErrorType {
@@ -3572,6 +3724,9 @@ pub mod actor_ref_err {
                 9 => ::std::option::Option::Some(ErrorType::ResultSendFailed),
                 10 => ::std::option::Option::Some(ErrorType::NotSupported),
                 11 => ::std::option::Option::Some(ErrorType::NotImplemented),
+                12 => ::std::option::Option::Some(ErrorType::AuthenticationFailed),
+                13 => ::std::option::Option::Some(ErrorType::IncompatibleProtocol),
+                14 => ::std::option::Option::Some(ErrorType::NodeUnavailable),
                 _ => ::std::option::Option::None
             }
         }
@@ -3589,6 +3744,9 @@ pub mod actor_ref_err {
             ErrorType::ResultSendFailed,
             ErrorType::NotSupported,
             ErrorType::NotImplemented,
+            ErrorType::AuthenticationFailed,
+            ErrorType::IncompatibleProtocol,
+            ErrorType::NodeUnavailable,
         ];
     }
 
@@ -3945,99 +4103,107 @@ impl MessageWrapErr {
 
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\rnetwork.proto\x12\x0ecoerce.network\x1a\x1egoogle/protobuf/wrappers.\
-    proto\x1a\x1fgoogle/protobuf/timestamp.proto\"\x8f\x01\n\nRemoteNode\x12\
+    proto\x1a\x1fgoogle/protobuf/timestamp.proto\"\xa5\x01\n\nRemoteNode\x12\
     \x17\n\x07node_id\x18\x01\x20\x01(\x04R\x06nodeId\x12\x12\n\x04addr\x18\
     \x02\x20\x01(\tR\x04addr\x12B\n\x0fnode_started_at\x18\x03\x20\x01(\x0b2\
     \x1a.google.protobuf.TimestampR\rnodeStartedAt\x12\x10\n\x03tag\x18\x04\
-    \x20\x01(\tR\x03tag\"s\n\rIdentifyEvent\x12$\n\x0esource_node_id\x18\x01\
-    \x20\x01(\x04R\x0csourceNodeId\x12&\n\x0fsource_node_tag\x18\x02\x20\x01\
-    (\tR\rsourceNodeTag\x12\x14\n\x05token\x18\x03\x20\x01(\tR\x05token\"\
-    \xf0\x02\n\x0cNodeIdentity\x12\x17\n\x07node_id\x18\x01\x20\x01(\x04R\
-    \x06nodeId\x12\x19\n\x08node_tag\x18\x02\x20\x01(\tR\x07nodeTag\x12\x12\
-    \n\x04addr\x18\x03\x20\x01(\tR\x04addr\x12/\n\x13application_version\x18\
-    \x04\x20\x01(\tR\x12applicationVersion\x12)\n\x10protocol_version\x18\
-    \x05\x20\x01(\tR\x0fprotocolVersion\x12B\n\x0fnode_started_at\x18\x06\
-    \x20\x01(\x0b2\x1a.google.protobuf.TimestampR\rnodeStartedAt\x120\n\x05p\
-    eers\x18\x07\x20\x03(\x0b2\x1a.coerce.network.RemoteNodeR\x05peers\x12F\
-    \n\x0ccapabilities\x18\x08\x20\x01(\x0b2\".coerce.network.SystemCapabili\
-    tiesR\x0ccapabilities\"H\n\x12SystemCapabilities\x12\x16\n\x06actors\x18\
-    \x01\x20\x03(\tR\x06actors\x12\x1a\n\x08messages\x18\x02\x20\x03(\tR\x08\
-    messages\"\xd6\x01\n\x0fClientHandshake\x12\x17\n\x07node_id\x18\x01\x20\
-    \x01(\x04R\x06nodeId\x120\n\x05nodes\x18\x02\x20\x03(\x0b2\x1a.coerce.ne\
-    twork.RemoteNodeR\x05nodes\x12\x19\n\x08node_tag\x18\x03\x20\x01(\tR\x07\
-    nodeTag\x12\x19\n\x08trace_id\x18\x04\x20\x01(\tR\x07traceId\x12B\n\x0fn\
-    ode_started_at\x18\x05\x20\x01(\x0b2\x1a.google.protobuf.TimestampR\rnod\
-    eStartedAt\"`\n\x0cClientResult\x12\x1d\n\nmessage_id\x18\x01\x20\x01(\t\
-    R\tmessageId\x12\x16\n\x06result\x18\x02\x20\x01(\x0cR\x06result\x12\x19\
-    \n\x08trace_id\x18\x03\x20\x01(\tR\x07traceId\"x\n\tClientErr\x12\x1d\n\
-    \nmessage_id\x18\x01\x20\x01(\tR\tmessageId\x121\n\x05error\x18\x02\x20\
-    \x01(\x0b2\x1b.coerce.network.ActorRefErrR\x05error\x12\x19\n\x08trace_i\
-    d\x18\x03\x20\x01(\tR\x07traceId\"\x8b\x01\n\tPingEvent\x12\x1d\n\nmessa\
-    ge_id\x18\x01\x20\x01(\tR\tmessageId\x12\x19\n\x08trace_id\x18\x02\x20\
+    \x20\x01(\tR\x03tag\x12\x14\n\x05roles\x18\x05\x20\x03(\tR\x05roles\"\
+    \xcf\x01\n\rIdentifyEvent\x12$\n\x0esource_node_id\x18\x01\x20\x01(\x04R\
+    \x0csourceNodeId\x12&\n\x0fsource_node_tag\x18\x02\x20\x01(\tR\rsourceNo\
+    deTag\x12\x14\n\x05token\x18\x03\x20\x01(\x0cR\x05token\x12\x1d\n\nmessa\
+    ge_id\x18\x04\x20\x01(\tR\tmessageId\x12;\n\x08protocol\x18\x05\x20\x01(\
+    \x0b2\x1f.coerce.network.ProtocolVersionR\x08protocol\"h\n\x0fProtocolVe\
+    rsion\x12\x18\n\x07version\x18\x01\x20\x01(\rR\x07version\x12\x1f\n\x0bm\
+    in_version\x18\x02\x20\x01(\rR\nminVersion\x12\x1a\n\x08features\x18\x03\
+    \x20\x03(\tR\x08features\"\xea\x03\n\x0cNodeIdentity\x12\x17\n\x07node_i\
+    d\x18\x01\x20\x01(\x04R\x06nodeId\x12\x19\n\x08node_tag\x18\x02\x20\x01(\
+    \tR\x07nodeTag\x12\x12\n\x04addr\x18\x03\x20\x01(\tR\x04addr\x12/\n\x13a\
+    pplication_version\x18\x04\x20\x01(\tR\x12applicationVersion\x12)\n\x10p\
+    rotocol_version\x18\x05\x20\x01(\tR\x0fprotocolVersion\x12B\n\x0fnode_st\
+    arted_at\x18\x06\x20\x01(\x0b2\x1a.google.protobuf.TimestampR\rnodeStart\
+    edAt\x120\n\x05peers\x18\x07\x20\x03(\x0b2\x1a.coerce.network.RemoteNode\
+    R\x05peers\x12F\n\x0ccapabilities\x18\x08\x20\x01(\x0b2\".coerce.network\
+    .SystemCapabilitiesR\x0ccapabilities\x12%\n\x0eauth_challenge\x18\t\x20\
+    \x01(\x0cR\rauthChallenge\x12;\n\x08protocol\x18\n\x20\x01(\x0b2\x1f.coe\
+    rce.network.ProtocolVersionR\x08protocol\x12\x14\n\x05roles\x18\x0b\x20\
+    \x03(\tR\x05roles\"H\n\x12SystemCapabilities\x12\x16\n\x06actors\x18\x01\
+    \x20\x03(\tR\x06actors\x12\x1a\n\x08messages\x18\x02\x20\x03(\tR\x08mess\
+    ages\"\xd6\x01\n\x0fClientHandshake\x12\x17\n\x07node_id\x18\x01\x20\x01\
+    (\x04R\x06nodeId\x120\n\x05nodes\x18\x02\x20\x03(\x0b2\x1a.coerce.networ\
+    k.RemoteNodeR\x05nodes\x12\x19\n\x08node_tag\x18\x03\x20\x01(\tR\x07node\
+    Tag\x12\x19\n\x08trace_id\x18\x04\x20\x01(\tR\x07traceId\x12B\n\x0fnode_\
+    started_at\x18\x05\x20\x01(\x0b2\x1a.google.protobuf.TimestampR\rnodeSta\
+    rtedAt\"`\n\x0cClientResult\x12\x1d\n\nmessage_id\x18\x01\x20\x01(\tR\tm\
+    essageId\x12\x16\n\x06result\x18\x02\x20\x01(\x0cR\x06result\x12\x19\n\
+    \x08trace_id\x18\x03\x20\x01(\tR\x07traceId\"x\n\tClientErr\x12\x1d\n\nm\
+    essage_id\x18\x01\x20\x01(\tR\tmessageId\x121\n\x05error\x18\x02\x20\x01\
+    (\x0b2\x1b.coerce.network.ActorRefErrR\x05error\x12\x19\n\x08trace_id\
+    \x18\x03\x20\x01(\tR\x07traceId\"\xa5\x01\n\tPingEvent\x12\x1d\n\nmessag\
+    e_id\x18\x01\x20\x01(\tR\tmessageId\x12\x19\n\x08trace_id\x18\x02\x20\
     \x01(\tR\x07traceId\x12\x17\n\x07node_id\x18\x03\x20\x01(\x04R\x06nodeId\
-    \x12+\n\x11system_terminated\x18\x04\x20\x01(\x08R\x10systemTerminated\"\
-    E\n\tPongEvent\x12\x1d\n\nmessage_id\x18\x01\x20\x01(\tR\tmessageId\x12\
-    \x19\n\x08trace_id\x18\x02\x20\x01(\tR\x07traceId\"\x9e\x01\n\x10CreateA\
-    ctorEvent\x12\x1d\n\nmessage_id\x18\x01\x20\x01(\tR\tmessageId\x12\x19\n\
-    \x08actor_id\x18\x02\x20\x01(\tR\x07actorId\x12\x1d\n\nactor_type\x18\
-    \x03\x20\x01(\tR\tactorType\x12\x16\n\x06recipe\x18\x04\x20\x01(\x0cR\
-    \x06recipe\x12\x19\n\x08trace_id\x18\x05\x20\x01(\tR\x07traceId\"e\n\x0e\
-    FindActorEvent\x12\x1d\n\nmessage_id\x18\x01\x20\x01(\tR\tmessageId\x12\
-    \x19\n\x08actor_id\x18\x02\x20\x01(\tR\x07actorId\x12\x19\n\x08trace_id\
-    \x18\x03\x20\x01(\tR\x07traceId\"{\n\x0cActorAddress\x12\x19\n\x08actor_\
-    id\x18\x01\x20\x01(\tR\x07actorId\x125\n\x07node_id\x18\x02\x20\x01(\x0b\
-    2\x1c.google.protobuf.UInt64ValueR\x06nodeId\x12\x19\n\x08trace_id\x18\
-    \x03\x20\x01(\tR\x07traceId\"\xf5\x01\n\x0eMessageRequest\x12\x1d\n\nmes\
-    sage_id\x18\x01\x20\x01(\tR\tmessageId\x12!\n\x0chandler_type\x18\x02\
-    \x20\x01(\tR\x0bhandlerType\x12\x19\n\x08actor_id\x18\x03\x20\x01(\tR\
-    \x07actorId\x12\x18\n\x07message\x18\x04\x20\x01(\x0cR\x07message\x12\
-    \x19\n\x08trace_id\x18\x05\x20\x01(\tR\x07traceId\x12+\n\x11requires_res\
-    ponse\x18\x06\x20\x01(\x08R\x10requiresResponse\x12$\n\x0eorigin_node_id\
-    \x18\x07\x20\x01(\x04R\x0coriginNodeId\"\xe6\x01\n\x10SessionHandshake\
-    \x12\x17\n\x07node_id\x18\x01\x20\x01(\x04R\x06nodeId\x120\n\x05nodes\
-    \x18\x02\x20\x03(\x0b2\x1a.coerce.network.RemoteNodeR\x05nodes\x12\x14\n\
-    \x05token\x18\x03\x20\x01(\x0cR\x05token\x12\x19\n\x08node_tag\x18\x04\
-    \x20\x01(\tR\x07nodeTag\x12;\n\x0bclient_type\x18\x05\x20\x01(\x0e2\x1a.\
-    coerce.network.ClientTypeR\nclientType\x12\x19\n\x08trace_id\x18\x06\x20\
-    \x01(\tR\x07traceId\"q\n\x12StreamPublishEvent\x12\x14\n\x05topic\x18\
-    \x01\x20\x01(\tR\x05topic\x12\x10\n\x03key\x18\x02\x20\x01(\tR\x03key\
-    \x12\x18\n\x07message\x18\x03\x20\x01(\x0cR\x07message\x12\x19\n\x08trac\
-    e_id\x18\x04\x20\x01(\tR\x07traceId\"Y\n\x0cNewNodeEvent\x12.\n\x04node\
-    \x18\x01\x20\x01(\x0b2\x1a.coerce.network.RemoteNodeR\x04node\x12\x19\n\
-    \x08trace_id\x18\x02\x20\x01(\tR\x07traceId\"]\n\x10NodeRemovedEvent\x12\
-    .\n\x04node\x18\x01\x20\x01(\x0b2\x1a.coerce.network.RemoteNodeR\x04node\
-    \x12\x19\n\x08trace_id\x18\x02\x20\x01(\tR\x07traceId\"H\n\x12LeaderChan\
-    gedEvent\x12\x17\n\x07node_id\x18\x01\x20\x01(\x04R\x06nodeId\x12\x19\n\
-    \x08trace_id\x18\x02\x20\x01(\tR\x07traceId\"i\n\x0bRaftRequest\x12\x1d\
-    \n\nmessage_id\x18\x01\x20\x01(\tR\tmessageId\x12!\n\x0crequest_type\x18\
-    \x02\x20\x01(\rR\x0brequestType\x12\x18\n\x07payload\x18\x03\x20\x01(\
-    \x0cR\x07payload\"\xf0\x04\n\x0bActorRefErr\x129\n\x04type\x18\x01\x20\
-    \x01(\x0e2%.coerce.network.ActorRefErr.ErrorTypeR\x04type\x12\x19\n\x08a\
-    ctor_id\x18\x02\x20\x01(\tR\x07actorId\x12!\n\x0cmessage_type\x18\x03\
-    \x20\x01(\tR\x0bmessageType\x12\x1d\n\nactor_type\x18\x04\x20\x01(\tR\ta\
-    ctorType\x12*\n\x11time_taken_millis\x18\x05\x20\x01(\x04R\x0ftimeTakenM\
-    illis\x12O\n\x13serialization_error\x18\x06\x20\x01(\x0e2\x1e.coerce.net\
-    work.MessageWrapErrR\x12serializationError\x12U\n\x15deserialization_err\
-    or\x18\x07\x20\x01(\x0e2\x20.coerce.network.MessageUnwrapErrR\x14deseria\
-    lizationError\"\xf4\x01\n\tErrorType\x12\x14\n\x10ActorUnavailable\x10\0\
-    \x12\x0c\n\x08NotFound\x10\x01\x12\x11\n\rAlreadyExists\x10\x02\x12\x11\
-    \n\rSerialisation\x10\x03\x12\x13\n\x0fDeserialisation\x10\x04\x12\x0b\n\
-    \x07Timeout\x10\x05\x12\x16\n\x12StartChannelClosed\x10\x06\x12\x0e\n\nI\
-    nvalidRef\x10\x07\x12\x17\n\x13ResultChannelClosed\x10\x08\x12\x14\n\x10\
-    ResultSendFailed\x10\t\x12\x10\n\x0cNotSupported\x10\n\x12\x12\n\x0eNotI\
-    mplemented\x10\x0b*\xbc\x01\n\x05Event\x12\x0c\n\x08Identify\x10\0\x12\r\
-    \n\tHandshake\x10\x01\x12\n\n\x06Result\x10\x02\x12\x07\n\x03Err\x10\x03\
-    \x12\x08\n\x04Ping\x10\x04\x12\x08\n\x04Pong\x10\x05\x12\x0f\n\x0bCreate\
-    Actor\x10\x06\x12\r\n\tFindActor\x10\x07\x12\x11\n\rRegisterActor\x10\
-    \x08\x12\x0f\n\x0bNotifyActor\x10\t\x12\x11\n\rStreamPublish\x10\n\x12\
-    \x08\n\x04Raft\x10\x0b\x12\x0c\n\x08Identity\x10\x0c*$\n\nClientType\x12\
-    \n\n\x06Client\x10\0\x12\n\n\x06Worker\x10\x01*S\n\x0bSystemEvent\x12\
-    \x12\n\x0eClusterNewNode\x10\0\x12\x16\n\x12ClusterNodeRemoved\x10\x01\
-    \x12\x18\n\x14ClusterLeaderChanged\x10\x02*W\n\x10MessageUnwrapErr\x12\
-    \x14\n\x10UnknownUnwrapErr\x10\0\x12\x15\n\x11UnwrapUnsupported\x10\x01\
-    \x12\x16\n\x12DeserializationErr\x10\x02*O\n\x0eMessageWrapErr\x12\x12\n\
-    \x0eUnknownWrapErr\x10\0\x12\x13\n\x0fWrapUnsupported\x10\x01\x12\x14\n\
-    \x10SerializationErr\x10\x02b\x06proto3\
+    \x12+\n\x11system_terminated\x18\x04\x20\x01(\x08R\x10systemTerminated\
+    \x12\x18\n\x07leaving\x18\x05\x20\x01(\x08R\x07leaving\"E\n\tPongEvent\
+    \x12\x1d\n\nmessage_id\x18\x01\x20\x01(\tR\tmessageId\x12\x19\n\x08trace\
+    _id\x18\x02\x20\x01(\tR\x07traceId\"\x9e\x01\n\x10CreateActorEvent\x12\
+    \x1d\n\nmessage_id\x18\x01\x20\x01(\tR\tmessageId\x12\x19\n\x08actor_id\
+    \x18\x02\x20\x01(\tR\x07actorId\x12\x1d\n\nactor_type\x18\x03\x20\x01(\t\
+    R\tactorType\x12\x16\n\x06recipe\x18\x04\x20\x01(\x0cR\x06recipe\x12\x19\
+    \n\x08trace_id\x18\x05\x20\x01(\tR\x07traceId\"e\n\x0eFindActorEvent\x12\
+    \x1d\n\nmessage_id\x18\x01\x20\x01(\tR\tmessageId\x12\x19\n\x08actor_id\
+    \x18\x02\x20\x01(\tR\x07actorId\x12\x19\n\x08trace_id\x18\x03\x20\x01(\t\
+    R\x07traceId\"{\n\x0cActorAddress\x12\x19\n\x08actor_id\x18\x01\x20\x01(\
+    \tR\x07actorId\x125\n\x07node_id\x18\x02\x20\x01(\x0b2\x1c.google.protob\
+    uf.UInt64ValueR\x06nodeId\x12\x19\n\x08trace_id\x18\x03\x20\x01(\tR\x07t\
+    raceId\"\xf5\x01\n\x0eMessageRequest\x12\x1d\n\nmessage_id\x18\x01\x20\
+    \x01(\tR\tmessageId\x12!\n\x0chandler_type\x18\x02\x20\x01(\tR\x0bhandle\
+    rType\x12\x19\n\x08actor_id\x18\x03\x20\x01(\tR\x07actorId\x12\x18\n\x07\
+    message\x18\x04\x20\x01(\x0cR\x07message\x12\x19\n\x08trace_id\x18\x05\
+    \x20\x01(\tR\x07traceId\x12+\n\x11requires_response\x18\x06\x20\x01(\x08\
+    R\x10requiresResponse\x12$\n\x0eorigin_node_id\x18\x07\x20\x01(\x04R\x0c\
+    originNodeId\"\xe6\x01\n\x10SessionHandshake\x12\x17\n\x07node_id\x18\
+    \x01\x20\x01(\x04R\x06nodeId\x120\n\x05nodes\x18\x02\x20\x03(\x0b2\x1a.c\
+    oerce.network.RemoteNodeR\x05nodes\x12\x14\n\x05token\x18\x03\x20\x01(\
+    \x0cR\x05token\x12\x19\n\x08node_tag\x18\x04\x20\x01(\tR\x07nodeTag\x12;\
+    \n\x0bclient_type\x18\x05\x20\x01(\x0e2\x1a.coerce.network.ClientTypeR\n\
+    clientType\x12\x19\n\x08trace_id\x18\x06\x20\x01(\tR\x07traceId\"q\n\x12\
+    StreamPublishEvent\x12\x14\n\x05topic\x18\x01\x20\x01(\tR\x05topic\x12\
+    \x10\n\x03key\x18\x02\x20\x01(\tR\x03key\x12\x18\n\x07message\x18\x03\
+    \x20\x01(\x0cR\x07message\x12\x19\n\x08trace_id\x18\x04\x20\x01(\tR\x07t\
+    raceId\"Y\n\x0cNewNodeEvent\x12.\n\x04node\x18\x01\x20\x01(\x0b2\x1a.coe\
+    rce.network.RemoteNodeR\x04node\x12\x19\n\x08trace_id\x18\x02\x20\x01(\t\
+    R\x07traceId\"]\n\x10NodeRemovedEvent\x12.\n\x04node\x18\x01\x20\x01(\
+    \x0b2\x1a.coerce.network.RemoteNodeR\x04node\x12\x19\n\x08trace_id\x18\
+    \x02\x20\x01(\tR\x07traceId\"H\n\x12LeaderChangedEvent\x12\x17\n\x07node\
+    _id\x18\x01\x20\x01(\x04R\x06nodeId\x12\x19\n\x08trace_id\x18\x02\x20\
+    \x01(\tR\x07traceId\"\xd2\x05\n\x0bActorRefErr\x129\n\x04type\x18\x01\
+    \x20\x01(\x0e2%.coerce.network.ActorRefErr.ErrorTypeR\x04type\x12\x19\n\
+    \x08actor_id\x18\x02\x20\x01(\tR\x07actorId\x12!\n\x0cmessage_type\x18\
+    \x03\x20\x01(\tR\x0bmessageType\x12\x1d\n\nactor_type\x18\x04\x20\x01(\t\
+    R\tactorType\x12*\n\x11time_taken_millis\x18\x05\x20\x01(\x04R\x0ftimeTa\
+    kenMillis\x12O\n\x13serialization_error\x18\x06\x20\x01(\x0e2\x1e.coerce\
+    .network.MessageWrapErrR\x12serializationError\x12U\n\x15deserialization\
+    _error\x18\x07\x20\x01(\x0e2\x20.coerce.network.MessageUnwrapErrR\x14des\
+    erializationError\x12\x17\n\x07node_id\x18\x08\x20\x01(\x04R\x06nodeId\"\
+    \xbd\x02\n\tErrorType\x12\x14\n\x10ActorUnavailable\x10\0\x12\x0c\n\x08N\
+    otFound\x10\x01\x12\x11\n\rAlreadyExists\x10\x02\x12\x11\n\rSerialisatio\
+    n\x10\x03\x12\x13\n\x0fDeserialisation\x10\x04\x12\x0b\n\x07Timeout\x10\
+    \x05\x12\x16\n\x12StartChannelClosed\x10\x06\x12\x0e\n\nInvalidRef\x10\
+    \x07\x12\x17\n\x13ResultChannelClosed\x10\x08\x12\x14\n\x10ResultSendFai\
+    led\x10\t\x12\x10\n\x0cNotSupported\x10\n\x12\x12\n\x0eNotImplemented\
+    \x10\x0b\x12\x18\n\x14AuthenticationFailed\x10\x0c\x12\x18\n\x14Incompat\
+    ibleProtocol\x10\r\x12\x13\n\x0fNodeUnavailable\x10\x0e*\xbc\x01\n\x05Ev\
+    ent\x12\x0c\n\x08Identify\x10\0\x12\r\n\tHandshake\x10\x01\x12\n\n\x06Re\
+    sult\x10\x02\x12\x07\n\x03Err\x10\x03\x12\x08\n\x04Ping\x10\x04\x12\x08\
+    \n\x04Pong\x10\x05\x12\x0f\n\x0bCreateActor\x10\x06\x12\r\n\tFindActor\
+    \x10\x07\x12\x11\n\rRegisterActor\x10\x08\x12\x0f\n\x0bNotifyActor\x10\t\
+    \x12\x11\n\rStreamPublish\x10\n\x12\x08\n\x04Raft\x10\x0b\x12\x0c\n\x08I\
+    dentity\x10\x0c*$\n\nClientType\x12\n\n\x06Client\x10\0\x12\n\n\x06Worke\
+    r\x10\x01*S\n\x0bSystemEvent\x12\x12\n\x0eClusterNewNode\x10\0\x12\x16\n\
+    \x12ClusterNodeRemoved\x10\x01\x12\x18\n\x14ClusterLeaderChanged\x10\x02\
+    *W\n\x10MessageUnwrapErr\x12\x14\n\x10UnknownUnwrapErr\x10\0\x12\x15\n\
+    \x11UnwrapUnsupported\x10\x01\x12\x16\n\x12DeserializationErr\x10\x02*O\
+    \n\x0eMessageWrapErr\x12\x12\n\x0eUnknownWrapErr\x10\0\x12\x13\n\x0fWrap\
+    Unsupported\x10\x01\x12\x14\n\x10SerializationErr\x10\x02b\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -4060,6 +4226,7 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             let mut messages = ::std::vec::Vec::with_capacity(20);
             messages.push(RemoteNode::generated_message_descriptor_data());
             messages.push(IdentifyEvent::generated_message_descriptor_data());
+            messages.push(ProtocolVersion::generated_message_descriptor_data());
             messages.push(NodeIdentity::generated_message_descriptor_data());
             messages.push(SystemCapabilities::generated_message_descriptor_data());
             messages.push(ClientHandshake::generated_message_descriptor_data());
@@ -4076,7 +4243,6 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(NewNodeEvent::generated_message_descriptor_data());
             messages.push(NodeRemovedEvent::generated_message_descriptor_data());
             messages.push(LeaderChangedEvent::generated_message_descriptor_data());
-            messages.push(RaftRequest::generated_message_descriptor_data());
             messages.push(ActorRefErr::generated_message_descriptor_data());
             let mut enums = ::std::vec::Vec::with_capacity(6);
             enums.push(Event::generated_enum_descriptor_data());
@@ -0,0 +1,163 @@
+use crate::remote::net::proto::network as proto;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+/// The protocol version spoken by this node. Nodes that don't advertise a structured protocol
+/// version are treated as [`LEGACY_PROTOCOL_VERSION`].
+pub const PROTOCOL_VERSION: u32 = 2;
+
+/// The oldest protocol version this node can communicate with.
+pub const MIN_PROTOCOL_VERSION: u32 = 1;
+
+pub const LEGACY_PROTOCOL_VERSION: u32 = 1;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ProtocolFeature {
+    /// Frames above a size threshold are compressed with LZ4
+    Lz4Compression,
+}
+
+impl ProtocolFeature {
+    pub fn all() -> Vec<ProtocolFeature> {
+        vec![ProtocolFeature::Lz4Compression]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProtocolFeature::Lz4Compression => "lz4-compression",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<ProtocolFeature> {
+        match name {
+            "lz4-compression" => Some(ProtocolFeature::Lz4Compression),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ProtocolVersion {
+    pub version: u32,
+    pub min_version: u32,
+    pub features: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct NegotiatedProtocol {
+    version: u32,
+    features: HashSet<ProtocolFeature>,
+}
+
+#[derive(Debug)]
+pub struct IncompatibleProtocol {
+    pub local: ProtocolVersion,
+    pub peer: ProtocolVersion,
+}
+
+impl ProtocolVersion {
+    pub fn current(features: &[ProtocolFeature]) -> ProtocolVersion {
+        ProtocolVersion {
+            version: PROTOCOL_VERSION,
+            min_version: MIN_PROTOCOL_VERSION,
+            features: features.iter().map(|f| f.name().to_string()).collect(),
+        }
+    }
+
+    pub fn legacy() -> ProtocolVersion {
+        ProtocolVersion {
+            version: LEGACY_PROTOCOL_VERSION,
+            min_version: LEGACY_PROTOCOL_VERSION,
+            features: vec![],
+        }
+    }
+
+    pub fn is_compatible(&self, peer: &ProtocolVersion) -> bool {
+        peer.version >= self.min_version && self.version >= peer.min_version
+    }
+
+    /// Selects the highest protocol version and the features supported by both nodes.
+    pub fn negotiate(
+        &self,
+        peer: &ProtocolVersion,
+    ) -> Result<NegotiatedProtocol, IncompatibleProtocol> {
+        if !self.is_compatible(peer) {
+            return Err(IncompatibleProtocol {
+                local: self.clone(),
+                peer: peer.clone(),
+            });
+        }
+
+        let features = self
+            .features
+            .iter()
+            .filter(|f| peer.features.contains(f))
+            .filter_map(|f| ProtocolFeature::from_name(f))
+            .collect();
+
+        Ok(NegotiatedProtocol {
+            version: self.version.min(peer.version),
+            features,
+        })
+    }
+}
+
+impl NegotiatedProtocol {
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn supports(&self, feature: ProtocolFeature) -> bool {
+        self.features.contains(&feature)
+    }
+
+    pub fn features(&self) -> impl Iterator<Item = &ProtocolFeature> {
+        self.features.iter()
+    }
+}
+
+impl Display for ProtocolVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "version={}, min_version={}, features={:?}",
+            self.version, self.min_version, &self.features
+        )
+    }
+}
+
+impl Display for IncompatibleProtocol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "incompatible protocol versions (local: {}, peer: {})",
+            &self.local, &self.peer
+        )
+    }
+}
+
+impl std::error::Error for IncompatibleProtocol {}
+
+impl From<Option<proto::ProtocolVersion>> for ProtocolVersion {
+    fn from(protocol: Option<proto::ProtocolVersion>) -> Self {
+        match protocol {
+            Some(protocol) => ProtocolVersion {
+                version: protocol.version,
+                min_version: protocol.min_version,
+                features: protocol.features,
+            },
+            None => ProtocolVersion::legacy(),
+        }
+    }
+}
+
+impl From<&ProtocolVersion> for proto::ProtocolVersion {
+    fn from(protocol: &ProtocolVersion) -> Self {
+        proto::ProtocolVersion {
+            version: protocol.version,
+            min_version: protocol.min_version,
+            features: protocol.features.clone(),
+            ..Default::default()
+        }
+    }
+}
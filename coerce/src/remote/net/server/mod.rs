@@ -1,10 +1,14 @@
 use crate::actor::scheduler::ActorType::Anonymous;
 use crate::actor::LocalActorRef;
+use crate::remote::net::codec::NetworkCodec;
 use crate::remote::net::server::session::store::{NewSession, RemoteSessionStore};
 use crate::remote::net::server::session::RemoteSession;
+use crate::remote::net::stream::NetworkStream;
+use crate::remote::net::tls::TlsConfigRef;
+use crate::remote::net::transport::{PeerAddr, TransportListener};
 use crate::remote::system::RemoteActorSystem;
-use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio_util::sync::CancellationToken;
 
 pub mod session;
@@ -33,8 +37,17 @@ pub struct RemoteServerConfig {
     /// used by the inbound client, rather than the address provided by
     /// the node via the handshake.
     pub override_incoming_node_addr: bool,
+
+    /// When set, all inbound connections must complete a TLS handshake
+    /// before a session is created
+    pub tls: Option<TlsConfigRef>,
+
+    /// How long an inbound connection has to complete the TLS handshake before it is dropped
+    pub tls_handshake_timeout: Duration,
 }
 
+const DEFAULT_TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(3);
+
 impl RemoteServerConfig {
     pub fn new(
         listen_addr: String,
@@ -45,8 +58,20 @@ impl RemoteServerConfig {
             listen_addr,
             external_node_addr,
             override_incoming_node_addr,
+            tls: None,
+            tls_handshake_timeout: DEFAULT_TLS_HANDSHAKE_TIMEOUT,
         }
     }
+
+    pub fn with_tls(mut self, tls: Option<TlsConfigRef>) -> Self {
+        self.tls = tls;
+        self
+    }
+
+    pub fn with_tls_handshake_timeout(mut self, tls_handshake_timeout: Duration) -> Self {
+        self.tls_handshake_timeout = tls_handshake_timeout;
+        self
+    }
 }
 
 impl RemoteServer {
@@ -67,7 +92,11 @@ impl RemoteServer {
             &config
         );
 
-        let listener = tokio::net::TcpListener::bind(&config.listen_addr).await?;
+        let listener = system
+            .config()
+            .transport()
+            .listen(&config.listen_addr)
+            .await?;
 
         let session_store = system
             .actor_system()
@@ -80,12 +109,14 @@ impl RemoteServer {
             .unwrap();
 
         let remote_server_config = Arc::new(config);
+        let codec = NetworkCodec::new(system.config().max_frame_size());
         let cancellation_token = CancellationToken::new();
         tokio::spawn(server_loop(
             listener,
             session_store,
             cancellation_token.clone(),
             remote_server_config,
+            codec,
         ));
 
         self.cancellation_token = Some(cancellation_token);
@@ -104,9 +135,9 @@ pub async fn cancellation(cancellation_token: CancellationToken) {
 }
 
 pub async fn accept(
-    listener: &tokio::net::TcpListener,
+    listener: &mut Box<dyn TransportListener>,
     cancellation_token: CancellationToken,
-) -> Option<tokio::io::Result<(tokio::net::TcpStream, SocketAddr)>> {
+) -> Option<tokio::io::Result<(NetworkStream, PeerAddr)>> {
     tokio::select! {
         _ = cancellation(cancellation_token) => {
             None
@@ -119,29 +150,56 @@ pub async fn accept(
 }
 
 pub async fn server_loop(
-    listener: tokio::net::TcpListener,
+    mut listener: Box<dyn TransportListener>,
     session_store: LocalActorRef<RemoteSessionStore>,
     cancellation_token: CancellationToken,
     remote_server_config: RemoteServerConfigRef,
+    codec: NetworkCodec,
 ) {
     loop {
-        match accept(&listener, cancellation_token.clone()).await {
+        match accept(&mut listener, cancellation_token.clone()).await {
             Some(Ok((stream, addr))) => {
                 let remote_server_config = remote_server_config.clone();
                 trace!(target: "RemoteServer", "client accepted {}", addr);
-                let session_id = uuid::Uuid::new_v4();
-
-                let session = session_store
-                    .send(NewSession(RemoteSession::new(
-                        session_id,
-                        addr,
-                        stream,
-                        remote_server_config,
-                    )))
-                    .await;
-
-                if let Err(e) = session {
-                    error!(target: "RemoteServer", "error creating session actor (session_id={}, addr={}), error: {:?}", session_id, addr, e);
+
+                match &remote_server_config.tls {
+                    Some(tls) => {
+                        let tls = tls.clone();
+                        let session_store = session_store.clone();
+                        let handshake_timeout = remote_server_config.tls_handshake_timeout;
+                        tokio::spawn(async move {
+                            let handshake = tls.accept(stream);
+                            let stream = match tokio::time::timeout(handshake_timeout, handshake)
+                                .await
+                            {
+                                Ok(stream) => stream,
+                                Err(_) => {
+                                    warn!(target: "RemoteServer", "TLS handshake timed out after {}ms (addr={}), dropping connection", handshake_timeout.as_millis(), addr);
+                                    return;
+                                }
+                            };
+
+                            match stream {
+                                Ok(stream) => {
+                                    start_session(
+                                        stream.into(),
+                                        addr,
+                                        &session_store,
+                                        remote_server_config,
+                                        codec,
+                                    )
+                                    .await
+                                }
+                                Err(e) => {
+                                    warn!(target: "RemoteServer", "TLS handshake failed (addr={}), error: {}", addr, e)
+                                }
+                            }
+                        });
+                    }
+                    None => {
+                        start_session(stream, addr, &session_store, remote_server_config, codec)
+                            .await
+                    }
                 }
             }
             Some(Err(e)) => error!(target: "RemoteServer", "error accepting client: {:?}", e),
@@ -149,5 +207,28 @@ pub async fn server_loop(
         }
     }
 
-    info!("tcp listener {:?} stopped", &listener)
+    info!("listener (addr={}) stopped", listener.local_addr())
+}
+
+async fn start_session(
+    stream: NetworkStream,
+    addr: PeerAddr,
+    session_store: &LocalActorRef<RemoteSessionStore>,
+    remote_server_config: RemoteServerConfigRef,
+    codec: NetworkCodec,
+) {
+    let session_id = uuid::Uuid::new_v4();
+    let session = session_store
+        .send(NewSession(RemoteSession::new(
+            session_id,
+            addr.clone(),
+            stream,
+            remote_server_config,
+            codec,
+        )))
+        .await;
+
+    if let Err(e) = session {
+        error!(target: "RemoteServer", "error creating session actor (session_id={}, addr={}), error: {:?}", session_id, addr, e);
+    }
 }
@@ -1,37 +1,40 @@
 use crate::actor::context::ActorContext;
-use crate::actor::message::{Handler, Message};
-use crate::actor::{Actor, ActorId, IntoActorId, LocalActorRef};
-use crate::remote::actor::message::NodeTerminated;
+use crate::actor::message::{Handler, Message, MessageUnwrapErr};
+use crate::actor::{Actor, ActorId, ActorRefErr, IntoActorId, LocalActorRef};
+use crate::remote::actor::message::{NodeLeaving, NodeTerminated, RegisterClientSession};
 use crate::remote::actor::RemoteResponse;
 use crate::remote::cluster::discovery::{Discover, Seed};
-use crate::remote::cluster::node::RemoteNode;
+use crate::remote::cluster::node::{NodeStatus, RemoteNode};
+use crate::remote::net::auth::{new_challenge, AuthChallenge, AuthErr};
+use crate::remote::net::client::ClientType;
 use crate::remote::net::codec::NetworkCodec;
 use crate::remote::net::message::{
     datetime_to_timestamp, timestamp_to_datetime, ClientEvent, SessionEvent,
 };
+use crate::remote::net::metrics::NetworkMetrics;
 use crate::remote::net::proto::network::{
-    ActorAddress, ClientHandshake, ClientResult, CreateActorEvent, MessageRequest, NodeIdentity,
-    PongEvent, RemoteNode as RemoteNodeProto, SessionHandshake, StreamPublishEvent,
-    SystemCapabilities,
+    ActorAddress, ClientErr, ClientHandshake, ClientResult, CreateActorEvent, IdentifyEvent,
+    MessageRequest, NodeIdentity, PingEvent, PongEvent, RemoteNode as RemoteNodeProto,
+    SessionHandshake, StreamPublishEvent, SystemCapabilities,
 };
+use crate::remote::net::protocol::{NegotiatedProtocol, ProtocolFeature, ProtocolVersion};
 use crate::remote::net::server::session::store::{RemoteSessionStore, SessionClosed, SessionWrite};
 use crate::remote::net::server::RemoteServerConfigRef;
+use crate::remote::net::stream::{NetworkStream, NetworkStreamReader, NetworkStreamWriter};
+use crate::remote::net::transport::PeerAddr;
 use crate::remote::net::{receive_loop, StreamData, StreamReceiver};
 use crate::remote::stream::mediator::PublishRaw;
 use crate::remote::system::{NodeId, RemoteActorSystem};
 use crate::CARGO_PKG_VERSION;
+use bytes::Bytes;
 use futures::SinkExt;
 use protobuf::well_known_types::wrappers::UInt64Value;
 use protobuf::{Message as ProtoMessage, MessageField};
 use std::collections::HashMap;
-use std::io::Error;
-use std::net::SocketAddr;
+use std::io::{Error, ErrorKind};
 use std::str::FromStr;
 use std::sync::Arc;
-use tokio::io::{ReadHalf, WriteHalf};
-use tokio::net::TcpStream;
 use tokio::sync::oneshot;
-use tokio_util::codec::{FramedRead, FramedWrite};
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
@@ -39,9 +42,9 @@ pub mod store;
 
 pub struct RemoteSession {
     id: Uuid,
-    addr: SocketAddr,
-    write: FramedWrite<WriteHalf<TcpStream>, NetworkCodec>,
-    read: Option<FramedRead<ReadHalf<TcpStream>, NetworkCodec>>,
+    addr: PeerAddr,
+    write: NetworkStreamWriter,
+    read: Option<NetworkStreamReader>,
     read_cancellation_token: Option<CancellationToken>,
     remote_server_config: RemoteServerConfigRef,
 }
@@ -49,18 +52,17 @@ pub struct RemoteSession {
 impl RemoteSession {
     pub fn new(
         id: Uuid,
-        addr: SocketAddr,
-        stream: TcpStream,
+        addr: PeerAddr,
+        stream: NetworkStream,
         remote_server_config: RemoteServerConfigRef,
+        codec: NetworkCodec,
     ) -> RemoteSession {
-        let (read, write) = tokio::io::split(stream);
-        let read = Some(FramedRead::new(read, NetworkCodec));
-        let write = FramedWrite::new(write, NetworkCodec);
+        let (read, write) = stream.into_framed(codec);
         RemoteSession {
             id,
             addr,
             write,
-            read,
+            read: Some(read),
             read_cancellation_token: Some(CancellationToken::new()),
             remote_server_config,
         }
@@ -90,6 +92,7 @@ impl Actor for RemoteSession {
                     .as_ref()
                     .map(datetime_to_timestamp)
                     .into(),
+                roles: node.roles,
                 ..RemoteNodeProto::default()
             })
             .collect::<Vec<RemoteNodeProto>>();
@@ -101,14 +104,19 @@ impl Actor for RemoteSession {
             ..Default::default()
         });
 
+        let auth_challenge = system.config().authenticator().map(|_| new_challenge());
+
         self.write(ClientEvent::Identity(NodeIdentity {
             node_id: system.node_id(),
             node_tag: system.node_tag().to_string(),
-            application_version: format!("pkg_version={},protocol_version=1", CARGO_PKG_VERSION),
+            application_version: CARGO_PKG_VERSION.to_string(),
             addr: self.remote_server_config.external_node_addr.to_string(),
             node_started_at: Some(datetime_to_timestamp(system.started_at())).into(),
             peers: peers.into(),
             capabilities: capabilities.into(),
+            auth_challenge: auth_challenge.clone().unwrap_or_default(),
+            protocol: Some((&system.config().protocol()).into()).into(),
+            roles: system.node_roles().to_vec(),
             ..Default::default()
         }))
         .await;
@@ -119,8 +127,9 @@ impl Actor for RemoteSession {
             SessionMessageReceiver::new(
                 self.id,
                 self.actor_ref(ctx),
-                self.addr,
+                self.addr.clone(),
                 self.remote_server_config.clone(),
+                auth_challenge,
             ),
         ));
     }
@@ -144,6 +153,29 @@ impl Handler<SessionWrite> for RemoteSession {
     }
 }
 
+pub struct SetSessionProtocol(pub NegotiatedProtocol);
+
+impl Message for SetSessionProtocol {
+    type Result = ();
+}
+
+#[async_trait]
+impl Handler<SetSessionProtocol> for RemoteSession {
+    async fn handle(&mut self, message: SetSessionProtocol, _ctx: &mut ActorContext) {
+        debug!(
+            "session protocol negotiated (addr={}, session_id={}, version={}, features={:?})",
+            &self.addr,
+            &self.id,
+            message.0.version(),
+            message.0.features().collect::<Vec<_>>()
+        );
+
+        self.write
+            .encoder_mut()
+            .set_compression(message.0.supports(ProtocolFeature::Lz4Compression));
+    }
+}
+
 impl RemoteSession {
     pub async fn write(&mut self, message: ClientEvent) {
         match message.write_to_bytes() {
@@ -166,9 +198,10 @@ pub struct SessionMessageReceiver {
     session_id: Uuid,
     session: LocalActorRef<RemoteSession>,
     node_id: Option<NodeId>,
-    addr: SocketAddr,
+    addr: PeerAddr,
     should_close: bool,
     server_config: RemoteServerConfigRef,
+    auth_challenge: Option<Vec<u8>>,
 }
 
 #[derive(Debug)]
@@ -181,18 +214,131 @@ impl SessionMessageReceiver {
     pub fn new(
         session_id: Uuid,
         session: LocalActorRef<RemoteSession>,
-        addr: SocketAddr,
+        addr: PeerAddr,
         server_config: RemoteServerConfigRef,
+        auth_challenge: Option<Vec<u8>>,
     ) -> SessionMessageReceiver {
         SessionMessageReceiver {
             session_id,
             session,
             addr,
             server_config,
+            auth_challenge,
             node_id: None,
             should_close: false,
         }
     }
+
+    fn is_authenticated(&self) -> bool {
+        self.auth_challenge.is_none()
+    }
+
+    /// Whether a node id sent by the peer is the one the session identified (and, when
+    /// authentication is enabled, authenticated) as. Peers that never identified are only
+    /// trusted when authentication is disabled.
+    fn is_session_node(&self, node_id: NodeId) -> bool {
+        self.node_id
+            .map_or(self.is_authenticated(), |session_node_id| {
+                session_node_id == node_id
+            })
+    }
+
+    async fn identify(&mut self, mut identify: IdentifyEvent, sys: &RemoteActorSystem) {
+        let local_protocol = sys.config().protocol();
+        let peer_protocol = ProtocolVersion::from(identify.protocol.take());
+        let protocol = match local_protocol.negotiate(&peer_protocol) {
+            Ok(protocol) => protocol,
+            Err(e) => {
+                warn!(target: "RemoteServer", "closing connection (addr={}, session_id={}) - error: {}", &self.addr, &self.session_id, &e);
+                self.close_with_err(&identify.message_id, ActorRefErr::IncompatibleProtocol);
+                return;
+            }
+        };
+
+        if let Some(challenge) = &self.auth_challenge {
+            let authenticator = match sys.config().authenticator() {
+                Some(authenticator) => authenticator,
+                None => {
+                    let error = AuthErr::Other("no authenticator configured".to_string());
+                    self.reject(&identify.message_id, error).await;
+                    return;
+                }
+            };
+
+            let challenge = AuthChallenge {
+                node_id: identify.source_node_id,
+                node_tag: &identify.source_node_tag,
+                challenge,
+            };
+
+            if let Err(e) = authenticator.verify(&challenge, &identify.token) {
+                self.reject(&identify.message_id, e).await;
+                return;
+            }
+
+            debug!(target: "RemoteServer", "node authenticated (id={}, tag={}, session_id={})", &identify.source_node_id, &identify.source_node_tag, &self.session_id);
+            self.auth_challenge = None;
+        }
+
+        self.node_id = Some(identify.source_node_id);
+
+        // Frames sent before the session protocol is set are never compressed
+        let _ = self.session.send(SetSessionProtocol(protocol)).await;
+
+        if !identify.message_id.is_empty() {
+            let result = ClientEvent::Result(ClientResult {
+                message_id: identify.message_id,
+                ..Default::default()
+            });
+
+            let _ = self
+                .session
+                .send(SessionWrite(self.session_id, result))
+                .await;
+        }
+    }
+
+    async fn reject(&mut self, message_id: &str, error: AuthErr) {
+        NetworkMetrics::incr_auth_failures(&error);
+
+        warn!(target: "RemoteServer", "authentication failed, closing connection (addr={}, session_id={}) - error: {}", &self.addr, &self.session_id, &error);
+
+        self.close_with_err(message_id, ActorRefErr::AuthenticationFailed);
+    }
+
+    fn close_with_err(&mut self, message_id: &str, error: ActorRefErr) {
+        let message_id = if message_id.is_empty() {
+            Uuid::nil().to_string()
+        } else {
+            message_id.to_string()
+        };
+
+        let error = ClientEvent::Err(ClientErr {
+            message_id,
+            error: Some(error.into()).into(),
+            ..Default::default()
+        });
+
+        let _ = self.session.notify(SessionWrite(self.session_id, error));
+        self.should_close = true;
+    }
+
+    fn close_with_protocol_err(&mut self) {
+        self.close_with_err(
+            "",
+            ActorRefErr::Deserialisation(MessageUnwrapErr::DeserializationErr),
+        );
+    }
+
+    fn request_id(&mut self, message_id: &str) -> Option<Uuid> {
+        let request_id = Uuid::from_str(message_id).ok();
+        if request_id.is_none() {
+            warn!(target: "RemoteServer", "invalid message_id received, closing connection (addr={}, session_id={})", &self.addr, &self.session_id);
+            self.close_with_protocol_err();
+        }
+
+        request_id
+    }
 }
 
 #[async_trait]
@@ -200,32 +346,54 @@ impl StreamReceiver for SessionMessageReceiver {
     type Message = SessionEvent;
 
     async fn on_receive(&mut self, msg: SessionEvent, sys: &RemoteActorSystem) {
+        if !self.is_authenticated() {
+            match &msg {
+                SessionEvent::Identify(_)
+                | SessionEvent::Ping(PingEvent {
+                    system_terminated: false,
+                    ..
+                }) => {}
+                _ => {
+                    self.reject("", AuthErr::Unauthenticated).await;
+                    return;
+                }
+            }
+        }
+
         match msg {
             SessionEvent::Identify(identify) => {
-                let _session_id = self.session_id;
-                let _system = sys.clone();
-                let _session = self.session.clone();
-
                 trace!(target: "RemoteServer", "received identify from node (id={}, tag={}), session_id={}", &identify.source_node_id, &identify.source_node_tag, &self.session_id);
+
+                self.identify(identify, sys).await;
             }
 
             SessionEvent::Handshake(msg) => {
                 trace!(target: "RemoteServer", "handshake {}, {:?}, type: {:?}", &msg.node_id, &msg.nodes, &msg.client_type);
 
+                if !self.is_session_node(msg.node_id) {
+                    self.reject("", AuthErr::NodeMismatch).await;
+                    return;
+                }
+
                 tokio::spawn(session_handshake(
                     sys.clone(),
                     msg,
                     self.session_id,
                     self.session.clone(),
-                    self.addr,
+                    self.addr.clone(),
                     self.server_config.clone(),
                 ));
             }
 
             SessionEvent::FindActor(find_actor) => {
                 trace!(target: "RemoteServer", "actor lookup {}, {}", &self.session_id, &find_actor.actor_id);
+                let message_id = match self.request_id(&find_actor.message_id) {
+                    Some(message_id) => message_id,
+                    None => return,
+                };
+
                 tokio::spawn(session_handle_lookup(
-                    Uuid::from_str(&find_actor.message_id).unwrap(),
+                    message_id,
                     find_actor.actor_id.into_actor_id(),
                     self.session_id,
                     sys.clone(),
@@ -242,8 +410,14 @@ impl StreamReceiver for SessionMessageReceiver {
             }
 
             SessionEvent::NotifyActor(msg) => {
+                let message_id = match self.request_id(&msg.message_id) {
+                    Some(message_id) => message_id,
+                    None => return,
+                };
+
                 tokio::spawn(session_handle_message(
                     msg,
+                    message_id,
                     self.session_id,
                     sys.clone(),
                     self.session.clone(),
@@ -259,12 +433,22 @@ impl StreamReceiver for SessionMessageReceiver {
                     return;
                 }
 
+                // Only the node itself can say it's terminated or leaving
+                if (ping.system_terminated || ping.leaving) && !self.is_session_node(ping.node_id) {
+                    self.reject("", AuthErr::NodeMismatch).await;
+                    return;
+                }
+
                 if ping.system_terminated {
                     let _ = sys.heartbeat().notify(NodeTerminated(ping.node_id));
 
                     debug!(target: "RemoteServer", "Notified registry - node_id={} is terminated (session_id={})", &ping.node_id, &self.session_id);
                     self.close();
                 } else {
+                    if ping.leaving {
+                        let _ = sys.heartbeat().notify(NodeLeaving(ping.node_id));
+                    }
+
                     trace!(target: "RemoteServer", "ping received, sending pong");
                     self.session
                         .send(SessionWrite(
@@ -283,8 +467,14 @@ impl StreamReceiver for SessionMessageReceiver {
 
             SessionEvent::CreateActor(msg) => {
                 trace!(target: "RemoteServer", "create actor {}, {:?}", self.session_id, &msg.actor_id);
+                let message_id = match self.request_id(&msg.message_id) {
+                    Some(message_id) => message_id,
+                    None => return,
+                };
+
                 tokio::spawn(session_create_actor(
                     msg,
+                    message_id,
                     self.session_id,
                     sys.clone(),
                     self.session.clone(),
@@ -296,10 +486,11 @@ impl StreamReceiver for SessionMessageReceiver {
                 tokio::spawn(session_stream_publish(msg, sys.clone()));
             }
 
-            SessionEvent::Raft(req) => {}
-
             SessionEvent::Result(res) => {
-                match sys.pop_request(Uuid::from_str(&res.message_id).unwrap()) {
+                match Uuid::from_str(&res.message_id)
+                    .ok()
+                    .and_then(|id| sys.pop_request(id))
+                {
                     Some(res_tx) => {
                         let _ = res_tx.send(RemoteResponse::Ok(res.result));
                     }
@@ -309,8 +500,11 @@ impl StreamReceiver for SessionMessageReceiver {
                 }
             }
             SessionEvent::Err(err) => {
-                let e = err.error.unwrap().into();
-                match sys.pop_request(Uuid::from_str(&err.message_id).unwrap()) {
+                let e = err.error.unwrap_or_default().into();
+                match Uuid::from_str(&err.message_id)
+                    .ok()
+                    .and_then(|id| sys.pop_request(id))
+                {
                     Some(res_tx) => {
                         let _ = res_tx.send(RemoteResponse::Err(e));
                     }
@@ -328,9 +522,11 @@ impl StreamReceiver for SessionMessageReceiver {
 
     fn on_deserialisation_failed(&mut self) {
         warn!(
-            "message serialisation failed (addr={}, session_id={})",
+            "message serialisation failed, closing connection (addr={}, session_id={})",
             &self.addr, &self.session_id
-        )
+        );
+
+        self.close_with_protocol_err();
     }
 
     fn on_stream_lost(&mut self, error: Error) {
@@ -338,6 +534,10 @@ impl StreamReceiver for SessionMessageReceiver {
             "stream connection lost (addr={}, session_id={}) - error: {}",
             &self.addr, self.session_id, error
         );
+
+        if error.kind() == ErrorKind::InvalidData {
+            self.close_with_protocol_err();
+        }
     }
 
     async fn close(&mut self) {
@@ -354,7 +554,7 @@ async fn session_handshake(
     handshake: SessionHandshake,
     session_id: Uuid,
     session: LocalActorRef<RemoteSession>,
-    session_addr: SocketAddr,
+    session_addr: PeerAddr,
     server_config: RemoteServerConfigRef,
 ) {
     // let mut headers = HashMap::<String, String>::new();
@@ -370,6 +570,7 @@ async fn session_handshake(
         &handshake.trace_id
     );
 
+    let client_type = ClientType::from(handshake.client_type.enum_value_or_default());
     let nodes = ctx.get_nodes().await;
     let mut response = ClientHandshake {
         node_id: ctx.node_id(),
@@ -379,6 +580,11 @@ async fn session_handshake(
     };
 
     for node in nodes {
+        // Cluster clients only need to know which nodes they can route requests to
+        if client_type == ClientType::Client && node.status == NodeStatus::Terminated {
+            continue;
+        }
+
         response.nodes.push(RemoteNodeProto {
             node_id: node.id,
             addr: node.addr,
@@ -388,10 +594,33 @@ async fn session_handshake(
                 .as_ref()
                 .map(datetime_to_timestamp)
                 .into(),
+            roles: node.roles,
             ..RemoteNodeProto::default()
         });
     }
 
+    if client_type == ClientType::Client {
+        // Cluster clients don't become members of the cluster, the handshake only tells them
+        // which nodes are, and where to send the results of their requests.
+        let _ = ctx.client_registry().notify(RegisterClientSession {
+            node_id: handshake.node_id,
+            session_id,
+            session: session.clone(),
+        });
+
+        session
+            .send(SessionWrite(session_id, ClientEvent::Handshake(response)))
+            .await
+            .expect("send session write (handshake)");
+
+        debug!(
+            "[{}] written cluster client handshake ack (node_id={}), request_id={}",
+            &session_id, &handshake.node_id, &handshake.trace_id
+        );
+
+        return;
+    }
+
     let self_id = ctx.node_id();
 
     let nodes = handshake
@@ -401,16 +630,18 @@ async fn session_handshake(
         .map(|n| {
             let started_at = n.node_started_at.into_option().map(timestamp_to_datetime);
 
-            let addr =
-                if server_config.override_incoming_node_addr && n.node_id == handshake.node_id {
-                    let host = session_addr.ip();
+            let addr = match session_addr.ip() {
+                Some(host)
+                    if server_config.override_incoming_node_addr
+                        && n.node_id == handshake.node_id =>
+                {
                     info!("parsing addr={}", &n.addr);
                     let port = n.addr.split(":").last().unwrap();
                     format!("{}:{}", host, port)
-                } else {
-                    n.addr
-                };
-            RemoteNode::new(n.node_id, addr, n.tag, started_at)
+                }
+                _ => n.addr,
+            };
+            RemoteNode::new(n.node_id, addr, n.tag, started_at).with_roles(n.roles)
         })
         .collect();
 
@@ -447,6 +678,7 @@ async fn session_handshake(
 
 async fn session_handle_message(
     msg: MessageRequest,
+    message_id: Uuid,
     session_id: Uuid,
     ctx: RemoteActorSystem,
     session: LocalActorRef<RemoteSession>,
@@ -463,23 +695,17 @@ async fn session_handle_message(
     let actor_id = msg.actor_id.into_actor_id();
 
     match ctx
-        .handle_message(
-            msg.handler_type.as_str(),
-            actor_id.clone(),
-            msg.message.as_slice(),
-        )
+        .handle_message(msg.handler_type.as_str(), actor_id.clone(), msg.message)
         .await
     {
         Ok(buf) => {
             if msg.requires_response {
-                send_result(msg.message_id.parse().unwrap(), buf, session_id, session).await;
+                send_result(message_id, buf, session_id, session).await;
             }
         }
         Err(e) => {
             error!(target: "RemoteSession", "[node={}] failed to handle message (handler_type={}, target_actor_id={}), error={:?}", ctx.node_id(), &msg.handler_type, &actor_id, e);
-            let _ = ctx
-                .notify_rpc_err(msg.message_id.parse().unwrap(), e, msg.origin_node_id)
-                .await;
+            let _ = ctx.notify_rpc_err(message_id, e, msg.origin_node_id).await;
         }
     }
 }
@@ -501,7 +727,7 @@ async fn session_handle_lookup(
     };
 
     match response.write_to_bytes() {
-        Ok(buf) => send_result(msg_id, buf, session_id, session).await,
+        Ok(buf) => send_result(msg_id, buf.into(), session_id, session).await,
         Err(_) => {
             error!(target: "RemoteSession", "failed to handle message, todo: send err");
         }
@@ -510,11 +736,11 @@ async fn session_handle_lookup(
 
 async fn session_create_actor(
     msg: CreateActorEvent,
+    message_id: Uuid,
     session_id: Uuid,
     ctx: RemoteActorSystem,
     session: LocalActorRef<RemoteSession>,
 ) {
-    let msg_id = msg.message_id.clone();
     let actor_id = if msg.actor_id.is_empty() {
         None
     } else {
@@ -526,7 +752,7 @@ async fn session_create_actor(
         .handle_create_actor(actor_id, msg.actor_type, msg.recipe, None)
         .await
     {
-        Ok(buf) => send_result(msg_id.parse().unwrap(), buf.to_vec(), session_id, session).await,
+        Ok(buf) => send_result(message_id, buf.into(), session_id, session).await,
         Err(_) => {
             error!(target: "RemoteSession", "failed to handle message, todo: send err");
         }
@@ -542,7 +768,7 @@ async fn session_stream_publish(msg: Arc<StreamPublishEvent>, sys: RemoteActorSy
 
 async fn send_result(
     msg_id: Uuid,
-    res: Vec<u8>,
+    res: Bytes,
     session_id: Uuid,
     session: LocalActorRef<RemoteSession>,
 ) {
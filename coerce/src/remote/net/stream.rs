@@ -0,0 +1,138 @@
+use crate::remote::net::codec::NetworkCodec;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncWrite, DuplexStream, ReadBuf, ReadHalf, WriteHalf};
+use tokio::net::TcpStream;
+use tokio_rustls::TlsStream;
+use tokio_util::codec::{FramedRead, FramedWrite};
+
+#[cfg(unix)]
+use tokio::net::UnixStream;
+
+pub type NetworkStreamReader = FramedRead<ReadHalf<NetworkStream>, NetworkCodec>;
+
+pub type NetworkStreamWriter = FramedWrite<WriteHalf<NetworkStream>, NetworkCodec>;
+
+/// A byte stream that can be carried by a [`NetworkStream`], for transports other than the
+/// ones provided by coerce.
+pub trait AsyncStream: AsyncRead + AsyncWrite + Send + Sync + Unpin {}
+
+impl<S: AsyncRead + AsyncWrite + Send + Sync + Unpin> AsyncStream for S {}
+
+pub enum NetworkStream {
+    Tcp(TcpStream),
+    #[cfg(unix)]
+    Unix(UnixStream),
+    Memory(DuplexStream),
+    Tls(Box<TlsStream<NetworkStream>>),
+    Custom(Box<dyn AsyncStream>),
+}
+
+impl NetworkStream {
+    pub fn custom<S: 'static + AsyncStream>(stream: S) -> NetworkStream {
+        NetworkStream::Custom(Box::new(stream))
+    }
+
+    pub fn is_tls(&self) -> bool {
+        matches!(self, NetworkStream::Tls(_))
+    }
+
+    /// Enables TCP keepalive, probes are sent once the connection has been idle for `idle_time`.
+    /// Has no effect on non-TCP streams.
+    pub fn set_keepalive(&self, idle_time: Duration) -> io::Result<()> {
+        match self {
+            NetworkStream::Tcp(stream) => socket2::SockRef::from(stream)
+                .set_tcp_keepalive(&socket2::TcpKeepalive::new().with_time(idle_time)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Splits the stream into a framed reader and writer, both using the provided codec.
+    pub fn into_framed(self, codec: NetworkCodec) -> (NetworkStreamReader, NetworkStreamWriter) {
+        let (read, write) = tokio::io::split(self);
+        (FramedRead::new(read, codec), FramedWrite::new(write, codec))
+    }
+}
+
+impl From<TcpStream> for NetworkStream {
+    fn from(stream: TcpStream) -> Self {
+        NetworkStream::Tcp(stream)
+    }
+}
+
+#[cfg(unix)]
+impl From<UnixStream> for NetworkStream {
+    fn from(stream: UnixStream) -> Self {
+        NetworkStream::Unix(stream)
+    }
+}
+
+impl From<DuplexStream> for NetworkStream {
+    fn from(stream: DuplexStream) -> Self {
+        NetworkStream::Memory(stream)
+    }
+}
+
+impl From<TlsStream<NetworkStream>> for NetworkStream {
+    fn from(stream: TlsStream<NetworkStream>) -> Self {
+        NetworkStream::Tls(Box::new(stream))
+    }
+}
+
+impl AsyncRead for NetworkStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        match self.get_mut() {
+            NetworkStream::Tcp(stream) => Pin::new(stream).poll_read(cx, buf),
+            #[cfg(unix)]
+            NetworkStream::Unix(stream) => Pin::new(stream).poll_read(cx, buf),
+            NetworkStream::Memory(stream) => Pin::new(stream).poll_read(cx, buf),
+            NetworkStream::Tls(stream) => Pin::new(stream.as_mut()).poll_read(cx, buf),
+            NetworkStream::Custom(stream) => Pin::new(stream.as_mut()).poll_read(cx, buf),
+        }
+    }
+}
+
+impl AsyncWrite for NetworkStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, io::Error>> {
+        match self.get_mut() {
+            NetworkStream::Tcp(stream) => Pin::new(stream).poll_write(cx, buf),
+            #[cfg(unix)]
+            NetworkStream::Unix(stream) => Pin::new(stream).poll_write(cx, buf),
+            NetworkStream::Memory(stream) => Pin::new(stream).poll_write(cx, buf),
+            NetworkStream::Tls(stream) => Pin::new(stream.as_mut()).poll_write(cx, buf),
+            NetworkStream::Custom(stream) => Pin::new(stream.as_mut()).poll_write(cx, buf),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+        match self.get_mut() {
+            NetworkStream::Tcp(stream) => Pin::new(stream).poll_flush(cx),
+            #[cfg(unix)]
+            NetworkStream::Unix(stream) => Pin::new(stream).poll_flush(cx),
+            NetworkStream::Memory(stream) => Pin::new(stream).poll_flush(cx),
+            NetworkStream::Tls(stream) => Pin::new(stream.as_mut()).poll_flush(cx),
+            NetworkStream::Custom(stream) => Pin::new(stream.as_mut()).poll_flush(cx),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+        match self.get_mut() {
+            NetworkStream::Tcp(stream) => Pin::new(stream).poll_shutdown(cx),
+            #[cfg(unix)]
+            NetworkStream::Unix(stream) => Pin::new(stream).poll_shutdown(cx),
+            NetworkStream::Memory(stream) => Pin::new(stream).poll_shutdown(cx),
+            NetworkStream::Tls(stream) => Pin::new(stream.as_mut()).poll_shutdown(cx),
+            NetworkStream::Custom(stream) => Pin::new(stream.as_mut()).poll_shutdown(cx),
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_rustls::rustls::server::AllowAnyAuthenticatedClient;
+use tokio_rustls::rustls::{
+    Certificate, ClientConfig, PrivateKey, RootCertStore, ServerConfig, ServerName,
+};
+use tokio_rustls::{TlsAcceptor, TlsConnector, TlsStream};
+
+pub use tokio_rustls::rustls;
+
+pub type TlsConfigRef = Arc<TlsConfig>;
+
+/// TLS configuration used to secure cluster traffic. The same configuration is used
+/// when accepting connections in `RemoteServer` and when connecting via `RemoteClient`,
+/// so every node presents the same certificate chain and trusts the same CA bundle.
+pub struct TlsConfig {
+    acceptor: TlsAcceptor,
+    connector: TlsConnector,
+    server_name: Option<String>,
+    mutual_tls: bool,
+}
+
+impl Debug for TlsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TlsConfig")
+            .field("server_name", &self.server_name)
+            .field("mutual_tls", &self.mutual_tls)
+            .finish()
+    }
+}
+
+#[derive(Debug)]
+pub enum TlsConfigErr {
+    MissingCertificateChain,
+    MissingPrivateKey,
+    MissingTrustedCa,
+    InvalidPem(io::Error),
+    Rustls(rustls::Error),
+}
+
+impl TlsConfig {
+    pub fn builder() -> TlsConfigBuilder {
+        TlsConfigBuilder::default()
+    }
+
+    pub fn is_mutual_tls(&self) -> bool {
+        self.mutual_tls
+    }
+
+    pub async fn accept<S>(&self, stream: S) -> io::Result<TlsStream<S>>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        self.acceptor.accept(stream).await.map(TlsStream::Server)
+    }
+
+    pub async fn connect<S>(&self, addr: &str, stream: S) -> io::Result<TlsStream<S>>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        let server_name = self.server_name(addr)?;
+        self.connector
+            .connect(server_name, stream)
+            .await
+            .map(TlsStream::Client)
+    }
+
+    fn server_name(&self, addr: &str) -> io::Result<ServerName> {
+        let name = match &self.server_name {
+            Some(server_name) => server_name.as_str(),
+            None => addr_host(addr),
+        };
+
+        ServerName::try_from(name)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, format!("{}: {}", e, name)))
+    }
+}
+
+fn addr_host(addr: &str) -> &str {
+    let host = addr.rsplit_once(':').map_or(addr, |(host, _port)| host);
+    host.trim_start_matches('[').trim_end_matches(']')
+}
+
+#[derive(Default)]
+pub struct TlsConfigBuilder {
+    certificate_chain: Vec<Certificate>,
+    private_key: Option<PrivateKey>,
+    trusted_ca: Vec<Certificate>,
+    server_name: Option<String>,
+    mutual_tls: bool,
+}
+
+impl TlsConfigBuilder {
+    pub fn from_pem_files(
+        certificate_chain: impl AsRef<Path>,
+        private_key: impl AsRef<Path>,
+        trusted_ca: impl AsRef<Path>,
+    ) -> Result<Self, TlsConfigErr> {
+        let read = |path: &Path| std::fs::read(path).map_err(TlsConfigErr::InvalidPem);
+
+        TlsConfigBuilder::default()
+            .certificate_chain_pem(&read(certificate_chain.as_ref())?)?
+            .private_key_pem(&read(private_key.as_ref())?)?
+            .trusted_ca_pem(&read(trusted_ca.as_ref())?)
+    }
+
+    pub fn certificate_chain(mut self, certificate_chain: Vec<Vec<u8>>) -> Self {
+        self.certificate_chain = certificate_chain.into_iter().map(Certificate).collect();
+        self
+    }
+
+    pub fn certificate_chain_pem(self, pem: &[u8]) -> Result<Self, TlsConfigErr> {
+        let certificate_chain = rustls_pemfile::certs(&mut io::BufReader::new(pem))
+            .map_err(TlsConfigErr::InvalidPem)?;
+
+        Ok(self.certificate_chain(certificate_chain))
+    }
+
+    pub fn private_key(mut self, private_key: Vec<u8>) -> Self {
+        self.private_key = Some(PrivateKey(private_key));
+        self
+    }
+
+    pub fn private_key_pem(self, pem: &[u8]) -> Result<Self, TlsConfigErr> {
+        let mut reader = io::BufReader::new(pem);
+        loop {
+            match rustls_pemfile::read_one(&mut reader).map_err(TlsConfigErr::InvalidPem)? {
+                Some(rustls_pemfile::Item::PKCS8Key(key))
+                | Some(rustls_pemfile::Item::RSAKey(key))
+                | Some(rustls_pemfile::Item::ECKey(key)) => return Ok(self.private_key(key)),
+                Some(_) => continue,
+                None => return Err(TlsConfigErr::MissingPrivateKey),
+            }
+        }
+    }
+
+    pub fn trusted_ca(mut self, certificate: Vec<u8>) -> Self {
+        self.trusted_ca.push(Certificate(certificate));
+        self
+    }
+
+    pub fn trusted_ca_pem(mut self, pem: &[u8]) -> Result<Self, TlsConfigErr> {
+        let certificates = rustls_pemfile::certs(&mut io::BufReader::new(pem))
+            .map_err(TlsConfigErr::InvalidPem)?;
+
+        self.trusted_ca
+            .extend(certificates.into_iter().map(Certificate));
+        Ok(self)
+    }
+
+    /// Overrides the name used to verify the certificate presented by remote nodes,
+    /// by default the host of the node's address is used.
+    pub fn server_name(mut self, server_name: impl ToString) -> Self {
+        self.server_name = Some(server_name.to_string());
+        self
+    }
+
+    /// Require connecting nodes to present a certificate signed by a trusted CA.
+    pub fn mutual_tls(mut self, mutual_tls: bool) -> Self {
+        self.mutual_tls = mutual_tls;
+        self
+    }
+
+    pub fn build(self) -> Result<TlsConfig, TlsConfigErr> {
+        if self.certificate_chain.is_empty() {
+            return Err(TlsConfigErr::MissingCertificateChain);
+        }
+
+        let private_key = self.private_key.ok_or(TlsConfigErr::MissingPrivateKey)?;
+        if self.trusted_ca.is_empty() {
+            return Err(TlsConfigErr::MissingTrustedCa);
+        }
+
+        let mut roots = RootCertStore::empty();
+        for certificate in &self.trusted_ca {
+            roots.add(certificate)?;
+        }
+
+        let server_config = ServerConfig::builder().with_safe_defaults();
+        let server_config = if self.mutual_tls {
+            server_config
+                .with_client_cert_verifier(AllowAnyAuthenticatedClient::new(roots.clone()).boxed())
+        } else {
+            server_config.with_no_client_auth()
+        }
+        .with_single_cert(self.certificate_chain.clone(), private_key.clone())?;
+
+        let client_config = ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots);
+
+        let client_config = if self.mutual_tls {
+            client_config.with_client_auth_cert(self.certificate_chain, private_key)?
+        } else {
+            client_config.with_no_client_auth()
+        };
+
+        Ok(TlsConfig {
+            acceptor: TlsAcceptor::from(Arc::new(server_config)),
+            connector: TlsConnector::from(Arc::new(client_config)),
+            server_name: self.server_name,
+            mutual_tls: self.mutual_tls,
+        })
+    }
+}
+
+impl From<rustls::Error> for TlsConfigErr {
+    fn from(e: rustls::Error) -> Self {
+        TlsConfigErr::Rustls(e)
+    }
+}
+
+impl Display for TlsConfigErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TlsConfigErr::MissingCertificateChain => write!(f, "no certificate chain provided"),
+            TlsConfigErr::MissingPrivateKey => write!(f, "no private key provided"),
+            TlsConfigErr::MissingTrustedCa => write!(f, "no trusted CA certificates provided"),
+            TlsConfigErr::InvalidPem(e) => write!(f, "invalid PEM: {}", e),
+            TlsConfigErr::Rustls(e) => write!(f, "invalid TLS configuration: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for TlsConfigErr {}
@@ -0,0 +1,294 @@
+use crate::remote::net::stream::NetworkStream;
+use crate::remote::net::transport::{Transport, TransportListener, TransportRef};
+use parking_lot::Mutex;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, DuplexStream};
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+
+const PUMP_BUFFER_SIZE: usize = 64 * 1024;
+
+/// The faults applied to frames sent in either direction between two nodes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LinkFaults {
+    /// Connections are refused and every frame is dropped.
+    pub partitioned: bool,
+
+    /// Delay applied to every frame.
+    pub latency: Duration,
+
+    /// Upper bound of a random delay added on top of `latency`.
+    pub jitter: Duration,
+
+    /// Fraction of frames that are dropped, between `0.0` and `1.0`.
+    pub drop_rate: f64,
+}
+
+/// Control API for injecting network faults between nodes, used for testing failure detection,
+/// leader changes and shard reallocation under partial partitions.
+///
+/// Each node is given its own transport via [`FaultInjector::transport`], nodes are identified
+/// by the address they listen on. Faults are applied to connections made by the wrapped
+/// transports, so every node in the cluster must use a transport created by the same injector.
+/// Frames are inspected as they pass through, so TLS must not be enabled alongside it.
+#[derive(Clone)]
+pub struct FaultInjector {
+    state: Arc<Mutex<FaultState>>,
+}
+
+struct FaultState {
+    nodes: HashSet<String>,
+    links: HashMap<(String, String), LinkFaults>,
+    rng: StdRng,
+}
+
+pub struct FaultInjectingTransport {
+    inner: TransportRef,
+    injector: FaultInjector,
+    local_addr: Mutex<Option<String>>,
+}
+
+impl FaultInjector {
+    pub fn new() -> FaultInjector {
+        Self::with_rng(StdRng::from_entropy())
+    }
+
+    /// Creates an injector with a seeded random number generator, so that frame drops and
+    /// jitter are reproducible.
+    pub fn with_seed(seed: u64) -> FaultInjector {
+        Self::with_rng(StdRng::seed_from_u64(seed))
+    }
+
+    fn with_rng(rng: StdRng) -> FaultInjector {
+        FaultInjector {
+            state: Arc::new(Mutex::new(FaultState {
+                nodes: HashSet::new(),
+                links: HashMap::new(),
+                rng,
+            })),
+        }
+    }
+
+    /// Wraps `inner` in a transport subject to the faults configured on this injector.
+    pub fn transport(&self, inner: TransportRef) -> TransportRef {
+        Arc::new(FaultInjectingTransport {
+            inner,
+            injector: self.clone(),
+            local_addr: Mutex::new(None),
+        })
+    }
+
+    pub fn partition(&self, a: &str, b: &str) {
+        self.update_link(a, b, |faults| faults.partitioned = true);
+    }
+
+    /// Partitions `addr` from every other node known to the injector.
+    pub fn isolate(&self, addr: &str) {
+        let nodes: Vec<String> = self.state.lock().nodes.iter().cloned().collect();
+        for node in nodes.iter().filter(|n| n.as_str() != addr) {
+            self.partition(addr, node);
+        }
+    }
+
+    pub fn set_latency(&self, a: &str, b: &str, latency: Duration, jitter: Duration) {
+        self.update_link(a, b, |faults| {
+            faults.latency = latency;
+            faults.jitter = jitter;
+        });
+    }
+
+    pub fn set_drop_rate(&self, a: &str, b: &str, drop_rate: f64) {
+        self.update_link(a, b, |faults| faults.drop_rate = drop_rate.clamp(0.0, 1.0));
+    }
+
+    /// Removes all faults between `a` and `b`.
+    pub fn heal(&self, a: &str, b: &str) {
+        self.state.lock().links.remove(&link(a, b));
+    }
+
+    /// Removes all faults between every node.
+    pub fn heal_all(&self) {
+        self.state.lock().links.clear();
+    }
+
+    pub fn link_faults(&self, a: &str, b: &str) -> LinkFaults {
+        self.state
+            .lock()
+            .links
+            .get(&link(a, b))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn update_link(&self, a: &str, b: &str, f: impl FnOnce(&mut LinkFaults)) {
+        let mut state = self.state.lock();
+        f(state.links.entry(link(a, b)).or_default());
+    }
+
+    fn register_node(&self, addr: &str) {
+        self.state.lock().nodes.insert(addr.to_string());
+    }
+
+    fn is_partitioned(&self, a: &str, b: &str) -> bool {
+        self.state
+            .lock()
+            .links
+            .get(&link(a, b))
+            .is_some_and(|faults| faults.partitioned)
+    }
+
+    /// Returns when the frame should be delivered, or `None` if it should be dropped.
+    fn frame_delay(&self, a: &str, b: &str) -> Option<Duration> {
+        let mut state = self.state.lock();
+        let faults = match state.links.get(&link(a, b)) {
+            Some(faults) => faults.clone(),
+            None => return Some(Duration::ZERO),
+        };
+
+        if faults.partitioned || (faults.drop_rate > 0.0 && state.rng.gen_bool(faults.drop_rate)) {
+            return None;
+        }
+
+        let jitter = if faults.jitter.is_zero() {
+            Duration::ZERO
+        } else {
+            Duration::from_nanos(state.rng.gen_range(0..=faults.jitter.as_nanos() as u64))
+        };
+
+        Some(faults.latency + jitter)
+    }
+}
+
+impl Default for FaultInjector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Transport for FaultInjectingTransport {
+    async fn listen(&self, addr: &str) -> io::Result<Box<dyn TransportListener>> {
+        let listener = self.inner.listen(addr).await?;
+
+        self.injector.register_node(addr);
+        *self.local_addr.lock() = Some(addr.to_string());
+        Ok(listener)
+    }
+
+    async fn connect(&self, addr: &str) -> io::Result<NetworkStream> {
+        let local_addr = self.local_addr.lock().clone().unwrap_or_default();
+        if self.injector.is_partitioned(&local_addr, addr) {
+            return Err(io::Error::new(
+                io::ErrorKind::ConnectionRefused,
+                format!("{} is partitioned from {}", local_addr, addr),
+            ));
+        }
+
+        let stream = self.inner.connect(addr).await?;
+        let (client, pump) = tokio::io::duplex(PUMP_BUFFER_SIZE);
+
+        tokio::spawn(pump_frames(
+            pump,
+            stream,
+            self.injector.clone(),
+            local_addr,
+            addr.to_string(),
+        ));
+
+        Ok(client.into())
+    }
+
+    async fn resolves(&self, addr: &str) -> bool {
+        self.inner.resolves(addr).await
+    }
+}
+
+fn link(a: &str, b: &str) -> (String, String) {
+    if a <= b {
+        (a.to_string(), b.to_string())
+    } else {
+        (b.to_string(), a.to_string())
+    }
+}
+
+async fn pump_frames(
+    local: DuplexStream,
+    remote: NetworkStream,
+    injector: FaultInjector,
+    local_addr: String,
+    remote_addr: String,
+) {
+    let (local_read, local_write) = tokio::io::split(local);
+    let (remote_read, remote_write) = tokio::io::split(remote);
+
+    let outbound = forward_frames(
+        local_read,
+        remote_write,
+        injector.clone(),
+        local_addr.clone(),
+        remote_addr.clone(),
+    );
+
+    let inbound = forward_frames(remote_read, local_write, injector, local_addr, remote_addr);
+
+    // Once either side has closed, both streams are dropped which closes the other side too
+    tokio::select! {
+        _ = outbound => {},
+        _ = inbound => {},
+    }
+}
+
+async fn forward_frames<R: AsyncRead + Unpin, W: AsyncWrite + Unpin>(
+    mut reader: R,
+    mut writer: W,
+    injector: FaultInjector,
+    local_addr: String,
+    remote_addr: String,
+) {
+    let (frame_tx, mut frame_rx) = mpsc::unbounded_channel::<(Instant, Vec<u8>)>();
+
+    let read = async move {
+        let mut last_delivery = Instant::now();
+        while let Ok(frame) = read_frame(&mut reader).await {
+            if let Some(delay) = injector.frame_delay(&local_addr, &remote_addr) {
+                // Frames are never reordered, a frame is delivered no earlier than the previous one
+                last_delivery = last_delivery.max(Instant::now() + delay);
+                if frame_tx.send((last_delivery, frame)).is_err() {
+                    break;
+                }
+            } else {
+                trace!("dropped frame ({} -> {})", &local_addr, &remote_addr);
+            }
+        }
+    };
+
+    let write = async move {
+        while let Some((deliver_at, frame)) = frame_rx.recv().await {
+            tokio::time::sleep_until(deliver_at).await;
+            if writer.write_all(&frame).await.is_err() {
+                break;
+            }
+        }
+
+        let _ = writer.shutdown().await;
+    };
+
+    tokio::join!(read, write);
+}
+
+async fn read_frame<R: AsyncRead + Unpin>(reader: &mut R) -> io::Result<Vec<u8>> {
+    let len = reader.read_u32_le().await?;
+
+    let mut frame = len.to_le_bytes().to_vec();
+    let read = reader.take(len as u64).read_to_end(&mut frame).await?;
+    if read < len as usize {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+
+    Ok(frame)
+}
@@ -0,0 +1,121 @@
+use crate::remote::net::stream::NetworkStream;
+use crate::remote::net::transport::{PeerAddr, Transport, TransportListener};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::io::DuplexStream;
+use tokio::sync::mpsc;
+
+const DEFAULT_BUFFER_SIZE: usize = 64 * 1024;
+
+type ConnectionSender = mpsc::UnboundedSender<(DuplexStream, PeerAddr)>;
+
+/// In-process transport, connections are in-memory duplex streams. Nodes can only connect to
+/// each other when they share the same `MemoryTransport` (or a clone of it), which makes it
+/// possible to run multi-node clusters within a single process without binding any ports.
+#[derive(Clone)]
+pub struct MemoryTransport {
+    listeners: Arc<Mutex<HashMap<String, ConnectionSender>>>,
+    next_connection_id: Arc<AtomicU64>,
+    buffer_size: usize,
+}
+
+pub struct MemoryTransportListener {
+    addr: String,
+    connections: mpsc::UnboundedReceiver<(DuplexStream, PeerAddr)>,
+    listeners: Arc<Mutex<HashMap<String, ConnectionSender>>>,
+}
+
+impl MemoryTransport {
+    pub fn new() -> MemoryTransport {
+        MemoryTransport {
+            listeners: Arc::new(Mutex::new(HashMap::new())),
+            next_connection_id: Arc::new(AtomicU64::new(1)),
+            buffer_size: DEFAULT_BUFFER_SIZE,
+        }
+    }
+
+    /// The maximum number of bytes buffered in each direction of a connection.
+    pub fn with_buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = buffer_size;
+        self
+    }
+}
+
+impl Default for MemoryTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Transport for MemoryTransport {
+    async fn listen(&self, addr: &str) -> io::Result<Box<dyn TransportListener>> {
+        let mut listeners = self.listeners.lock();
+        if listeners.get(addr).is_some_and(|s| !s.is_closed()) {
+            return Err(io::Error::new(
+                io::ErrorKind::AddrInUse,
+                format!("memory address {} is already in use", addr),
+            ));
+        }
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        listeners.insert(addr.to_string(), tx);
+
+        Ok(Box::new(MemoryTransportListener {
+            addr: addr.to_string(),
+            connections: rx,
+            listeners: self.listeners.clone(),
+        }))
+    }
+
+    async fn connect(&self, addr: &str) -> io::Result<NetworkStream> {
+        let listener = self.listeners.lock().get(addr).cloned();
+        let refused = || {
+            io::Error::new(
+                io::ErrorKind::ConnectionRefused,
+                format!("no memory listener at {}", addr),
+            )
+        };
+
+        let listener = listener.ok_or_else(refused)?;
+        let (client, server) = tokio::io::duplex(self.buffer_size);
+        let connection_id = self.next_connection_id.fetch_add(1, Ordering::Relaxed);
+
+        listener
+            .send((server, PeerAddr::Memory(connection_id)))
+            .map_err(|_| refused())?;
+
+        Ok(client.into())
+    }
+}
+
+#[async_trait]
+impl TransportListener for MemoryTransportListener {
+    async fn accept(&mut self) -> io::Result<(NetworkStream, PeerAddr)> {
+        match self.connections.recv().await {
+            Some((stream, addr)) => Ok((stream.into(), addr)),
+            None => Err(io::Error::new(
+                io::ErrorKind::NotConnected,
+                "memory listener closed",
+            )),
+        }
+    }
+
+    fn local_addr(&self) -> String {
+        self.addr.clone()
+    }
+}
+
+impl Drop for MemoryTransportListener {
+    fn drop(&mut self) {
+        self.connections.close();
+
+        let mut listeners = self.listeners.lock();
+        if listeners.get(&self.addr).is_some_and(|s| s.is_closed()) {
+            listeners.remove(&self.addr);
+        }
+    }
+}
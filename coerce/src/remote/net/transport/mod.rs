@@ -0,0 +1,76 @@
+use crate::remote::net::stream::NetworkStream;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+pub mod fault;
+pub mod memory;
+pub mod tcp;
+
+#[cfg(unix)]
+pub mod unix;
+
+pub use fault::{FaultInjector, LinkFaults};
+pub use memory::MemoryTransport;
+pub use tcp::TcpTransport;
+
+#[cfg(unix)]
+pub use unix::UnixTransport;
+
+pub type TransportRef = Arc<dyn Transport>;
+
+/// Establishes the streams used for communication between nodes. Node addresses (the listen address,
+/// seed addresses and the addresses advertised by nodes) are interpreted by the transport, so all
+/// nodes in a cluster are expected to use the same kind of transport.
+#[async_trait]
+pub trait Transport: 'static + Send + Sync {
+    async fn listen(&self, addr: &str) -> io::Result<Box<dyn TransportListener>>;
+
+    async fn connect(&self, addr: &str) -> io::Result<NetworkStream>;
+
+    /// Whether `addr` can currently be resolved, checked before discovering peers from a seed address.
+    async fn resolves(&self, _addr: &str) -> bool {
+        true
+    }
+}
+
+#[async_trait]
+pub trait TransportListener: 'static + Send + Sync {
+    async fn accept(&mut self) -> io::Result<(NetworkStream, PeerAddr)>;
+
+    fn local_addr(&self) -> String;
+}
+
+/// The address of an inbound connection.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PeerAddr {
+    Socket(SocketAddr),
+    Unix(String),
+    Memory(u64),
+}
+
+impl PeerAddr {
+    pub fn ip(&self) -> Option<IpAddr> {
+        match self {
+            PeerAddr::Socket(addr) => Some(addr.ip()),
+            _ => None,
+        }
+    }
+}
+
+impl Display for PeerAddr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PeerAddr::Socket(addr) => write!(f, "{}", addr),
+            PeerAddr::Unix(path) => write!(f, "unix:{}", path),
+            PeerAddr::Memory(id) => write!(f, "memory:{}", id),
+        }
+    }
+}
+
+impl From<SocketAddr> for PeerAddr {
+    fn from(addr: SocketAddr) -> Self {
+        PeerAddr::Socket(addr)
+    }
+}
@@ -0,0 +1,42 @@
+use crate::remote::net::stream::NetworkStream;
+use crate::remote::net::transport::{PeerAddr, Transport, TransportListener};
+use std::io;
+use tokio::net::{lookup_host, TcpListener, TcpStream};
+
+/// The default transport, nodes listen on and connect to `host:port` addresses.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TcpTransport;
+
+pub struct TcpTransportListener {
+    listener: TcpListener,
+}
+
+#[async_trait]
+impl Transport for TcpTransport {
+    async fn listen(&self, addr: &str) -> io::Result<Box<dyn TransportListener>> {
+        let listener = TcpListener::bind(addr).await?;
+        Ok(Box::new(TcpTransportListener { listener }))
+    }
+
+    async fn connect(&self, addr: &str) -> io::Result<NetworkStream> {
+        TcpStream::connect(addr).await.map(NetworkStream::from)
+    }
+
+    async fn resolves(&self, addr: &str) -> bool {
+        lookup_host(addr).await.is_ok()
+    }
+}
+
+#[async_trait]
+impl TransportListener for TcpTransportListener {
+    async fn accept(&mut self) -> io::Result<(NetworkStream, PeerAddr)> {
+        let (stream, addr) = self.listener.accept().await?;
+        Ok((stream.into(), addr.into()))
+    }
+
+    fn local_addr(&self) -> String {
+        self.listener
+            .local_addr()
+            .map_or_else(|_| "unknown".to_string(), |addr| addr.to_string())
+    }
+}
@@ -0,0 +1,58 @@
+use crate::remote::net::stream::NetworkStream;
+use crate::remote::net::transport::{PeerAddr, Transport, TransportListener};
+use std::io;
+use std::os::unix::fs::FileTypeExt;
+use std::path::PathBuf;
+use tokio::net::{UnixListener, UnixStream};
+
+/// Unix domain socket transport for nodes running on the same host, node addresses are socket paths.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnixTransport;
+
+pub struct UnixTransportListener {
+    listener: UnixListener,
+    path: PathBuf,
+}
+
+#[async_trait]
+impl Transport for UnixTransport {
+    async fn listen(&self, addr: &str) -> io::Result<Box<dyn TransportListener>> {
+        let path = PathBuf::from(addr);
+
+        // Sockets left behind by a previous process would otherwise prevent binding
+        if let Ok(metadata) = std::fs::symlink_metadata(&path) {
+            if metadata.file_type().is_socket() {
+                std::fs::remove_file(&path)?;
+            }
+        }
+
+        let listener = UnixListener::bind(&path)?;
+        Ok(Box::new(UnixTransportListener { listener, path }))
+    }
+
+    async fn connect(&self, addr: &str) -> io::Result<NetworkStream> {
+        UnixStream::connect(addr).await.map(NetworkStream::from)
+    }
+}
+
+#[async_trait]
+impl TransportListener for UnixTransportListener {
+    async fn accept(&mut self) -> io::Result<(NetworkStream, PeerAddr)> {
+        let (stream, addr) = self.listener.accept().await?;
+        let addr = addr
+            .as_pathname()
+            .map_or_else(|| "unnamed".to_string(), |p| p.display().to_string());
+
+        Ok((stream.into(), PeerAddr::Unix(addr)))
+    }
+
+    fn local_addr(&self) -> String {
+        self.path.display().to_string()
+    }
+}
+
+impl Drop for UnixTransportListener {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
@@ -0,0 +1,230 @@
+use crate::actor::message::Message;
+use crate::actor::scheduler::timer::TimerTick;
+use crate::remote::raft::proto::raft as proto;
+use crate::remote::raft::{LogEntry, RaftError, RaftStatus};
+use crate::remote::system::NodeId;
+use coerce_macros::ProtoMessage;
+use tokio::sync::oneshot;
+
+#[derive(ProtoMessage)]
+#[proto(proto::RequestVote)]
+pub struct RequestVote {
+    pub term: u64,
+    pub candidate_id: NodeId,
+    pub last_log_index: u64,
+    pub last_log_term: u64,
+}
+
+#[derive(ProtoMessage)]
+#[proto(proto::VoteResponse)]
+pub struct VoteResponse {
+    pub term: u64,
+    pub voter_id: NodeId,
+    pub vote_granted: bool,
+}
+
+/// Replicates log entries from the leader, also sent with no entries as a heartbeat.
+#[derive(ProtoMessage)]
+#[proto(proto::AppendEntries)]
+pub struct AppendEntries {
+    pub term: u64,
+    pub leader_id: NodeId,
+    pub prev_log_index: u64,
+    pub prev_log_term: u64,
+    pub entries: Vec<LogEntry>,
+    pub leader_commit: u64,
+}
+
+/// When `success` is false, `match_index` is the index the follower's log is known to match up
+/// to, so the leader can skip back to it rather than retrying one entry at a time.
+#[derive(ProtoMessage)]
+#[proto(proto::AppendEntriesResponse)]
+pub struct AppendEntriesResponse {
+    pub term: u64,
+    pub follower_id: NodeId,
+    pub success: bool,
+    pub match_index: u64,
+}
+
+/// Sent in place of `AppendEntries` to a follower that's behind the leader's snapshot, answered
+/// with an `AppendEntriesResponse` matching up to `last_included_index`.
+#[derive(ProtoMessage)]
+#[proto(proto::InstallSnapshot)]
+pub struct InstallSnapshot {
+    pub term: u64,
+    pub leader_id: NodeId,
+    pub last_included_index: u64,
+    pub last_included_term: u64,
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone)]
+pub struct RaftTick;
+
+/// Appends a command to the log, the result of applying the command to the state machine is
+/// sent once the entry has been committed.
+pub struct Propose {
+    pub command: Vec<u8>,
+    pub result_tx: oneshot::Sender<Result<Vec<u8>, RaftError>>,
+}
+
+pub struct GetStatus;
+
+impl Message for RaftTick {
+    type Result = ();
+}
+
+impl TimerTick for RaftTick {}
+
+impl Message for Propose {
+    type Result = ();
+}
+
+impl Message for GetStatus {
+    type Result = RaftStatus;
+}
+
+impl From<&RequestVote> for proto::RequestVote {
+    fn from(msg: &RequestVote) -> Self {
+        proto::RequestVote {
+            term: msg.term,
+            candidate_id: msg.candidate_id,
+            last_log_index: msg.last_log_index,
+            last_log_term: msg.last_log_term,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::RequestVote> for RequestVote {
+    fn from(msg: proto::RequestVote) -> Self {
+        RequestVote {
+            term: msg.term,
+            candidate_id: msg.candidate_id,
+            last_log_index: msg.last_log_index,
+            last_log_term: msg.last_log_term,
+        }
+    }
+}
+
+impl From<&VoteResponse> for proto::VoteResponse {
+    fn from(msg: &VoteResponse) -> Self {
+        proto::VoteResponse {
+            term: msg.term,
+            voter_id: msg.voter_id,
+            vote_granted: msg.vote_granted,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::VoteResponse> for VoteResponse {
+    fn from(msg: proto::VoteResponse) -> Self {
+        VoteResponse {
+            term: msg.term,
+            voter_id: msg.voter_id,
+            vote_granted: msg.vote_granted,
+        }
+    }
+}
+
+impl From<&AppendEntries> for proto::AppendEntries {
+    fn from(msg: &AppendEntries) -> Self {
+        proto::AppendEntries {
+            term: msg.term,
+            leader_id: msg.leader_id,
+            prev_log_index: msg.prev_log_index,
+            prev_log_term: msg.prev_log_term,
+            entries: msg.entries.iter().map(|e| e.into()).collect(),
+            leader_commit: msg.leader_commit,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::AppendEntries> for AppendEntries {
+    fn from(msg: proto::AppendEntries) -> Self {
+        AppendEntries {
+            term: msg.term,
+            leader_id: msg.leader_id,
+            prev_log_index: msg.prev_log_index,
+            prev_log_term: msg.prev_log_term,
+            entries: msg.entries.into_iter().map(|e| e.into()).collect(),
+            leader_commit: msg.leader_commit,
+        }
+    }
+}
+
+impl From<&AppendEntriesResponse> for proto::AppendEntriesResponse {
+    fn from(msg: &AppendEntriesResponse) -> Self {
+        proto::AppendEntriesResponse {
+            term: msg.term,
+            follower_id: msg.follower_id,
+            success: msg.success,
+            match_index: msg.match_index,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::AppendEntriesResponse> for AppendEntriesResponse {
+    fn from(msg: proto::AppendEntriesResponse) -> Self {
+        AppendEntriesResponse {
+            term: msg.term,
+            follower_id: msg.follower_id,
+            success: msg.success,
+            match_index: msg.match_index,
+        }
+    }
+}
+
+impl From<&InstallSnapshot> for proto::InstallSnapshot {
+    fn from(msg: &InstallSnapshot) -> Self {
+        proto::InstallSnapshot {
+            term: msg.term,
+            leader_id: msg.leader_id,
+            last_included_index: msg.last_included_index,
+            last_included_term: msg.last_included_term,
+            data: msg.data.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::InstallSnapshot> for InstallSnapshot {
+    fn from(msg: proto::InstallSnapshot) -> Self {
+        InstallSnapshot {
+            term: msg.term,
+            leader_id: msg.leader_id,
+            last_included_index: msg.last_included_index,
+            last_included_term: msg.last_included_term,
+            data: msg.data,
+        }
+    }
+}
+
+impl From<&LogEntry> for proto::LogEntry {
+    fn from(entry: &LogEntry) -> Self {
+        proto::LogEntry {
+            index: entry.index,
+            term: entry.term,
+            noop: entry.command.is_none(),
+            command: entry.command.clone().unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::LogEntry> for LogEntry {
+    fn from(entry: proto::LogEntry) -> Self {
+        LogEntry {
+            index: entry.index,
+            term: entry.term,
+            command: if entry.noop {
+                None
+            } else {
+                Some(entry.command)
+            },
+        }
+    }
+}
@@ -1,232 +1,1010 @@
-// use crate::actor::LocalActorRef;
-// use crate::remote::net::message::{ClientEvent, SessionEvent};
-// use crate::remote::net::proto::network::{ClientResult, RaftRequest as ProtoRaftRequest};
-// use crate::remote::net::server::session::{RemoteSessionStore, SessionWrite};
-// use crate::remote::net::StreamData;
-// use crate::remote::system::RemoteActorSystem;
-// use anyhow::Result;
-// use async_raft::raft::{
-//     AppendEntriesRequest, AppendEntriesResponse, InstallSnapshotRequest, InstallSnapshotResponse,
-//     VoteRequest, VoteResponse,
-// };
-// use async_raft::{Config, Raft, RaftNetwork};
-// use memstore::{ClientRequest, ClientResponse, MemStore};
-//
-// use std::sync::Arc;
-// use uuid::Uuid;
-//
-// pub type RaftCore = Raft<ClientRequest, ClientResponse, RaftRouter, MemStore>;
-//
-// pub struct RaftSystem {
-//     raft: RaftCore,
-// }
-//
-// pub struct RaftRouter {
-//     system: RemoteActorSystem,
-// }
-//
-// impl RaftSystem {
-//     pub fn new(system: RemoteActorSystem) -> Arc<RaftSystem> {
-//         let node_id = system.node_id();
-//         let cluster_name = "coerce".to_string();
-//         let config = Config::build(cluster_name)
-//             .heartbeat_interval(1000)
-//             .election_timeout_min(60000 * 30)
-//             .election_timeout_max(60000 * 60)
-//             .validate()
-//             .unwrap();
-//
-//         Arc::new(RaftSystem {
-//             raft: Raft::new(
-//                 node_id,
-//                 Arc::new(config),
-//                 Arc::new(RaftRouter::new(system)),
-//                 Arc::new(MemStore::new(node_id)),
-//             ),
-//         })
-//     }
-//
-//     pub fn core(&self) -> &RaftCore {
-//         &self.raft
-//     }
-// }
-//
-// impl RaftRouter {
-//     pub fn new(system: RemoteActorSystem) -> RaftRouter {
-//         RaftRouter { system }
-//     }
-// }
-//
-// #[derive(Debug)]
-// pub enum RaftRequest {
-//     AppendEntries(AppendEntriesRequest<ClientRequest>),
-//     InstallSnapshot(InstallSnapshotRequest),
-//     Vote(VoteRequest),
-// }
-//
-// impl RaftSystem {
-//     pub async fn inbound_request(
-//         &self,
-//         proto_req: ProtoRaftRequest,
-//         session_id: Uuid,
-//         sessions: &LocalActorRef<RemoteSessionStore>,
-//     ) {
-//         let message_id = Uuid::parse_str(&proto_req.message_id).unwrap();
-//         let req = RaftRequest::from_proto(proto_req);
-//
-//         info!(
-//             "inbound req, session={}, message_id={}",
-//             &session_id, &message_id
-//         );
-//
-//         let result = match req {
-//             RaftRequest::AppendEntries(append_entries) => self
-//                 .raft
-//                 .append_entries(append_entries)
-//                 .await
-//                 .map(|res| res.write_to_bytes()),
-//
-//             RaftRequest::InstallSnapshot(snapshot) => self
-//                 .raft
-//                 .install_snapshot(snapshot)
-//                 .await
-//                 .map(|res| res.write_to_bytes()),
-//
-//             RaftRequest::Vote(vote) => self.raft.vote(vote).await.map(|res| res.write_to_bytes()),
-//         };
-//
-//         match result {
-//             Ok(Some(result)) => {
-//                 let result = ClientEvent::Result(ClientResult {
-//                     message_id: message_id.to_string(),
-//                     result,
-//                     ..Default::default()
-//                 });
-//
-//                 sessions.send(SessionWrite(session_id, result)).await;
-//             }
-//             Err(_e) => {
-//                 // todo: send err
-//             }
-//             Ok(None) => {
-//                 // todo: send serialisation err
-//             }
-//         }
-//     }
-// }
-//
-// impl RaftRequest {
-//     fn request_type(&self) -> u32 {
-//         match &self {
-//             &RaftRequest::AppendEntries(_) => 1,
-//             &RaftRequest::InstallSnapshot(_) => 2,
-//             &RaftRequest::Vote(_) => 3,
-//         }
-//     }
-//
-//     fn write_to_bytes(&self) -> Vec<u8> {
-//         // TODO: protobuf for the raft request (json is temporary)
-//         match self {
-//             RaftRequest::AppendEntries(append_entries) => {
-//                 serde_json::to_vec(&append_entries).expect("serialise append_entries request")
-//             }
-//             RaftRequest::InstallSnapshot(install_snapshot) => {
-//                 serde_json::to_vec(&install_snapshot).expect("serialise install_snapshot request")
-//             }
-//             RaftRequest::Vote(vote) => serde_json::to_vec(&vote).expect("serialise vote request"),
-//         }
-//     }
-//
-//     fn from_proto(raft_req: ProtoRaftRequest) -> Self {
-//         match raft_req.request_type {
-//             1 => RaftRequest::AppendEntries(serde_json::from_slice(&raft_req.payload).unwrap()),
-//             2 => RaftRequest::InstallSnapshot(serde_json::from_slice(&raft_req.payload).unwrap()),
-//             3 => RaftRequest::Vote(serde_json::from_slice(&raft_req.payload).unwrap()),
-//             i => panic!("unexpected payload type: {}", i),
-//         }
-//     }
-//
-//     pub fn to_proto(self, message_id: Uuid) -> ProtoRaftRequest {
-//         let request_type = self.request_type();
-//         let payload = self.write_to_bytes();
-//         let message_id = message_id.to_string();
-//
-//         ProtoRaftRequest {
-//             request_type,
-//             payload,
-//             message_id,
-//             ..Default::default()
-//         }
-//     }
-// }
-//
-// #[async_trait]
-// impl RaftNetwork<ClientRequest> for RaftRouter {
-//     async fn append_entries(
-//         &self,
-//         target: u64,
-//         rpc: AppendEntriesRequest<ClientRequest>,
-//     ) -> Result<AppendEntriesResponse> {
-//         let message_id = Uuid::new_v4();
-//         let event = SessionEvent::Raft(RaftRequest::AppendEntries(rpc).to_proto(message_id));
-//
-//         info!("append_entries rpc, id={}", &message_id);
-//         let res = self.system.node_rpc(message_id, event, target).await?;
-//         info!("append_entries rpc, id={} is complete", &message_id);
-//         Ok(res)
-//     }
-//
-//     async fn install_snapshot(
-//         &self,
-//         target: u64,
-//         rpc: InstallSnapshotRequest,
-//     ) -> Result<InstallSnapshotResponse> {
-//         let message_id = Uuid::new_v4();
-//         let event = SessionEvent::Raft(RaftRequest::InstallSnapshot(rpc).to_proto(message_id));
-//
-//         info!("install_snapshot rpc, id={}", &message_id);
-//         let res = self.system.node_rpc(message_id, event, target).await?;
-//         info!("install_snapshot rpc, id={} is complete", &message_id);
-//         Ok(res)
-//     }
-//
-//     async fn vote(&self, target: u64, rpc: VoteRequest) -> Result<VoteResponse> {
-//         let message_id = Uuid::new_v4();
-//         let event = SessionEvent::Raft(RaftRequest::Vote(rpc).to_proto(message_id));
-//
-//         info!("vote rpc, id={}", &message_id);
-//         let res = self.system.node_rpc(message_id, event, target).await?;
-//         info!("vote rpc, id={} is complete", &message_id);
-//         Ok(res)
-//     }
-// }
-//
-// impl StreamData for InstallSnapshotResponse {
-//     fn read_from_bytes(data: Vec<u8>) -> Option<Self> {
-//         serde_json::from_slice(&data).map_or(None, |s| s)
-//     }
-//
-//     fn write_to_bytes(&self) -> Option<Vec<u8>> {
-//         serde_json::to_vec(&self).map_or(None, |s| Some(s))
-//     }
-// }
-//
-// impl StreamData for AppendEntriesResponse {
-//     fn read_from_bytes(data: Vec<u8>) -> Option<Self> {
-//         serde_json::from_slice(&data).map_or(None, |s| s)
-//     }
-//
-//     fn write_to_bytes(&self) -> Option<Vec<u8>> {
-//         serde_json::to_vec(&self).map_or(None, |s| Some(s))
-//     }
-// }
-//
-// impl StreamData for VoteResponse {
-//     fn read_from_bytes(data: Vec<u8>) -> Option<Self> {
-//         serde_json::from_slice(&data).map_or(None, |s| s)
-//     }
-//
-//     fn write_to_bytes(&self) -> Option<Vec<u8>> {
-//         serde_json::to_vec(&self).map_or(None, |s| Some(s))
-//     }
-// }
+use crate::actor::context::ActorContext;
+use crate::actor::message::{Handler, Message};
+use crate::actor::scheduler::timer::Timer;
+use crate::actor::{Actor, ActorId, ActorRef, IntoActor, IntoActorId, LocalActorRef};
+use crate::remote::heartbeat::{LeaderCleared, LeaderElected};
+use crate::remote::raft::message::{
+    AppendEntries, AppendEntriesResponse, GetStatus, InstallSnapshot, Propose, RaftTick,
+    RequestVote, VoteResponse,
+};
+use crate::remote::raft::state_machine::{NoopStateMachine, StateMachine, StateMachineFactory};
+use crate::remote::raft::storage::{RaftStorage, RecoveredState};
+use crate::remote::system::builder::RemoteSystemConfigBuilder;
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use crate::remote::RemoteActorRef;
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+
+pub mod message;
+pub mod proto;
+pub mod state_machine;
+mod storage;
+
+pub fn raft_actor_id(node_id: NodeId) -> ActorId {
+    format!("Raft-{}", node_id).into_actor_id()
+}
+
+/// Raft consensus between a fixed set of voting nodes. Log entries are replicated to every voter
+/// and applied to the state machine once a majority have stored them.
+///
+/// When the actor system has `Persistence` configured, the current term, vote and log are written
+/// to its journal storage before they're acted on, so a voter that restarts rejoins with the same
+/// node id and the state it had before. Without persistence the state is only kept in memory, and
+/// a voter that restarts has forgotten its vote and log.
+#[derive(Clone)]
+pub struct RaftConfig {
+    /// The node ids of every voting member, including this node.
+    pub voters: Vec<NodeId>,
+
+    /// Followers that haven't heard from a leader within a random timeout between
+    /// `election_timeout_min` and `election_timeout_max` start an election.
+    pub election_timeout_min: Duration,
+
+    pub election_timeout_max: Duration,
+
+    /// How often the leader sends entries (or an empty heartbeat) to each follower.
+    pub heartbeat_interval: Duration,
+
+    /// The maximum number of entries sent to a follower in a single `AppendEntries`.
+    pub max_entries_per_append: usize,
+
+    /// Once this many entries have been applied since the last snapshot, the state machine is
+    /// snapshotted and the log entries it covers are discarded. Zero disables snapshots.
+    pub snapshot_threshold: u64,
+
+    /// When enabled, the Raft leader is used as the cluster leader rather than `Heartbeat`
+    /// electing the oldest healthy node.
+    pub cluster_leader: bool,
+
+    /// Creates the state machine committed entries are applied to.
+    pub state_machine: StateMachineFactory,
+}
+
+impl RaftConfig {
+    pub fn new(voters: Vec<NodeId>) -> RaftConfig {
+        RaftConfig {
+            voters,
+            election_timeout_min: Duration::from_millis(1500),
+            election_timeout_max: Duration::from_millis(3000),
+            heartbeat_interval: Duration::from_millis(500),
+            max_entries_per_append: 64,
+            snapshot_threshold: 1024,
+            cluster_leader: false,
+            state_machine: Arc::new(|| Box::new(NoopStateMachine)),
+        }
+    }
+
+    pub fn with_state_machine<F: 'static + Fn() -> Box<dyn StateMachine> + Send + Sync>(
+        mut self,
+        state_machine: F,
+    ) -> Self {
+        self.state_machine = Arc::new(state_machine);
+        self
+    }
+
+    pub fn with_cluster_leader(mut self, cluster_leader: bool) -> Self {
+        self.cluster_leader = cluster_leader;
+        self
+    }
+
+    pub fn with_snapshot_threshold(mut self, snapshot_threshold: u64) -> Self {
+        self.snapshot_threshold = snapshot_threshold;
+        self
+    }
+}
+
+pub(crate) fn raft(builder: &mut RemoteSystemConfigBuilder) -> &mut RemoteSystemConfigBuilder {
+    builder
+        .with_control_handler::<Raft, RequestVote>("Raft.RequestVote")
+        .with_control_handler::<Raft, VoteResponse>("Raft.VoteResponse")
+        .with_control_handler::<Raft, AppendEntries>("Raft.AppendEntries")
+        .with_control_handler::<Raft, AppendEntriesResponse>("Raft.AppendEntriesResponse")
+        .with_control_handler::<Raft, InstallSnapshot>("Raft.InstallSnapshot")
+}
+
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub index: u64,
+    pub term: u64,
+
+    /// `None` for the no-op entry a leader appends when it's elected.
+    pub command: Option<Vec<u8>>,
+}
+
+/// The state machine as of `last_included_index`, replacing the log entries up to and including it.
+#[derive(Debug, Clone, Default)]
+pub struct RaftSnapshot {
+    pub last_included_index: u64,
+    pub last_included_term: u64,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RaftRole {
+    Follower,
+    Candidate,
+    Leader,
+}
+
+#[derive(Debug, Clone)]
+pub struct RaftStatus {
+    pub node_id: NodeId,
+    pub role: RaftRole,
+    pub term: u64,
+    pub leader_id: Option<NodeId>,
+    pub last_log_index: u64,
+    pub commit_index: u64,
+    pub last_applied: u64,
+    pub snapshot_index: u64,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum RaftError {
+    /// Commands can only be proposed to the leader, the current leader is included if known.
+    NotLeader(Option<NodeId>),
+
+    /// The node stopped being the leader before the command was committed, the command may or may
+    /// not have been applied.
+    LeadershipLost,
+
+    /// The leader couldn't write the command to its log.
+    PersistFailed,
+
+    /// Raft isn't running on this node.
+    Unavailable,
+}
+
+pub struct Raft {
+    system: RemoteActorSystem,
+    config: RaftConfig,
+    state_machine: Box<dyn StateMachine>,
+    node_id: NodeId,
+    role: RaftRole,
+    current_term: u64,
+    voted_for: Option<NodeId>,
+    persisted_term: u64,
+    persisted_vote: Option<NodeId>,
+    storage: Option<RaftStorage>,
+    leader_id: Option<NodeId>,
+    snapshot: RaftSnapshot,
+    log: Vec<LogEntry>,
+    commit_index: u64,
+    last_applied: u64,
+    votes: HashSet<NodeId>,
+    next_index: HashMap<NodeId, u64>,
+    match_index: HashMap<NodeId, u64>,
+    last_contact: HashMap<NodeId, Instant>,
+    pending_proposals: HashMap<u64, PendingProposal>,
+    election_deadline: Instant,
+    last_broadcast: Instant,
+    tick_timer: Option<Timer>,
+}
+
+struct PendingProposal {
+    term: u64,
+    result_tx: oneshot::Sender<Result<Vec<u8>, RaftError>>,
+}
+
+impl Raft {
+    /// Starts Raft on this node, after recovering its persisted state. Returns `None` if the
+    /// state couldn't be recovered, since the node could otherwise vote twice in the same term.
+    pub async fn start(
+        system: RemoteActorSystem,
+        config: RaftConfig,
+    ) -> Option<LocalActorRef<Raft>> {
+        let actor_system = system.actor_system().clone();
+        let node_id = system.node_id();
+        let mut state_machine = (config.state_machine)();
+
+        let mut storage = RaftStorage::new(&system);
+        let recovered = match storage.as_mut() {
+            Some(storage) => match storage.recover().await {
+                Ok(recovered) => recovered,
+                Err(e) => {
+                    error!(
+                        "[node={}] failed to recover raft state, error: {}",
+                        node_id, e
+                    );
+                    return None;
+                }
+            },
+            None => {
+                warn!(
+                    "[node={}] no persistence configured, raft state won't survive a restart",
+                    node_id
+                );
+                RecoveredState::default()
+            }
+        };
+
+        let snapshot_index = recovered.snapshot.last_included_index;
+        if snapshot_index > 0 {
+            state_machine.restore(&recovered.snapshot.data);
+        }
+
+        let raft = Raft {
+            system,
+            config,
+            state_machine,
+            node_id,
+            role: RaftRole::Follower,
+            current_term: recovered.term,
+            voted_for: recovered.voted_for,
+            persisted_term: recovered.term,
+            persisted_vote: recovered.voted_for,
+            storage,
+            leader_id: None,
+            snapshot: recovered.snapshot,
+            log: recovered.log,
+            commit_index: snapshot_index,
+            last_applied: snapshot_index,
+            votes: HashSet::new(),
+            next_index: HashMap::new(),
+            match_index: HashMap::new(),
+            last_contact: HashMap::new(),
+            pending_proposals: HashMap::new(),
+            election_deadline: Instant::now(),
+            last_broadcast: Instant::now(),
+            tick_timer: None,
+        }
+        .into_actor(Some(raft_actor_id(node_id)), &actor_system)
+        .await
+        .expect("create Raft actor");
+
+        Some(raft)
+    }
+
+    fn peers(&self) -> Vec<NodeId> {
+        self.config
+            .voters
+            .iter()
+            .copied()
+            .filter(|n| *n != self.node_id)
+            .collect()
+    }
+
+    fn quorum(&self) -> usize {
+        self.config.voters.len() / 2 + 1
+    }
+
+    fn snapshot_index(&self) -> u64 {
+        self.snapshot.last_included_index
+    }
+
+    fn last_log_index(&self) -> u64 {
+        self.log
+            .last()
+            .map_or(self.snapshot.last_included_index, |e| e.index)
+    }
+
+    fn last_log_term(&self) -> u64 {
+        self.log
+            .last()
+            .map_or(self.snapshot.last_included_term, |e| e.term)
+    }
+
+    /// The position of the entry at `index` in `log`, `None` if it's covered by the snapshot.
+    fn log_offset(&self, index: u64) -> Option<usize> {
+        index
+            .checked_sub(self.snapshot_index() + 1)
+            .map(|offset| offset as usize)
+    }
+
+    fn term_at(&self, index: u64) -> Option<u64> {
+        if index == self.snapshot_index() {
+            Some(self.snapshot.last_included_term)
+        } else {
+            self.log.get(self.log_offset(index)?).map(|e| e.term)
+        }
+    }
+
+    /// Persists the current term and vote if they've changed. Must be called before sending
+    /// anything that depends on them, returns false if they couldn't be written.
+    async fn persist_hard_state(&mut self) -> bool {
+        if self.current_term == self.persisted_term && self.voted_for == self.persisted_vote {
+            return true;
+        }
+
+        if let Some(storage) = self.storage.as_mut() {
+            if let Err(e) = storage
+                .save_hard_state(self.current_term, self.voted_for)
+                .await
+            {
+                error!(
+                    "[node={}] failed to persist raft term and vote, error: {}",
+                    self.node_id, e
+                );
+                return false;
+            }
+        }
+
+        self.persisted_term = self.current_term;
+        self.persisted_vote = self.voted_for;
+        true
+    }
+
+    async fn persist_entries(&mut self, entries: &[LogEntry]) -> bool {
+        let storage = match self.storage.as_mut() {
+            Some(storage) => storage,
+            None => return true,
+        };
+
+        match storage.append(entries).await {
+            Ok(()) => true,
+            Err(e) => {
+                error!(
+                    "[node={}] failed to persist raft log entries, error: {}",
+                    self.node_id, e
+                );
+                false
+            }
+        }
+    }
+
+    fn reset_election_deadline(&mut self) {
+        let min = self.config.election_timeout_min;
+        let max = self.config.election_timeout_max.max(min);
+        let timeout = rand::thread_rng().gen_range(min..=max);
+
+        self.election_deadline = Instant::now() + timeout;
+    }
+
+    async fn send<M: 'static + Message<Result = ()> + Sync + Send>(
+        &self,
+        node_id: NodeId,
+        message: M,
+    ) where
+        Raft: Handler<M>,
+    {
+        let actor_ref: ActorRef<Raft> =
+            RemoteActorRef::new(raft_actor_id(node_id), node_id, self.system.clone()).into();
+
+        if let Err(e) = actor_ref.notify(message).await {
+            trace!(
+                "unable to send message to node_id={}, error: {}",
+                node_id,
+                e
+            );
+        }
+    }
+
+    fn set_leader(&mut self, leader_id: Option<NodeId>) {
+        if self.leader_id == leader_id {
+            return;
+        }
+
+        info!(
+            "[node={}] raft leader changed to {:?} (term={})",
+            self.node_id, leader_id, self.current_term
+        );
+
+        self.leader_id = leader_id;
+        if self.config.cluster_leader {
+            let _ = match leader_id {
+                Some(leader_id) => self.system.heartbeat().notify(LeaderElected(leader_id)),
+                None => self.system.heartbeat().notify(LeaderCleared),
+            };
+        }
+    }
+
+    fn become_follower(&mut self, term: u64, leader_id: Option<NodeId>) {
+        if term > self.current_term {
+            self.current_term = term;
+            self.voted_for = None;
+        }
+
+        if self.role == RaftRole::Leader {
+            info!(
+                "[node={}] stepping down as raft leader (term={})",
+                self.node_id, self.current_term
+            );
+
+            for (_, proposal) in self.pending_proposals.drain() {
+                let _ = proposal.result_tx.send(Err(RaftError::LeadershipLost));
+            }
+        }
+
+        self.role = RaftRole::Follower;
+        self.set_leader(leader_id);
+    }
+
+    async fn start_election(&mut self) {
+        self.current_term += 1;
+        self.role = RaftRole::Candidate;
+        self.voted_for = Some(self.node_id);
+        self.votes = HashSet::from([self.node_id]);
+        self.set_leader(None);
+        self.reset_election_deadline();
+
+        debug!(
+            "[node={}] starting raft election (term={})",
+            self.node_id, self.current_term
+        );
+
+        if !self.persist_hard_state().await {
+            return;
+        }
+
+        if self.votes.len() >= self.quorum() {
+            self.become_leader().await;
+            return;
+        }
+
+        for peer in self.peers() {
+            let request_vote = RequestVote {
+                term: self.current_term,
+                candidate_id: self.node_id,
+                last_log_index: self.last_log_index(),
+                last_log_term: self.last_log_term(),
+            };
+
+            self.send(peer, request_vote).await;
+        }
+    }
+
+    async fn become_leader(&mut self) {
+        self.role = RaftRole::Leader;
+        self.set_leader(Some(self.node_id));
+
+        let next_index = self.last_log_index() + 1;
+        let now = Instant::now();
+        for peer in self.peers() {
+            self.next_index.insert(peer, next_index);
+            self.match_index.insert(peer, 0);
+            self.last_contact.insert(peer, now);
+        }
+
+        // Entries from previous terms can only be committed once an entry from the current term
+        // has been, so the new leader appends a no-op straight away.
+        if self.append(None).await.is_none() {
+            self.become_follower(self.current_term, None);
+            self.reset_election_deadline();
+            return;
+        }
+
+        self.broadcast_append().await;
+        self.advance_commit_index().await;
+    }
+
+    /// Appends an entry to the leader's log, once it's been persisted.
+    async fn append(&mut self, command: Option<Vec<u8>>) -> Option<u64> {
+        let entry = LogEntry {
+            index: self.last_log_index() + 1,
+            term: self.current_term,
+            command,
+        };
+
+        if !self.persist_entries(std::slice::from_ref(&entry)).await {
+            return None;
+        }
+
+        let index = entry.index;
+        self.log.push(entry);
+        Some(index)
+    }
+
+    async fn broadcast_append(&mut self) {
+        self.last_broadcast = Instant::now();
+        for peer in self.peers() {
+            self.send_append(peer).await;
+        }
+    }
+
+    async fn send_append(&self, peer: NodeId) {
+        let next_index = self
+            .next_index
+            .get(&peer)
+            .copied()
+            .unwrap_or(1)
+            .clamp(1, self.last_log_index() + 1);
+
+        // Followers that are behind the snapshot are sent it in place of the entries it replaced
+        if next_index <= self.snapshot_index() {
+            let install_snapshot = InstallSnapshot {
+                term: self.current_term,
+                leader_id: self.node_id,
+                last_included_index: self.snapshot.last_included_index,
+                last_included_term: self.snapshot.last_included_term,
+                data: self.snapshot.data.clone(),
+            };
+
+            self.send(peer, install_snapshot).await;
+            return;
+        }
+
+        let prev_log_index = next_index - 1;
+        let entries = self
+            .log
+            .iter()
+            .skip((prev_log_index - self.snapshot_index()) as usize)
+            .take(self.config.max_entries_per_append)
+            .cloned()
+            .collect();
+
+        let append_entries = AppendEntries {
+            term: self.current_term,
+            leader_id: self.node_id,
+            prev_log_index,
+            prev_log_term: self.term_at(prev_log_index).unwrap_or(0),
+            entries,
+            leader_commit: self.commit_index,
+        };
+
+        self.send(peer, append_entries).await;
+    }
+
+    async fn advance_commit_index(&mut self) {
+        let quorum = self.quorum();
+        for index in (self.commit_index + 1..=self.last_log_index()).rev() {
+            if self.term_at(index) != Some(self.current_term) {
+                break;
+            }
+
+            let replicas = 1 + self.match_index.values().filter(|m| **m >= index).count();
+            if replicas >= quorum {
+                self.commit_index = index;
+                break;
+            }
+        }
+
+        self.apply_committed().await;
+    }
+
+    async fn apply_committed(&mut self) {
+        while self.last_applied < self.commit_index {
+            self.last_applied += 1;
+
+            let entry = &self.log[(self.last_applied - self.snapshot_index() - 1) as usize];
+            let result = match &entry.command {
+                Some(command) => self.state_machine.apply(entry.index, command),
+                None => vec![],
+            };
+
+            if let Some(proposal) = self.pending_proposals.remove(&entry.index) {
+                let result = if proposal.term == entry.term {
+                    Ok(result)
+                } else {
+                    Err(RaftError::LeadershipLost)
+                };
+
+                let _ = proposal.result_tx.send(result);
+            }
+        }
+
+        self.compact().await;
+    }
+
+    /// Snapshots the state machine once `snapshot_threshold` entries have been applied since the
+    /// last snapshot, discarding the log entries the snapshot replaces.
+    async fn compact(&mut self) {
+        let threshold = self.config.snapshot_threshold;
+        if threshold == 0 || self.last_applied - self.snapshot_index() < threshold {
+            return;
+        }
+
+        let offset = (self.last_applied - self.snapshot_index()) as usize;
+        let snapshot = RaftSnapshot {
+            last_included_index: self.last_applied,
+            last_included_term: self.term_at(self.last_applied).unwrap_or(0),
+            data: self.state_machine.snapshot(),
+        };
+
+        if let Some(storage) = self.storage.as_mut() {
+            if let Err(e) = storage.save_snapshot(&snapshot, &self.log[offset..]).await {
+                error!(
+                    "[node={}] failed to persist raft snapshot, error: {}",
+                    self.node_id, e
+                );
+                return;
+            }
+        }
+
+        debug!(
+            "[node={}] raft snapshot taken (last_included_index={}, compacted_entries={})",
+            self.node_id, snapshot.last_included_index, offset
+        );
+
+        self.log.drain(..offset);
+        self.snapshot = snapshot;
+    }
+
+    /// A leader that can't reach a majority of voters steps down, rather than continuing to
+    /// act as leader on the minority side of a partition.
+    fn has_quorum_contact(&self) -> bool {
+        let timeout = self.config.election_timeout_max;
+        let reachable = self
+            .last_contact
+            .values()
+            .filter(|c| c.elapsed() < timeout)
+            .count();
+
+        reachable + 1 >= self.quorum()
+    }
+
+    fn status(&self) -> RaftStatus {
+        RaftStatus {
+            node_id: self.node_id,
+            role: self.role,
+            term: self.current_term,
+            leader_id: self.leader_id,
+            last_log_index: self.last_log_index(),
+            commit_index: self.commit_index,
+            last_applied: self.last_applied,
+            snapshot_index: self.snapshot_index(),
+        }
+    }
+}
+
+#[async_trait]
+impl Actor for Raft {
+    async fn started(&mut self, ctx: &mut ActorContext) {
+        debug!(
+            "[node={}] starting raft (voters={:?})",
+            self.node_id, &self.config.voters
+        );
+
+        self.reset_election_deadline();
+
+        let tick_interval = self
+            .config
+            .heartbeat_interval
+            .min(self.config.election_timeout_min)
+            / 2;
+
+        self.tick_timer = Some(Timer::start(self.actor_ref(ctx), tick_interval, RaftTick));
+    }
+
+    async fn stopped(&mut self, _ctx: &mut ActorContext) {
+        if let Some(tick_timer) = self.tick_timer.take() {
+            tick_timer.stop();
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<RaftTick> for Raft {
+    async fn handle(&mut self, _message: RaftTick, _ctx: &mut ActorContext) {
+        match self.role {
+            RaftRole::Leader => {
+                if !self.has_quorum_contact() {
+                    warn!(
+                        "[node={}] raft leader lost contact with a majority of voters",
+                        self.node_id
+                    );
+
+                    self.become_follower(self.current_term, None);
+                    self.reset_election_deadline();
+                } else if self.last_broadcast.elapsed() >= self.config.heartbeat_interval {
+                    self.broadcast_append().await;
+                }
+            }
+
+            RaftRole::Follower | RaftRole::Candidate => {
+                if Instant::now() >= self.election_deadline {
+                    self.start_election().await;
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<RequestVote> for Raft {
+    async fn handle(&mut self, message: RequestVote, _ctx: &mut ActorContext) {
+        if message.term > self.current_term {
+            self.become_follower(message.term, None);
+        }
+
+        let log_up_to_date = (message.last_log_term, message.last_log_index)
+            >= (self.last_log_term(), self.last_log_index());
+
+        let vote_granted = message.term == self.current_term
+            && log_up_to_date
+            && self.voted_for.map_or(true, |v| v == message.candidate_id);
+
+        if vote_granted {
+            self.voted_for = Some(message.candidate_id);
+            self.reset_election_deadline();
+        }
+
+        if !self.persist_hard_state().await {
+            return;
+        }
+
+        let response = VoteResponse {
+            term: self.current_term,
+            voter_id: self.node_id,
+            vote_granted,
+        };
+
+        self.send(message.candidate_id, response).await;
+    }
+}
+
+#[async_trait]
+impl Handler<VoteResponse> for Raft {
+    async fn handle(&mut self, message: VoteResponse, _ctx: &mut ActorContext) {
+        if message.term > self.current_term {
+            self.become_follower(message.term, None);
+            return;
+        }
+
+        if self.role != RaftRole::Candidate
+            || message.term != self.current_term
+            || !message.vote_granted
+        {
+            return;
+        }
+
+        self.votes.insert(message.voter_id);
+        if self.votes.len() >= self.quorum() {
+            info!(
+                "[node={}] elected raft leader (term={}, votes={})",
+                self.node_id,
+                self.current_term,
+                self.votes.len()
+            );
+
+            self.become_leader().await;
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<AppendEntries> for Raft {
+    async fn handle(&mut self, message: AppendEntries, _ctx: &mut ActorContext) {
+        if message.term < self.current_term {
+            if !self.persist_hard_state().await {
+                return;
+            }
+
+            let response = AppendEntriesResponse {
+                term: self.current_term,
+                follower_id: self.node_id,
+                success: false,
+                match_index: 0,
+            };
+
+            self.send(message.leader_id, response).await;
+            return;
+        }
+
+        self.become_follower(message.term, Some(message.leader_id));
+        self.reset_election_deadline();
+
+        if !self.persist_hard_state().await {
+            return;
+        }
+
+        let match_index =
+            (message.prev_log_index + message.entries.len() as u64).max(self.snapshot_index());
+
+        let mut entries = message.entries;
+        if message.prev_log_index < self.snapshot_index() {
+            // Entries covered by the snapshot are committed, so they already match the leader's
+            let snapshot_index = self.snapshot_index();
+            entries.retain(|e| e.index > snapshot_index);
+        } else if self.term_at(message.prev_log_index) != Some(message.prev_log_term) {
+            let response = AppendEntriesResponse {
+                term: self.current_term,
+                follower_id: self.node_id,
+                success: false,
+                match_index: self
+                    .last_log_index()
+                    .min(message.prev_log_index.saturating_sub(1)),
+            };
+
+            self.send(message.leader_id, response).await;
+            return;
+        }
+
+        // Conflicting entries are always uncommitted, everything from the first entry the log
+        // doesn't already have is replaced by the leader's entries, once they've been persisted.
+        let new_entries: Vec<LogEntry> = entries
+            .into_iter()
+            .skip_while(|e| self.term_at(e.index) == Some(e.term))
+            .collect();
+
+        if let Some(offset) = new_entries.first().and_then(|e| self.log_offset(e.index)) {
+            if !self.persist_entries(&new_entries).await {
+                return;
+            }
+
+            self.log.truncate(offset);
+            self.log.extend(new_entries);
+        }
+
+        if message.leader_commit > self.commit_index {
+            self.commit_index = self
+                .commit_index
+                .max(message.leader_commit.min(match_index));
+            self.apply_committed().await;
+        }
+
+        let response = AppendEntriesResponse {
+            term: self.current_term,
+            follower_id: self.node_id,
+            success: true,
+            match_index,
+        };
+
+        self.send(message.leader_id, response).await;
+    }
+}
+
+#[async_trait]
+impl Handler<AppendEntriesResponse> for Raft {
+    async fn handle(&mut self, message: AppendEntriesResponse, _ctx: &mut ActorContext) {
+        if message.term > self.current_term {
+            self.become_follower(message.term, None);
+            self.reset_election_deadline();
+            return;
+        }
+
+        if self.role != RaftRole::Leader || message.term != self.current_term {
+            return;
+        }
+
+        let follower_id = message.follower_id;
+        self.last_contact.insert(follower_id, Instant::now());
+
+        if message.success {
+            let match_index = self.match_index.entry(follower_id).or_insert(0);
+            *match_index = (*match_index).max(message.match_index);
+
+            let next_index = *match_index + 1;
+            self.next_index.insert(follower_id, next_index);
+            self.advance_commit_index().await;
+
+            if next_index <= self.last_log_index() {
+                self.send_append(follower_id).await;
+            }
+        } else {
+            let next_index = self.next_index.get(&follower_id).copied().unwrap_or(1);
+            let next_index = next_index
+                .saturating_sub(1)
+                .min(message.match_index + 1)
+                .max(1);
+
+            self.next_index.insert(follower_id, next_index);
+            self.send_append(follower_id).await;
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<Propose> for Raft {
+    async fn handle(&mut self, message: Propose, _ctx: &mut ActorContext) {
+        if self.role != RaftRole::Leader {
+            let _ = message
+                .result_tx
+                .send(Err(RaftError::NotLeader(self.leader_id)));
+            return;
+        }
+
+        let index = match self.append(Some(message.command)).await {
+            Some(index) => index,
+            None => {
+                let _ = message.result_tx.send(Err(RaftError::PersistFailed));
+                return;
+            }
+        };
+
+        self.pending_proposals.insert(
+            index,
+            PendingProposal {
+                term: self.current_term,
+                result_tx: message.result_tx,
+            },
+        );
+
+        self.broadcast_append().await;
+        self.advance_commit_index().await;
+    }
+}
+
+#[async_trait]
+impl Handler<InstallSnapshot> for Raft {
+    async fn handle(&mut self, message: InstallSnapshot, _ctx: &mut ActorContext) {
+        if message.term < self.current_term {
+            if !self.persist_hard_state().await {
+                return;
+            }
+
+            let response = AppendEntriesResponse {
+                term: self.current_term,
+                follower_id: self.node_id,
+                success: false,
+                match_index: 0,
+            };
+
+            self.send(message.leader_id, response).await;
+            return;
+        }
+
+        self.become_follower(message.term, Some(message.leader_id));
+        self.reset_election_deadline();
+
+        if !self.persist_hard_state().await {
+            return;
+        }
+
+        let last_included_index = message.last_included_index;
+        if last_included_index > self.commit_index {
+            let snapshot = RaftSnapshot {
+                last_included_index,
+                last_included_term: message.last_included_term,
+                data: message.data,
+            };
+
+            // Entries following the snapshot are kept if the log agrees with its last entry
+            let log = match self.log_offset(last_included_index) {
+                Some(offset)
+                    if self.term_at(last_included_index) == Some(message.last_included_term) =>
+                {
+                    self.log[offset + 1..].to_vec()
+                }
+                _ => vec![],
+            };
+
+            if let Some(storage) = self.storage.as_mut() {
+                if let Err(e) = storage.save_snapshot(&snapshot, &log).await {
+                    error!(
+                        "[node={}] failed to persist installed raft snapshot, error: {}",
+                        self.node_id, e
+                    );
+                    return;
+                }
+            }
+
+            info!(
+                "[node={}] installed raft snapshot from leader={} (last_included_index={})",
+                self.node_id, message.leader_id, last_included_index
+            );
+
+            self.state_machine.restore(&snapshot.data);
+            self.snapshot = snapshot;
+            self.log = log;
+            self.commit_index = last_included_index;
+            self.last_applied = last_included_index;
+        }
+
+        let response = AppendEntriesResponse {
+            term: self.current_term,
+            follower_id: self.node_id,
+            success: true,
+            match_index: last_included_index,
+        };
+
+        self.send(message.leader_id, response).await;
+    }
+}
+
+#[async_trait]
+impl Handler<GetStatus> for Raft {
+    async fn handle(&mut self, _message: GetStatus, _ctx: &mut ActorContext) -> RaftStatus {
+        self.status()
+    }
+}
+
+impl Display for RaftError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RaftError::NotLeader(Some(leader_id)) => {
+                write!(f, "not the leader, current leader is node_id={}", leader_id)
+            }
+            RaftError::NotLeader(None) => write!(f, "not the leader, no leader is known"),
+            RaftError::LeadershipLost => {
+                write!(f, "leadership was lost before the command was committed")
+            }
+            RaftError::PersistFailed => write!(f, "failed to persist the command to the raft log"),
+            RaftError::Unavailable => write!(f, "raft is not running on this node"),
+        }
+    }
+}
+
+impl std::error::Error for RaftError {}
@@ -0,0 +1,1828 @@
+// This file is generated by rust-protobuf 3.2.0. Do not edit
+// .proto file is parsed by protoc 3.21.9
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `raft.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_2_0;
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.raft.LogEntry)
+pub struct LogEntry {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.raft.LogEntry.index)
+    pub index: u64,
+    // @@protoc_insertion_point(field:coerce.raft.LogEntry.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:coerce.raft.LogEntry.command)
+    pub command: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:coerce.raft.LogEntry.noop)
+    pub noop: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.raft.LogEntry.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LogEntry {
+    fn default() -> &'a LogEntry {
+        <LogEntry as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LogEntry {
+    pub fn new() -> LogEntry {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "index",
+            |m: &LogEntry| { &m.index },
+            |m: &mut LogEntry| { &mut m.index },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "term",
+            |m: &LogEntry| { &m.term },
+            |m: &mut LogEntry| { &mut m.term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "command",
+            |m: &LogEntry| { &m.command },
+            |m: &mut LogEntry| { &mut m.command },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "noop",
+            |m: &LogEntry| { &m.noop },
+            |m: &mut LogEntry| { &mut m.noop },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LogEntry>(
+            "LogEntry",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LogEntry {
+    const NAME: &'static str = "LogEntry";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.index = is.read_uint64()?;
+                },
+                16 => {
+                    self.term = is.read_uint64()?;
+                },
+                26 => {
+                    self.command = is.read_bytes()?;
+                },
+                32 => {
+                    self.noop = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.index != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.index);
+        }
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.term);
+        }
+        if !self.command.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.command);
+        }
+        if self.noop != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.index != 0 {
+            os.write_uint64(1, self.index)?;
+        }
+        if self.term != 0 {
+            os.write_uint64(2, self.term)?;
+        }
+        if !self.command.is_empty() {
+            os.write_bytes(3, &self.command)?;
+        }
+        if self.noop != false {
+            os.write_bool(4, self.noop)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LogEntry {
+        LogEntry::new()
+    }
+
+    fn clear(&mut self) {
+        self.index = 0;
+        self.term = 0;
+        self.command.clear();
+        self.noop = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LogEntry {
+        static instance: LogEntry = LogEntry {
+            index: 0,
+            term: 0,
+            command: ::std::vec::Vec::new(),
+            noop: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for LogEntry {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LogEntry").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LogEntry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LogEntry {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.raft.RequestVote)
+pub struct RequestVote {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.raft.RequestVote.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:coerce.raft.RequestVote.candidate_id)
+    pub candidate_id: u64,
+    // @@protoc_insertion_point(field:coerce.raft.RequestVote.last_log_index)
+    pub last_log_index: u64,
+    // @@protoc_insertion_point(field:coerce.raft.RequestVote.last_log_term)
+    pub last_log_term: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.raft.RequestVote.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RequestVote {
+    fn default() -> &'a RequestVote {
+        <RequestVote as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestVote {
+    pub fn new() -> RequestVote {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "term",
+            |m: &RequestVote| { &m.term },
+            |m: &mut RequestVote| { &mut m.term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "candidate_id",
+            |m: &RequestVote| { &m.candidate_id },
+            |m: &mut RequestVote| { &mut m.candidate_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "last_log_index",
+            |m: &RequestVote| { &m.last_log_index },
+            |m: &mut RequestVote| { &mut m.last_log_index },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "last_log_term",
+            |m: &RequestVote| { &m.last_log_term },
+            |m: &mut RequestVote| { &mut m.last_log_term },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RequestVote>(
+            "RequestVote",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RequestVote {
+    const NAME: &'static str = "RequestVote";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.term = is.read_uint64()?;
+                },
+                16 => {
+                    self.candidate_id = is.read_uint64()?;
+                },
+                24 => {
+                    self.last_log_index = is.read_uint64()?;
+                },
+                32 => {
+                    self.last_log_term = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.term);
+        }
+        if self.candidate_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.candidate_id);
+        }
+        if self.last_log_index != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.last_log_index);
+        }
+        if self.last_log_term != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.last_log_term);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.term != 0 {
+            os.write_uint64(1, self.term)?;
+        }
+        if self.candidate_id != 0 {
+            os.write_uint64(2, self.candidate_id)?;
+        }
+        if self.last_log_index != 0 {
+            os.write_uint64(3, self.last_log_index)?;
+        }
+        if self.last_log_term != 0 {
+            os.write_uint64(4, self.last_log_term)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RequestVote {
+        RequestVote::new()
+    }
+
+    fn clear(&mut self) {
+        self.term = 0;
+        self.candidate_id = 0;
+        self.last_log_index = 0;
+        self.last_log_term = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RequestVote {
+        static instance: RequestVote = RequestVote {
+            term: 0,
+            candidate_id: 0,
+            last_log_index: 0,
+            last_log_term: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RequestVote {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RequestVote").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RequestVote {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestVote {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.raft.VoteResponse)
+pub struct VoteResponse {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.raft.VoteResponse.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:coerce.raft.VoteResponse.voter_id)
+    pub voter_id: u64,
+    // @@protoc_insertion_point(field:coerce.raft.VoteResponse.vote_granted)
+    pub vote_granted: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.raft.VoteResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a VoteResponse {
+    fn default() -> &'a VoteResponse {
+        <VoteResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl VoteResponse {
+    pub fn new() -> VoteResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "term",
+            |m: &VoteResponse| { &m.term },
+            |m: &mut VoteResponse| { &mut m.term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "voter_id",
+            |m: &VoteResponse| { &m.voter_id },
+            |m: &mut VoteResponse| { &mut m.voter_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "vote_granted",
+            |m: &VoteResponse| { &m.vote_granted },
+            |m: &mut VoteResponse| { &mut m.vote_granted },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<VoteResponse>(
+            "VoteResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for VoteResponse {
+    const NAME: &'static str = "VoteResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.term = is.read_uint64()?;
+                },
+                16 => {
+                    self.voter_id = is.read_uint64()?;
+                },
+                24 => {
+                    self.vote_granted = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.term);
+        }
+        if self.voter_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.voter_id);
+        }
+        if self.vote_granted != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.term != 0 {
+            os.write_uint64(1, self.term)?;
+        }
+        if self.voter_id != 0 {
+            os.write_uint64(2, self.voter_id)?;
+        }
+        if self.vote_granted != false {
+            os.write_bool(3, self.vote_granted)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> VoteResponse {
+        VoteResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.term = 0;
+        self.voter_id = 0;
+        self.vote_granted = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static VoteResponse {
+        static instance: VoteResponse = VoteResponse {
+            term: 0,
+            voter_id: 0,
+            vote_granted: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for VoteResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("VoteResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for VoteResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VoteResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.raft.AppendEntries)
+pub struct AppendEntries {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.raft.AppendEntries.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:coerce.raft.AppendEntries.leader_id)
+    pub leader_id: u64,
+    // @@protoc_insertion_point(field:coerce.raft.AppendEntries.prev_log_index)
+    pub prev_log_index: u64,
+    // @@protoc_insertion_point(field:coerce.raft.AppendEntries.prev_log_term)
+    pub prev_log_term: u64,
+    // @@protoc_insertion_point(field:coerce.raft.AppendEntries.entries)
+    pub entries: ::std::vec::Vec<LogEntry>,
+    // @@protoc_insertion_point(field:coerce.raft.AppendEntries.leader_commit)
+    pub leader_commit: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.raft.AppendEntries.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AppendEntries {
+    fn default() -> &'a AppendEntries {
+        <AppendEntries as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AppendEntries {
+    pub fn new() -> AppendEntries {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "term",
+            |m: &AppendEntries| { &m.term },
+            |m: &mut AppendEntries| { &mut m.term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "leader_id",
+            |m: &AppendEntries| { &m.leader_id },
+            |m: &mut AppendEntries| { &mut m.leader_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "prev_log_index",
+            |m: &AppendEntries| { &m.prev_log_index },
+            |m: &mut AppendEntries| { &mut m.prev_log_index },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "prev_log_term",
+            |m: &AppendEntries| { &m.prev_log_term },
+            |m: &mut AppendEntries| { &mut m.prev_log_term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "entries",
+            |m: &AppendEntries| { &m.entries },
+            |m: &mut AppendEntries| { &mut m.entries },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "leader_commit",
+            |m: &AppendEntries| { &m.leader_commit },
+            |m: &mut AppendEntries| { &mut m.leader_commit },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AppendEntries>(
+            "AppendEntries",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AppendEntries {
+    const NAME: &'static str = "AppendEntries";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.term = is.read_uint64()?;
+                },
+                16 => {
+                    self.leader_id = is.read_uint64()?;
+                },
+                24 => {
+                    self.prev_log_index = is.read_uint64()?;
+                },
+                32 => {
+                    self.prev_log_term = is.read_uint64()?;
+                },
+                42 => {
+                    self.entries.push(is.read_message()?);
+                },
+                48 => {
+                    self.leader_commit = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.term);
+        }
+        if self.leader_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.leader_id);
+        }
+        if self.prev_log_index != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.prev_log_index);
+        }
+        if self.prev_log_term != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.prev_log_term);
+        }
+        for value in &self.entries {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.leader_commit != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.leader_commit);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.term != 0 {
+            os.write_uint64(1, self.term)?;
+        }
+        if self.leader_id != 0 {
+            os.write_uint64(2, self.leader_id)?;
+        }
+        if self.prev_log_index != 0 {
+            os.write_uint64(3, self.prev_log_index)?;
+        }
+        if self.prev_log_term != 0 {
+            os.write_uint64(4, self.prev_log_term)?;
+        }
+        for v in &self.entries {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        };
+        if self.leader_commit != 0 {
+            os.write_uint64(6, self.leader_commit)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AppendEntries {
+        AppendEntries::new()
+    }
+
+    fn clear(&mut self) {
+        self.term = 0;
+        self.leader_id = 0;
+        self.prev_log_index = 0;
+        self.prev_log_term = 0;
+        self.entries.clear();
+        self.leader_commit = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AppendEntries {
+        static instance: AppendEntries = AppendEntries {
+            term: 0,
+            leader_id: 0,
+            prev_log_index: 0,
+            prev_log_term: 0,
+            entries: ::std::vec::Vec::new(),
+            leader_commit: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AppendEntries {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AppendEntries").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AppendEntries {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AppendEntries {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.raft.AppendEntriesResponse)
+pub struct AppendEntriesResponse {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.raft.AppendEntriesResponse.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:coerce.raft.AppendEntriesResponse.follower_id)
+    pub follower_id: u64,
+    // @@protoc_insertion_point(field:coerce.raft.AppendEntriesResponse.success)
+    pub success: bool,
+    // @@protoc_insertion_point(field:coerce.raft.AppendEntriesResponse.match_index)
+    pub match_index: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.raft.AppendEntriesResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AppendEntriesResponse {
+    fn default() -> &'a AppendEntriesResponse {
+        <AppendEntriesResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AppendEntriesResponse {
+    pub fn new() -> AppendEntriesResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "term",
+            |m: &AppendEntriesResponse| { &m.term },
+            |m: &mut AppendEntriesResponse| { &mut m.term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "follower_id",
+            |m: &AppendEntriesResponse| { &m.follower_id },
+            |m: &mut AppendEntriesResponse| { &mut m.follower_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "success",
+            |m: &AppendEntriesResponse| { &m.success },
+            |m: &mut AppendEntriesResponse| { &mut m.success },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "match_index",
+            |m: &AppendEntriesResponse| { &m.match_index },
+            |m: &mut AppendEntriesResponse| { &mut m.match_index },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AppendEntriesResponse>(
+            "AppendEntriesResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AppendEntriesResponse {
+    const NAME: &'static str = "AppendEntriesResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.term = is.read_uint64()?;
+                },
+                16 => {
+                    self.follower_id = is.read_uint64()?;
+                },
+                24 => {
+                    self.success = is.read_bool()?;
+                },
+                32 => {
+                    self.match_index = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.term);
+        }
+        if self.follower_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.follower_id);
+        }
+        if self.success != false {
+            my_size += 1 + 1;
+        }
+        if self.match_index != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.match_index);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.term != 0 {
+            os.write_uint64(1, self.term)?;
+        }
+        if self.follower_id != 0 {
+            os.write_uint64(2, self.follower_id)?;
+        }
+        if self.success != false {
+            os.write_bool(3, self.success)?;
+        }
+        if self.match_index != 0 {
+            os.write_uint64(4, self.match_index)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AppendEntriesResponse {
+        AppendEntriesResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.term = 0;
+        self.follower_id = 0;
+        self.success = false;
+        self.match_index = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AppendEntriesResponse {
+        static instance: AppendEntriesResponse = AppendEntriesResponse {
+            term: 0,
+            follower_id: 0,
+            success: false,
+            match_index: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AppendEntriesResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AppendEntriesResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AppendEntriesResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AppendEntriesResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.raft.InstallSnapshot)
+pub struct InstallSnapshot {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.raft.InstallSnapshot.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:coerce.raft.InstallSnapshot.leader_id)
+    pub leader_id: u64,
+    // @@protoc_insertion_point(field:coerce.raft.InstallSnapshot.last_included_index)
+    pub last_included_index: u64,
+    // @@protoc_insertion_point(field:coerce.raft.InstallSnapshot.last_included_term)
+    pub last_included_term: u64,
+    // @@protoc_insertion_point(field:coerce.raft.InstallSnapshot.data)
+    pub data: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.raft.InstallSnapshot.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a InstallSnapshot {
+    fn default() -> &'a InstallSnapshot {
+        <InstallSnapshot as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl InstallSnapshot {
+    pub fn new() -> InstallSnapshot {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "term",
+            |m: &InstallSnapshot| { &m.term },
+            |m: &mut InstallSnapshot| { &mut m.term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "leader_id",
+            |m: &InstallSnapshot| { &m.leader_id },
+            |m: &mut InstallSnapshot| { &mut m.leader_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "last_included_index",
+            |m: &InstallSnapshot| { &m.last_included_index },
+            |m: &mut InstallSnapshot| { &mut m.last_included_index },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "last_included_term",
+            |m: &InstallSnapshot| { &m.last_included_term },
+            |m: &mut InstallSnapshot| { &mut m.last_included_term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "data",
+            |m: &InstallSnapshot| { &m.data },
+            |m: &mut InstallSnapshot| { &mut m.data },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<InstallSnapshot>(
+            "InstallSnapshot",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for InstallSnapshot {
+    const NAME: &'static str = "InstallSnapshot";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.term = is.read_uint64()?;
+                },
+                16 => {
+                    self.leader_id = is.read_uint64()?;
+                },
+                24 => {
+                    self.last_included_index = is.read_uint64()?;
+                },
+                32 => {
+                    self.last_included_term = is.read_uint64()?;
+                },
+                42 => {
+                    self.data = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.term);
+        }
+        if self.leader_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.leader_id);
+        }
+        if self.last_included_index != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.last_included_index);
+        }
+        if self.last_included_term != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.last_included_term);
+        }
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.data);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.term != 0 {
+            os.write_uint64(1, self.term)?;
+        }
+        if self.leader_id != 0 {
+            os.write_uint64(2, self.leader_id)?;
+        }
+        if self.last_included_index != 0 {
+            os.write_uint64(3, self.last_included_index)?;
+        }
+        if self.last_included_term != 0 {
+            os.write_uint64(4, self.last_included_term)?;
+        }
+        if !self.data.is_empty() {
+            os.write_bytes(5, &self.data)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> InstallSnapshot {
+        InstallSnapshot::new()
+    }
+
+    fn clear(&mut self) {
+        self.term = 0;
+        self.leader_id = 0;
+        self.last_included_index = 0;
+        self.last_included_term = 0;
+        self.data.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static InstallSnapshot {
+        static instance: InstallSnapshot = InstallSnapshot {
+            term: 0,
+            leader_id: 0,
+            last_included_index: 0,
+            last_included_term: 0,
+            data: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for InstallSnapshot {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("InstallSnapshot").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for InstallSnapshot {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for InstallSnapshot {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.raft.HardState)
+pub struct HardState {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.raft.HardState.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:coerce.raft.HardState.voted_for)
+    pub voted_for: u64,
+    // @@protoc_insertion_point(field:coerce.raft.HardState.voted)
+    pub voted: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.raft.HardState.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a HardState {
+    fn default() -> &'a HardState {
+        <HardState as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HardState {
+    pub fn new() -> HardState {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "term",
+            |m: &HardState| { &m.term },
+            |m: &mut HardState| { &mut m.term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "voted_for",
+            |m: &HardState| { &m.voted_for },
+            |m: &mut HardState| { &mut m.voted_for },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "voted",
+            |m: &HardState| { &m.voted },
+            |m: &mut HardState| { &mut m.voted },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<HardState>(
+            "HardState",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for HardState {
+    const NAME: &'static str = "HardState";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.term = is.read_uint64()?;
+                },
+                16 => {
+                    self.voted_for = is.read_uint64()?;
+                },
+                24 => {
+                    self.voted = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.term);
+        }
+        if self.voted_for != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.voted_for);
+        }
+        if self.voted != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.term != 0 {
+            os.write_uint64(1, self.term)?;
+        }
+        if self.voted_for != 0 {
+            os.write_uint64(2, self.voted_for)?;
+        }
+        if self.voted != false {
+            os.write_bool(3, self.voted)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> HardState {
+        HardState::new()
+    }
+
+    fn clear(&mut self) {
+        self.term = 0;
+        self.voted_for = 0;
+        self.voted = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static HardState {
+        static instance: HardState = HardState {
+            term: 0,
+            voted_for: 0,
+            voted: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for HardState {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("HardState").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for HardState {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HardState {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.raft.Snapshot)
+pub struct Snapshot {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.raft.Snapshot.last_included_index)
+    pub last_included_index: u64,
+    // @@protoc_insertion_point(field:coerce.raft.Snapshot.last_included_term)
+    pub last_included_term: u64,
+    // @@protoc_insertion_point(field:coerce.raft.Snapshot.log_generation)
+    pub log_generation: u64,
+    // @@protoc_insertion_point(field:coerce.raft.Snapshot.data)
+    pub data: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.raft.Snapshot.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Snapshot {
+    fn default() -> &'a Snapshot {
+        <Snapshot as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Snapshot {
+    pub fn new() -> Snapshot {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "last_included_index",
+            |m: &Snapshot| { &m.last_included_index },
+            |m: &mut Snapshot| { &mut m.last_included_index },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "last_included_term",
+            |m: &Snapshot| { &m.last_included_term },
+            |m: &mut Snapshot| { &mut m.last_included_term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "log_generation",
+            |m: &Snapshot| { &m.log_generation },
+            |m: &mut Snapshot| { &mut m.log_generation },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "data",
+            |m: &Snapshot| { &m.data },
+            |m: &mut Snapshot| { &mut m.data },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Snapshot>(
+            "Snapshot",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Snapshot {
+    const NAME: &'static str = "Snapshot";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.last_included_index = is.read_uint64()?;
+                },
+                16 => {
+                    self.last_included_term = is.read_uint64()?;
+                },
+                24 => {
+                    self.log_generation = is.read_uint64()?;
+                },
+                34 => {
+                    self.data = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.last_included_index != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.last_included_index);
+        }
+        if self.last_included_term != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.last_included_term);
+        }
+        if self.log_generation != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.log_generation);
+        }
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.data);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.last_included_index != 0 {
+            os.write_uint64(1, self.last_included_index)?;
+        }
+        if self.last_included_term != 0 {
+            os.write_uint64(2, self.last_included_term)?;
+        }
+        if self.log_generation != 0 {
+            os.write_uint64(3, self.log_generation)?;
+        }
+        if !self.data.is_empty() {
+            os.write_bytes(4, &self.data)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Snapshot {
+        Snapshot::new()
+    }
+
+    fn clear(&mut self) {
+        self.last_included_index = 0;
+        self.last_included_term = 0;
+        self.log_generation = 0;
+        self.data.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Snapshot {
+        static instance: Snapshot = Snapshot {
+            last_included_index: 0,
+            last_included_term: 0,
+            log_generation: 0,
+            data: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Snapshot {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Snapshot").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Snapshot {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Snapshot {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.raft.KeyValueCommand)
+pub struct KeyValueCommand {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.raft.KeyValueCommand.key)
+    pub key: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.raft.KeyValueCommand.value)
+    pub value: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:coerce.raft.KeyValueCommand.delete)
+    pub delete: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.raft.KeyValueCommand.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a KeyValueCommand {
+    fn default() -> &'a KeyValueCommand {
+        <KeyValueCommand as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KeyValueCommand {
+    pub fn new() -> KeyValueCommand {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "key",
+            |m: &KeyValueCommand| { &m.key },
+            |m: &mut KeyValueCommand| { &mut m.key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "value",
+            |m: &KeyValueCommand| { &m.value },
+            |m: &mut KeyValueCommand| { &mut m.value },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "delete",
+            |m: &KeyValueCommand| { &m.delete },
+            |m: &mut KeyValueCommand| { &mut m.delete },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<KeyValueCommand>(
+            "KeyValueCommand",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for KeyValueCommand {
+    const NAME: &'static str = "KeyValueCommand";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.key = is.read_string()?;
+                },
+                18 => {
+                    self.value = is.read_bytes()?;
+                },
+                24 => {
+                    self.delete = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.value);
+        }
+        if self.delete != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(2, &self.value)?;
+        }
+        if self.delete != false {
+            os.write_bool(3, self.delete)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> KeyValueCommand {
+        KeyValueCommand::new()
+    }
+
+    fn clear(&mut self) {
+        self.key.clear();
+        self.value.clear();
+        self.delete = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static KeyValueCommand {
+        static instance: KeyValueCommand = KeyValueCommand {
+            key: ::std::string::String::new(),
+            value: ::std::vec::Vec::new(),
+            delete: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for KeyValueCommand {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("KeyValueCommand").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for KeyValueCommand {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KeyValueCommand {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.raft.KeyValueSnapshot)
+pub struct KeyValueSnapshot {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.raft.KeyValueSnapshot.entries)
+    pub entries: ::std::collections::HashMap<::std::string::String, ::std::vec::Vec<u8>>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.raft.KeyValueSnapshot.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a KeyValueSnapshot {
+    fn default() -> &'a KeyValueSnapshot {
+        <KeyValueSnapshot as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KeyValueSnapshot {
+    pub fn new() -> KeyValueSnapshot {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "entries",
+            |m: &KeyValueSnapshot| { &m.entries },
+            |m: &mut KeyValueSnapshot| { &mut m.entries },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<KeyValueSnapshot>(
+            "KeyValueSnapshot",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for KeyValueSnapshot {
+    const NAME: &'static str = "KeyValueSnapshot";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_bytes()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.entries.insert(key, value);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for (k, v) in &self.entries {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::bytes_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for (k, v) in &self.entries {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::bytes_size(2, &v);
+            os.write_raw_varint32(10)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_bytes(2, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> KeyValueSnapshot {
+        KeyValueSnapshot::new()
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static KeyValueSnapshot {
+        static instance: ::protobuf::rt::Lazy<KeyValueSnapshot> = ::protobuf::rt::Lazy::new();
+        instance.get(KeyValueSnapshot::new)
+    }
+}
+
+impl ::protobuf::MessageFull for KeyValueSnapshot {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("KeyValueSnapshot").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for KeyValueSnapshot {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KeyValueSnapshot {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\nraft.proto\x12\x0bcoerce.raft\"b\n\x08LogEntry\x12\x14\n\x05index\
+    \x18\x01\x20\x01(\x04R\x05index\x12\x12\n\x04term\x18\x02\x20\x01(\x04R\
+    \x04term\x12\x18\n\x07command\x18\x03\x20\x01(\x0cR\x07command\x12\x12\n\
+    \x04noop\x18\x04\x20\x01(\x08R\x04noop\"\x8e\x01\n\x0bRequestVote\x12\
+    \x12\n\x04term\x18\x01\x20\x01(\x04R\x04term\x12!\n\x0ccandidate_id\x18\
+    \x02\x20\x01(\x04R\x0bcandidateId\x12$\n\x0elast_log_index\x18\x03\x20\
+    \x01(\x04R\x0clastLogIndex\x12\"\n\rlast_log_term\x18\x04\x20\x01(\x04R\
+    \x0blastLogTerm\"`\n\x0cVoteResponse\x12\x12\n\x04term\x18\x01\x20\x01(\
+    \x04R\x04term\x12\x19\n\x08voter_id\x18\x02\x20\x01(\x04R\x07voterId\x12\
+    !\n\x0cvote_granted\x18\x03\x20\x01(\x08R\x0bvoteGranted\"\xe0\x01\n\rAp\
+    pendEntries\x12\x12\n\x04term\x18\x01\x20\x01(\x04R\x04term\x12\x1b\n\tl\
+    eader_id\x18\x02\x20\x01(\x04R\x08leaderId\x12$\n\x0eprev_log_index\x18\
+    \x03\x20\x01(\x04R\x0cprevLogIndex\x12\"\n\rprev_log_term\x18\x04\x20\
+    \x01(\x04R\x0bprevLogTerm\x12/\n\x07entries\x18\x05\x20\x03(\x0b2\x15.co\
+    erce.raft.LogEntryR\x07entries\x12#\n\rleader_commit\x18\x06\x20\x01(\
+    \x04R\x0cleaderCommit\"\x87\x01\n\x15AppendEntriesResponse\x12\x12\n\x04\
+    term\x18\x01\x20\x01(\x04R\x04term\x12\x1f\n\x0bfollower_id\x18\x02\x20\
+    \x01(\x04R\nfollowerId\x12\x18\n\x07success\x18\x03\x20\x01(\x08R\x07suc\
+    cess\x12\x1f\n\x0bmatch_index\x18\x04\x20\x01(\x04R\nmatchIndex\"\xb4\
+    \x01\n\x0fInstallSnapshot\x12\x12\n\x04term\x18\x01\x20\x01(\x04R\x04ter\
+    m\x12\x1b\n\tleader_id\x18\x02\x20\x01(\x04R\x08leaderId\x12.\n\x13last_\
+    included_index\x18\x03\x20\x01(\x04R\x11lastIncludedIndex\x12,\n\x12last\
+    _included_term\x18\x04\x20\x01(\x04R\x10lastIncludedTerm\x12\x12\n\x04da\
+    ta\x18\x05\x20\x01(\x0cR\x04data\"R\n\tHardState\x12\x12\n\x04term\x18\
+    \x01\x20\x01(\x04R\x04term\x12\x1b\n\tvoted_for\x18\x02\x20\x01(\x04R\
+    \x08votedFor\x12\x14\n\x05voted\x18\x03\x20\x01(\x08R\x05voted\"\xa3\x01\
+    \n\x08Snapshot\x12.\n\x13last_included_index\x18\x01\x20\x01(\x04R\x11la\
+    stIncludedIndex\x12,\n\x12last_included_term\x18\x02\x20\x01(\x04R\x10la\
+    stIncludedTerm\x12%\n\x0elog_generation\x18\x03\x20\x01(\x04R\rlogGenera\
+    tion\x12\x12\n\x04data\x18\x04\x20\x01(\x0cR\x04data\"Q\n\x0fKeyValueCom\
+    mand\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\
+    \x02\x20\x01(\x0cR\x05value\x12\x16\n\x06delete\x18\x03\x20\x01(\x08R\
+    \x06delete\"\x94\x01\n\x10KeyValueSnapshot\x12D\n\x07entries\x18\x01\x20\
+    \x03(\x0b2*.coerce.raft.KeyValueSnapshot.EntriesEntryR\x07entries\x1a:\n\
+    \x0cEntriesEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\
+    \x05value\x18\x02\x20\x01(\x0cR\x05value:\x028\x01b\x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(0);
+            let mut messages = ::std::vec::Vec::with_capacity(10);
+            messages.push(LogEntry::generated_message_descriptor_data());
+            messages.push(RequestVote::generated_message_descriptor_data());
+            messages.push(VoteResponse::generated_message_descriptor_data());
+            messages.push(AppendEntries::generated_message_descriptor_data());
+            messages.push(AppendEntriesResponse::generated_message_descriptor_data());
+            messages.push(InstallSnapshot::generated_message_descriptor_data());
+            messages.push(HardState::generated_message_descriptor_data());
+            messages.push(Snapshot::generated_message_descriptor_data());
+            messages.push(KeyValueCommand::generated_message_descriptor_data());
+            messages.push(KeyValueSnapshot::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(0);
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
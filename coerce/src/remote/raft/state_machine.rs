@@ -0,0 +1,124 @@
+use crate::remote::raft::proto::raft as proto;
+use parking_lot::RwLock;
+use protobuf::Message as ProtoMessage;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Applies committed log entries. Every node applies the same commands in the same order, so
+/// state machines must be deterministic.
+pub trait StateMachine: 'static + Send + Sync {
+    /// Applies a committed command, the result is returned to the node that proposed it.
+    fn apply(&mut self, index: u64, command: &[u8]) -> Vec<u8>;
+
+    /// Serializes the current state, so the log entries applied so far can be discarded.
+    fn snapshot(&self) -> Vec<u8>;
+
+    /// Replaces the current state with a snapshot taken by `snapshot`, on this node or another.
+    fn restore(&mut self, snapshot: &[u8]);
+}
+
+pub type StateMachineFactory = Arc<dyn Fn() -> Box<dyn StateMachine> + Send + Sync>;
+
+/// A state machine that ignores every command, used when Raft is only used for leader election.
+pub struct NoopStateMachine;
+
+impl StateMachine for NoopStateMachine {
+    fn apply(&mut self, _index: u64, _command: &[u8]) -> Vec<u8> {
+        vec![]
+    }
+
+    fn snapshot(&self) -> Vec<u8> {
+        vec![]
+    }
+
+    fn restore(&mut self, _snapshot: &[u8]) {}
+}
+
+/// A replicated key/value map. Clones share the same map, so a clone can be kept to read the
+/// state while another is owned by the Raft actor.
+///
+/// Applying a command returns the previous value of the key, or an empty vec if there wasn't one.
+#[derive(Clone, Default)]
+pub struct KeyValueStore {
+    entries: Arc<RwLock<BTreeMap<String, Vec<u8>>>>,
+}
+
+impl KeyValueStore {
+    pub fn new() -> KeyValueStore {
+        KeyValueStore::default()
+    }
+
+    pub fn get(&self, key: &str) -> Option<Vec<u8>> {
+        self.entries.read().get(key).cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.read().is_empty()
+    }
+
+    pub fn set_command(key: impl ToString, value: Vec<u8>) -> Vec<u8> {
+        proto::KeyValueCommand {
+            key: key.to_string(),
+            value,
+            ..Default::default()
+        }
+        .write_to_bytes()
+        .expect("serialize KeyValueCommand")
+    }
+
+    pub fn delete_command(key: impl ToString) -> Vec<u8> {
+        proto::KeyValueCommand {
+            key: key.to_string(),
+            delete: true,
+            ..Default::default()
+        }
+        .write_to_bytes()
+        .expect("serialize KeyValueCommand")
+    }
+}
+
+impl StateMachine for KeyValueStore {
+    fn apply(&mut self, index: u64, command: &[u8]) -> Vec<u8> {
+        let command = match proto::KeyValueCommand::parse_from_bytes(command) {
+            Ok(command) => command,
+            Err(e) => {
+                error!("invalid key/value command at index={}, error: {}", index, e);
+                return vec![];
+            }
+        };
+
+        let mut entries = self.entries.write();
+        let previous = if command.delete {
+            entries.remove(&command.key)
+        } else {
+            entries.insert(command.key, command.value)
+        };
+
+        previous.unwrap_or_default()
+    }
+
+    fn snapshot(&self) -> Vec<u8> {
+        proto::KeyValueSnapshot {
+            entries: self
+                .entries
+                .read()
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+            ..Default::default()
+        }
+        .write_to_bytes()
+        .expect("serialize KeyValueSnapshot")
+    }
+
+    fn restore(&mut self, snapshot: &[u8]) {
+        match proto::KeyValueSnapshot::parse_from_bytes(snapshot) {
+            Ok(snapshot) => *self.entries.write() = snapshot.entries.into_iter().collect(),
+            Err(e) => error!("invalid key/value snapshot, error: {}", e),
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use crate::persistent::journal::storage::{JournalEntry, JournalStorageRef};
+use crate::remote::raft::proto::raft as proto;
+use crate::remote::raft::{LogEntry, Raft, RaftSnapshot};
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use anyhow::Result;
+use protobuf::Message;
+use std::any::TypeId;
+
+const HARD_STATE: &str = "Raft.HardState";
+const LOG_ENTRY: &str = "Raft.LogEntry";
+const SNAPSHOT: &str = "Raft.Snapshot";
+
+/// Persists the Raft state of a node to the journal storage of the actor system's `Persistence`,
+/// so a voter that restarts rejoins with the term, vote and log it had before.
+///
+/// - `Raft-{node_id}-state`: the current term and vote, as the latest snapshot.
+/// - `Raft-{node_id}-snapshot`: the latest state machine snapshot, and the generation of the log
+///   that follows it.
+/// - `Raft-{node_id}-log-{generation}`: log entries, as messages. Entries replacing conflicting
+///   ones are appended rather than overwritten, so the log is rebuilt by replaying in order.
+///
+/// When a snapshot is taken, the entries after it are copied to a new log generation and the
+/// old generation is deleted, so the persisted log doesn't grow without bound.
+pub(crate) struct RaftStorage {
+    journal: JournalStorageRef,
+    node_id: NodeId,
+    log_generation: u64,
+    log_sequence: i64,
+    hard_state_sequence: i64,
+    snapshot_sequence: i64,
+}
+
+#[derive(Default)]
+pub(crate) struct RecoveredState {
+    pub term: u64,
+    pub voted_for: Option<NodeId>,
+    pub snapshot: RaftSnapshot,
+    pub log: Vec<LogEntry>,
+}
+
+impl RaftStorage {
+    pub fn new(system: &RemoteActorSystem) -> Option<RaftStorage> {
+        let journal = system
+            .actor_system()
+            .persistence()?
+            .provider(TypeId::of::<Raft>())
+            .journal_storage()?;
+
+        Some(RaftStorage {
+            journal,
+            node_id: system.node_id(),
+            log_generation: 0,
+            log_sequence: 0,
+            hard_state_sequence: 0,
+            snapshot_sequence: 0,
+        })
+    }
+
+    pub async fn recover(&mut self) -> Result<RecoveredState> {
+        let mut state = RecoveredState::default();
+
+        if let Some(entry) = self.journal.read_latest_snapshot(&self.state_id()).await? {
+            let hard_state = proto::HardState::parse_from_bytes(&entry.bytes)?;
+
+            self.hard_state_sequence = entry.sequence;
+            state.term = hard_state.term;
+            state.voted_for = hard_state.voted.then_some(hard_state.voted_for);
+        }
+
+        if let Some(entry) = self
+            .journal
+            .read_latest_snapshot(&self.snapshot_id())
+            .await?
+        {
+            let snapshot = proto::Snapshot::parse_from_bytes(&entry.bytes)?;
+
+            self.snapshot_sequence = entry.sequence;
+            self.log_generation = snapshot.log_generation;
+            state.snapshot = RaftSnapshot {
+                last_included_index: snapshot.last_included_index,
+                last_included_term: snapshot.last_included_term,
+                data: snapshot.data,
+            };
+        }
+
+        let messages = self
+            .journal
+            .read_latest_messages(&self.log_id(self.log_generation), 0)
+            .await?
+            .unwrap_or_default();
+
+        let snapshot_index = state.snapshot.last_included_index;
+        for message in messages {
+            let entry: LogEntry = proto::LogEntry::parse_from_bytes(&message.bytes)?.into();
+
+            self.log_sequence = message.sequence;
+            if entry.index <= snapshot_index {
+                continue;
+            }
+
+            // A rewritten entry replaces everything from its index onwards
+            state
+                .log
+                .truncate((entry.index - snapshot_index - 1) as usize);
+            state.log.push(entry);
+        }
+
+        Ok(state)
+    }
+
+    pub async fn save_hard_state(&mut self, term: u64, voted_for: Option<NodeId>) -> Result<()> {
+        let bytes = proto::HardState {
+            term,
+            voted_for: voted_for.unwrap_or_default(),
+            voted: voted_for.is_some(),
+            ..Default::default()
+        }
+        .write_to_bytes()?;
+
+        self.hard_state_sequence += 1;
+        self.journal
+            .write_snapshot(
+                &self.state_id(),
+                JournalEntry {
+                    sequence: self.hard_state_sequence,
+                    payload_type: HARD_STATE.to_string(),
+                    bytes,
+                },
+            )
+            .await
+    }
+
+    pub async fn append(&mut self, entries: &[LogEntry]) -> Result<()> {
+        let log_id = self.log_id(self.log_generation);
+        for entry in entries {
+            self.log_sequence += 1;
+            self.journal
+                .write_message(&log_id, log_entry(self.log_sequence, entry)?)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Saves the snapshot, replacing the persisted log with `log`, the entries that follow it.
+    pub async fn save_snapshot(&mut self, snapshot: &RaftSnapshot, log: &[LogEntry]) -> Result<()> {
+        let previous_log_id = self.log_id(self.log_generation);
+        let log_generation = self.log_generation + 1;
+        let log_id = self.log_id(log_generation);
+
+        // Left over if a previous attempt failed before the snapshot was written
+        self.journal.delete_all(&log_id).await?;
+
+        let mut log_sequence = 0;
+        for entry in log {
+            log_sequence += 1;
+            self.journal
+                .write_message(&log_id, log_entry(log_sequence, entry)?)
+                .await?;
+        }
+
+        let bytes = proto::Snapshot {
+            last_included_index: snapshot.last_included_index,
+            last_included_term: snapshot.last_included_term,
+            log_generation,
+            data: snapshot.data.clone(),
+            ..Default::default()
+        }
+        .write_to_bytes()?;
+
+        self.journal
+            .write_snapshot(
+                &self.snapshot_id(),
+                JournalEntry {
+                    sequence: self.snapshot_sequence + 1,
+                    payload_type: SNAPSHOT.to_string(),
+                    bytes,
+                },
+            )
+            .await?;
+
+        self.snapshot_sequence += 1;
+        self.log_generation = log_generation;
+        self.log_sequence = log_sequence;
+
+        if let Err(e) = self.journal.delete_all(&previous_log_id).await {
+            warn!(
+                "[node={}] failed to delete compacted raft log, error: {}",
+                self.node_id, e
+            );
+        }
+
+        Ok(())
+    }
+
+    fn state_id(&self) -> String {
+        format!("Raft-{}-state", self.node_id)
+    }
+
+    fn snapshot_id(&self) -> String {
+        format!("Raft-{}-snapshot", self.node_id)
+    }
+
+    fn log_id(&self, generation: u64) -> String {
+        format!("Raft-{}-log-{}", self.node_id, generation)
+    }
+}
+
+fn log_entry(sequence: i64, entry: &LogEntry) -> Result<JournalEntry> {
+    Ok(JournalEntry {
+        sequence,
+        payload_type: LOG_ENTRY.to_string(),
+        bytes: proto::LogEntry::from(entry).write_to_bytes()?,
+    })
+}
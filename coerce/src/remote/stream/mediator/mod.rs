@@ -1,5 +1,5 @@
 use crate::actor::context::ActorContext;
-use crate::actor::message::{Handler, Message};
+use crate::actor::message::{Bytes, Handler, Message};
 use crate::actor::{Actor, LocalActorRef};
 use crate::remote::actor::message::SetRemote;
 use crate::remote::net::message::SessionEvent;
@@ -77,7 +77,7 @@ pub struct Publish<T: Topic> {
 pub struct PublishRaw {
     pub topic: String,
     pub key: String,
-    pub message: Vec<u8>,
+    pub message: Bytes,
 }
 
 impl<A: Actor, T: Topic> Message for Subscribe<A, T> {
@@ -197,7 +197,7 @@ impl<T: Topic> Handler<Publish<T>> for StreamMediator {
                     let nodes: Vec<NodeId> = self.nodes.iter().copied().collect();
 
                     tokio::spawn(async move {
-                        let message = bytes;
+                        let message = bytes.into();
                         let publish = Arc::new(StreamPublishEvent {
                             topic,
                             message,
@@ -1,5 +1,5 @@
 use crate::actor::context::ActorContext;
-use crate::actor::message::{Handler, Message};
+use crate::actor::message::{Bytes, Handler, Message};
 
 use crate::actor::{Actor, LocalActorRef};
 use crate::remote::net::StreamData;
@@ -107,7 +107,7 @@ impl<T: Topic> Message for Receive<T> {
 
 #[async_trait]
 pub trait TopicEmitter: 'static + Any + Send + Sync {
-    async fn emit_serialised(&self, key: &str, bytes: Vec<u8>);
+    async fn emit_serialised(&self, key: &str, bytes: Bytes);
 
     async fn emit(&self, key: &str, msg: Arc<dyn Any + Sync + Send>);
 
@@ -157,7 +157,7 @@ impl<T: Topic> TopicSubscriberStore<T> {
 
 #[async_trait]
 impl<T: Topic + 'static> TopicEmitter for TopicSubscriberStore<T> {
-    async fn emit_serialised(&self, key: &str, bytes: Vec<u8>) {
+    async fn emit_serialised(&self, key: &str, bytes: Bytes) {
         // let topic_data = format!("{}-{}", T::topic_name(), &key);
         // let span = tracing::debug_span!(
         //     "PubSub::emit",
@@ -10,6 +10,7 @@ use crate::remote::cluster::node::RemoteNode;
 use crate::remote::net::message::{datetime_to_timestamp, timestamp_to_datetime};
 use crate::remote::net::proto::network as proto;
 use crate::remote::system::NodeId;
+use bytes::Bytes;
 use protobuf::{Enum, Error, Message};
 
 pub struct SystemTopic;
@@ -19,6 +20,9 @@ pub enum ClusterEvent {
     NodeAdded(Arc<RemoteNode>),
     NodeRemoved(Arc<RemoteNode>),
     LeaderChanged(NodeId),
+    LeaderCleared,
+    NodeDowned(NodeId),
+    RoleLeaderChanged(String, Option<NodeId>),
 }
 
 #[derive(Debug)]
@@ -46,6 +50,7 @@ impl From<NewNodeEvent> for SystemEvent {
                 .node_started_at
                 .into_option()
                 .map(|d| timestamp_to_datetime(d)),
+            roles: node.roles,
         })))
     }
 }
@@ -62,6 +67,7 @@ impl From<NodeRemovedEvent> for SystemEvent {
                 .node_started_at
                 .into_option()
                 .map(|d| timestamp_to_datetime(d)),
+            roles: node.roles,
         })))
     }
 }
@@ -72,7 +78,7 @@ impl From<LeaderChangedEvent> for SystemEvent {
 }
 
 impl StreamData for SystemEvent {
-    fn read_from_bytes(data: Vec<u8>) -> Option<Self> {
+    fn read_from_bytes(data: Bytes) -> Option<Self> {
         match data.split_first() {
             Some((event, message)) => match SysEvent::from_i32(*event as i32) {
                 Some(SysEvent::ClusterNodeRemoved) => {
@@ -119,6 +125,11 @@ impl StreamData for SystemEvent {
 
                     write_event(SysEvent::ClusterLeaderChanged, event.write_to_bytes())
                 }
+                // Downing decisions, role leaders and a lost Raft leader are observed by each
+                // node, they're only ever published locally
+                ClusterEvent::NodeDowned(_)
+                | ClusterEvent::LeaderCleared
+                | ClusterEvent::RoleLeaderChanged(..) => None,
             },
         }
     }
@@ -134,6 +145,7 @@ fn get_proto_node(node: &RemoteNode) -> proto::RemoteNode {
             .as_ref()
             .map(datetime_to_timestamp)
             .into(),
+        roles: node.roles.clone(),
         ..proto::RemoteNode::default()
     }
     .into()
@@ -11,6 +11,8 @@ use crate::remote::system::{NodeId, NodeRpcErr, RemoteActorSystem};
 use crate::remote::{RemoteActorRef, RemoteMessageHeader};
 use protobuf::well_known_types::wrappers::UInt64Value;
 use protobuf::{Message as ProtoMessage, MessageField};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use tokio::sync::oneshot;
 use uuid::Uuid;
 
@@ -22,9 +24,33 @@ pub enum RemoteActorErr {
     MessageSerializationErr,
     ResultSerializationErr,
     ActorNotSupported,
+    NoNodeWithRole(String),
     NodeErr(NodeRpcErr),
 }
 
+/// The node `deploy_actor` creates the actor on.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DeployTarget {
+    Local,
+    Node(NodeId),
+
+    /// A node with the given role, the local node if it has the role, otherwise a node chosen by
+    /// the actor's id. Fails with `RemoteActorErr::NoNodeWithRole` if no available node has it.
+    Role(String),
+}
+
+impl From<Option<NodeId>> for DeployTarget {
+    fn from(node_id: Option<NodeId>) -> Self {
+        node_id.map_or(DeployTarget::Local, DeployTarget::Node)
+    }
+}
+
+impl From<NodeId> for DeployTarget {
+    fn from(node_id: NodeId) -> Self {
+        DeployTarget::Node(node_id)
+    }
+}
+
 impl RemoteActorSystem {
     pub fn register_actor(&self, actor_id: ActorId, node_id: Option<NodeId>) {
         let _ = self
@@ -108,11 +134,15 @@ impl RemoteActorSystem {
         &self,
         id: Option<ActorId>,
         recipe: F::Recipe,
-        node: Option<NodeId>,
+        target: impl Into<DeployTarget>,
     ) -> Result<ActorRef<F::Actor>, RemoteActorErr> {
         let self_id = self.node_id();
         let id = id.map_or_else(new_actor_id, |id| id);
-        let node = node.map_or_else(|| self_id, |n| n);
+        let node = match target.into() {
+            DeployTarget::Local => self_id,
+            DeployTarget::Node(node_id) => node_id,
+            DeployTarget::Role(role) => self.role_node(&id, &role).await?,
+        };
         let actor_type: String = F::Actor::type_name().into();
 
         let recipe = recipe.write_to_bytes();
@@ -169,6 +199,29 @@ impl RemoteActorSystem {
         }
     }
 
+    async fn role_node(&self, id: &ActorId, role: &str) -> Result<NodeId, RemoteActorErr> {
+        if self.has_role(role) {
+            return Ok(self.node_id());
+        }
+
+        let mut node_ids: Vec<NodeId> = self
+            .nodes_with_role(role)
+            .await
+            .into_iter()
+            .map(|n| n.id)
+            .collect();
+
+        if node_ids.is_empty() {
+            return Err(RemoteActorErr::NoNodeWithRole(role.to_string()));
+        }
+
+        node_ids.sort();
+
+        let mut hasher = DefaultHasher::new();
+        id.hash(&mut hasher);
+        Ok(node_ids[(hasher.finish() % node_ids.len() as u64) as usize])
+    }
+
     pub async fn handle_create_actor(
         &self,
         actor_id: Option<ActorId>,
@@ -6,8 +6,15 @@ use crate::remote::actor::{
     BoxedActorHandler, BoxedMessageHandler, RemoteClientRegistry, RemoteHandler, RemoteRegistry,
     RemoteSystemConfig,
 };
-use crate::remote::handler::{RemoteActorHandler, RemoteActorMessageHandler};
+use crate::remote::handler::{registered_handlers, RemoteActorHandler, RemoteActorMessageHandler};
 use crate::remote::heartbeat::{Heartbeat, HeartbeatConfig};
+use crate::remote::net::auth::AuthenticatorRef;
+use crate::remote::net::client::buffer::WriteBufferConfig;
+use crate::remote::net::client::config::RemoteClientConfig;
+use crate::remote::net::codec::DEFAULT_MAX_FRAME_SIZE;
+use crate::remote::net::protocol::ProtocolFeature;
+use crate::remote::net::tls::TlsConfigRef;
+use crate::remote::net::transport::{TcpTransport, TransportRef};
 
 use crate::remote::stream::mediator::StreamMediator;
 
@@ -16,13 +23,21 @@ use crate::remote::system::{AtomicNodeId, NodeId, RemoteActorSystem, RemoteSyste
 use futures::TryFutureExt;
 use rand::RngCore;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
 use crate::actor::scheduler::ActorType;
 use crate::remote::cluster::discovery::NodeDiscovery;
 
+use crate::remote::cluster::crdt::crdt;
+use crate::remote::cluster::lease::lease;
 use crate::remote::cluster::sharding::sharding;
+use crate::remote::cluster::singleton::singleton;
+use crate::remote::cluster::split_brain::SplitBrainResolverConfig;
+use crate::remote::cluster::swim::{swim, SwimConfig};
+use crate::remote::delivery::delivery;
+use crate::remote::raft::{raft, RaftConfig};
 use chrono::Utc;
 use uuid::Uuid;
 
@@ -44,7 +59,13 @@ impl RemoteActorSystemBuilder {
             node_id: None,
             node_tag: None,
             inner: None,
-            config_builders: vec![Box::new(sharding)],
+            config_builders: vec![
+                Box::new(sharding),
+                Box::new(singleton),
+                Box::new(crdt),
+                Box::new(lease),
+                Box::new(delivery),
+            ],
             mediator: Some(mediator),
             single_node_cluster: false,
             server_auth_token: None,
@@ -92,11 +113,27 @@ impl RemoteActorSystemBuilder {
         self
     }
 
+    pub fn with_client_config(self, client_config: RemoteClientConfig) -> Self {
+        self.configure(move |c| c.client_config(client_config))
+    }
+
+    /// Declares the roles this node has, sharded entities and deployed actors can be constrained
+    /// to only run on nodes with a given role.
+    pub fn with_roles(self, roles: impl IntoIterator<Item = impl ToString>) -> Self {
+        let roles: Vec<String> = roles.into_iter().map(|r| r.to_string()).collect();
+        self.configure(move |c| c.roles(roles))
+    }
+
+    pub fn with_role(self, role: impl ToString) -> Self {
+        self.with_roles([role])
+    }
+
     pub fn single_node(mut self) -> Self {
         self.single_node_cluster = true;
         self
     }
 
+    /// Shared secret used to authenticate nodes via HMAC challenge-response.
     pub fn server_auth_token(mut self, server_auth_token: Option<impl ToString>) -> Self {
         self.server_auth_token = server_auth_token.map(|s| s.to_string());
         self
@@ -111,6 +148,7 @@ impl RemoteActorSystemBuilder {
         };
 
         let mut config_builder = RemoteSystemConfigBuilder::new(inner.clone());
+        config_builder.with_registered_handlers();
 
         self.config_builders.into_iter().for_each(|h| {
             h(&mut config_builder);
@@ -170,6 +208,10 @@ impl RemoteActorSystemBuilder {
             } else {
                 -1
             })),
+            terminated_nodes: Arc::new(parking_lot::RwLock::new(HashSet::new())),
+            role_leaders: Arc::new(parking_lot::RwLock::new(HashMap::new())),
+            leaving: Arc::new(AtomicBool::new(false)),
+            shard_hosts: Arc::new(parking_lot::RwLock::new(vec![])),
         };
 
         let inner = Arc::new(core.clone());
@@ -186,6 +228,12 @@ impl RemoteActorSystemBuilder {
             .await
             .expect("no system set");
 
+        system
+            .client_registry()
+            .send(SetRemote(system.clone()))
+            .await
+            .expect("no system set");
+
         system
             .heartbeat()
             .send(SetRemote(system.clone()))
@@ -222,8 +270,22 @@ pub(crate) type ConfigBuilderFn =
 pub struct RemoteSystemConfigBuilder {
     system: ActorSystem,
     heartbeat: Option<HeartbeatConfig>,
+    tls: Option<TlsConfigRef>,
+    transport: Option<TransportRef>,
+    authenticator: Option<AuthenticatorRef>,
+    max_frame_size: Option<usize>,
+    protocol_features: Option<Vec<ProtocolFeature>>,
+    connection_lanes: Option<usize>,
+    write_buffer: Option<WriteBufferConfig>,
+    client_config: Option<RemoteClientConfig>,
+    swim_config: Option<SwimConfig>,
+    split_brain_resolver: Option<SplitBrainResolverConfig>,
+    raft_config: Option<RaftConfig>,
+    roles: Vec<String>,
+    leader_role: Option<String>,
     actors: HashMap<String, BoxedActorHandler>,
     handlers: HashMap<String, BoxedMessageHandler>,
+    control_handlers: HashSet<String>,
 }
 
 impl RemoteSystemConfigBuilder {
@@ -231,8 +293,22 @@ impl RemoteSystemConfigBuilder {
         RemoteSystemConfigBuilder {
             actors: HashMap::new(),
             handlers: HashMap::new(),
+            control_handlers: HashSet::new(),
             system,
             heartbeat: None,
+            tls: None,
+            transport: None,
+            authenticator: None,
+            max_frame_size: None,
+            protocol_features: None,
+            connection_lanes: None,
+            write_buffer: None,
+            client_config: None,
+            swim_config: None,
+            split_brain_resolver: None,
+            raft_config: None,
+            roles: vec![],
+            leader_role: None,
         }
     }
 
@@ -246,6 +322,35 @@ impl RemoteSystemConfigBuilder {
         self
     }
 
+    /// Registers a handler used for cluster coordination. Messages to control handlers are sent
+    /// via the control lane, so they aren't delayed behind user traffic.
+    pub fn with_control_handler<A: Actor, M: Message>(
+        &mut self,
+        identifier: &'static str,
+    ) -> &mut Self
+    where
+        A: Handler<M>,
+    {
+        self.control_handlers.insert(String::from(identifier));
+        self.with_handler::<A, M>(identifier)
+    }
+
+    pub fn with_registered_handlers(&mut self) -> &mut Self {
+        let mut identifiers = HashSet::new();
+        for registration in registered_handlers() {
+            if !identifiers.insert(registration.identifier) {
+                panic!(
+                    "remote handler identifier `{}` is registered more than once",
+                    registration.identifier
+                );
+            }
+
+            registration.register(self);
+        }
+
+        self
+    }
+
     pub fn with_actor<F: ActorFactory>(&mut self, factory: F) -> &mut Self
     where
         F: 'static + ActorFactory + Send + Sync,
@@ -262,6 +367,101 @@ impl RemoteSystemConfigBuilder {
         self
     }
 
+    pub fn tls(&mut self, tls: TlsConfigRef) -> &mut Self {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// The transport used to listen for and connect to other nodes, defaults to TCP.
+    pub fn transport(&mut self, transport: TransportRef) -> &mut Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Authenticates nodes connecting to this node, and the credentials this node presents to others.
+    /// Takes precedence over `RemoteActorSystemBuilder::server_auth_token`.
+    pub fn authenticator(&mut self, authenticator: AuthenticatorRef) -> &mut Self {
+        self.authenticator = Some(authenticator);
+        self
+    }
+
+    /// The largest frame this node will send or accept, connections sending larger frames are closed.
+    pub fn max_frame_size(&mut self, max_frame_size: usize) -> &mut Self {
+        self.max_frame_size = Some(max_frame_size);
+        self
+    }
+
+    /// The optional protocol features this node advertises, defaults to all supported features.
+    /// Features are only used on a connection when both nodes advertise them.
+    pub fn protocol_features(&mut self, protocol_features: Vec<ProtocolFeature>) -> &mut Self {
+        self.protocol_features = Some(protocol_features);
+        self
+    }
+
+    /// The number of connections opened to each node. With more than one connection, the first
+    /// is dedicated to control traffic (heartbeats, handshakes and sharding coordination) and
+    /// actor messages are spread across the rest by actor id, preserving per-actor ordering.
+    pub fn connection_lanes(&mut self, connection_lanes: usize) -> &mut Self {
+        self.connection_lanes = Some(connection_lanes);
+        self
+    }
+
+    /// Limits on the messages buffered for each connection while a node is unreachable,
+    /// and what happens to new messages once the limit is reached.
+    pub fn write_buffer(&mut self, write_buffer: WriteBufferConfig) -> &mut Self {
+        self.write_buffer = Some(write_buffer);
+        self
+    }
+
+    /// Connect timeouts, reconnection backoff and keepalive settings used by connections
+    /// to other nodes.
+    pub fn client_config(&mut self, client_config: RemoteClientConfig) -> &mut Self {
+        self.client_config = Some(client_config);
+        self
+    }
+
+    /// Replaces pinging every node on each heartbeat with SWIM gossip membership, each node
+    /// probes one member per protocol period and membership changes are piggybacked on the probes.
+    pub fn swim(&mut self, swim_config: SwimConfig) -> &mut Self {
+        self.swim_config = Some(swim_config);
+        swim(self)
+    }
+
+    /// Decides which side of a network partition survives, nodes on the losing side are downed
+    /// rather than electing a leader of their own.
+    pub fn split_brain_resolver(
+        &mut self,
+        split_brain_resolver: SplitBrainResolverConfig,
+    ) -> &mut Self {
+        self.split_brain_resolver = Some(split_brain_resolver);
+        self
+    }
+
+    /// Runs Raft consensus between the configured voters, started once the cluster worker
+    /// is listening.
+    pub fn raft(&mut self, raft_config: RaftConfig) -> &mut Self {
+        self.raft_config = Some(raft_config);
+        raft(self)
+    }
+
+    pub fn roles(&mut self, roles: impl IntoIterator<Item = impl ToString>) -> &mut Self {
+        for role in roles {
+            let role = role.to_string();
+            if !self.roles.contains(&role) {
+                self.roles.push(role);
+            }
+        }
+
+        self
+    }
+
+    /// Restricts leader election to nodes with the given role. Shard coordinators run on the
+    /// leader unless the sharded entity has a coordinator role of its own.
+    pub fn leader_role(&mut self, leader_role: impl ToString) -> &mut Self {
+        self.leader_role = Some(leader_role.to_string());
+        self
+    }
+
     pub fn build(
         self,
         tag: Option<String>,
@@ -279,14 +479,30 @@ impl RemoteSystemConfigBuilder {
         }
 
         let node_tag = tag.map_or_else(|| format!("cluster-node-{}", Uuid::new_v4()), |t| t);
-        Arc::new(RemoteSystemConfig::new(
-            node_tag,
-            actor_types,
-            handler_types,
-            self.handlers,
-            self.actors,
-            self.heartbeat.unwrap_or_default(),
-            server_auth_token,
-        ))
+        Arc::new(
+            RemoteSystemConfig::new(
+                node_tag,
+                actor_types,
+                handler_types,
+                self.handlers,
+                self.actors,
+                self.heartbeat.unwrap_or_default(),
+                server_auth_token,
+            )
+            .with_tls(self.tls)
+            .with_transport(self.transport.unwrap_or_else(|| Arc::new(TcpTransport)))
+            .with_authenticator(self.authenticator)
+            .with_max_frame_size(self.max_frame_size.unwrap_or(DEFAULT_MAX_FRAME_SIZE))
+            .with_protocol_features(self.protocol_features.unwrap_or_else(ProtocolFeature::all))
+            .with_connection_lanes(self.connection_lanes.unwrap_or(1))
+            .with_write_buffer(self.write_buffer.unwrap_or_default())
+            .with_client_config(self.client_config.unwrap_or_default())
+            .with_swim_config(self.swim_config)
+            .with_split_brain_resolver(self.split_brain_resolver)
+            .with_raft_config(self.raft_config)
+            .with_roles(self.roles)
+            .with_leader_role(self.leader_role)
+            .with_control_handlers(self.control_handlers),
+        )
     }
 }
@@ -1,9 +1,18 @@
+use crate::actor::LocalActorRef;
 use crate::remote::actor::message::{ClientWrite, GetNodes, NewClient, RegisterNode, UpdateNodes};
-use crate::remote::cluster::node::{RemoteNode, RemoteNodeState};
+use crate::remote::cluster::node::{NodeStatus, RemoteNode, RemoteNodeState};
+use crate::remote::cluster::sharding::host::handoff::HandoffShards;
+use crate::remote::cluster::sharding::host::ShardHost;
 use crate::remote::net::client::{ClientType, RemoteClientRef};
 use crate::remote::net::message::SessionEvent;
+use crate::remote::net::proto::network::PingEvent;
 use crate::remote::system::{NodeId, RemoteActorSystem};
+use chrono::MIN_DATETIME;
+use futures::future::join_all;
+use std::collections::HashMap;
 use std::sync::atomic::Ordering;
+use tokio::sync::oneshot;
+use uuid::Uuid;
 
 impl RemoteActorSystem {
     pub async fn register_node(&self, node: RemoteNode) {
@@ -30,6 +39,58 @@ impl RemoteActorSystem {
             .unwrap()
     }
 
+    /// Nodes with the given role that haven't been marked unhealthy or terminated, oldest first.
+    pub async fn nodes_with_role(&self, role: &str) -> Vec<RemoteNodeState> {
+        let mut nodes: Vec<RemoteNodeState> = self
+            .get_nodes()
+            .await
+            .into_iter()
+            .filter(|n| {
+                n.has_role(role)
+                    && matches!(n.status, NodeStatus::Joining | NodeStatus::Healthy)
+                    && !self.is_node_terminated(n.id)
+            })
+            .collect();
+
+        nodes.sort_by_key(|n| (n.node_started_at.unwrap_or(MIN_DATETIME), n.id));
+        nodes
+    }
+
+    /// The node leading the given role, or the cluster leader when there's no role.
+    ///
+    /// A role's leader is the oldest healthy node with the role. It's chosen by the heartbeat at
+    /// the same time as the cluster leader, so it's also held back while the split brain resolver
+    /// decides which side of a partition survives.
+    pub fn role_leader(&self, role: Option<&str>) -> Option<NodeId> {
+        match role {
+            Some(role) => self.inner.role_leaders.read().get(role).copied(),
+            None => self.current_leader(),
+        }
+    }
+
+    /// Replaces the leaders of every role, returning the roles whose leader changed.
+    pub(crate) fn update_role_leaders(
+        &self,
+        role_leaders: HashMap<String, NodeId>,
+    ) -> Vec<(String, Option<NodeId>)> {
+        let mut current = self.inner.role_leaders.write();
+        let mut changed: Vec<(String, Option<NodeId>)> = role_leaders
+            .iter()
+            .filter(|(role, leader)| current.get(*role) != Some(*leader))
+            .map(|(role, leader)| (role.clone(), Some(*leader)))
+            .collect();
+
+        changed.extend(
+            current
+                .keys()
+                .filter(|role| !role_leaders.contains_key(*role))
+                .map(|role| (role.clone(), None)),
+        );
+
+        *current = role_leaders;
+        changed
+    }
+
     pub async fn notify_node(&self, node_id: NodeId, message: SessionEvent) {
         self.inner
             .clients_ref
@@ -38,6 +99,22 @@ impl RemoteActorSystem {
             .unwrap()
     }
 
+    /// Whether the node has been declared terminated, messages to terminated nodes fail
+    /// immediately with `ActorRefErr::NodeUnavailable`.
+    pub fn is_node_terminated(&self, node_id: NodeId) -> bool {
+        self.inner.terminated_nodes.read().contains(&node_id)
+    }
+
+    /// Returns whether the node's terminated status changed.
+    pub(crate) fn set_node_terminated(&self, node_id: NodeId, terminated: bool) -> bool {
+        let mut terminated_nodes = self.inner.terminated_nodes.write();
+        if terminated {
+            terminated_nodes.insert(node_id)
+        } else {
+            terminated_nodes.remove(&node_id)
+        }
+    }
+
     pub fn current_leader(&self) -> Option<NodeId> {
         let n = self.inner.current_leader.load(Ordering::SeqCst);
         if n >= 0 {
@@ -59,6 +136,97 @@ impl RemoteActorSystem {
         }
     }
 
+    pub fn clear_leader(&self) -> Option<NodeId> {
+        let n = self.inner.current_leader.swap(-1, Ordering::SeqCst);
+        if n >= 0 {
+            Some(n as NodeId)
+        } else {
+            None
+        }
+    }
+
+    /// Whether `leave` has been called, the node is then reported as `Leaving` to the cluster.
+    pub fn is_leaving(&self) -> bool {
+        self.inner.leaving.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn register_shard_host(&self, shard_host: LocalActorRef<ShardHost>) {
+        self.inner.shard_hosts.write().push(shard_host);
+    }
+
+    /// Gracefully leaves the cluster. The shards hosted on this node are handed off to other
+    /// nodes, once their entities have been stopped, then the rest of the cluster is told that
+    /// the node is leaving, before the actor system is shut down.
+    ///
+    /// Returns the number of shards that were handed off.
+    pub async fn leave(&self) -> usize {
+        info!("[node={}] leaving the cluster", self.node_id());
+
+        let shard_hosts = self.inner.shard_hosts.read().clone();
+        let mut handed_off = 0;
+        for shard_host in shard_hosts {
+            let (tx, rx) = oneshot::channel();
+            match shard_host.send(HandoffShards(tx)).await {
+                Ok(_) => handed_off += rx.await.unwrap_or(0),
+                Err(e) => warn!(
+                    "failed to hand off shards, host={:?}, err={}",
+                    shard_host.actor_id(),
+                    e
+                ),
+            }
+        }
+
+        self.inner.leaving.store(true, Ordering::SeqCst);
+        self.announce_leaving().await;
+
+        info!(
+            "[node={}] left the cluster, {} shards handed off",
+            self.node_id(),
+            handed_off
+        );
+
+        self.actor_system().shutdown().await;
+        handed_off
+    }
+
+    /// Pings every other node with `leaving` set, waiting for each to respond (or time out), so
+    /// the cluster knows about it before this node stops responding.
+    async fn announce_leaving(&self) {
+        let node_id = self.node_id();
+        let ping_timeout = self.config().heartbeat_config().ping_timeout;
+        let nodes = self
+            .get_nodes()
+            .await
+            .into_iter()
+            .filter(|n| n.id != node_id && n.status != NodeStatus::Terminated);
+
+        join_all(nodes.map(|node| async move {
+            let message_id = Uuid::new_v4();
+            let (res_tx, res_rx) = oneshot::channel();
+            self.push_request(message_id, res_tx);
+
+            self.notify_node(
+                node.id,
+                SessionEvent::Ping(PingEvent {
+                    message_id: message_id.to_string(),
+                    node_id,
+                    leaving: true,
+                    ..Default::default()
+                }),
+            )
+            .await;
+
+            if tokio::time::timeout(ping_timeout, res_rx).await.is_err() {
+                self.pop_request(message_id);
+                warn!(
+                    "[node={}] node_id={} didn't acknowledge that this node is leaving",
+                    node_id, node.id
+                );
+            }
+        }))
+        .await;
+    }
+
     pub async fn get_remote_client(&self, addr: String) -> Option<RemoteClientRef> {
         self.client_registry()
             .send(NewClient {
@@ -68,6 +236,6 @@ impl RemoteActorSystem {
             })
             .await
             .expect("get client from RemoteClientRegistry")
-            .map(RemoteClientRef::from)
+            .map(|client| RemoteClientRef::new(client, self.config().client_config().clone()))
     }
 }
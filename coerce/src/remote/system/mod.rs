@@ -1,7 +1,9 @@
 use chrono::{DateTime, Utc};
-use std::sync::atomic::AtomicI64;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, AtomicI64};
 use std::sync::Arc;
 
+use crate::actor::context::StopReason;
 use crate::actor::system::ActorSystem;
 use crate::actor::LocalActorRef;
 use crate::remote::actor::{
@@ -10,6 +12,7 @@ use crate::remote::actor::{
 use crate::remote::cluster::builder::client::ClusterClientBuilder;
 use crate::remote::cluster::builder::worker::ClusterWorkerBuilder;
 use crate::remote::cluster::discovery::NodeDiscovery;
+use crate::remote::cluster::sharding::host::ShardHost;
 use crate::remote::heartbeat::Heartbeat;
 use crate::remote::stream::mediator::StreamMediator;
 use crate::remote::system::builder::RemoteActorSystemBuilder;
@@ -45,6 +48,10 @@ pub struct RemoteSystemCore {
     mediator_ref: Option<LocalActorRef<StreamMediator>>,
     config: Arc<RemoteSystemConfig>,
     current_leader: Arc<AtomicNodeId>,
+    terminated_nodes: Arc<parking_lot::RwLock<HashSet<NodeId>>>,
+    role_leaders: Arc<parking_lot::RwLock<HashMap<String, NodeId>>>,
+    leaving: Arc<AtomicBool>,
+    shard_hosts: Arc<parking_lot::RwLock<Vec<LocalActorRef<ShardHost>>>>,
 }
 
 impl RemoteActorSystem {
@@ -55,15 +62,29 @@ impl RemoteActorSystem {
 
 impl RemoteSystemCore {
     pub async fn shutdown(&self) {
-        let _ = self.heartbeat_ref.stop().await;
-        let _ = self.clients_ref.stop().await;
+        let _ = self
+            .heartbeat_ref
+            .stop_with_reason(StopReason::SystemShutdown)
+            .await;
+        let _ = self
+            .clients_ref
+            .stop_with_reason(StopReason::SystemShutdown)
+            .await;
 
         if let Some(mediator_ref) = self.mediator_ref.as_ref() {
-            let _ = mediator_ref.stop().await;
+            let _ = mediator_ref
+                .stop_with_reason(StopReason::SystemShutdown)
+                .await;
         }
 
-        let _ = self.discovery_ref.stop().await;
-        let _ = self.registry_ref.stop().await;
+        let _ = self
+            .discovery_ref
+            .stop_with_reason(StopReason::SystemShutdown)
+            .await;
+        let _ = self
+            .registry_ref
+            .stop_with_reason(StopReason::SystemShutdown)
+            .await;
 
         info!("shutdown complete");
     }
@@ -96,6 +117,14 @@ impl RemoteActorSystem {
         self.inner.config.node_tag()
     }
 
+    pub fn node_roles(&self) -> &[String] {
+        self.inner.config.roles()
+    }
+
+    pub fn has_role(&self, role: &str) -> bool {
+        self.node_roles().iter().any(|r| r == role)
+    }
+
     pub fn node_id(&self) -> NodeId {
         self.inner.node_id
     }
@@ -1,8 +1,25 @@
-// use crate::remote::raft::RaftSystem;
-// use crate::remote::system::RemoteActorSystem;
-//
-// impl RemoteActorSystem {
-//     pub fn raft(&self) -> Option<&RaftSystem> {
-//         self.inner.raft.as_ref().map(|s| s.as_ref())
-//     }
-// }
+use crate::actor::LocalActorRef;
+use crate::remote::raft::message::Propose;
+use crate::remote::raft::{raft_actor_id, Raft, RaftError};
+use crate::remote::system::RemoteActorSystem;
+use tokio::sync::oneshot;
+
+impl RemoteActorSystem {
+    pub async fn raft(&self) -> Option<LocalActorRef<Raft>> {
+        self.actor_system()
+            .get_tracked_actor::<Raft>(raft_actor_id(self.node_id()))
+            .await
+    }
+
+    /// Proposes a command to the local Raft node, returning the result of applying it to the
+    /// state machine once committed. Fails with `RaftError::NotLeader` unless this node is the leader.
+    pub async fn raft_propose(&self, command: Vec<u8>) -> Result<Vec<u8>, RaftError> {
+        let raft = self.raft().await.ok_or(RaftError::Unavailable)?;
+
+        let (result_tx, result_rx) = oneshot::channel();
+        raft.notify(Propose { command, result_tx })
+            .map_err(|_| RaftError::Unavailable)?;
+
+        result_rx.await.unwrap_or(Err(RaftError::Unavailable))
+    }
+}
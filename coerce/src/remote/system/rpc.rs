@@ -1,3 +1,4 @@
+use bytes::Bytes;
 use futures::SinkExt;
 use protobuf::Message as ProtoMessage;
 use std::error::Error;
@@ -82,7 +83,7 @@ impl RemoteActorSystem {
         message_id: Uuid,
         event: SessionEvent,
         node_id: NodeId,
-    ) -> Result<Vec<u8>, NodeRpcErr> {
+    ) -> Result<Bytes, NodeRpcErr> {
         let (res_tx, res_rx) = oneshot::channel();
 
         trace!(target: "NodeRpc", "message_id={}, created channel, storing request", &message_id);
@@ -102,7 +103,7 @@ impl RemoteActorSystem {
         }
     }
 
-    pub async fn notify_raw_rpc_result(&self, request_id: Uuid, result: Vec<u8>, node_id: NodeId) {
+    pub async fn notify_raw_rpc_result(&self, request_id: Uuid, result: Bytes, node_id: NodeId) {
         if node_id == self.node_id() {
             let result_sender = self.pop_request(request_id);
             if let Some(result_sender) = result_sender {
@@ -157,8 +158,8 @@ impl RemoteActorSystem {
         &self,
         identifier: &str,
         actor_id: ActorId,
-        buffer: &[u8],
-    ) -> Result<Vec<u8>, ActorRefErr> {
+        buffer: Bytes,
+    ) -> Result<Bytes, ActorRefErr> {
         let handler = self.inner.config.message_handler(identifier);
 
         if let Some(handler) = handler {
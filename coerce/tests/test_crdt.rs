@@ -0,0 +1,139 @@
+use coerce::remote::cluster::crdt::gcounter::GCounter;
+use coerce::remote::cluster::crdt::lww_register::LWWRegister;
+use coerce::remote::cluster::crdt::message::DataEnvelope;
+use coerce::remote::cluster::crdt::ormap::ORMap;
+use coerce::remote::cluster::crdt::orset::ORSet;
+use coerce::remote::cluster::crdt::pncounter::PNCounter;
+use coerce::remote::cluster::crdt::{Consistency, Crdt, DataErr};
+
+#[test]
+pub fn test_gcounter_merge() {
+    let mut a = GCounter::new();
+    let mut b = GCounter::new();
+
+    a.increment(1, 3);
+    b.increment(2, 5);
+    b.increment(1, 1);
+
+    let mut merged = a.clone();
+    merged.merge(&b);
+    merged.merge(&b);
+
+    assert_eq!(merged.value(), 8);
+    assert_eq!(merged.node_value(1), 3);
+
+    b.merge(&a);
+    assert_eq!(merged, b);
+}
+
+#[test]
+pub fn test_pncounter_merge() {
+    let mut a = PNCounter::new();
+    let mut b = PNCounter::new();
+
+    a.increment(1, 10);
+    b.decrement(2, 4);
+    a.decrement(1, 1);
+
+    a.merge(&b);
+    b.merge(&a);
+
+    assert_eq!(a.value(), 5);
+    assert_eq!(a, b);
+}
+
+#[test]
+pub fn test_lww_register_merge() {
+    let mut a = LWWRegister::new();
+    let mut b = LWWRegister::new();
+
+    a.set(1, "first".to_string());
+    b.merge(&a);
+    b.set(2, "second".to_string());
+
+    a.merge(&b);
+    assert_eq!(a.get(), Some(&"second".to_string()));
+    assert!(a.timestamp() > 0);
+
+    b.merge(&LWWRegister::new());
+    assert_eq!(b.get(), Some(&"second".to_string()));
+}
+
+#[test]
+pub fn test_orset_add_wins() {
+    let mut a = ORSet::new();
+    a.add(1, "flag-a".to_string());
+    a.add(1, "flag-b".to_string());
+
+    let mut b = a.clone();
+
+    // Concurrently, node 1 removes flag-a while node 2 re-adds it
+    a.remove(&"flag-a".to_string());
+    b.add(2, "flag-a".to_string());
+
+    a.merge(&b);
+    b.merge(&a);
+
+    assert!(a.contains(&"flag-a".to_string()));
+    assert_eq!(a, b);
+    assert_eq!(a.len(), 2);
+}
+
+#[test]
+pub fn test_orset_remove() {
+    let mut a = ORSet::new();
+    a.add(1, 1);
+    a.add(1, 2);
+
+    let mut b = a.clone();
+    b.remove(&1);
+
+    a.merge(&b);
+    assert!(!a.contains(&1));
+    assert_eq!(a.elements().collect::<Vec<_>>(), vec![&2]);
+
+    // A stale replica doesn't bring the removed element back
+    let stale = b.clone();
+    b.clear();
+    b.merge(&stale);
+    assert!(b.is_empty());
+}
+
+#[test]
+pub fn test_ormap_merge() {
+    let mut a: ORMap<String, GCounter> = ORMap::new();
+    let mut b: ORMap<String, GCounter> = ORMap::new();
+
+    a.update(1, "online".to_string(), |c| c.increment(1, 2));
+    b.update(2, "online".to_string(), |c| c.increment(2, 3));
+    b.update(2, "away".to_string(), |c| c.increment(2, 1));
+
+    a.merge(&b);
+    assert_eq!(a.get(&"online".to_string()).map(|c| c.value()), Some(5));
+    assert_eq!(a.len(), 2);
+
+    b.merge(&a);
+    b.remove(&"away".to_string());
+    a.merge(&b);
+
+    assert!(!a.contains_key(&"away".to_string()));
+    assert_eq!(a.keys().collect::<Vec<_>>(), vec![&"online".to_string()]);
+}
+
+#[test]
+pub fn test_data_envelope_round_trip() {
+    let mut counter = GCounter::new();
+    counter.increment(1, 7);
+
+    let envelope = DataEnvelope::new("counter".to_string(), &counter).unwrap();
+    assert_eq!(envelope.value::<GCounter>(), Ok(counter));
+    assert_eq!(envelope.value::<PNCounter>(), Err(DataErr::TypeMismatch));
+}
+
+#[test]
+pub fn test_consistency_required_replicas() {
+    assert_eq!(Consistency::Local.required_replicas(5), 1);
+    assert_eq!(Consistency::Majority.required_replicas(5), 3);
+    assert_eq!(Consistency::Majority.required_replicas(4), 3);
+    assert_eq!(Consistency::All.required_replicas(5), 5);
+}
@@ -1,6 +1,7 @@
-use coerce::actor::context::ActorStatus;
+use coerce::actor::context::{ActorContext, ActorStatus, StopReason};
+use coerce::actor::message::{Handler, Message};
 use coerce::actor::system::ActorSystem;
-use coerce::actor::{ActorRefErr, IntoActor};
+use coerce::actor::{Actor, ActorId, ActorRefErr, IntoActor, LocalActorRef};
 
 use std::time::Duration;
 use tokio::time::sleep;
@@ -73,3 +74,111 @@ pub async fn test_actor_lifecycle_stopping() {
     assert_eq!(stopping, Ok(()));
     assert_eq!(msg_send, Err(ActorRefErr::InvalidRef));
 }
+
+struct StopReasonActor {
+    on_stopped: Option<tokio::sync::oneshot::Sender<Option<StopReason>>>,
+}
+
+#[async_trait]
+impl Actor for StopReasonActor {
+    async fn stopped(&mut self, ctx: &mut ActorContext) {
+        let _ = self
+            .on_stopped
+            .take()
+            .unwrap()
+            .send(ctx.stop_reason().cloned());
+    }
+}
+
+#[tokio::test]
+pub async fn test_actor_lifecycle_stop_reason() {
+    let system = ActorSystem::new();
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    let actor_ref = StopReasonActor {
+        on_stopped: Some(tx),
+    }
+    .into_anon_actor(Some("stop-reason-actor"), &system)
+    .await
+    .unwrap();
+
+    let stopping = actor_ref.stop_with_reason(StopReason::Passivated).await;
+
+    assert_eq!(stopping, Ok(()));
+    assert_eq!(rx.await.unwrap(), Some(StopReason::Passivated));
+}
+
+#[tokio::test]
+pub async fn test_actor_lifecycle_system_shutdown_reason() {
+    let system = ActorSystem::new();
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    let _actor_ref = StopReasonActor {
+        on_stopped: Some(tx),
+    }
+    .into_actor(Some("stop-reason-actor"), &system)
+    .await
+    .unwrap();
+
+    system.shutdown().await;
+
+    assert_eq!(rx.await.unwrap(), Some(StopReason::SystemShutdown));
+}
+
+struct Watcher {
+    on_watched_stopped: Option<tokio::sync::oneshot::Sender<(ActorId, StopReason)>>,
+}
+
+struct WatchActor(LocalActorRef<TestActor>);
+
+impl Message for WatchActor {
+    type Result = ();
+}
+
+#[async_trait]
+impl Actor for Watcher {
+    async fn on_watched_stopped(
+        &mut self,
+        id: &ActorId,
+        reason: &StopReason,
+        _ctx: &mut ActorContext,
+    ) {
+        let _ = self
+            .on_watched_stopped
+            .take()
+            .unwrap()
+            .send((id.clone(), reason.clone()));
+    }
+}
+
+#[async_trait]
+impl Handler<WatchActor> for Watcher {
+    async fn handle(&mut self, message: WatchActor, ctx: &mut ActorContext) {
+        let _ = ctx.watch(&message.0);
+    }
+}
+
+#[tokio::test]
+pub async fn test_actor_lifecycle_watch() {
+    let system = ActorSystem::new();
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    let watcher = Watcher {
+        on_watched_stopped: Some(tx),
+    }
+    .into_anon_actor(Some("watcher"), &system)
+    .await
+    .unwrap();
+
+    let watched = TestActor::new()
+        .into_anon_actor(Some("watched"), &system)
+        .await
+        .unwrap();
+
+    watcher.send(WatchActor(watched.clone())).await.unwrap();
+
+    // ensure the watch request has been processed before stopping
+    let _ = watched.status().await;
+    let _ = watched.stop_with_reason(StopReason::NodeLost).await;
+
+    let (actor_id, reason) = rx.await.unwrap();
+    assert_eq!(actor_id, watched.actor_id().clone());
+    assert_eq!(reason, StopReason::NodeLost);
+}
@@ -1,8 +1,17 @@
 use coerce::actor::context::ActorContext;
-use coerce::actor::message::{Envelope, EnvelopeType, Handler, Message, MessageWrapErr};
+use coerce::actor::message::{
+    Bytes, Envelope, EnvelopeType, Handler, Message, MessageUnwrapErr, MessageWrapErr,
+};
 use coerce::actor::system::ActorSystem;
 use coerce::actor::{Actor, IntoActor, Receiver};
+use coerce::persistent::journal::snapshot::Snapshot;
+use coerce::remote::cluster::sharding::proto::sharding as proto;
+use coerce_macros::{
+    BincodeMessage, BincodeSnapshot, JsonMessage, JsonSnapshot, MsgPackMessage, MsgPackSnapshot,
+    ProtoMessage,
+};
 use futures::FutureExt;
+use std::fmt::Debug;
 use util::*;
 
 pub mod util;
@@ -192,7 +201,7 @@ pub async fn test_actor_receiver() {
     )
 }
 
-#[derive(coerce_macros::JsonMessage, Serialize, Deserialize)]
+#[derive(JsonMessage, Serialize, Deserialize)]
 #[result("()")]
 struct MessageOne {}
 
@@ -222,3 +231,197 @@ pub async fn test_messages_into_remote_envelope() {
         Some(MessageWrapErr::NotTransmittable)
     );
 }
+
+#[derive(ProtoMessage, Debug, Eq, PartialEq)]
+#[proto(proto::AllocateShard)]
+#[result(ShardLocation, proto::RemoteShard)]
+pub struct LocateShard {
+    shard_id: u32,
+    rebalancing: bool,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct ShardLocation {
+    shard_id: u32,
+    node_id: u64,
+}
+
+impl From<&LocateShard> for proto::AllocateShard {
+    fn from(msg: &LocateShard) -> Self {
+        proto::AllocateShard {
+            shard_id: msg.shard_id,
+            rebalancing: msg.rebalancing,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::AllocateShard> for LocateShard {
+    fn from(msg: proto::AllocateShard) -> Self {
+        LocateShard {
+            shard_id: msg.shard_id,
+            rebalancing: msg.rebalancing,
+        }
+    }
+}
+
+impl From<ShardLocation> for proto::RemoteShard {
+    fn from(location: ShardLocation) -> Self {
+        proto::RemoteShard {
+            shard_id: location.shard_id,
+            node_id: location.node_id,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::RemoteShard> for ShardLocation {
+    fn from(location: proto::RemoteShard) -> Self {
+        ShardLocation {
+            shard_id: location.shard_id,
+            node_id: location.node_id,
+        }
+    }
+}
+
+#[derive(ProtoMessage, Debug, Eq, PartialEq)]
+#[proto(proto::StopShard)]
+pub struct StopShard {
+    shard_id: u32,
+}
+
+impl From<&StopShard> for proto::StopShard {
+    fn from(msg: &StopShard) -> Self {
+        proto::StopShard {
+            shard_id: msg.shard_id,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::StopShard> for StopShard {
+    fn from(msg: proto::StopShard) -> Self {
+        StopShard {
+            shard_id: msg.shard_id,
+        }
+    }
+}
+
+#[tokio::test]
+pub async fn test_proto_message_round_trip() {
+    let message = LocateShard {
+        shard_id: 42,
+        rebalancing: true,
+    };
+
+    let bytes = message.as_bytes().expect("serialise message");
+    assert_eq!(LocateShard::from_bytes(bytes.into()), Ok(message));
+
+    let result = ShardLocation {
+        shard_id: 42,
+        node_id: 7,
+    };
+
+    let bytes = LocateShard::write_remote_result(result).expect("serialise result");
+    assert_eq!(
+        LocateShard::read_remote_result(bytes.into()),
+        Ok(ShardLocation {
+            shard_id: 42,
+            node_id: 7
+        })
+    );
+
+    let stop_shard = StopShard { shard_id: 1 };
+    let bytes = stop_shard.as_bytes().expect("serialise message");
+    assert_eq!(StopShard::from_bytes(bytes.into()), Ok(stop_shard));
+    assert_eq!(StopShard::write_remote_result(()), Ok(vec![]));
+    assert_eq!(StopShard::read_remote_result(Bytes::new()), Ok(()));
+}
+
+#[tokio::test]
+pub async fn test_proto_message_invalid_bytes() {
+    let result = LocateShard::from_bytes(Bytes::from_static(&[0xff, 0xff, 0xff]));
+
+    assert_eq!(result.err(), Some(MessageUnwrapErr::DeserializationErr));
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub struct SerdeMessage {
+    id: u64,
+    name: String,
+    tags: Vec<String>,
+}
+
+#[derive(JsonMessage, Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+#[result("SerdeMessage")]
+pub struct JsonRoundTrip(SerdeMessage);
+
+#[derive(BincodeMessage, Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+#[result("SerdeMessage")]
+pub struct BincodeRoundTrip(SerdeMessage);
+
+#[derive(MsgPackMessage, Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+#[result("SerdeMessage")]
+pub struct MsgPackRoundTrip(SerdeMessage);
+
+#[derive(JsonSnapshot, Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub struct JsonSnapshotRoundTrip(SerdeMessage);
+
+#[derive(BincodeSnapshot, Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub struct BincodeSnapshotRoundTrip(SerdeMessage);
+
+#[derive(MsgPackSnapshot, Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub struct MsgPackSnapshotRoundTrip(SerdeMessage);
+
+fn serde_message() -> SerdeMessage {
+    SerdeMessage {
+        id: 1,
+        name: "coerce".to_string(),
+        tags: vec!["actor".to_string(), "runtime".to_string()],
+    }
+}
+
+fn assert_message_round_trip<M: Message<Result = SerdeMessage> + Debug + Eq>(message: M) {
+    let bytes = message.as_bytes().expect("serialise message");
+    assert_eq!(M::from_bytes(bytes.into()), Ok(message));
+
+    let result = M::write_remote_result(serde_message()).expect("serialise result");
+    assert_eq!(M::read_remote_result(result.into()), Ok(serde_message()));
+}
+
+fn assert_snapshot_round_trip<S: Snapshot + Debug + Eq + Clone>(snapshot: S) {
+    let bytes = snapshot
+        .clone()
+        .into_remote_envelope()
+        .expect("serialise snapshot")
+        .into_bytes();
+
+    assert_eq!(S::from_remote_envelope(bytes).ok(), Some(snapshot));
+}
+
+#[test]
+pub fn test_serde_message_round_trip() {
+    assert_message_round_trip(JsonRoundTrip(serde_message()));
+    assert_message_round_trip(BincodeRoundTrip(serde_message()));
+    assert_message_round_trip(MsgPackRoundTrip(serde_message()));
+}
+
+#[test]
+pub fn test_serde_snapshot_round_trip() {
+    assert_snapshot_round_trip(JsonSnapshotRoundTrip(serde_message()));
+    assert_snapshot_round_trip(BincodeSnapshotRoundTrip(serde_message()));
+    assert_snapshot_round_trip(MsgPackSnapshotRoundTrip(serde_message()));
+}
+
+#[test]
+pub fn test_serde_message_invalid_bytes() {
+    assert_eq!(
+        BincodeRoundTrip::from_bytes(Bytes::from_static(&[0xff])).err(),
+        Some(MessageUnwrapErr::DeserializationErr)
+    );
+
+    assert_eq!(
+        MsgPackRoundTrip::from_bytes(Bytes::from_static(&[0xc1])).err(),
+        Some(MessageUnwrapErr::DeserializationErr)
+    );
+}
@@ -0,0 +1,289 @@
+use coerce::actor::system::ActorSystem;
+use coerce::actor::ActorRefErr;
+use coerce::remote::net::auth::jwt::{Algorithm, DecodingKey, EncodingKey};
+use coerce::remote::net::auth::{
+    AuthChallenge, AuthErr, Authenticator, AuthenticatorRef, HmacAuthenticator, JwtAuthenticator,
+};
+use coerce::remote::net::codec::NetworkCodec;
+use coerce::remote::net::message::{ClientEvent, SessionEvent};
+use coerce::remote::net::proto::network::{FindActorEvent, IdentifyEvent, PingEvent};
+use coerce::remote::net::StreamData;
+use coerce::remote::system::RemoteActorSystem;
+use futures::{SinkExt, StreamExt};
+use std::sync::Arc;
+use tokio::net::TcpStream;
+use tokio_util::codec::Framed;
+use uuid::Uuid;
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+async fn auth_node(id: u64, authenticator: Option<AuthenticatorRef>) -> RemoteActorSystem {
+    RemoteActorSystem::builder()
+        .with_tag(format!("auth-node-{}", id))
+        .with_id(id)
+        .with_actor_system(ActorSystem::new())
+        .configure(move |c| match authenticator {
+            Some(authenticator) => c.authenticator(authenticator),
+            None => c,
+        })
+        .build()
+        .await
+}
+
+async fn start_cluster(
+    authenticator_a: Option<AuthenticatorRef>,
+    authenticator_b: Option<AuthenticatorRef>,
+    port: u16,
+) -> (RemoteActorSystem, RemoteActorSystem) {
+    let remote = auth_node(1, authenticator_a).await;
+    let remote_2 = auth_node(2, authenticator_b).await;
+
+    remote
+        .clone()
+        .cluster_worker()
+        .listen_addr(format!("localhost:{}", port))
+        .start()
+        .await;
+
+    remote_2
+        .clone()
+        .cluster_worker()
+        .listen_addr(format!("localhost:{}", port + 1))
+        .with_seed_addr(format!("localhost:{}", port))
+        .start()
+        .await;
+
+    (remote, remote_2)
+}
+
+#[tokio::test]
+pub async fn test_remote_auth_hmac_cluster_formation() {
+    util::create_trace_logger();
+
+    let authenticator: AuthenticatorRef = Arc::new(HmacAuthenticator::new("cluster-secret"));
+    let (remote, remote_2) =
+        start_cluster(Some(authenticator.clone()), Some(authenticator), 30401).await;
+
+    assert_eq!(remote.get_nodes().await.len(), 2);
+    assert_eq!(remote_2.get_nodes().await.len(), 2);
+}
+
+#[tokio::test]
+pub async fn test_remote_auth_jwt_cluster_formation() {
+    util::create_trace_logger();
+
+    let authenticator: AuthenticatorRef = Arc::new(JwtAuthenticator::from_secret(b"jwt-secret"));
+    let (remote, remote_2) =
+        start_cluster(Some(authenticator.clone()), Some(authenticator), 30403).await;
+
+    assert_eq!(remote.get_nodes().await.len(), 2);
+    assert_eq!(remote_2.get_nodes().await.len(), 2);
+}
+
+#[tokio::test]
+pub async fn test_remote_auth_rejects_invalid_credentials() {
+    util::create_trace_logger();
+
+    let (remote, _remote_2) = start_cluster(
+        Some(Arc::new(HmacAuthenticator::new("cluster-secret"))),
+        Some(Arc::new(HmacAuthenticator::new("wrong-secret"))),
+        30405,
+    )
+    .await;
+
+    assert_eq!(remote.get_nodes().await.len(), 1);
+}
+
+#[tokio::test]
+pub async fn test_remote_auth_rejects_unauthenticated_requests() {
+    util::create_trace_logger();
+
+    let remote = auth_node(1, Some(Arc::new(HmacAuthenticator::new("cluster-secret")))).await;
+    remote
+        .clone()
+        .cluster_worker()
+        .listen_addr("localhost:30407")
+        .start()
+        .await;
+
+    let stream = TcpStream::connect("localhost:30407").await.unwrap();
+    let mut framed = Framed::new(stream, NetworkCodec::default());
+
+    let identity = framed.next().await.unwrap().unwrap();
+    match ClientEvent::read_from_bytes(identity) {
+        Some(ClientEvent::Identity(identity)) => assert!(!identity.auth_challenge.is_empty()),
+        _ => panic!("expected identity"),
+    }
+
+    let find_actor = SessionEvent::FindActor(FindActorEvent {
+        message_id: Uuid::new_v4().to_string(),
+        actor_id: "actor".to_string(),
+        ..Default::default()
+    });
+
+    framed
+        .send(&find_actor.write_to_bytes().unwrap())
+        .await
+        .unwrap();
+
+    let error = framed.next().await.unwrap().unwrap();
+    match ClientEvent::read_from_bytes(error) {
+        Some(ClientEvent::Err(e)) => {
+            let error: ActorRefErr = e.error.unwrap().into();
+            assert!(matches!(error, ActorRefErr::AuthenticationFailed));
+        }
+        _ => panic!("expected authentication error"),
+    }
+
+    assert!(matches!(framed.next().await, None | Some(Err(_))));
+}
+
+#[tokio::test]
+pub async fn test_remote_auth_rejects_events_for_other_nodes() {
+    util::create_trace_logger();
+
+    let authenticator: AuthenticatorRef = Arc::new(HmacAuthenticator::new("cluster-secret"));
+    let (remote, _remote_2) = start_cluster(
+        Some(authenticator.clone()),
+        Some(authenticator.clone()),
+        30409,
+    )
+    .await;
+
+    let stream = TcpStream::connect("localhost:30409").await.unwrap();
+    let mut framed = Framed::new(stream, NetworkCodec::default());
+
+    let identity = framed.next().await.unwrap().unwrap();
+    let challenge = match ClientEvent::read_from_bytes(identity) {
+        Some(ClientEvent::Identity(identity)) => identity.auth_challenge,
+        _ => panic!("expected identity"),
+    };
+
+    // Authenticates as node 3..
+    let token = authenticator
+        .credentials(&AuthChallenge {
+            node_id: 3,
+            node_tag: "auth-node-3",
+            challenge: &challenge,
+        })
+        .unwrap();
+
+    let identify = SessionEvent::Identify(IdentifyEvent {
+        source_node_id: 3,
+        source_node_tag: "auth-node-3".to_string(),
+        token,
+        message_id: Uuid::new_v4().to_string(),
+        protocol: Some((&remote.config().protocol()).into()).into(),
+        ..Default::default()
+    });
+
+    framed
+        .send(&identify.write_to_bytes().unwrap())
+        .await
+        .unwrap();
+
+    let result = framed.next().await.unwrap().unwrap();
+    assert!(matches!(
+        ClientEvent::read_from_bytes(result),
+        Some(ClientEvent::Result(_))
+    ));
+
+    // ..then claims node 2 has terminated
+    let ping = SessionEvent::Ping(PingEvent {
+        message_id: Uuid::new_v4().to_string(),
+        node_id: 2,
+        system_terminated: true,
+        ..Default::default()
+    });
+
+    framed.send(&ping.write_to_bytes().unwrap()).await.unwrap();
+
+    let error = framed.next().await.unwrap().unwrap();
+    match ClientEvent::read_from_bytes(error) {
+        Some(ClientEvent::Err(e)) => {
+            let error: ActorRefErr = e.error.unwrap().into();
+            assert!(matches!(error, ActorRefErr::AuthenticationFailed));
+        }
+        _ => panic!("expected authentication error"),
+    }
+
+    assert!(matches!(framed.next().await, None | Some(Err(_))));
+    assert!(!remote.is_node_terminated(2));
+}
+
+#[test]
+pub fn test_hmac_authenticator_secret_rotation() {
+    let challenge = AuthChallenge {
+        node_id: 1,
+        node_tag: "node-1",
+        challenge: b"challenge",
+    };
+
+    let old = HmacAuthenticator::new("old-secret");
+    let new = HmacAuthenticator::new("new-secret").accept_secret("old-secret");
+
+    let credentials = old.credentials(&challenge).unwrap();
+    assert!(new.verify(&challenge, &credentials).is_ok());
+    assert!(matches!(
+        HmacAuthenticator::new("new-secret").verify(&challenge, &credentials),
+        Err(AuthErr::InvalidCredentials)
+    ));
+
+    let other_challenge = AuthChallenge {
+        challenge: b"other-challenge",
+        ..challenge
+    };
+
+    assert!(matches!(
+        new.verify(&other_challenge, &credentials),
+        Err(AuthErr::InvalidCredentials)
+    ));
+}
+
+#[test]
+pub fn test_jwt_authenticator_verifies_node_and_key_id() {
+    let challenge = AuthChallenge {
+        node_id: 1,
+        node_tag: "node-1",
+        challenge: b"challenge",
+    };
+
+    let node_1 = JwtAuthenticator::new(
+        Algorithm::HS256,
+        EncodingKey::from_secret(b"key-1"),
+        DecodingKey::from_secret(b"key-1"),
+    )
+    .with_key_id("key-1")
+    .with_issuer("coerce");
+
+    let server = JwtAuthenticator::from_secret(b"key-2")
+        .with_key_id("key-2")
+        .with_decoding_key("key-1", DecodingKey::from_secret(b"key-1"))
+        .with_issuer("coerce");
+
+    let credentials = node_1.credentials(&challenge).unwrap();
+    assert!(server.verify(&challenge, &credentials).is_ok());
+
+    let impersonated = AuthChallenge {
+        node_id: 2,
+        ..challenge
+    };
+
+    assert!(matches!(
+        server.verify(&impersonated, &credentials),
+        Err(AuthErr::NodeMismatch)
+    ));
+
+    let unknown_key = JwtAuthenticator::from_secret(b"key-3").with_key_id("key-3");
+    let credentials = unknown_key.credentials(&challenge).unwrap();
+    assert!(matches!(
+        server.verify(&challenge, &credentials),
+        Err(AuthErr::InvalidCredentials)
+    ));
+}
@@ -0,0 +1,145 @@
+use coerce::actor::system::ActorSystem;
+use coerce::actor::{ActorRefErr, LocalActorRef};
+use coerce::remote::actor::RemoteResponse;
+use coerce::remote::net::client::config::RemoteClientConfig;
+use coerce::remote::net::client::send::Write;
+use coerce::remote::net::client::{ClientType, RemoteClient};
+use coerce::remote::net::message::SessionEvent;
+use coerce::remote::net::proto::network::MessageRequest;
+use coerce::remote::net::transport::{MemoryTransport, TransportRef};
+use coerce::remote::system::RemoteActorSystem;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::oneshot;
+use uuid::Uuid;
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+fn client_config(max_reconnect_attempts: usize) -> RemoteClientConfig {
+    RemoteClientConfig {
+        connect_timeout: Duration::from_millis(100),
+        reconnect_initial_delay: Duration::from_millis(10),
+        reconnect_jitter: 0.0,
+        max_reconnect_attempts: Some(max_reconnect_attempts),
+        ..Default::default()
+    }
+}
+
+async fn client_node(
+    transport: TransportRef,
+    client_config: RemoteClientConfig,
+) -> RemoteActorSystem {
+    RemoteActorSystem::builder()
+        .with_tag("client-config-node")
+        .with_id(1)
+        .with_actor_system(ActorSystem::new())
+        .with_client_config(client_config)
+        .configure(move |c| c.transport(transport.clone()))
+        .build()
+        .await
+}
+
+async fn client(addr: &str, system: &RemoteActorSystem) -> LocalActorRef<RemoteClient> {
+    RemoteClient::new(addr.to_string(), 0, system.clone(), ClientType::Worker).await
+}
+
+fn request(system: &RemoteActorSystem) -> (SessionEvent, oneshot::Receiver<RemoteResponse>) {
+    let (res_tx, res_rx) = oneshot::channel();
+    let message_id = Uuid::new_v4();
+    system.push_request(message_id, res_tx);
+
+    let event = SessionEvent::NotifyActor(MessageRequest {
+        message_id: message_id.to_string(),
+        handler_type: "EchoActor.Echo".to_string(),
+        actor_id: "echo-actor".to_string(),
+        requires_response: true,
+        ..Default::default()
+    });
+
+    (event, res_rx)
+}
+
+async fn assert_node_unavailable(res_rx: oneshot::Receiver<RemoteResponse>) {
+    let res = tokio::time::timeout(Duration::from_secs(2), res_rx)
+        .await
+        .expect("request was not failed")
+        .unwrap();
+
+    match res {
+        RemoteResponse::Err(e) => assert_eq!(e, ActorRefErr::NodeUnavailable(0)),
+        RemoteResponse::Ok(_) => panic!("expected request to fail"),
+    }
+}
+
+#[test]
+pub fn test_remote_client_reconnect_backoff() {
+    let config = RemoteClientConfig {
+        reconnect_initial_delay: Duration::from_millis(100),
+        reconnect_max_delay: Duration::from_millis(500),
+        reconnect_multiplier: 2.0,
+        reconnect_jitter: 0.0,
+        ..Default::default()
+    };
+
+    assert_eq!(config.reconnect_delay(1), Duration::from_millis(100));
+    assert_eq!(config.reconnect_delay(2), Duration::from_millis(200));
+    assert_eq!(config.reconnect_delay(3), Duration::from_millis(400));
+    assert_eq!(config.reconnect_delay(4), Duration::from_millis(500));
+    assert_eq!(config.reconnect_delay(100), Duration::from_millis(500));
+
+    let config = RemoteClientConfig {
+        reconnect_jitter: 0.5,
+        ..config
+    };
+
+    for _ in 0..100 {
+        let delay = config.reconnect_delay(2);
+        assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(300));
+    }
+
+    assert!(!RemoteClientConfig::default().is_unreachable(usize::MAX));
+    assert!(!client_config(3).is_unreachable(2));
+    assert!(client_config(3).is_unreachable(3));
+}
+
+#[tokio::test]
+pub async fn test_remote_client_unreachable_after_max_attempts() {
+    util::create_trace_logger();
+
+    let system = client_node(Arc::new(MemoryTransport::new()), client_config(3)).await;
+    let client = client("unreachable-node", &system).await;
+
+    let (event, res_rx) = request(&system);
+    client.notify(Write(event)).unwrap();
+
+    // Buffered requests are failed once the node is marked as unreachable
+    assert_node_unavailable(res_rx).await;
+
+    // ..and any further requests are failed straight away
+    let (event, res_rx) = request(&system);
+    client.notify(Write(event)).unwrap();
+    assert_node_unavailable(res_rx).await;
+}
+
+#[tokio::test]
+pub async fn test_remote_client_connect_timeout() {
+    util::create_trace_logger();
+
+    let transport: TransportRef = Arc::new(MemoryTransport::new());
+
+    // Connections to the listener succeed, but nothing is ever sent back
+    let _listener = transport.listen("silent-node").await.unwrap();
+
+    let system = client_node(transport, client_config(1)).await;
+    let client = client("silent-node", &system).await;
+
+    let (event, res_rx) = request(&system);
+    client.notify(Write(event)).unwrap();
+    assert_node_unavailable(res_rx).await;
+}
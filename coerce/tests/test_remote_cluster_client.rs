@@ -9,10 +9,23 @@ extern crate async_trait;
 #[macro_use]
 extern crate coerce_macros;
 
+use coerce::actor::scheduler::ActorType::Tracked;
 use coerce::actor::system::ActorSystem;
-use coerce::actor::{ActorCreationErr, ActorFactory, ActorRecipe};
+use coerce::actor::{ActorCreationErr, ActorFactory, ActorRecipe, ActorRef, IntoActorId};
 
-use coerce::remote::system::RemoteActorSystem;
+use coerce::persistent::journal::provider::inmemory::InMemoryStorageProvider;
+use coerce::persistent::Persistence;
+use coerce::remote::cluster::builder::client::ClusterClientErr;
+use coerce::remote::cluster::client::RemoteClusterClient;
+use coerce::remote::cluster::sharding::host::stats::GetStats;
+use coerce::remote::cluster::sharding::Sharding;
+use coerce::remote::net::server::RemoteServer;
+use coerce::remote::net::transport::{MemoryTransport, TransportRef};
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use coerce::remote::RemoteActorRef;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
 use util::*;
 
 #[derive(Serialize, Deserialize)]
@@ -23,7 +36,7 @@ struct TestActorFactory;
 
 impl ActorRecipe for TestActorRecipe {
     fn read_from_bytes(bytes: &Vec<u8>) -> Option<Self> {
-        serde_json::from_slice(&bytes).unwrap()
+        serde_json::from_slice(bytes).ok()
     }
 
     fn write_to_bytes(&self) -> Option<Vec<u8>> {
@@ -86,3 +99,228 @@ pub async fn test_remote_cluster_client_get_actor() {
 //
 //     assert_eq!(actor.is_some(), false);
 // }
+
+const NODE_1: &str = "cluster-client-node-1";
+const NODE_2: &str = "cluster-client-node-2";
+const NODE_3: &str = "cluster-client-node-3";
+
+async fn system(id: NodeId, transport: TransportRef) -> RemoteActorSystem {
+    let system =
+        ActorSystem::new().to_persistent(Persistence::from(InMemoryStorageProvider::new()));
+    RemoteActorSystem::builder()
+        .with_tag(format!("cluster-client-system-{}", id))
+        .with_id(id)
+        .with_actor_system(system)
+        .with_handlers(|handlers| {
+            handlers
+                .with_actor(TestActorFactory)
+                .with_handler::<TestActor, GetStatusRequest>("GetStatusRequest")
+                .with_handler::<TestActor, SetStatusRequest>("SetStatusRequest")
+        })
+        .configure(move |c| c.transport(transport.clone()))
+        .build()
+        .await
+}
+
+async fn worker(
+    id: NodeId,
+    listen_addr: &str,
+    seed_addr: Option<&str>,
+    transport: &TransportRef,
+) -> (RemoteActorSystem, RemoteServer) {
+    let remote = system(id, transport.clone()).await;
+    let mut builder = remote.clone().cluster_worker().listen_addr(listen_addr);
+    if let Some(seed_addr) = seed_addr {
+        builder = builder.with_seed_addr(seed_addr);
+    }
+
+    let server = builder.start().await;
+    (remote, server)
+}
+
+async fn member_ids(client: &RemoteClusterClient) -> HashSet<NodeId> {
+    client.members().await.into_iter().map(|n| n.id).collect()
+}
+
+async fn wait_for_members(client: &RemoteClusterClient, expected: &[NodeId]) -> bool {
+    let expected: HashSet<NodeId> = expected.iter().copied().collect();
+    let expected = &expected;
+    wait_until(
+        || async move {
+            if &member_ids(client).await == expected {
+                return true;
+            }
+
+            client.refresh().await;
+            false
+        },
+        Duration::from_secs(5),
+    )
+    .await
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_client_routes_to_members() {
+    util::create_trace_logger();
+
+    let transport: TransportRef = Arc::new(MemoryTransport::new());
+    let (remote_1, _server_1) = worker(1, NODE_1, None, &transport).await;
+    let (remote_2, _server_2) = worker(2, NODE_2, Some(NODE_1), &transport).await;
+
+    let client = system(10, transport.clone())
+        .await
+        .cluster_client()
+        .with_seed_addr(NODE_1)
+        .start()
+        .await
+        .expect("cluster client");
+
+    assert_eq!(member_ids(&client).await, HashSet::from([1, 2]));
+
+    // The client isn't a member of the cluster
+    for remote in [&remote_1, &remote_2] {
+        assert!(remote.get_nodes().await.iter().all(|n| n.id != 10));
+    }
+
+    let _ = remote_2
+        .actor_system()
+        .new_actor("status-actor".into_actor_id(), TestActor::new(), Tracked)
+        .await
+        .unwrap();
+
+    let actor = client
+        .actor_ref::<TestActor>("status-actor".into_actor_id())
+        .await
+        .expect("locate actor");
+
+    assert_eq!(actor.node_id(), Some(2));
+    assert_eq!(
+        actor
+            .send(SetStatusRequest {
+                status: TestActorStatus::Active,
+            })
+            .await
+            .unwrap(),
+        SetStatusResponse::Ok
+    );
+
+    assert_eq!(
+        actor.send(GetStatusRequest).await.unwrap(),
+        GetStatusResponse::Ok(TestActorStatus::Active)
+    );
+
+    // Members that join after the client has started are picked up when it refreshes
+    let _worker_3 = worker(3, NODE_3, Some(NODE_1), &transport).await;
+    assert!(wait_for_members(&client, &[1, 2, 3]).await);
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_client_sharded_requests() {
+    util::create_trace_logger();
+
+    let transport: TransportRef = Arc::new(MemoryTransport::new());
+    let (remote_1, _server_1) = worker(1, NODE_1, None, &transport).await;
+    let (remote_2, _server_2) = worker(2, NODE_2, Some(NODE_1), &transport).await;
+
+    let _sharding_1 = Sharding::<TestActorFactory>::builder(remote_1.clone())
+        .build()
+        .await;
+
+    let _sharding_2 = Sharding::<TestActorFactory>::builder(remote_2.clone())
+        .build()
+        .await;
+
+    let client = system(10, transport.clone())
+        .await
+        .cluster_client()
+        .with_seed_addr(NODE_1)
+        .start()
+        .await
+        .expect("cluster client");
+
+    let sharding = Sharding::<TestActorFactory>::builder(client.system().clone())
+        .build()
+        .await;
+
+    for i in 0..10 {
+        let actor = sharding.get(format!("actor-{}", i), Some(TestActorRecipe));
+        let res = tokio::time::timeout(
+            Duration::from_secs(5),
+            actor.send(SetStatusRequest {
+                status: TestActorStatus::Active,
+            }),
+        )
+        .await
+        .expect("sharded request timed out");
+
+        assert_eq!(res.unwrap(), SetStatusResponse::Ok);
+        assert_eq!(
+            actor.send(GetStatusRequest).await.unwrap(),
+            GetStatusResponse::Ok(TestActorStatus::Active)
+        );
+    }
+
+    // Shards are only ever allocated to members of the cluster
+    let stats = sharding
+        .shard_host()
+        .send(GetStats)
+        .await
+        .unwrap()
+        .await
+        .unwrap();
+
+    assert_eq!(stats.hosted_shard_count, 0);
+    assert!(stats.remote_shard_count > 0);
+    assert!(stats.remote_shards.iter().all(|s| s.node_id != 10));
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_client_contact_point_failover() {
+    util::create_trace_logger();
+
+    let transport: TransportRef = Arc::new(MemoryTransport::new());
+    let (remote_1, server_1) = worker(1, NODE_1, None, &transport).await;
+    let (remote_2, _server_2) = worker(2, NODE_2, Some(NODE_1), &transport).await;
+
+    let client = system(10, transport.clone())
+        .await
+        .cluster_client()
+        .with_seed_addrs([NODE_1, NODE_2])
+        .start()
+        .await
+        .expect("cluster client");
+
+    assert_eq!(member_ids(&client).await, HashSet::from([1, 2]));
+
+    let _ = remote_2
+        .actor_system()
+        .new_actor("status-actor".into_actor_id(), TestActor::new(), Tracked)
+        .await
+        .unwrap();
+
+    let mut server_1 = server_1;
+    server_1.stop();
+    remote_1.actor_system().shutdown().await;
+
+    // The first seed is no longer reachable, the client falls back to the second
+    assert!(wait_for_members(&client, &[2]).await);
+
+    let actor = ActorRef::from(RemoteActorRef::<TestActor>::new(
+        "status-actor".into_actor_id(),
+        2,
+        client.system().clone(),
+    ));
+
+    assert_eq!(
+        actor.send(GetStatusRequest).await.unwrap(),
+        GetStatusResponse::None
+    );
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_client_requires_seed_addr() {
+    let transport: TransportRef = Arc::new(MemoryTransport::new());
+    let client = system(10, transport).await.cluster_client().start().await;
+
+    assert_eq!(client.err(), Some(ClusterClientErr::NoSeedAddrs));
+}
@@ -0,0 +1,194 @@
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::Handler;
+use coerce::actor::system::ActorSystem;
+use coerce::actor::{Actor, IntoActor};
+use coerce::remote::cluster::crdt::gcounter::GCounter;
+use coerce::remote::cluster::crdt::orset::ORSet;
+use coerce::remote::cluster::crdt::pncounter::PNCounter;
+use coerce::remote::cluster::crdt::{Consistency, DataTopic, DistributedData};
+use coerce::remote::net::transport::{MemoryTransport, TransportRef};
+use coerce::remote::stream::pubsub::{PubSub, Receive, Subscription};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use util::{memory_node, start_cluster, wait_for_cluster, wait_until};
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+async fn crdt_cluster(nodes: usize, gossip_interval: Duration) -> Vec<DistributedData> {
+    let transport: TransportRef = Arc::new(MemoryTransport::new());
+    let systems = start_cluster(nodes, |id| {
+        memory_node(id, ActorSystem::new(), transport.clone())
+    })
+    .await;
+
+    assert!(wait_for_cluster(&systems).await);
+
+    let mut data = vec![];
+    for system in systems {
+        data.push(
+            DistributedData::builder(system)
+                .with_gossip_interval(gossip_interval)
+                .with_timeout(Duration::from_secs(2))
+                .build()
+                .await,
+        );
+    }
+
+    data
+}
+
+#[tokio::test]
+pub async fn test_crdt_replicated_writes() {
+    util::create_trace_logger();
+
+    let nodes = crdt_cluster(3, Duration::from_secs(60)).await;
+
+    let value = nodes[0]
+        .update("visits", Consistency::All, |c: &mut GCounter| {
+            c.increment(1, 2)
+        })
+        .await
+        .unwrap();
+    assert_eq!(value.value(), 2);
+
+    // Writes with `All` have been merged by every replica by the time they complete
+    for node in &nodes {
+        let counter: GCounter = node
+            .get("visits", Consistency::Local)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(counter.value(), 2);
+    }
+
+    nodes[1]
+        .update("visits", Consistency::Majority, |c: &mut GCounter| {
+            c.increment(2, 3)
+        })
+        .await
+        .unwrap();
+
+    nodes[2]
+        .update("presence", Consistency::Local, |c: &mut PNCounter| {
+            c.increment(3, 1)
+        })
+        .await
+        .unwrap();
+
+    let counter: GCounter = nodes[0]
+        .get("visits", Consistency::All)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(counter.value(), 5);
+
+    // Reading with `All` merges in the local-only write from node 3
+    let presence: PNCounter = nodes[0]
+        .get("presence", Consistency::All)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(presence.value(), 1);
+
+    let missing: Option<GCounter> = nodes[0].get("missing", Consistency::All).await.unwrap();
+    assert!(missing.is_none());
+}
+
+#[tokio::test]
+pub async fn test_crdt_gossip_convergence() {
+    util::create_trace_logger();
+
+    let nodes = crdt_cluster(3, Duration::from_millis(50)).await;
+
+    for (i, node) in nodes.iter().enumerate() {
+        let node_id = node.node_id();
+        node.update("flags", Consistency::Local, move |s: &mut ORSet<String>| {
+            s.add(node_id, format!("flag-{}", i))
+        })
+        .await
+        .unwrap();
+    }
+
+    let nodes = &nodes;
+    let converged = wait_until(
+        || async move {
+            for node in nodes {
+                let flags: Option<ORSet<String>> =
+                    node.get("flags", Consistency::Local).await.unwrap();
+                if !flags.is_some_and(|f| f.len() == 3) {
+                    return false;
+                }
+            }
+
+            true
+        },
+        Duration::from_secs(5),
+    )
+    .await;
+
+    assert!(converged);
+}
+
+struct FlagSubscriber {
+    subscription: Option<Subscription>,
+    changes: mpsc::UnboundedSender<usize>,
+}
+
+#[async_trait]
+impl Actor for FlagSubscriber {
+    async fn started(&mut self, ctx: &mut ActorContext) {
+        self.subscription = Some(
+            PubSub::subscribe::<Self, _>(DataTopic("flags".to_string()), ctx)
+                .await
+                .unwrap(),
+        );
+    }
+}
+
+#[async_trait]
+impl Handler<Receive<DataTopic>> for FlagSubscriber {
+    async fn handle(&mut self, message: Receive<DataTopic>, _ctx: &mut ActorContext) {
+        assert_eq!(message.0.key(), "flags");
+
+        let flags: ORSet<String> = message.0.value().unwrap();
+        let _ = self.changes.send(flags.len());
+    }
+}
+
+#[tokio::test]
+pub async fn test_crdt_change_subscription() {
+    util::create_trace_logger();
+
+    let nodes = crdt_cluster(2, Duration::from_secs(60)).await;
+    let (changes, mut changes_rx) = mpsc::unbounded_channel();
+
+    let _subscriber = FlagSubscriber {
+        subscription: None,
+        changes,
+    }
+    .into_actor(Some("flag-subscriber"), nodes[1].system().actor_system())
+    .await
+    .unwrap();
+
+    let node_id = nodes[0].node_id();
+    nodes[0]
+        .update("flags", Consistency::All, move |s: &mut ORSet<String>| {
+            s.add(node_id, "dark-mode".to_string())
+        })
+        .await
+        .unwrap();
+
+    let received = tokio::time::timeout(Duration::from_secs(5), changes_rx.recv())
+        .await
+        .unwrap();
+
+    assert_eq!(received, Some(1));
+}
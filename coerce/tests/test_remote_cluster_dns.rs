@@ -0,0 +1,282 @@
+use coerce::actor::system::ActorSystem;
+use coerce::remote::cluster::discovery::dns::DnsClusterSeed;
+use coerce::remote::net::transport::{MemoryTransport, TransportRef};
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use hickory_resolver::proto::op::{Message, MessageType, OpCode};
+use hickory_resolver::proto::rr::rdata::{A, AAAA, SRV};
+use hickory_resolver::proto::rr::{Name, RData, Record, RecordType};
+use parking_lot::Mutex;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::UdpSocket;
+use util::{healthy_nodes, memory_node, wait_until};
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+type StubRecords = Arc<Mutex<Vec<Record>>>;
+
+/// A minimal DNS server, answers queries from `records`. Address records of SRV targets are
+/// included as additional records in SRV responses, queries for names starting with
+/// `unresponsive` are never answered.
+async fn start_stub_dns(records: StubRecords) -> SocketAddr {
+    let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let addr = socket.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let mut buf = [0u8; 512];
+        while let Ok((len, src)) = socket.recv_from(&mut buf).await {
+            let request = Message::from_vec(&buf[..len]).unwrap();
+            let query = request.queries()[0].clone();
+            if query.name().to_string().starts_with("unresponsive") {
+                continue;
+            }
+
+            let mut response = Message::new();
+            response
+                .set_id(request.id())
+                .set_message_type(MessageType::Response)
+                .set_op_code(OpCode::Query)
+                .add_query(query.clone());
+
+            let records = records.lock().clone();
+            let answers: Vec<Record> = records
+                .iter()
+                .filter(|r| r.name() == query.name() && r.record_type() == query.query_type())
+                .cloned()
+                .collect();
+
+            for answer in &answers {
+                if let Some(RData::SRV(srv)) = answer.data() {
+                    for record in records
+                        .iter()
+                        .filter(|r| r.name() == srv.target() && r.record_type() == RecordType::A)
+                    {
+                        response.add_additional(record.clone());
+                    }
+                }
+            }
+
+            response.add_answers(answers);
+            let _ = socket.send_to(&response.to_vec().unwrap(), src).await;
+        }
+    });
+
+    addr
+}
+
+fn a_record(name: &str, ip: Ipv4Addr) -> Record {
+    Record::from_rdata(Name::from_str(name).unwrap(), 60, RData::A(A(ip)))
+}
+
+fn aaaa_record(name: &str, ip: Ipv6Addr) -> Record {
+    Record::from_rdata(Name::from_str(name).unwrap(), 60, RData::AAAA(AAAA(ip)))
+}
+
+fn srv_record(name: &str, target: &str, port: u16) -> Record {
+    Record::from_rdata(
+        Name::from_str(name).unwrap(),
+        60,
+        RData::SRV(SRV::new(0, 0, port, Name::from_str(target).unwrap())),
+    )
+}
+
+#[tokio::test]
+pub async fn test_dns_seed_resolve() {
+    util::create_trace_logger();
+
+    let records: StubRecords = Arc::new(Mutex::new(vec![
+        a_record("seed.coerce.test.", Ipv4Addr::new(10, 0, 0, 1)),
+        a_record("seed.coerce.test.", Ipv4Addr::new(10, 0, 0, 2)),
+        aaaa_record("seed.coerce.test.", Ipv6Addr::LOCALHOST),
+        srv_record("_coerce._tcp.coerce.test.", "node-1.coerce.test.", 30101),
+        srv_record("_coerce._tcp.coerce.test.", "node-2.coerce.test.", 30102),
+        a_record("node-1.coerce.test.", Ipv4Addr::new(10, 0, 1, 1)),
+        aaaa_record("node-2.coerce.test.", Ipv6Addr::LOCALHOST),
+    ]));
+
+    let upstream = start_stub_dns(records).await;
+
+    let addrs = DnsClusterSeed::host(upstream, "seed.coerce.test.", 30101)
+        .resolve()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        addrs,
+        vec!["10.0.0.1:30101", "10.0.0.2:30101", "[::1]:30101"]
+    );
+
+    // node-2 only has an AAAA record, which isn't in the SRV response and is queried separately
+    let addrs = DnsClusterSeed::srv(upstream, "_coerce._tcp.coerce.test.")
+        .resolve()
+        .await
+        .unwrap();
+
+    assert_eq!(addrs, vec!["10.0.1.1:30101", "[::1]:30102"]);
+
+    let addrs = DnsClusterSeed::host(upstream, "missing.coerce.test.", 30101)
+        .resolve()
+        .await
+        .unwrap();
+
+    assert!(addrs.is_empty());
+}
+
+#[tokio::test]
+pub async fn test_dns_seed_srv_skips_failing_targets() {
+    util::create_trace_logger();
+
+    let records: StubRecords = Arc::new(Mutex::new(vec![
+        srv_record("_coerce._tcp.coerce.test.", "node-1.coerce.test.", 30101),
+        srv_record(
+            "_coerce._tcp.coerce.test.",
+            "unresponsive.coerce.test.",
+            30102,
+        ),
+        a_record("node-1.coerce.test.", Ipv4Addr::new(10, 0, 1, 1)),
+    ]));
+
+    let upstream = start_stub_dns(records).await;
+
+    let addrs = DnsClusterSeed::srv(upstream, "_coerce._tcp.coerce.test.")
+        .with_timeout(Duration::from_millis(200))
+        .resolve()
+        .await
+        .unwrap();
+
+    assert_eq!(addrs, vec!["10.0.1.1:30101"]);
+}
+
+const SRV_NAME: &str = "_coerce._tcp.cluster.test.";
+
+/// Nodes listen on addresses the stub DNS server can point SRV records at.
+fn node_socket_addr(id: NodeId) -> SocketAddr {
+    SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 31500 + id as u16)
+}
+
+fn node_srv_record(id: NodeId) -> Vec<Record> {
+    let target = format!("node-{}.cluster.test.", id);
+    vec![
+        srv_record(SRV_NAME, &target, node_socket_addr(id).port()),
+        a_record(&target, Ipv4Addr::LOCALHOST),
+    ]
+}
+
+async fn start_node(
+    id: NodeId,
+    transport: TransportRef,
+    dns_seed: Option<DnsClusterSeed>,
+) -> RemoteActorSystem {
+    let remote = memory_node(id, ActorSystem::new(), transport).build().await;
+
+    let mut worker = remote
+        .clone()
+        .cluster_worker()
+        .listen_addr(node_socket_addr(id));
+
+    if let Some(dns_seed) = dns_seed {
+        worker = worker.with_dns_seed(dns_seed);
+    }
+
+    worker.start().await;
+    remote
+}
+
+async fn wait_for_healthy_nodes(systems: &[&RemoteActorSystem], count: usize) -> bool {
+    wait_until(
+        || async move {
+            for system in systems {
+                if healthy_nodes(system).await != count {
+                    return false;
+                }
+            }
+
+            true
+        },
+        Duration::from_secs(5),
+    )
+    .await
+}
+
+#[tokio::test]
+pub async fn test_dns_seed_discovery() {
+    util::create_trace_logger();
+
+    let records: StubRecords = Arc::new(Mutex::new(vec![]));
+    records.lock().extend(node_srv_record(1));
+    records.lock().extend(node_srv_record(2));
+
+    let upstream = start_stub_dns(records.clone()).await;
+    let dns_seed = DnsClusterSeed::srv(upstream, SRV_NAME)
+        .with_refresh_interval(Duration::from_millis(100))
+        .with_timeout(Duration::from_secs(1));
+
+    let transport: TransportRef = Arc::new(MemoryTransport::new());
+    let node_1 = start_node(1, transport.clone(), None).await;
+    let node_2 = start_node(2, transport.clone(), Some(dns_seed)).await;
+
+    assert!(wait_for_healthy_nodes(&[&node_1, &node_2], 2).await);
+
+    // Node 3 isn't seeded, it's found once node 2 refreshes the DNS records
+    let node_3 = start_node(3, transport.clone(), None).await;
+    records.lock().extend(node_srv_record(3));
+
+    assert!(wait_for_healthy_nodes(&[&node_1, &node_2, &node_3], 3).await);
+}
+
+#[tokio::test]
+pub async fn test_dns_seed_discovery_ignores_known_host_names() {
+    util::create_trace_logger();
+
+    // Node 1 is known to node 2 by its host name, DNS resolves it to its IP address
+    let node_1 = RemoteActorSystem::builder()
+        .with_id(1)
+        .with_actor_system(ActorSystem::new())
+        .build()
+        .await;
+
+    node_1
+        .clone()
+        .cluster_worker()
+        .listen_addr("127.0.0.1:31511")
+        .external_addr("localhost:31511")
+        .start()
+        .await;
+
+    let node_2 = RemoteActorSystem::builder()
+        .with_id(2)
+        .with_actor_system(ActorSystem::new())
+        .build()
+        .await;
+
+    node_2
+        .clone()
+        .cluster_worker()
+        .listen_addr("127.0.0.1:31512")
+        .with_seed_addr("localhost:31511")
+        .start()
+        .await;
+
+    assert!(wait_for_healthy_nodes(&[&node_1, &node_2], 2).await);
+
+    let records: StubRecords = Arc::new(Mutex::new(vec![
+        srv_record(SRV_NAME, "node-1.cluster.test.", 31511),
+        a_record("node-1.cluster.test.", Ipv4Addr::LOCALHOST),
+    ]));
+
+    let upstream = start_stub_dns(records).await;
+    let discovered = DnsClusterSeed::srv(upstream, SRV_NAME)
+        .discover(&node_2)
+        .await
+        .unwrap();
+
+    assert_eq!(discovered, 0);
+}
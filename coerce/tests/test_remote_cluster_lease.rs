@@ -0,0 +1,222 @@
+use coerce::actor::system::ActorSystem;
+use coerce::persistent::journal::provider::inmemory::InMemoryStorageProvider;
+use coerce::persistent::journal::provider::StorageProvider;
+use coerce::remote::cluster::lease::{
+    ClusterLeaseStore, JournalLeaseStore, LeaseErr, LeaseStore, LeaseStoreRef, Leases, StoreLease,
+};
+use coerce::remote::cluster::split_brain::SplitBrainLease;
+use coerce::remote::net::transport::{MemoryTransport, TransportRef};
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+use util::{memory_node, start_cluster, wait_for_cluster};
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+fn journal_store() -> LeaseStoreRef {
+    let storage = InMemoryStorageProvider::new().journal_storage().unwrap();
+    Arc::new(JournalLeaseStore::new(storage))
+}
+
+async fn lease_cluster(nodes: usize) -> Vec<RemoteActorSystem> {
+    let transport: TransportRef = Arc::new(MemoryTransport::new());
+    let systems = start_cluster(nodes, |id| {
+        memory_node(id, ActorSystem::new(), transport.clone())
+    })
+    .await;
+
+    assert!(wait_for_cluster(&systems).await);
+    systems
+}
+
+#[tokio::test]
+pub async fn test_journal_lease_store() {
+    util::create_trace_logger();
+
+    let store = journal_store();
+    let ttl = Duration::from_millis(200);
+
+    assert_eq!(store.acquire("job", 1, ttl).await, Ok(true));
+    assert_eq!(store.acquire("job", 2, ttl).await, Ok(false));
+    assert_eq!(store.renew("job", 1, ttl).await, Ok(true));
+    assert_eq!(store.holder("job").await, Ok(Some(1)));
+
+    assert_eq!(store.release("job", 2).await, Ok(false));
+    assert_eq!(store.release("job", 1).await, Ok(true));
+    assert_eq!(store.holder("job").await, Ok(None));
+
+    assert_eq!(store.acquire("job", 2, ttl).await, Ok(true));
+
+    // Leases that aren't renewed become available once the TTL passes
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(store.holder("job").await, Ok(None));
+    assert_eq!(store.acquire("job", 1, ttl).await, Ok(true));
+}
+
+#[tokio::test]
+pub async fn test_journal_lease_concurrent_acquire() {
+    util::create_trace_logger();
+
+    // Each node has its own store, sharing the same storage backend
+    let storage = InMemoryStorageProvider::new().journal_storage().unwrap();
+    let node_1 = JournalLeaseStore::new(storage.clone());
+    let node_2 = JournalLeaseStore::new(storage);
+    let ttl = Duration::from_secs(5);
+
+    for i in 0..50 {
+        let name = format!("job-{}", i);
+        let (acquired_1, acquired_2) =
+            tokio::join!(node_1.acquire(&name, 1, ttl), node_2.acquire(&name, 2, ttl));
+
+        let acquired_1 = acquired_1.unwrap();
+        let acquired_2 = acquired_2.unwrap();
+        assert!(acquired_1 ^ acquired_2);
+
+        let expected_holder = if acquired_1 { 1 } else { 2 };
+        assert_eq!(node_1.holder(&name).await, Ok(Some(expected_holder)));
+        assert_eq!(node_2.holder(&name).await, Ok(Some(expected_holder)));
+    }
+}
+
+#[tokio::test]
+pub async fn test_cluster_lease_guard() {
+    util::create_trace_logger();
+
+    let max_ttl = Duration::from_millis(300);
+    let systems = lease_cluster(2).await;
+
+    let mut leases = vec![];
+    for system in &systems {
+        let store = ClusterLeaseStore::start(system.clone(), max_ttl).await;
+        leases.push(Leases::new(system, Arc::new(store)));
+    }
+
+    // A new leader doesn't grant leases until any granted by a previous leader have expired
+    tokio::time::sleep(max_ttl * 2).await;
+
+    let guard = leases[1].acquire("job", max_ttl).await.unwrap();
+    assert!(guard.is_held());
+
+    assert_eq!(
+        leases[0].acquire("job", max_ttl).await.err(),
+        Some(LeaseErr::AlreadyHeld)
+    );
+    assert_eq!(
+        leases[1].acquire("job", max_ttl).await.err(),
+        Some(LeaseErr::AlreadyHeld)
+    );
+
+    // The guard keeps renewing the lease in the background
+    tokio::time::sleep(max_ttl * 3).await;
+    assert!(guard.is_held());
+    assert_eq!(leases[0].holder("job").await, Ok(Some(2)));
+
+    assert_eq!(guard.release().await, Ok(true));
+    assert_eq!(leases[0].holder("job").await, Ok(None));
+
+    let guard = leases[0].acquire("job", max_ttl).await.unwrap();
+    drop(guard);
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(leases[1].acquire("job", max_ttl).await.is_ok());
+}
+
+#[tokio::test]
+pub async fn test_lease_expiry_callback() {
+    util::create_trace_logger();
+
+    let store = journal_store();
+    let system = RemoteActorSystem::builder()
+        .with_id(1)
+        .with_actor_system(ActorSystem::new())
+        .build()
+        .await;
+
+    let leases = Leases::new(&system, store.clone());
+    let ttl = Duration::from_millis(150);
+
+    let guard = leases.acquire("job", ttl).await.unwrap();
+    let (expired_tx, expired_rx) = oneshot::channel();
+    guard.on_expiry(move || {
+        let _ = expired_tx.send(());
+    });
+
+    // Another node takes the lease, the next renewal fails and the lease expires
+    assert_eq!(store.release("job", 1).await, Ok(true));
+    assert_eq!(store.acquire("job", 2, ttl).await, Ok(true));
+
+    tokio::time::timeout(Duration::from_secs(2), expired_rx)
+        .await
+        .unwrap()
+        .unwrap();
+
+    guard.expired().await;
+    assert!(!guard.is_held());
+    assert_eq!(guard.release().await, Ok(false));
+}
+
+/// Renewals never complete, as if the store had become unreachable.
+struct StalledStore(LeaseStoreRef);
+
+#[async_trait]
+impl LeaseStore for StalledStore {
+    async fn acquire(&self, name: &str, owner: NodeId, ttl: Duration) -> Result<bool, LeaseErr> {
+        self.0.acquire(name, owner, ttl).await
+    }
+
+    async fn renew(&self, _name: &str, _owner: NodeId, _ttl: Duration) -> Result<bool, LeaseErr> {
+        futures::future::pending().await
+    }
+
+    async fn release(&self, name: &str, owner: NodeId) -> Result<bool, LeaseErr> {
+        self.0.release(name, owner).await
+    }
+
+    async fn holder(&self, name: &str) -> Result<Option<NodeId>, LeaseErr> {
+        self.0.holder(name).await
+    }
+}
+
+#[tokio::test]
+pub async fn test_lease_expires_when_renewals_stall() {
+    util::create_trace_logger();
+
+    let system = RemoteActorSystem::builder()
+        .with_id(1)
+        .with_actor_system(ActorSystem::new())
+        .build()
+        .await;
+
+    let leases = Leases::new(&system, Arc::new(StalledStore(journal_store())));
+    let ttl = Duration::from_millis(300);
+
+    let acquired_at = Instant::now();
+    let guard = leases.acquire("job", ttl).await.unwrap();
+
+    // The lease expires once the TTL has passed, without waiting for a renewal to fail
+    tokio::time::timeout(Duration::from_secs(2), guard.expired())
+        .await
+        .unwrap();
+
+    let held_for = acquired_at.elapsed();
+    assert!(held_for <= ttl + Duration::from_millis(100));
+    assert!(!guard.is_held());
+}
+
+#[tokio::test]
+pub async fn test_split_brain_store_lease() {
+    util::create_trace_logger();
+
+    let lease = StoreLease::new(journal_store(), "split-brain", Duration::from_secs(5));
+
+    assert!(lease.acquire(1).await);
+    assert!(lease.acquire(1).await);
+    assert!(!lease.acquire(2).await);
+}
@@ -0,0 +1,171 @@
+use coerce::actor::system::ActorSystem;
+use coerce::actor::{ActorCreationErr, ActorFactory, ActorRecipe};
+use coerce::persistent::journal::provider::inmemory::InMemoryStorageProvider;
+use coerce::persistent::Persistence;
+use coerce::remote::cluster::node::NodeStatus;
+use coerce::remote::cluster::sharding::coordinator::stats::GetShardingStats;
+use coerce::remote::cluster::sharding::host::stats::GetStats;
+use coerce::remote::cluster::sharding::host::GetCoordinator;
+use coerce::remote::cluster::sharding::Sharding;
+use coerce::remote::net::transport::{MemoryTransport, TransportRef};
+use coerce::remote::system::RemoteActorSystem;
+use std::sync::Arc;
+use std::time::Duration;
+use util::*;
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+#[derive(Serialize, Deserialize)]
+struct TestActorRecipe;
+
+#[derive(Clone)]
+struct TestActorFactory;
+
+impl ActorRecipe for TestActorRecipe {
+    fn read_from_bytes(bytes: &Vec<u8>) -> Option<Self> {
+        serde_json::from_slice(bytes).ok()
+    }
+
+    fn write_to_bytes(&self) -> Option<Vec<u8>> {
+        serde_json::to_vec(&self).ok()
+    }
+}
+
+#[async_trait]
+impl ActorFactory for TestActorFactory {
+    type Actor = TestActor;
+    type Recipe = TestActorRecipe;
+
+    async fn create(&self, _recipe: Self::Recipe) -> Result<Self::Actor, ActorCreationErr> {
+        Ok(TestActor::new())
+    }
+}
+
+async fn sharded_cluster(
+    nodes: usize,
+) -> (Vec<RemoteActorSystem>, Vec<Sharding<TestActorFactory>>) {
+    let transport: TransportRef = Arc::new(MemoryTransport::new());
+    let systems = start_cluster(nodes, |id| {
+        let system =
+            ActorSystem::new().to_persistent(Persistence::from(InMemoryStorageProvider::new()));
+
+        memory_node(id, system, transport.clone()).with_handlers(|handlers| {
+            handlers
+                .with_actor(TestActorFactory)
+                .with_handler::<TestActor, GetStatusRequest>("GetStatusRequest")
+                .with_handler::<TestActor, SetStatusRequest>("SetStatusRequest")
+        })
+    })
+    .await;
+
+    assert!(wait_for_cluster(&systems).await);
+
+    let mut shardings = vec![];
+    for system in &systems {
+        shardings.push(
+            Sharding::<TestActorFactory>::builder(system.clone())
+                .build()
+                .await,
+        );
+    }
+
+    (systems, shardings)
+}
+
+async fn set_status(sharding: &Sharding<TestActorFactory>, actor_id: String) {
+    let actor = sharding.get(actor_id, Some(TestActorRecipe));
+    let res = tokio::time::timeout(
+        Duration::from_secs(5),
+        actor.send(SetStatusRequest {
+            status: TestActorStatus::Active,
+        }),
+    )
+    .await
+    .expect("sharded request timed out");
+
+    assert_eq!(res.unwrap(), SetStatusResponse::Ok);
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_leave_hands_off_shards() {
+    util::create_trace_logger();
+
+    let (systems, shardings) = sharded_cluster(3).await;
+    assert_eq!(systems[0].current_leader(), Some(1));
+
+    for i in 0..30 {
+        set_status(&shardings[0], format!("actor-{}", i)).await;
+    }
+
+    let hosted_shards = shardings[2]
+        .shard_host()
+        .send(GetStats)
+        .await
+        .unwrap()
+        .await
+        .unwrap()
+        .hosted_shard_count;
+
+    assert!(hosted_shards > 0);
+
+    let handed_off = systems[2].leave().await;
+    assert_eq!(handed_off, hosted_shards as usize);
+    assert!(systems[2].actor_system().is_terminated());
+
+    // The remaining nodes were told the node was leaving, so there's no need to wait for it to
+    // miss enough heartbeats to be declared terminated
+    for system in &systems[..2] {
+        let node_3 = system
+            .get_nodes()
+            .await
+            .into_iter()
+            .find(|n| n.id == 3)
+            .expect("node 3");
+
+        assert!(matches!(
+            node_3.status,
+            NodeStatus::Leaving | NodeStatus::Terminated
+        ));
+    }
+
+    let coordinator = shardings[0]
+        .shard_host()
+        .send(GetCoordinator)
+        .await
+        .unwrap()
+        .expect("coordinator");
+
+    let stats = coordinator.send(GetShardingStats).await.unwrap();
+    assert!(!stats.shards.is_empty());
+    assert!(stats.shards.iter().all(|s| s.node_id != 3));
+
+    for i in 0..30 {
+        set_status(&shardings[0], format!("actor-{}", i)).await;
+        set_status(&shardings[1], format!("actor-{}", i)).await;
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_leave_excluded_from_leader_election() {
+    util::create_trace_logger();
+
+    let (systems, _shardings) = sharded_cluster(2).await;
+    assert_eq!(systems[1].current_leader(), Some(1));
+
+    systems[0].leave().await;
+
+    let system = &systems[1];
+    let elected = wait_until(
+        || async move { system.current_leader() == Some(2) },
+        Duration::from_secs(2),
+    )
+    .await;
+
+    assert!(elected);
+}
@@ -0,0 +1,275 @@
+use coerce::actor::{ActorCreationErr, ActorFactory, ActorRecipe, IntoActorId};
+use coerce::remote::cluster::sharding::host::stats::GetStats;
+use coerce::remote::cluster::sharding::host::GetCoordinator;
+use coerce::remote::cluster::sharding::Sharding;
+use coerce::remote::system::actor::{DeployTarget, RemoteActorErr};
+use coerce::remote::system::builder::RemoteSystemConfigBuilder;
+use coerce::remote::system::NodeId;
+use std::time::Duration;
+use util::*;
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+#[derive(Serialize, Deserialize)]
+struct TestActorRecipe;
+
+#[derive(Clone)]
+struct TestActorFactory;
+
+impl ActorRecipe for TestActorRecipe {
+    fn read_from_bytes(bytes: &Vec<u8>) -> Option<Self> {
+        serde_json::from_slice(bytes).ok()
+    }
+
+    fn write_to_bytes(&self) -> Option<Vec<u8>> {
+        serde_json::to_vec(&self).ok()
+    }
+}
+
+#[async_trait]
+impl ActorFactory for TestActorFactory {
+    type Actor = TestActor;
+    type Recipe = TestActorRecipe;
+
+    async fn create(&self, _recipe: Self::Recipe) -> Result<Self::Actor, ActorCreationErr> {
+        Ok(TestActor::new())
+    }
+}
+
+fn handlers(builder: &mut RemoteSystemConfigBuilder) -> &mut RemoteSystemConfigBuilder {
+    builder
+        .with_actor(TestActorFactory)
+        .with_handler::<TestActor, GetStatusRequest>("GetStatusRequest")
+        .with_handler::<TestActor, SetStatusRequest>("SetStatusRequest")
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_roles_advertised() {
+    util::create_trace_logger();
+
+    let systems = roles_cluster(
+        &[&["frontend"], &["worker", "storage"], &[]],
+        None,
+        handlers,
+    )
+    .await;
+
+    for system in &systems {
+        let nodes = system.get_nodes().await;
+        let roles = |id: NodeId| {
+            nodes
+                .iter()
+                .find(|n| n.id == id)
+                .map(|n| n.roles.clone())
+                .expect("node registered")
+        };
+
+        assert_eq!(roles(1), vec!["frontend".to_string()]);
+        assert_eq!(roles(2), vec!["worker".to_string(), "storage".to_string()]);
+        assert!(roles(3).is_empty());
+    }
+
+    assert_eq!(systems[1].role_leader(Some("worker")), Some(2));
+    assert_eq!(systems[0].role_leader(Some("unknown")), None);
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_leader_role() {
+    util::create_trace_logger();
+
+    let systems = roles_cluster(&[&[], &["seed"], &["seed"]], Some("seed"), handlers).await;
+
+    // Node 1 is the oldest, but doesn't have the leader role
+    for system in &systems {
+        assert_eq!(system.current_leader(), Some(2));
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_sharding_required_role() {
+    util::create_trace_logger();
+
+    let systems = roles_cluster(&[&[], &["worker"], &["worker"]], None, handlers).await;
+
+    let mut shardings = vec![];
+    for system in &systems {
+        shardings.push(
+            Sharding::<TestActorFactory>::builder(system.clone())
+                .with_role("worker")
+                .build()
+                .await,
+        );
+    }
+
+    for i in 0..20 {
+        let actor = shardings[0].get(format!("actor-{}", i), Some(TestActorRecipe));
+        let res = tokio::time::timeout(
+            Duration::from_secs(5),
+            actor.send(SetStatusRequest {
+                status: TestActorStatus::Active,
+            }),
+        )
+        .await
+        .expect("sharded request timed out");
+
+        assert_eq!(res.unwrap(), SetStatusResponse::Ok);
+    }
+
+    let stats = shardings[0]
+        .shard_host()
+        .send(GetStats)
+        .await
+        .unwrap()
+        .await
+        .unwrap();
+
+    // Node 1 doesn't have the worker role, so every shard is allocated to nodes 2 and 3
+    assert_eq!(stats.hosted_shard_count, 0);
+    assert!(stats.remote_shard_count > 0);
+    assert!(stats
+        .remote_shards
+        .iter()
+        .all(|s| s.node_id == 2 || s.node_id == 3));
+}
+
+#[tokio::test]
+pub async fn test_remote_sharding_coordinator_role() {
+    util::create_trace_logger();
+
+    let systems = roles_cluster(&[&[], &["coordinator"], &["coordinator"]], None, handlers).await;
+    assert_eq!(systems[0].current_leader(), Some(1));
+
+    let mut shardings = vec![];
+    for system in &systems {
+        shardings.push(
+            Sharding::<TestActorFactory>::builder(system.clone())
+                .with_coordinator_role("coordinator")
+                .build()
+                .await,
+        );
+    }
+
+    // The coordinator runs on the oldest node with the coordinator role, not on the leader
+    for sharding in &shardings {
+        assert_eq!(coordinator_node(sharding).await, Some(2));
+    }
+
+    let actor = shardings[0].get("actor-1", Some(TestActorRecipe));
+    let res = tokio::time::timeout(
+        Duration::from_secs(5),
+        actor.send(SetStatusRequest {
+            status: TestActorStatus::Active,
+        }),
+    )
+    .await
+    .expect("sharded request timed out");
+
+    assert_eq!(res.unwrap(), SetStatusResponse::Ok);
+}
+
+#[tokio::test]
+pub async fn test_remote_sharding_coordinator_role_handover() {
+    util::create_trace_logger();
+
+    let systems = roles_cluster(&[&[], &["coordinator"], &["coordinator"]], None, handlers).await;
+
+    let mut shardings = vec![];
+    for system in &systems {
+        shardings.push(
+            Sharding::<TestActorFactory>::builder(system.clone())
+                .with_coordinator_role("coordinator")
+                .build()
+                .await,
+        );
+    }
+
+    assert_eq!(coordinator_node(&shardings[0]).await, Some(2));
+
+    systems[1].leave().await;
+
+    // The coordinator moves as soon as node 2 is leaving, rather than once it has been removed,
+    // and the shard hosts locate it on the same node the spawners start it on.
+    let remaining = [&shardings[0], &shardings[2]];
+    let moved = wait_until(
+        || async move {
+            for sharding in remaining {
+                if coordinator_node(sharding).await != Some(3) {
+                    return false;
+                }
+            }
+
+            true
+        },
+        Duration::from_secs(5),
+    )
+    .await;
+
+    assert!(moved, "coordinator didn't move to node 3");
+
+    let actor = shardings[0].get("actor-1", Some(TestActorRecipe));
+    let res = tokio::time::timeout(
+        Duration::from_secs(5),
+        actor.send(SetStatusRequest {
+            status: TestActorStatus::Active,
+        }),
+    )
+    .await
+    .expect("sharded request timed out");
+
+    assert_eq!(res.unwrap(), SetStatusResponse::Ok);
+}
+
+async fn coordinator_node(sharding: &Sharding<TestActorFactory>) -> Option<NodeId> {
+    let coordinator = sharding.shard_host().send(GetCoordinator).await.unwrap()?;
+    Some(
+        coordinator
+            .node_id()
+            .unwrap_or_else(|| sharding.system().node_id()),
+    )
+}
+
+#[tokio::test]
+pub async fn test_remote_deploy_actor_to_role() {
+    util::create_trace_logger();
+
+    let systems = roles_cluster(&[&[], &["worker"]], None, handlers).await;
+
+    let actor = systems[0]
+        .deploy_actor::<TestActorFactory>(
+            Some("deployed-actor".into_actor_id()),
+            TestActorRecipe,
+            DeployTarget::Role("worker".to_string()),
+        )
+        .await
+        .expect("deploy actor");
+
+    assert_eq!(actor.node_id(), Some(2));
+    assert_eq!(
+        actor
+            .send(SetStatusRequest {
+                status: TestActorStatus::Active,
+            })
+            .await
+            .unwrap(),
+        SetStatusResponse::Ok
+    );
+
+    let result = systems[0]
+        .deploy_actor::<TestActorFactory>(
+            None,
+            TestActorRecipe,
+            DeployTarget::Role("storage".to_string()),
+        )
+        .await;
+
+    assert_eq!(
+        result.err(),
+        Some(RemoteActorErr::NoNodeWithRole("storage".to_string()))
+    );
+}
@@ -0,0 +1,196 @@
+use coerce::actor::{ActorCreationErr, ActorFactory, ActorRecipe, ActorRefErr};
+use coerce::remote::cluster::singleton::ClusterSingleton;
+use coerce::remote::system::builder::RemoteSystemConfigBuilder;
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use std::time::Duration;
+use util::*;
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+#[derive(Serialize, Deserialize)]
+struct TestActorRecipe;
+
+#[derive(Clone)]
+struct TestActorFactory;
+
+impl ActorRecipe for TestActorRecipe {
+    fn read_from_bytes(bytes: &Vec<u8>) -> Option<Self> {
+        serde_json::from_slice(bytes).ok()
+    }
+
+    fn write_to_bytes(&self) -> Option<Vec<u8>> {
+        serde_json::to_vec(&self).ok()
+    }
+}
+
+#[async_trait]
+impl ActorFactory for TestActorFactory {
+    type Actor = TestActor;
+    type Recipe = TestActorRecipe;
+
+    async fn create(&self, _recipe: Self::Recipe) -> Result<Self::Actor, ActorCreationErr> {
+        Ok(TestActor::new())
+    }
+}
+
+fn handlers(builder: &mut RemoteSystemConfigBuilder) -> &mut RemoteSystemConfigBuilder {
+    builder
+        .with_actor(TestActorFactory)
+        .with_handler::<TestActor, GetStatusRequest>("GetStatusRequest")
+        .with_handler::<TestActor, SetStatusRequest>("SetStatusRequest")
+}
+
+async fn start_singletons(
+    systems: &[RemoteActorSystem],
+    role: Option<&str>,
+) -> Vec<ClusterSingleton<TestActorFactory>> {
+    let mut singletons = vec![];
+    for system in systems {
+        let mut builder = ClusterSingleton::<TestActorFactory>::builder(system.clone());
+        if let Some(role) = role {
+            builder.with_role(role);
+        }
+
+        singletons.push(builder.build("test-singleton", TestActorRecipe).await);
+    }
+
+    singletons
+}
+
+async fn wait_for_singleton(singletons: &[ClusterSingleton<TestActorFactory>], node_id: NodeId) {
+    let located = wait_until(
+        || async move { singletons.iter().all(|s| s.current_node() == Some(node_id)) },
+        Duration::from_secs(5),
+    )
+    .await;
+
+    if located {
+        return;
+    }
+
+    let nodes: Vec<Option<NodeId>> = singletons.iter().map(|s| s.current_node()).collect();
+    panic!(
+        "singleton not located on node={}, nodes={:?}",
+        node_id, nodes
+    );
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_singleton_runs_on_leader() {
+    util::create_trace_logger();
+
+    let systems = roles_cluster(&[&[], &[], &[]], None, handlers).await;
+    let singletons = start_singletons(&systems, None).await;
+
+    wait_for_singleton(&singletons, 1).await;
+
+    let res = singletons[2]
+        .send(SetStatusRequest {
+            status: TestActorStatus::Active,
+        })
+        .await;
+
+    assert_eq!(res.unwrap(), SetStatusResponse::Ok);
+
+    // Every node talks to the same instance
+    for singleton in &singletons {
+        assert_eq!(
+            singleton.send(GetStatusRequest).await.unwrap(),
+            GetStatusResponse::Ok(TestActorStatus::Active)
+        );
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_singleton_role() {
+    util::create_trace_logger();
+
+    let systems = roles_cluster(&[&[], &["scheduler"], &["scheduler"]], None, handlers).await;
+    let singletons = start_singletons(&systems, Some("scheduler")).await;
+
+    wait_for_singleton(&singletons, 2).await;
+
+    let res = singletons[0]
+        .send(SetStatusRequest {
+            status: TestActorStatus::Active,
+        })
+        .await;
+
+    assert_eq!(res.unwrap(), SetStatusResponse::Ok);
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_singleton_handover() {
+    util::create_trace_logger();
+
+    let systems = roles_cluster(&[&[], &[], &[]], None, handlers).await;
+    let singletons = start_singletons(&systems, None).await;
+
+    wait_for_singleton(&singletons, 1).await;
+
+    let res = singletons[2]
+        .send(SetStatusRequest {
+            status: TestActorStatus::Active,
+        })
+        .await;
+
+    assert_eq!(res.unwrap(), SetStatusResponse::Ok);
+
+    systems[0].leave().await;
+
+    // Messages sent while the singleton is moving are held until the new instance has started
+    let res = tokio::time::timeout(Duration::from_secs(5), singletons[2].send(GetStatusRequest))
+        .await
+        .expect("singleton request timed out");
+
+    assert_eq!(res.unwrap(), GetStatusResponse::None);
+
+    wait_for_singleton(&singletons[1..], 2).await;
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_singleton_buffer_timeout() {
+    util::create_trace_logger();
+
+    // No node has the role, so the singleton never starts
+    let systems = roles_cluster(&[&[]], None, handlers).await;
+    let singleton = ClusterSingleton::<TestActorFactory>::builder(systems[0].clone())
+        .with_role("scheduler")
+        .with_buffer_timeout(Duration::from_millis(500))
+        .build("test-singleton", TestActorRecipe)
+        .await;
+
+    let res = tokio::time::timeout(Duration::from_secs(5), singleton.send(GetStatusRequest))
+        .await
+        .expect("buffered request never failed");
+
+    assert!(matches!(res, Err(ActorRefErr::Timeout { .. })));
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_singleton_buffer_limit() {
+    util::create_trace_logger();
+
+    let systems = roles_cluster(&[&[]], None, handlers).await;
+    let singleton = ClusterSingleton::<TestActorFactory>::builder(systems[0].clone())
+        .with_role("scheduler")
+        .with_max_buffered_messages(1)
+        .build("test-singleton", TestActorRecipe)
+        .await;
+
+    let actor_ref = singleton.actor_ref().clone();
+    let buffered = tokio::spawn(async move { actor_ref.send(GetStatusRequest).await });
+
+    // Wait for the first message to be buffered before sending the next one
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let res = singleton.send(GetStatusRequest).await;
+    assert!(matches!(res, Err(ActorRefErr::ActorUnavailable)));
+    assert!(!buffered.is_finished());
+}
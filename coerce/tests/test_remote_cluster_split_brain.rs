@@ -0,0 +1,164 @@
+use coerce::actor::system::ActorSystem;
+use coerce::remote::cluster::node::NodeStatus;
+use coerce::remote::cluster::split_brain::{
+    DowningAction, DowningStrategy, MemoryLease, SplitBrainResolverConfig,
+};
+use coerce::remote::net::transport::{FaultInjector, MemoryTransport, TransportRef};
+use coerce::remote::system::RemoteActorSystem;
+use std::sync::Arc;
+use std::time::Duration;
+use util::{
+    fast_failure_detection, memory_node, node_addr, node_status, start_cluster, wait_for_cluster,
+    wait_until,
+};
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+async fn sbr_cluster(
+    injector: &FaultInjector,
+    node_count: usize,
+    strategy: DowningStrategy,
+) -> Vec<RemoteActorSystem> {
+    let memory: TransportRef = Arc::new(MemoryTransport::new());
+    let systems = start_cluster(node_count, |id| {
+        let strategy = strategy.clone();
+        memory_node(id, ActorSystem::new(), injector.transport(memory.clone())).configure(
+            move |c| {
+                c.heartbeat(fast_failure_detection()).split_brain_resolver(
+                    SplitBrainResolverConfig {
+                        strategy: strategy.clone(),
+                        action: DowningAction::Shutdown,
+                        stable_after: Duration::from_millis(500),
+                    },
+                )
+            },
+        )
+    })
+    .await;
+
+    assert!(wait_for_cluster(&systems).await);
+    for system in &systems {
+        assert_eq!(system.current_leader(), Some(1));
+    }
+
+    systems
+}
+
+async fn wait_for_shutdown(systems: &[&RemoteActorSystem]) {
+    wait_until(
+        || async move { systems.iter().all(|s| s.actor_system().is_terminated()) },
+        Duration::from_secs(5),
+    )
+    .await;
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_split_brain_keep_majority() {
+    util::create_trace_logger();
+
+    let injector = FaultInjector::new();
+    let systems = sbr_cluster(&injector, 3, DowningStrategy::KeepMajority).await;
+
+    injector.isolate(&node_addr(3));
+    wait_for_shutdown(&[&systems[2]]).await;
+
+    assert!(systems[2].actor_system().is_terminated());
+    for system in &systems[..2] {
+        assert!(!system.actor_system().is_terminated());
+        assert_eq!(node_status(system, 3).await, Some(NodeStatus::Terminated));
+        assert_eq!(system.current_leader(), Some(1));
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_split_brain_keep_oldest() {
+    util::create_trace_logger();
+
+    let injector = FaultInjector::new();
+    let strategy = DowningStrategy::KeepOldest {
+        down_if_alone: false,
+    };
+    let systems = sbr_cluster(&injector, 3, strategy).await;
+
+    // Node 1 is the oldest, so it survives despite being in the minority
+    injector.isolate(&node_addr(1));
+    wait_for_shutdown(&[&systems[1], &systems[2]]).await;
+
+    assert!(!systems[0].actor_system().is_terminated());
+    assert!(systems[1].actor_system().is_terminated());
+    assert!(systems[2].actor_system().is_terminated());
+
+    assert_eq!(
+        node_status(&systems[0], 2).await,
+        Some(NodeStatus::Terminated)
+    );
+    assert_eq!(
+        node_status(&systems[0], 3).await,
+        Some(NodeStatus::Terminated)
+    );
+    assert_eq!(systems[0].current_leader(), Some(1));
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_split_brain_static_quorum() {
+    util::create_trace_logger();
+
+    let injector = FaultInjector::new();
+    let strategy = DowningStrategy::StaticQuorum { quorum_size: 2 };
+    let systems = sbr_cluster(&injector, 3, strategy).await;
+
+    injector.isolate(&node_addr(1));
+    wait_for_shutdown(&[&systems[0]]).await;
+
+    assert!(systems[0].actor_system().is_terminated());
+
+    // The surviving side only elects a new leader once the old one has been downed
+    let survivors = &systems[1..];
+    wait_until(
+        || async move { survivors.iter().all(|s| s.current_leader() == Some(2)) },
+        Duration::from_secs(2),
+    )
+    .await;
+
+    for system in &systems[1..] {
+        assert!(!system.actor_system().is_terminated());
+        assert_eq!(node_status(system, 1).await, Some(NodeStatus::Terminated));
+        assert_eq!(system.current_leader(), Some(2));
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_split_brain_lease() {
+    util::create_trace_logger();
+
+    let injector = FaultInjector::new();
+    let lease = Arc::new(MemoryLease::new(Duration::from_secs(60)));
+    let strategy = DowningStrategy::Lease {
+        lease: lease.clone(),
+    };
+    let systems = sbr_cluster(&injector, 2, strategy).await;
+
+    injector.partition(&node_addr(1), &node_addr(2));
+
+    let systems = &systems;
+    wait_until(
+        || async move { systems.iter().any(|s| s.actor_system().is_terminated()) },
+        Duration::from_secs(5),
+    )
+    .await;
+
+    // Only the side holding the lease survives
+    let holder = lease.holder().expect("lease holder");
+    for system in systems {
+        assert_eq!(
+            system.actor_system().is_terminated(),
+            system.node_id() != holder
+        );
+    }
+}
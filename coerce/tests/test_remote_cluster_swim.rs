@@ -0,0 +1,163 @@
+use coerce::actor::system::ActorSystem;
+use coerce::remote::cluster::node::NodeStatus;
+use coerce::remote::cluster::swim::message::GetMembers;
+use coerce::remote::cluster::swim::{swim_actor_id, Member, MemberStatus, Swim, SwimConfig};
+use coerce::remote::net::transport::{FaultInjector, MemoryTransport, TransportRef};
+use coerce::remote::system::builder::RemoteActorSystemBuilder;
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use std::sync::Arc;
+use std::time::Duration;
+use util::{
+    healthy_nodes, memory_node, node_addr, node_status, start_cluster, wait_for_cluster, wait_until,
+};
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+fn swim_config() -> SwimConfig {
+    SwimConfig {
+        probe_interval: Duration::from_millis(100),
+        probe_timeout: Duration::from_millis(50),
+        suspicion_timeout: Duration::from_secs(1),
+        ..SwimConfig::default()
+    }
+}
+
+fn swim_node(id: NodeId, transport: TransportRef) -> RemoteActorSystemBuilder {
+    memory_node(id, ActorSystem::new(), transport).configure(|c| c.swim(swim_config()))
+}
+
+async fn members(system: &RemoteActorSystem) -> Vec<Member> {
+    let swim = system
+        .actor_system()
+        .get_tracked_actor::<Swim>(swim_actor_id(system.node_id()))
+        .await
+        .expect("swim actor");
+
+    swim.send(GetMembers).await.unwrap()
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_swim_membership() {
+    util::create_trace_logger();
+
+    let transport: TransportRef = Arc::new(MemoryTransport::new());
+    let mut systems = start_cluster(3, |id| swim_node(id, transport.clone())).await;
+
+    // Node 4 only knows about node 3, nodes 1 and 2 are learned through gossip
+    let remote_4 = swim_node(4, transport).build().await;
+    remote_4
+        .clone()
+        .cluster_worker()
+        .listen_addr(node_addr(4))
+        .with_seed_addr(node_addr(3))
+        .start()
+        .await;
+
+    systems.push(remote_4);
+    wait_for_cluster(&systems).await;
+
+    for system in &systems {
+        assert_eq!(healthy_nodes(system).await, 4);
+
+        let members = members(system).await;
+        assert_eq!(members.len(), 3);
+        assert!(members.iter().all(|m| m.status == MemberStatus::Alive));
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_swim_indirect_probe() {
+    util::create_trace_logger();
+
+    let injector = FaultInjector::new();
+    let memory: TransportRef = Arc::new(MemoryTransport::new());
+    let systems = start_cluster(3, |id| swim_node(id, injector.transport(memory.clone()))).await;
+    let (remote, remote_2, remote_3) = (&systems[0], &systems[1], &systems[2]);
+
+    wait_for_cluster(&systems).await;
+
+    injector.partition(&node_addr(1), &node_addr(2));
+
+    // Nodes 1 and 2 can't reach each other, but can both reach node 3, which probes on their behalf
+    tokio::time::sleep(Duration::from_secs(2)).await;
+
+    assert_eq!(
+        node_status(remote, remote_2.node_id()).await,
+        Some(NodeStatus::Healthy)
+    );
+
+    assert_eq!(
+        node_status(remote_2, remote.node_id()).await,
+        Some(NodeStatus::Healthy)
+    );
+
+    assert_eq!(healthy_nodes(remote_3).await, 3);
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_swim_failure_detection() {
+    util::create_trace_logger();
+
+    let injector = FaultInjector::new();
+    let memory: TransportRef = Arc::new(MemoryTransport::new());
+    let systems = start_cluster(3, |id| swim_node(id, injector.transport(memory.clone()))).await;
+    let (remote, remote_2, remote_3) = (&systems[0], &systems[1], &systems[2]);
+
+    let systems = &systems;
+    wait_until(
+        || async move { systems.iter().all(|s| s.current_leader() == Some(1)) },
+        Duration::from_secs(5),
+    )
+    .await;
+
+    assert_eq!(remote_2.current_leader(), Some(remote.node_id()));
+    assert_eq!(remote_3.current_leader(), Some(remote.node_id()));
+
+    injector.isolate(&node_addr(1));
+
+    let survivors = &systems[1..];
+    wait_until(
+        || async move {
+            for system in survivors {
+                if node_status(system, 1).await != Some(NodeStatus::Terminated) {
+                    return false;
+                }
+            }
+
+            true
+        },
+        Duration::from_secs(5),
+    )
+    .await;
+
+    for system in survivors {
+        assert_eq!(
+            node_status(system, remote.node_id()).await,
+            Some(NodeStatus::Terminated)
+        );
+
+        let member = members(system)
+            .await
+            .into_iter()
+            .find(|m| m.node.id == remote.node_id())
+            .unwrap();
+
+        assert_eq!(member.status, MemberStatus::Dead);
+    }
+
+    // The leader was the oldest node, leadership moves to the next oldest healthy node
+    wait_until(
+        || async move { survivors.iter().all(|s| s.current_leader() == Some(2)) },
+        Duration::from_secs(2),
+    )
+    .await;
+
+    assert_eq!(remote_2.current_leader(), Some(remote_2.node_id()));
+    assert_eq!(remote_3.current_leader(), Some(remote_2.node_id()));
+}
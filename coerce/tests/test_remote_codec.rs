@@ -1,5 +1,19 @@
+use bytes::{BufMut, Bytes, BytesMut};
 use coerce::actor::system::ActorSystem;
+use coerce::actor::ActorRefErr;
+use coerce::remote::net::codec::{NetworkCodec, DEFAULT_MAX_FRAME_SIZE};
+use coerce::remote::net::message::{ClientEvent, SessionEvent};
+use coerce::remote::net::proto::network::MessageRequest;
+use coerce::remote::net::StreamData;
 use coerce::remote::system::RemoteActorSystem;
+use futures::StreamExt;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::io::ErrorKind;
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpStream;
+use tokio_util::codec::{Decoder, Encoder, Framed};
+use uuid::Uuid;
 
 use util::*;
 
@@ -43,3 +57,176 @@ pub async fn test_remote_create_message() {
         "TestActor.SetStatusRequest".to_string()
     );
 }
+
+#[test]
+pub fn test_remote_codec_decodes_without_copying_payload() {
+    let payload = vec![7u8; 64 * 1024];
+    let event = SessionEvent::NotifyActor(MessageRequest {
+        handler_type: "TestActor.SetStatusRequest".to_string(),
+        message: payload.clone().into(),
+        ..Default::default()
+    });
+
+    let mut buffer = BytesMut::new();
+    NetworkCodec::default()
+        .encode(&event.write_to_bytes().unwrap(), &mut buffer)
+        .unwrap();
+
+    let frame = NetworkCodec::default()
+        .decode(&mut buffer)
+        .unwrap()
+        .unwrap();
+    let frame_range = frame.as_ptr_range();
+
+    let message = match SessionEvent::read_from_bytes(frame.clone()) {
+        Some(SessionEvent::NotifyActor(message)) => message,
+        _ => panic!("expected NotifyActor event"),
+    };
+
+    assert_eq!(message.message, payload);
+    assert!(frame_range.contains(&message.message.as_ptr()));
+}
+
+#[test]
+pub fn test_remote_codec_rejects_invalid_frame_lengths() {
+    let mut codec = NetworkCodec::new(1024);
+
+    let mut oversized = BytesMut::new();
+    oversized.put_i32_le(1025);
+    oversized.put_slice(&[0u8; 16]);
+
+    let err = codec.decode(&mut oversized).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    let mut negative = BytesMut::new();
+    negative.put_i32_le(-1);
+    negative.put_slice(&[0u8; 16]);
+
+    let err = codec.decode(&mut negative).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    let err = codec
+        .encode(&vec![0u8; 1025], &mut BytesMut::new())
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+pub fn test_remote_codec_decodes_partial_frames() {
+    let mut codec = NetworkCodec::new(1024);
+
+    let mut encoded = BytesMut::new();
+    codec.encode(&vec![1, 2, 3], &mut encoded).unwrap();
+    codec.encode(&vec![], &mut encoded).unwrap();
+
+    let mut buffer = BytesMut::new();
+    for byte in encoded.iter().take(5) {
+        assert!(codec.decode(&mut buffer).unwrap().is_none());
+        buffer.put_u8(*byte);
+    }
+
+    buffer.put_slice(&encoded[5..]);
+    assert_eq!(
+        codec.decode(&mut buffer).unwrap().unwrap().as_ref(),
+        &[1, 2, 3]
+    );
+    assert!(codec.decode(&mut buffer).unwrap().unwrap().is_empty());
+    assert!(codec.decode(&mut buffer).unwrap().is_none());
+}
+
+#[test]
+pub fn test_remote_codec_caps_reservation_for_partial_frames() {
+    let mut codec = NetworkCodec::new(DEFAULT_MAX_FRAME_SIZE);
+
+    // A header announcing a large frame doesn't reserve the whole frame before it arrives
+    let mut buffer = BytesMut::new();
+    buffer.put_i32_le(DEFAULT_MAX_FRAME_SIZE as i32);
+    buffer.put_u8(1);
+
+    assert!(codec.decode(&mut buffer).unwrap().is_none());
+    assert!(buffer.capacity() < 1024 * 1024);
+}
+
+#[test]
+pub fn test_remote_codec_arbitrary_bytes_do_not_panic() {
+    let mut rng = StdRng::seed_from_u64(0xC0E7CE);
+
+    let valid_event = SessionEvent::NotifyActor(MessageRequest {
+        message_id: Uuid::new_v4().to_string(),
+        handler_type: "TestActor.SetStatusRequest".to_string(),
+        actor_id: "actor".to_string(),
+        message: vec![1u8; 64].into(),
+        ..Default::default()
+    })
+    .write_to_bytes()
+    .unwrap();
+
+    for _ in 0..10_000 {
+        let data = if rng.gen_bool(0.5) {
+            let len = rng.gen_range(0..256);
+            (0..len).map(|_| rng.gen()).collect::<Vec<u8>>()
+        } else {
+            let mut data = valid_event.clone();
+            for _ in 0..rng.gen_range(1..8) {
+                let i = rng.gen_range(0..data.len());
+                data[i] = rng.gen();
+            }
+
+            data
+        };
+
+        let mut codec = NetworkCodec::new(1024);
+        let mut buffer = BytesMut::from(data.as_slice());
+        while let Ok(Some(frame)) = codec.decode(&mut buffer) {
+            let _ = ClientEvent::read_from_bytes(frame.clone());
+            let _ = SessionEvent::read_from_bytes(frame);
+        }
+
+        let _ = ClientEvent::read_from_bytes(Bytes::from(data.clone()));
+        let _ = SessionEvent::read_from_bytes(Bytes::from(data));
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_server_closes_session_on_oversized_frame() {
+    util::create_trace_logger();
+
+    let remote = RemoteActorSystem::builder()
+        .with_tag("codec-node")
+        .with_id(1)
+        .with_actor_system(ActorSystem::new())
+        .configure(|c| c.max_frame_size(1024))
+        .build()
+        .await;
+
+    remote
+        .clone()
+        .cluster_worker()
+        .listen_addr("localhost:30501")
+        .start()
+        .await;
+
+    let stream = TcpStream::connect("localhost:30501").await.unwrap();
+    let mut framed = Framed::new(stream, NetworkCodec::default());
+
+    let identity = framed.next().await.unwrap().unwrap();
+    assert!(matches!(
+        ClientEvent::read_from_bytes(identity),
+        Some(ClientEvent::Identity(_))
+    ));
+
+    let mut header = BytesMut::new();
+    header.put_i32_le(64 * 1024);
+    framed.get_mut().write_all(&header).await.unwrap();
+
+    let error = framed.next().await.unwrap().unwrap();
+    match ClientEvent::read_from_bytes(error) {
+        Some(ClientEvent::Err(e)) => {
+            let error: ActorRefErr = e.error.unwrap().into();
+            assert!(matches!(error, ActorRefErr::Deserialisation(_)));
+        }
+        _ => panic!("expected error"),
+    }
+
+    assert!(matches!(framed.next().await, None | Some(Err(_))));
+}
@@ -0,0 +1,422 @@
+use bytes::Bytes;
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::Handler;
+use coerce::actor::scheduler::ActorType::Tracked;
+use coerce::actor::system::ActorSystem;
+use coerce::actor::{Actor, ActorRef, IntoActorId};
+use coerce::remote::actor::RemoteResponse;
+use coerce::remote::cluster::swim::SwimConfig;
+use coerce::remote::net::client::lanes::TrafficClass;
+use coerce::remote::net::message::SessionEvent;
+use coerce::remote::net::proto::network::{
+    ClientErr, ClientResult, MessageRequest, PingEvent, StreamPublishEvent,
+};
+use coerce::remote::net::stream::NetworkStream;
+use coerce::remote::net::transport::{MemoryTransport, Transport, TransportListener, TransportRef};
+use coerce::remote::raft::RaftConfig;
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use coerce::remote::RemoteActorRef;
+use coerce_macros::JsonMessage;
+use rand::Rng;
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::sync::oneshot;
+use tokio::time::Sleep;
+use util::{memory_node, start_cluster, wait_for_cluster, wait_until};
+use uuid::Uuid;
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+#[derive(Default)]
+pub struct SequenceActor {
+    received: Vec<u32>,
+}
+
+impl Actor for SequenceActor {}
+
+#[derive(JsonMessage, Serialize, Deserialize)]
+#[result("()")]
+pub struct Append(u32);
+
+#[derive(JsonMessage, Serialize, Deserialize)]
+#[result("Vec<u32>")]
+pub struct GetReceived;
+
+#[async_trait]
+impl Handler<Append> for SequenceActor {
+    async fn handle(&mut self, message: Append, _ctx: &mut ActorContext) {
+        self.received.push(message.0);
+    }
+}
+
+#[async_trait]
+impl Handler<GetReceived> for SequenceActor {
+    async fn handle(&mut self, _message: GetReceived, _ctx: &mut ActorContext) -> Vec<u32> {
+        self.received.clone()
+    }
+}
+
+async fn lanes_node(id: u64, connection_lanes: usize) -> RemoteActorSystem {
+    RemoteActorSystem::builder()
+        .with_tag(format!("lanes-node-{}", id))
+        .with_id(id)
+        .with_actor_system(ActorSystem::new())
+        .with_handlers(|handlers| {
+            handlers
+                .with_handler::<SequenceActor, Append>("SequenceActor.Append")
+                .with_handler::<SequenceActor, GetReceived>("SequenceActor.GetReceived")
+        })
+        .configure(move |c| c.connection_lanes(connection_lanes))
+        .build()
+        .await
+}
+
+#[tokio::test]
+pub async fn test_remote_connection_lanes_preserve_actor_ordering() {
+    util::create_trace_logger();
+
+    const ACTORS: usize = 8;
+    const MESSAGES: u32 = 50;
+
+    let remote = lanes_node(1, 4).await;
+    let remote_2 = lanes_node(2, 4).await;
+
+    remote
+        .clone()
+        .cluster_worker()
+        .listen_addr("localhost:30701")
+        .start()
+        .await;
+
+    remote_2
+        .clone()
+        .cluster_worker()
+        .listen_addr("localhost:30702")
+        .with_seed_addr("localhost:30701")
+        .start()
+        .await;
+
+    assert_eq!(remote.get_nodes().await.len(), 2);
+    assert_eq!(remote_2.get_nodes().await.len(), 2);
+
+    let mut actor_refs = vec![];
+    for i in 0..ACTORS {
+        let actor_id = format!("sequence-actor-{}", i).into_actor_id();
+        let _ = remote
+            .actor_system()
+            .new_actor(actor_id.clone(), SequenceActor::default(), Tracked)
+            .await
+            .unwrap();
+
+        actor_refs.push(ActorRef::from(RemoteActorRef::<SequenceActor>::new(
+            actor_id,
+            remote.node_id(),
+            remote_2.clone(),
+        )));
+    }
+
+    for n in 0..MESSAGES {
+        for actor_ref in &actor_refs {
+            actor_ref.notify(Append(n)).await.unwrap();
+        }
+    }
+
+    let expected: Vec<u32> = (0..MESSAGES).collect();
+    for actor_ref in &actor_refs {
+        let expected_len = expected.len();
+        wait_until(
+            || async move { actor_ref.send(GetReceived).await.unwrap().len() == expected_len },
+            Duration::from_secs(2),
+        )
+        .await;
+
+        assert_eq!(actor_ref.send(GetReceived).await.unwrap(), expected);
+    }
+}
+
+async fn coordination_node() -> RemoteActorSystem {
+    RemoteActorSystem::builder()
+        .with_tag("lanes-coordination")
+        .with_id(1)
+        .with_actor_system(ActorSystem::new())
+        .configure(|c| c.swim(SwimConfig::default()).raft(RaftConfig::new(vec![1])))
+        .build()
+        .await
+}
+
+fn notify(handler_type: &str, actor_id: &str) -> SessionEvent {
+    SessionEvent::NotifyActor(MessageRequest {
+        handler_type: handler_type.to_string(),
+        actor_id: actor_id.to_string(),
+        ..Default::default()
+    })
+}
+
+async fn assert_control(handler_types: &[&str]) {
+    let system = coordination_node().await;
+    for handler_type in handler_types {
+        assert_eq!(
+            notify(handler_type, "actor").traffic_class(system.config()),
+            TrafficClass::Control,
+            "{} should use the control lane",
+            handler_type
+        );
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_connection_lanes_traffic_class() {
+    let system = coordination_node().await;
+    let config = system.config();
+
+    let ping = SessionEvent::Ping(PingEvent::default());
+    assert_eq!(ping.traffic_class(config), TrafficClass::Control);
+
+    let actor_message =
+        |actor_id: &str| notify("SequenceActor.Append", actor_id).traffic_class(config);
+
+    assert!(matches!(actor_message("actor-1"), TrafficClass::Message(_)));
+    assert_eq!(actor_message("actor-1"), actor_message("actor-1"));
+    assert_ne!(actor_message("actor-1"), actor_message("actor-2"));
+
+    // Sharded entity requests are user traffic, only the coordination handlers use the control lane
+    assert!(matches!(
+        notify("Shard.RemoteEntityRequest", "shard-1").traffic_class(config),
+        TrafficClass::Message(_)
+    ));
+
+    let publish = SessionEvent::StreamPublish(Arc::new(StreamPublishEvent {
+        topic: "topic".to_string(),
+        ..Default::default()
+    }));
+
+    assert!(matches!(
+        publish.traffic_class(config),
+        TrafficClass::Message(_)
+    ));
+
+    // RPC results are routed by their request id, rather than sharing the control lane
+    let result = SessionEvent::Result(ClientResult {
+        message_id: Uuid::new_v4().to_string(),
+        ..Default::default()
+    });
+
+    let err = SessionEvent::Err(ClientErr {
+        message_id: Uuid::new_v4().to_string(),
+        ..Default::default()
+    });
+
+    assert!(matches!(
+        result.traffic_class(config),
+        TrafficClass::Message(_)
+    ));
+    assert!(matches!(
+        err.traffic_class(config),
+        TrafficClass::Message(_)
+    ));
+}
+
+#[tokio::test]
+pub async fn test_remote_connection_lanes_sharding_is_control() {
+    assert_control(&[
+        "ShardCoordinator.AllocateShard",
+        "ShardCoordinator.HandoffNodeShards",
+        "ShardHost.ShardAllocated",
+        "ShardHost.ShardReallocating",
+        "ShardHost.StopShard",
+    ])
+    .await;
+}
+
+#[tokio::test]
+pub async fn test_remote_connection_lanes_swim_is_control() {
+    assert_control(&["Swim.Ping", "Swim.PingReq", "Swim.Ack"]).await;
+}
+
+#[tokio::test]
+pub async fn test_remote_connection_lanes_raft_is_control() {
+    assert_control(&[
+        "Raft.RequestVote",
+        "Raft.VoteResponse",
+        "Raft.AppendEntries",
+        "Raft.AppendEntriesResponse",
+        "Raft.InstallSnapshot",
+    ])
+    .await;
+}
+
+#[tokio::test]
+pub async fn test_remote_connection_lanes_singleton_is_control() {
+    assert_control(&[
+        "SingletonManager.LocateSingleton",
+        "SingletonManager.SingletonStarted",
+        "SingletonManager.StopSingleton",
+    ])
+    .await;
+}
+
+#[tokio::test]
+pub async fn test_remote_connection_lanes_replicator_is_control() {
+    assert_control(&["Replicator.MergeData", "Replicator.ReadData"]).await;
+}
+
+#[tokio::test]
+pub async fn test_remote_connection_lanes_lease_is_control() {
+    assert_control(&[
+        "LeaseCoordinator.AcquireLease",
+        "LeaseCoordinator.ReleaseLease",
+        "LeaseCoordinator.GetLeaseHolder",
+    ])
+    .await;
+}
+
+/// Outbound connections write at most `THROTTLE_CHUNK` bytes every `THROTTLE_INTERVAL`, so large
+/// frames hold up whatever is queued behind them on the same connection.
+struct ThrottledTransport {
+    inner: MemoryTransport,
+}
+
+struct ThrottledStream {
+    inner: NetworkStream,
+    pause: Option<Pin<Box<Sleep>>>,
+}
+
+const THROTTLE_CHUNK: usize = 64 * 1024;
+const THROTTLE_INTERVAL: Duration = Duration::from_millis(10);
+
+#[async_trait]
+impl Transport for ThrottledTransport {
+    async fn listen(&self, addr: &str) -> io::Result<Box<dyn TransportListener>> {
+        self.inner.listen(addr).await
+    }
+
+    async fn connect(&self, addr: &str) -> io::Result<NetworkStream> {
+        let inner = self.inner.connect(addr).await?;
+        Ok(NetworkStream::custom(ThrottledStream {
+            inner,
+            pause: None,
+        }))
+    }
+}
+
+impl AsyncRead for ThrottledStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for ThrottledStream {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        if let Some(pause) = self.pause.as_mut() {
+            ready!(pause.as_mut().poll(cx));
+            self.pause = None;
+        }
+
+        let len = buf.len().min(THROTTLE_CHUNK);
+        let res = Pin::new(&mut self.inner).poll_write(cx, &buf[..len]);
+        if let Poll::Ready(Ok(_)) = &res {
+            self.pause = Some(Box::pin(tokio::time::sleep(THROTTLE_INTERVAL)));
+        }
+
+        res
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
+
+async fn ping(system: &RemoteActorSystem, node_id: NodeId) {
+    let message_id = Uuid::new_v4();
+    let ping = SessionEvent::Ping(PingEvent {
+        message_id: message_id.to_string(),
+        node_id: system.node_id(),
+        ..Default::default()
+    });
+
+    system
+        .node_rpc_raw(message_id, ping, node_id)
+        .await
+        .expect("pong");
+}
+
+#[tokio::test]
+pub async fn test_remote_connection_lanes_large_result_does_not_delay_ping() {
+    util::create_trace_logger();
+
+    // Takes around half a second to write through the throttled connection
+    const RESULT_LEN: usize = 3 * 1024 * 1024;
+
+    let transport: TransportRef = Arc::new(ThrottledTransport {
+        inner: MemoryTransport::new(),
+    });
+
+    let systems = start_cluster(2, |id| {
+        memory_node(id, ActorSystem::new(), transport.clone()).configure(|c| c.connection_lanes(4))
+    })
+    .await;
+
+    assert!(wait_for_cluster(&systems).await);
+
+    // Random, so it isn't shrunk by compression
+    let mut result = vec![0u8; RESULT_LEN];
+    rand::thread_rng().fill(result.as_mut_slice());
+
+    // Results forwarded on behalf of another actor (e.g. by a shard) are written by the
+    // `RemoteClientRegistry`, rather than in response to the request on the same connection
+    let request_id = Uuid::new_v4();
+    let (res_tx, res_rx) = oneshot::channel();
+    systems[1].push_request(request_id, res_tx);
+
+    // The sender waits for a response to the result too, which never comes
+    let sender = systems[0].clone();
+    tokio::spawn(async move {
+        sender
+            .notify_raw_rpc_result(request_id, Bytes::from(result), 2)
+            .await
+    });
+
+    let result = tokio::spawn(res_rx);
+
+    // Give node 1 time to start writing the result to node 2
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let started = Instant::now();
+    ping(&systems[0], 2).await;
+    let ping_time = started.elapsed();
+
+    assert!(!result.is_finished(), "result was written before the ping");
+    assert!(
+        ping_time < Duration::from_millis(200),
+        "ping took {:?}",
+        ping_time
+    );
+
+    match result.await.unwrap().unwrap() {
+        RemoteResponse::Ok(result) => assert_eq!(result.len(), RESULT_LEN),
+        RemoteResponse::Err(e) => panic!("result failed: {}", e),
+    }
+}
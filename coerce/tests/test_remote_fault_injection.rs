@@ -0,0 +1,393 @@
+use coerce::actor::context::{ActorContext, StopReason};
+use coerce::actor::message::{Handler, Message};
+use coerce::actor::scheduler::ActorType::Tracked;
+use coerce::actor::system::ActorSystem;
+use coerce::actor::{
+    Actor, ActorCreationErr, ActorFactory, ActorId, ActorRecipe, ActorRef, IntoActor, IntoActorId,
+    LocalActorRef,
+};
+use coerce::persistent::journal::provider::inmemory::InMemoryStorageProvider;
+use coerce::persistent::Persistence;
+use coerce::remote::actor::message::NewClient;
+use coerce::remote::cluster::node::NodeStatus;
+use coerce::remote::cluster::sharding::host::stats::GetStats;
+use coerce::remote::cluster::sharding::Sharding;
+use coerce::remote::net::client::{ClientType, RemoteClient};
+use coerce::remote::net::transport::{FaultInjector, MemoryTransport, TransportRef};
+use coerce::remote::system::builder::RemoteActorSystemBuilder;
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use coerce::remote::RemoteActorRef;
+use coerce_macros::JsonMessage;
+use std::io;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use util::{
+    fast_failure_detection, memory_node, node_addr, node_status, start_cluster, wait_for_cluster,
+    wait_until, GetStatusRequest, TestActor,
+};
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+pub struct EchoActor;
+
+impl Actor for EchoActor {}
+
+#[derive(JsonMessage, Serialize, Deserialize)]
+#[result("String")]
+pub struct Echo(String);
+
+#[async_trait]
+impl Handler<Echo> for EchoActor {
+    async fn handle(&mut self, message: Echo, _ctx: &mut ActorContext) -> String {
+        message.0
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TestActorRecipe;
+
+impl ActorRecipe for TestActorRecipe {
+    fn read_from_bytes(bytes: &Vec<u8>) -> Option<Self> {
+        serde_json::from_slice(bytes).ok()
+    }
+
+    fn write_to_bytes(&self) -> Option<Vec<u8>> {
+        serde_json::to_vec(&self).ok()
+    }
+}
+
+#[derive(Clone)]
+pub struct TestActorFactory;
+
+#[async_trait]
+impl ActorFactory for TestActorFactory {
+    type Actor = TestActor;
+    type Recipe = TestActorRecipe;
+
+    async fn create(&self, _recipe: Self::Recipe) -> Result<Self::Actor, ActorCreationErr> {
+        Ok(TestActor::new())
+    }
+}
+
+fn fault_node(id: NodeId, transport: TransportRef) -> RemoteActorSystemBuilder {
+    memory_node(id, ActorSystem::new(), transport)
+        .with_handlers(|handlers| handlers.with_handler::<EchoActor, Echo>("EchoActor.Echo"))
+}
+
+/// Two nodes, each connecting through `injector`, with an `EchoActor` on node 1 and a reference
+/// to it from node 2.
+async fn fault_cluster(injector: &FaultInjector) -> (Vec<RemoteActorSystem>, ActorRef<EchoActor>) {
+    let memory: TransportRef = Arc::new(MemoryTransport::new());
+    let systems = start_cluster(2, |id| fault_node(id, injector.transport(memory.clone()))).await;
+
+    let actor_id = "echo-actor".into_actor_id();
+    let _ = systems[0]
+        .actor_system()
+        .new_actor(actor_id.clone(), EchoActor, Tracked)
+        .await
+        .unwrap();
+
+    let actor_ref = ActorRef::from(RemoteActorRef::<EchoActor>::new(
+        actor_id,
+        1,
+        systems[1].clone(),
+    ));
+
+    (systems, actor_ref)
+}
+
+struct Watcher {
+    on_watched_stopped: Option<tokio::sync::oneshot::Sender<(ActorId, StopReason)>>,
+}
+
+struct WatchClient(LocalActorRef<RemoteClient>);
+
+impl Message for WatchClient {
+    type Result = ();
+}
+
+#[async_trait]
+impl Actor for Watcher {
+    async fn on_watched_stopped(
+        &mut self,
+        id: &ActorId,
+        reason: &StopReason,
+        _ctx: &mut ActorContext,
+    ) {
+        if let Some(on_watched_stopped) = self.on_watched_stopped.take() {
+            let _ = on_watched_stopped.send((id.clone(), reason.clone()));
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<WatchClient> for Watcher {
+    async fn handle(&mut self, message: WatchClient, ctx: &mut ActorContext) {
+        let _ = ctx.watch(&message.0);
+    }
+}
+
+/// A cluster of `nodes` nodes, each connecting through `injector`, that notices lost nodes
+/// within about a second.
+async fn failover_cluster(injector: &FaultInjector, nodes: usize) -> Vec<RemoteActorSystem> {
+    let memory: TransportRef = Arc::new(MemoryTransport::new());
+    let systems = start_cluster(nodes, |id| {
+        let system =
+            ActorSystem::new().to_persistent(Persistence::from(InMemoryStorageProvider::new()));
+
+        memory_node(id, system, injector.transport(memory.clone()))
+            .with_handlers(|handlers| {
+                handlers
+                    .with_actor(TestActorFactory)
+                    .with_handler::<TestActor, GetStatusRequest>("GetStatusRequest")
+            })
+            .configure(|c| c.heartbeat(fast_failure_detection()))
+    })
+    .await;
+
+    assert!(wait_for_cluster(&systems).await);
+    systems
+}
+
+/// The nodes hosting the shards `sharding`'s node knows about, other than its own.
+async fn remote_shard_nodes(sharding: &Sharding<TestActorFactory>) -> Vec<NodeId> {
+    let stats = sharding
+        .shard_host()
+        .send(GetStats)
+        .await
+        .unwrap()
+        .await
+        .unwrap();
+    stats.remote_shards.iter().map(|s| s.node_id).collect()
+}
+
+async fn wait_for_status(system: &RemoteActorSystem, node_id: NodeId, status: NodeStatus) -> bool {
+    wait_until(
+        || async move { node_status(system, node_id).await == Some(status) },
+        Duration::from_secs(5),
+    )
+    .await
+}
+
+async fn echo(actor_ref: &ActorRef<EchoActor>, timeout: Duration) -> Option<String> {
+    tokio::time::timeout(timeout, actor_ref.send(Echo("hello".to_string())))
+        .await
+        .ok()
+        .map(|res| res.unwrap())
+}
+
+#[tokio::test]
+pub async fn test_remote_fault_injection_partition_and_heal() {
+    util::create_trace_logger();
+
+    let injector = FaultInjector::new();
+    let (systems, actor_ref) = fault_cluster(&injector).await;
+
+    assert!(wait_for_status(&systems[1], 1, NodeStatus::Healthy).await);
+    assert_eq!(
+        echo(&actor_ref, Duration::from_secs(1)).await,
+        Some("hello".to_string())
+    );
+
+    injector.partition(&node_addr(1), &node_addr(2));
+    assert!(
+        injector
+            .link_faults(&node_addr(2), &node_addr(1))
+            .partitioned
+    );
+
+    assert!(wait_for_status(&systems[1], 1, NodeStatus::Unhealthy).await);
+    assert_eq!(echo(&actor_ref, Duration::from_millis(500)).await, None);
+
+    injector.heal(&node_addr(1), &node_addr(2));
+
+    assert!(wait_for_status(&systems[1], 1, NodeStatus::Healthy).await);
+    assert_eq!(
+        echo(&actor_ref, Duration::from_secs(1)).await,
+        Some("hello".to_string())
+    );
+}
+
+#[tokio::test]
+pub async fn test_remote_fault_injection_latency_and_drops() {
+    util::create_trace_logger();
+
+    let injector = FaultInjector::with_seed(1);
+    let (_systems, actor_ref) = fault_cluster(&injector).await;
+
+    injector.set_latency(
+        &node_addr(1),
+        &node_addr(2),
+        Duration::from_millis(200),
+        Duration::ZERO,
+    );
+
+    let start = Instant::now();
+    assert_eq!(
+        echo(&actor_ref, Duration::from_secs(2)).await,
+        Some("hello".to_string())
+    );
+
+    // Both the request and the response are delayed
+    assert!(start.elapsed() >= Duration::from_millis(400));
+
+    injector.heal_all();
+    injector.set_drop_rate(&node_addr(1), &node_addr(2), 1.0);
+    assert_eq!(echo(&actor_ref, Duration::from_millis(500)).await, None);
+
+    injector.set_drop_rate(&node_addr(1), &node_addr(2), 0.0);
+    assert_eq!(
+        echo(&actor_ref, Duration::from_secs(1)).await,
+        Some("hello".to_string())
+    );
+}
+
+#[tokio::test]
+pub async fn test_remote_fault_injection_refuses_partitioned_connections() {
+    let injector = FaultInjector::new();
+    let memory: TransportRef = Arc::new(MemoryTransport::new());
+
+    let node_1 = injector.transport(memory.clone());
+    let node_2 = injector.transport(memory.clone());
+    let node_3 = injector.transport(memory);
+
+    let _listener_1 = node_1.listen(&node_addr(1)).await.unwrap();
+    let _listener_2 = node_2.listen(&node_addr(2)).await.unwrap();
+    let _listener_3 = node_3.listen(&node_addr(3)).await.unwrap();
+
+    injector.isolate(&node_addr(1));
+
+    let error = node_2.connect(&node_addr(1)).await.err().unwrap();
+    assert_eq!(error.kind(), io::ErrorKind::ConnectionRefused);
+
+    let error = node_1.connect(&node_addr(3)).await.err().unwrap();
+    assert_eq!(error.kind(), io::ErrorKind::ConnectionRefused);
+
+    assert!(node_2.connect(&node_addr(3)).await.is_ok());
+
+    injector.heal_all();
+    assert!(node_2.connect(&node_addr(1)).await.is_ok());
+}
+
+#[tokio::test]
+pub async fn test_remote_fault_injection_leader_partition() {
+    util::create_trace_logger();
+
+    let injector = FaultInjector::new();
+    let systems = failover_cluster(&injector, 3).await;
+    for system in &systems {
+        assert_eq!(system.current_leader(), Some(1));
+    }
+
+    injector.isolate(&node_addr(1));
+
+    let majority = &systems[1..];
+    let new_leader_elected = wait_until(
+        || async move {
+            majority
+                .iter()
+                .all(|system| system.current_leader() == Some(2))
+        },
+        Duration::from_secs(5),
+    )
+    .await;
+
+    assert!(new_leader_elected);
+}
+
+#[tokio::test]
+pub async fn test_remote_fault_injection_shard_host_failure() {
+    util::create_trace_logger();
+
+    let injector = FaultInjector::new();
+    let systems = failover_cluster(&injector, 3).await;
+
+    let mut shardings = vec![];
+    for system in &systems {
+        shardings.push(
+            Sharding::<TestActorFactory>::builder(system.clone())
+                .build()
+                .await,
+        );
+    }
+
+    // Allocate enough shards for some of them to land on node 3
+    let actor_ids: Vec<String> = (0..30).map(|i| format!("actor-{}", i)).collect();
+    for actor_id in &actor_ids {
+        let actor = shardings[0].get(actor_id.clone(), Some(TestActorRecipe));
+        assert!(actor.send(GetStatusRequest).await.is_ok());
+    }
+
+    assert!(remote_shard_nodes(&shardings[0]).await.contains(&3));
+
+    // The node's connections close as it shuts down, so the others forget it straight away
+    // rather than waiting for a split brain resolver to down it
+    systems[2].actor_system().shutdown().await;
+
+    let sharding = &shardings[0];
+    let shards_moved = wait_until(
+        || async move { !remote_shard_nodes(sharding).await.contains(&3) },
+        Duration::from_secs(10),
+    )
+    .await;
+
+    assert!(shards_moved);
+
+    for actor_id in &actor_ids {
+        let actor = sharding.get(actor_id.clone(), Some(TestActorRecipe));
+        let res = tokio::time::timeout(Duration::from_secs(2), actor.send(GetStatusRequest)).await;
+        assert!(matches!(res, Ok(Ok(_))), "{} unreachable", actor_id);
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_fault_injection_lost_node_clients_stopped() {
+    util::create_trace_logger();
+
+    let injector = FaultInjector::new();
+    let systems = failover_cluster(&injector, 2).await;
+
+    // the registry hands back the existing client when one is already connected to the address
+    let client = systems[0]
+        .client_registry()
+        .send(NewClient {
+            addr: node_addr(2),
+            client_type: ClientType::Worker,
+            system: systems[0].clone(),
+        })
+        .await
+        .unwrap()
+        .expect("client to node 2");
+
+    let client_id = client.actor_id().clone();
+
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    let watcher = Watcher {
+        on_watched_stopped: Some(tx),
+    }
+    .into_anon_actor(Some("client-watcher"), systems[0].actor_system())
+    .await
+    .unwrap();
+
+    watcher.send(WatchClient(client)).await.unwrap();
+
+    systems[1].actor_system().shutdown().await;
+
+    let (stopped_id, reason) = tokio::time::timeout(Duration::from_secs(5), rx)
+        .await
+        .expect("client stopped")
+        .unwrap();
+
+    assert_eq!(stopped_id, client_id);
+    assert_eq!(
+        client_id,
+        format!("RemoteClient-{}", node_addr(2)).into_actor_id()
+    );
+    assert_eq!(reason, StopReason::NodeLost);
+}
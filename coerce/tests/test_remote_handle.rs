@@ -1,6 +1,10 @@
 use crate::util::create_trace_logger;
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::{Bytes, Handler};
 use coerce::actor::system::ActorSystem;
+use coerce::actor::Actor;
 use coerce::remote::system::RemoteActorSystem;
+use coerce_macros::{remote_handler, JsonMessage};
 use util::*;
 
 pub mod util;
@@ -66,13 +70,13 @@ pub async fn test_remote_handle_from_json() {
         .handle_message(
             "TestActor.SetStatusRequest",
             actor.id.clone(),
-            b"{\"status\": \"Active\"}",
+            Bytes::from_static(b"{\"status\": \"Active\"}"),
         )
         .await;
 
     let current_status = actor.send(GetStatusRequest).await;
 
-    assert_eq!(res, Ok(b"\"Ok\"".to_vec()));
+    assert_eq!(res, Ok(Bytes::from_static(b"\"Ok\"")));
 
     assert_eq!(initial_status, Ok(GetStatusResponse::None));
     assert_eq!(
@@ -80,3 +84,61 @@ pub async fn test_remote_handle_from_json() {
         Ok(GetStatusResponse::Ok(TestActorStatus::Active))
     );
 }
+
+pub struct RegisteredActor;
+
+impl Actor for RegisteredActor {}
+
+#[derive(JsonMessage, Serialize, Deserialize)]
+#[result("String")]
+pub struct Ping(String);
+
+#[derive(JsonMessage, Serialize, Deserialize)]
+#[result("usize")]
+pub struct Count(Vec<u8>);
+
+#[remote_handler("RegisteredActor.Ping")]
+#[async_trait]
+impl Handler<Ping> for RegisteredActor {
+    async fn handle(&mut self, message: Ping, _ctx: &mut ActorContext) -> String {
+        format!("pong: {}", message.0)
+    }
+}
+
+#[remote_handler]
+#[async_trait]
+impl Handler<Count> for RegisteredActor {
+    async fn handle(&mut self, message: Count, _ctx: &mut ActorContext) -> usize {
+        message.0.len()
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_handler_registration() {
+    let ctx = ActorSystem::new();
+    let actor = ctx.new_tracked_actor(RegisteredActor).await.unwrap();
+
+    let remote = RemoteActorSystem::builder()
+        .with_actor_system(ctx)
+        .build()
+        .await;
+
+    assert_eq!(
+        remote.handler_name::<RegisteredActor, Ping>(),
+        Some("RegisteredActor.Ping".to_string())
+    );
+    assert_eq!(
+        remote.handler_name::<RegisteredActor, Count>(),
+        Some("RegisteredActor.Count".to_string())
+    );
+
+    let res = remote
+        .handle_message(
+            "RegisteredActor.Ping",
+            actor.id.clone(),
+            Bytes::from_static(b"\"coerce\""),
+        )
+        .await;
+
+    assert_eq!(res, Ok(Bytes::from_static(b"\"pong: coerce\"")));
+}
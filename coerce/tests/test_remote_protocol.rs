@@ -0,0 +1,248 @@
+use bytes::BytesMut;
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::Handler;
+use coerce::actor::scheduler::ActorType::Tracked;
+use coerce::actor::system::ActorSystem;
+use coerce::actor::{Actor, ActorRef, ActorRefErr, IntoActorId};
+use coerce::remote::net::codec::NetworkCodec;
+use coerce::remote::net::message::{ClientEvent, SessionEvent};
+use coerce::remote::net::proto::network as proto;
+use coerce::remote::net::protocol::{
+    ProtocolFeature, ProtocolVersion, MIN_PROTOCOL_VERSION, PROTOCOL_VERSION,
+};
+use coerce::remote::net::StreamData;
+use coerce::remote::system::RemoteActorSystem;
+use coerce::remote::RemoteActorRef;
+use coerce_macros::JsonMessage;
+use futures::{SinkExt, StreamExt};
+use tokio::net::TcpStream;
+use tokio_util::codec::{Decoder, Encoder, Framed};
+use uuid::Uuid;
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+pub struct EchoActor;
+
+impl Actor for EchoActor {}
+
+#[derive(JsonMessage, Serialize, Deserialize)]
+#[result("String")]
+pub struct Echo(String);
+
+#[async_trait]
+impl Handler<Echo> for EchoActor {
+    async fn handle(&mut self, message: Echo, _ctx: &mut ActorContext) -> String {
+        message.0
+    }
+}
+
+async fn protocol_node(id: u64, features: Vec<ProtocolFeature>) -> RemoteActorSystem {
+    RemoteActorSystem::builder()
+        .with_tag(format!("protocol-node-{}", id))
+        .with_id(id)
+        .with_actor_system(ActorSystem::new())
+        .with_handlers(|handlers| handlers.with_handler::<EchoActor, Echo>("EchoActor.Echo"))
+        .configure(move |c| c.protocol_features(features))
+        .build()
+        .await
+}
+
+async fn assert_large_message_round_trip(
+    features_a: Vec<ProtocolFeature>,
+    features_b: Vec<ProtocolFeature>,
+    port: u16,
+) {
+    let remote = protocol_node(1, features_a).await;
+    let remote_2 = protocol_node(2, features_b).await;
+
+    remote
+        .clone()
+        .cluster_worker()
+        .listen_addr(format!("localhost:{}", port))
+        .start()
+        .await;
+
+    remote_2
+        .clone()
+        .cluster_worker()
+        .listen_addr(format!("localhost:{}", port + 1))
+        .with_seed_addr(format!("localhost:{}", port))
+        .start()
+        .await;
+
+    assert_eq!(remote.get_nodes().await.len(), 2);
+    assert_eq!(remote_2.get_nodes().await.len(), 2);
+
+    let actor_id = "echo".into_actor_id();
+    let _ = remote
+        .actor_system()
+        .new_actor(actor_id.clone(), EchoActor, Tracked)
+        .await
+        .unwrap();
+
+    let actor_ref = ActorRef::from(RemoteActorRef::<EchoActor>::new(
+        actor_id,
+        remote.node_id(),
+        remote_2.clone(),
+    ));
+
+    let payload = "coerce".repeat(64 * 1024);
+    let result = actor_ref.send(Echo(payload.clone())).await;
+
+    assert_eq!(result, Ok(payload));
+}
+
+#[tokio::test]
+pub async fn test_remote_protocol_compressed_cluster() {
+    util::create_trace_logger();
+
+    assert_large_message_round_trip(ProtocolFeature::all(), ProtocolFeature::all(), 30601).await;
+}
+
+#[tokio::test]
+pub async fn test_remote_protocol_mixed_features_cluster() {
+    util::create_trace_logger();
+
+    assert_large_message_round_trip(ProtocolFeature::all(), vec![], 30603).await;
+}
+
+#[tokio::test]
+pub async fn test_remote_protocol_rejects_incompatible_versions() {
+    util::create_trace_logger();
+
+    let remote = protocol_node(1, ProtocolFeature::all()).await;
+    remote
+        .clone()
+        .cluster_worker()
+        .listen_addr("localhost:30605")
+        .start()
+        .await;
+
+    let stream = TcpStream::connect("localhost:30605").await.unwrap();
+    let mut framed = Framed::new(stream, NetworkCodec::default());
+
+    let identity = framed.next().await.unwrap().unwrap();
+    match ClientEvent::read_from_bytes(identity) {
+        Some(ClientEvent::Identity(identity)) => {
+            let protocol = identity.protocol.unwrap();
+            assert_eq!(protocol.version, PROTOCOL_VERSION);
+            assert_eq!(protocol.min_version, MIN_PROTOCOL_VERSION);
+        }
+        _ => panic!("expected identity"),
+    }
+
+    let message_id = Uuid::new_v4().to_string();
+    let identify = SessionEvent::Identify(proto::IdentifyEvent {
+        source_node_id: 2,
+        source_node_tag: "protocol-node-2".to_string(),
+        message_id: message_id.clone(),
+        protocol: Some(proto::ProtocolVersion {
+            version: PROTOCOL_VERSION + 10,
+            min_version: PROTOCOL_VERSION + 10,
+            ..Default::default()
+        })
+        .into(),
+        ..Default::default()
+    });
+
+    framed
+        .send(&identify.write_to_bytes().unwrap())
+        .await
+        .unwrap();
+
+    let error = framed.next().await.unwrap().unwrap();
+    match ClientEvent::read_from_bytes(error) {
+        Some(ClientEvent::Err(e)) => {
+            assert_eq!(e.message_id, message_id);
+
+            let error: ActorRefErr = e.error.unwrap().into();
+            assert!(matches!(error, ActorRefErr::IncompatibleProtocol));
+        }
+        _ => panic!("expected incompatible protocol error"),
+    }
+
+    assert!(matches!(framed.next().await, None | Some(Err(_))));
+}
+
+#[test]
+pub fn test_protocol_negotiation() {
+    let local = ProtocolVersion::current(&ProtocolFeature::all());
+
+    let negotiated = local.negotiate(&ProtocolVersion::legacy()).unwrap();
+    assert_eq!(negotiated.version(), 1);
+    assert!(!negotiated.supports(ProtocolFeature::Lz4Compression));
+
+    let negotiated = local
+        .negotiate(&ProtocolVersion::current(&ProtocolFeature::all()))
+        .unwrap();
+    assert_eq!(negotiated.version(), PROTOCOL_VERSION);
+    assert!(negotiated.supports(ProtocolFeature::Lz4Compression));
+
+    let negotiated = local.negotiate(&ProtocolVersion::current(&[])).unwrap();
+    assert!(!negotiated.supports(ProtocolFeature::Lz4Compression));
+
+    let newer = ProtocolVersion {
+        version: PROTOCOL_VERSION + 1,
+        min_version: PROTOCOL_VERSION,
+        features: vec!["lz4-compression".to_string(), "unknown-feature".to_string()],
+    };
+
+    let negotiated = local.negotiate(&newer).unwrap();
+    assert_eq!(negotiated.version(), PROTOCOL_VERSION);
+    assert_eq!(negotiated.features().count(), 1);
+
+    let incompatible = ProtocolVersion {
+        version: PROTOCOL_VERSION + 2,
+        min_version: PROTOCOL_VERSION + 1,
+        features: vec![],
+    };
+
+    assert!(!local.is_compatible(&incompatible));
+    assert!(local.negotiate(&incompatible).is_err());
+}
+
+#[test]
+pub fn test_codec_compression() {
+    let mut encoder = NetworkCodec::default();
+    encoder.set_compression(true);
+
+    let mut decoder = NetworkCodec::default();
+
+    let mut item = vec![1u8];
+    item.extend("coerce".repeat(1024).into_bytes());
+
+    let mut buffer = BytesMut::new();
+    encoder.encode(&item, &mut buffer).unwrap();
+    assert!(buffer.len() < item.len());
+
+    let decoded = decoder.decode(&mut buffer).unwrap().unwrap();
+    assert_eq!(decoded.as_ref(), item.as_slice());
+
+    let small = vec![1u8, 2, 3, 4];
+    encoder.encode(&small, &mut buffer).unwrap();
+    assert_eq!(buffer.len(), 4 + small.len());
+
+    let decoded = decoder.decode(&mut buffer).unwrap().unwrap();
+    assert_eq!(decoded.as_ref(), small.as_slice());
+}
+
+#[test]
+pub fn test_codec_rejects_oversized_decompressed_frames() {
+    let mut encoder = NetworkCodec::default();
+    encoder.set_compression(true);
+
+    let mut item = vec![1u8];
+    item.extend(vec![0u8; 64 * 1024]);
+
+    let mut buffer = BytesMut::new();
+    encoder.encode(&item, &mut buffer).unwrap();
+
+    let mut decoder = NetworkCodec::new(32 * 1024);
+    assert!(decoder.decode(&mut buffer).is_err());
+}
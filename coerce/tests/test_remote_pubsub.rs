@@ -1,6 +1,6 @@
 use coerce::actor::context::ActorContext;
 
-use coerce::actor::message::Handler;
+use coerce::actor::message::{Bytes, Handler};
 use coerce::actor::system::ActorSystem;
 use coerce::actor::Actor;
 
@@ -202,7 +202,7 @@ pub async fn test_pubsub_distributed() {
 }
 
 impl StreamData for StatusEvent {
-    fn read_from_bytes(data: Vec<u8>) -> Option<Self> {
+    fn read_from_bytes(data: Bytes) -> Option<Self> {
         match data.first() {
             Some(0) => Some(StatusEvent::Offline),
             Some(1) => Some(StatusEvent::Online),
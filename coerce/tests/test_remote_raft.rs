@@ -0,0 +1,359 @@
+use coerce::actor::system::ActorSystem;
+use coerce::persistent::journal::provider::inmemory::InMemoryStorageProvider;
+use coerce::persistent::Persistence;
+use coerce::remote::net::transport::{FaultInjector, MemoryTransport, TransportRef};
+use coerce::remote::raft::message::GetStatus;
+use coerce::remote::raft::state_machine::KeyValueStore;
+use coerce::remote::raft::{Raft, RaftConfig, RaftError, RaftRole, RaftStatus};
+use coerce::remote::system::builder::RemoteActorSystemBuilder;
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use std::cell::Cell;
+use std::sync::Arc;
+use std::time::Duration;
+use util::{memory_node, node_addr, start_cluster, wait_until};
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+const VOTERS: [NodeId; 3] = [1, 2, 3];
+
+fn raft_node(
+    id: NodeId,
+    transport: TransportRef,
+    store: KeyValueStore,
+    snapshot_threshold: u64,
+) -> RemoteActorSystemBuilder {
+    let raft_config = RaftConfig {
+        election_timeout_min: Duration::from_millis(300),
+        election_timeout_max: Duration::from_millis(600),
+        heartbeat_interval: Duration::from_millis(50),
+        ..RaftConfig::new(VOTERS.to_vec())
+    }
+    .with_cluster_leader(true)
+    .with_snapshot_threshold(snapshot_threshold)
+    .with_state_machine(move || Box::new(store.clone()));
+
+    let actor_system =
+        ActorSystem::new().to_persistent(Persistence::from(InMemoryStorageProvider::new()));
+
+    memory_node(id, actor_system, transport).configure(move |c| c.raft(raft_config.clone()))
+}
+
+async fn raft_cluster(injector: &FaultInjector) -> (Vec<RemoteActorSystem>, Vec<KeyValueStore>) {
+    raft_cluster_with_snapshots(injector, 1024).await
+}
+
+async fn raft_cluster_with_snapshots(
+    injector: &FaultInjector,
+    snapshot_threshold: u64,
+) -> (Vec<RemoteActorSystem>, Vec<KeyValueStore>) {
+    let memory: TransportRef = Arc::new(MemoryTransport::new());
+    let mut stores = vec![];
+
+    let systems = start_cluster(VOTERS.len(), |id| {
+        let store = KeyValueStore::new();
+        stores.push(store.clone());
+
+        raft_node(
+            id,
+            injector.transport(memory.clone()),
+            store,
+            snapshot_threshold,
+        )
+    })
+    .await;
+
+    (systems, stores)
+}
+
+/// Stops the node's Raft actor and starts it again with an empty state machine, so everything it
+/// knows afterwards was recovered from its persisted state (or sent by the leader).
+async fn restart_raft(system: &RemoteActorSystem) -> KeyValueStore {
+    let _ = system.raft().await.unwrap().stop().await;
+
+    let store = KeyValueStore::new();
+    let raft_config = system
+        .config()
+        .raft_config()
+        .unwrap()
+        .clone()
+        .with_state_machine({
+            let store = store.clone();
+            move || Box::new(store.clone())
+        });
+
+    Raft::start(system.clone(), raft_config)
+        .await
+        .expect("recover raft state");
+
+    store
+}
+
+async fn status(system: &RemoteActorSystem) -> RaftStatus {
+    system.raft().await.unwrap().send(GetStatus).await.unwrap()
+}
+
+/// The leader every node in `systems` agrees on, if there is exactly one.
+async fn agreed_leader(systems: &[&RemoteActorSystem]) -> Option<NodeId> {
+    let mut leaders = vec![];
+    for system in systems {
+        let status = status(system).await;
+        if status.role == RaftRole::Leader {
+            leaders.push(status.node_id);
+        }
+    }
+
+    if leaders.len() != 1 {
+        return None;
+    }
+
+    let leader = leaders[0];
+    for system in systems {
+        if status(system).await.leader_id != Some(leader) {
+            return None;
+        }
+    }
+
+    Some(leader)
+}
+
+async fn wait_for_leader(systems: &[&RemoteActorSystem]) -> Option<NodeId> {
+    let leader = Cell::new(None);
+    let leader_ref = &leader;
+    wait_until(
+        || async move {
+            leader_ref.set(agreed_leader(systems).await);
+            leader_ref.get().is_some()
+        },
+        Duration::from_secs(5),
+    )
+    .await;
+
+    leader.get()
+}
+
+async fn wait_for_value(store: &KeyValueStore, key: &str, value: &[u8]) -> bool {
+    wait_until(
+        || async move { store.get(key).as_deref() == Some(value) },
+        Duration::from_secs(5),
+    )
+    .await
+}
+
+#[tokio::test]
+pub async fn test_remote_raft_leader_election() {
+    util::create_trace_logger();
+
+    let injector = FaultInjector::new();
+    let (systems, _stores) = raft_cluster(&injector).await;
+
+    let leader = wait_for_leader(&systems.iter().collect::<Vec<_>>())
+        .await
+        .expect("raft leader");
+
+    // The raft leader is used as the cluster leader
+    let systems = &systems;
+    wait_until(
+        || async move { systems.iter().all(|s| s.current_leader() == Some(leader)) },
+        Duration::from_secs(2),
+    )
+    .await;
+
+    for system in systems {
+        assert_eq!(system.current_leader(), Some(leader));
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_raft_replication() {
+    util::create_trace_logger();
+
+    let injector = FaultInjector::new();
+    let (systems, stores) = raft_cluster(&injector).await;
+
+    let leader = wait_for_leader(&systems.iter().collect::<Vec<_>>())
+        .await
+        .expect("raft leader");
+
+    let leader_system = &systems[leader as usize - 1];
+    let follower_system = systems.iter().find(|s| s.node_id() != leader).unwrap();
+
+    let previous = leader_system
+        .raft_propose(KeyValueStore::set_command("shard-1", b"node-1".to_vec()))
+        .await;
+    assert_eq!(previous, Ok(vec![]));
+
+    let previous = leader_system
+        .raft_propose(KeyValueStore::set_command("shard-1", b"node-2".to_vec()))
+        .await;
+    assert_eq!(previous, Ok(b"node-1".to_vec()));
+
+    assert_eq!(
+        follower_system
+            .raft_propose(KeyValueStore::delete_command("shard-1"))
+            .await,
+        Err(RaftError::NotLeader(Some(leader)))
+    );
+
+    for store in &stores {
+        assert!(wait_for_value(store, "shard-1", b"node-2").await);
+        assert_eq!(store.len(), 1);
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_raft_leader_partitioned() {
+    util::create_trace_logger();
+
+    let injector = FaultInjector::new();
+    let (systems, stores) = raft_cluster(&injector).await;
+
+    let old_leader = wait_for_leader(&systems.iter().collect::<Vec<_>>())
+        .await
+        .expect("raft leader");
+
+    let old_leader_system = &systems[old_leader as usize - 1];
+    let old_term = status(old_leader_system).await.term;
+
+    injector.isolate(&node_addr(old_leader));
+
+    // The majority side elects a new leader in a later term
+    let majority: Vec<&RemoteActorSystem> = systems
+        .iter()
+        .filter(|s| s.node_id() != old_leader)
+        .collect();
+
+    let new_leader = wait_for_leader(&majority).await.expect("new raft leader");
+    let new_leader_system = &systems[new_leader as usize - 1];
+
+    assert_ne!(new_leader, old_leader);
+    assert!(status(new_leader_system).await.term > old_term);
+
+    assert_eq!(
+        new_leader_system
+            .raft_propose(KeyValueStore::set_command("key", b"value".to_vec()))
+            .await,
+        Ok(vec![])
+    );
+
+    // The old leader can't reach a majority, so it steps down rather than accepting writes
+    let stepped_down = wait_until(
+        || async move { status(old_leader_system).await.role != RaftRole::Leader },
+        Duration::from_secs(2),
+    )
+    .await;
+
+    assert!(stepped_down);
+
+    // Stepping down clears the cluster leader, rather than leaving the node believing it leads
+    wait_until(
+        || async move { old_leader_system.current_leader().is_none() },
+        Duration::from_secs(2),
+    )
+    .await;
+
+    assert_eq!(old_leader_system.current_leader(), None);
+    assert!(matches!(
+        old_leader_system
+            .raft_propose(KeyValueStore::set_command("key", b"stale".to_vec()))
+            .await,
+        Err(RaftError::NotLeader(_))
+    ));
+
+    // Once healed, the old leader catches up with the entries it missed
+    injector.heal_all();
+
+    for store in &stores {
+        assert!(wait_for_value(store, "key", b"value").await);
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_raft_restart_recovers_state() {
+    util::create_trace_logger();
+
+    let injector = FaultInjector::new();
+    let (systems, stores) = raft_cluster(&injector).await;
+
+    let leader = wait_for_leader(&systems.iter().collect::<Vec<_>>())
+        .await
+        .expect("raft leader");
+
+    let leader_system = &systems[leader as usize - 1];
+    for i in 0..3 {
+        let command = KeyValueStore::set_command(format!("key-{}", i), b"value".to_vec());
+        assert!(leader_system.raft_propose(command).await.is_ok());
+    }
+
+    let follower = VOTERS.into_iter().find(|n| *n != leader).unwrap();
+    let follower_system = &systems[follower as usize - 1];
+    assert!(wait_for_value(&stores[follower as usize - 1], "key-2", b"value").await);
+
+    let before = status(follower_system).await;
+
+    // Isolated, the restarted node only knows what it persisted before it was stopped
+    injector.isolate(&node_addr(follower));
+    let store = restart_raft(follower_system).await;
+
+    let after = status(follower_system).await;
+    assert!(after.term >= before.term);
+    assert_eq!(after.last_log_index, before.last_log_index);
+
+    // Once it can reach the leader again, the recovered entries are committed and applied
+    injector.heal_all();
+
+    for i in 0..3 {
+        assert!(wait_for_value(&store, &format!("key-{}", i), b"value").await);
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_raft_snapshot_installed_on_lagging_follower() {
+    util::create_trace_logger();
+
+    let injector = FaultInjector::new();
+    let (systems, stores) = raft_cluster_with_snapshots(&injector, 5).await;
+
+    let leader = wait_for_leader(&systems.iter().collect::<Vec<_>>())
+        .await
+        .expect("raft leader");
+
+    let leader_system = &systems[leader as usize - 1];
+    let follower = VOTERS.into_iter().find(|n| *n != leader).unwrap();
+    let follower_system = &systems[follower as usize - 1];
+
+    // The remaining voters are still a majority, so entries keep being committed and compacted
+    injector.isolate(&node_addr(follower));
+
+    for i in 0..20 {
+        let command = KeyValueStore::set_command(format!("key-{}", i), b"value".to_vec());
+        assert!(leader_system.raft_propose(command).await.is_ok());
+    }
+
+    let leader_status = status(leader_system).await;
+    assert!(leader_status.snapshot_index >= 15);
+    assert!(leader_status.last_log_index - leader_status.snapshot_index < 5);
+
+    // The follower is behind the leader's snapshot, so it's sent the snapshot rather than entries
+    injector.heal_all();
+
+    let follower_store = &stores[follower as usize - 1];
+    for i in 0..20 {
+        assert!(wait_for_value(follower_store, &format!("key-{}", i), b"value").await);
+    }
+
+    assert!(status(follower_system).await.snapshot_index > 0);
+
+    // Restarting restores the state machine from the persisted snapshot
+    injector.isolate(&node_addr(follower));
+    let store = restart_raft(follower_system).await;
+
+    let snapshot_index = status(follower_system).await.snapshot_index;
+    assert!(snapshot_index > 0);
+    assert_eq!(store.get("key-0").as_deref(), Some(&b"value"[..]));
+}
@@ -0,0 +1,197 @@
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::{Handler, Message};
+use coerce::actor::system::ActorSystem;
+use coerce::actor::{Actor, ActorRef, IntoActor, LocalActorRef};
+use coerce::persistent::journal::provider::inmemory::InMemoryStorageProvider;
+use coerce::persistent::Persistence;
+use coerce::remote::delivery::consumer::ConsumerController;
+use coerce::remote::delivery::producer::{
+    Deliver, ProducerConfig, ProducerController, UnconfirmedCount,
+};
+use coerce::remote::net::transport::{FaultInjector, MemoryTransport, TransportRef};
+use coerce::remote::system::builder::RemoteActorSystemBuilder;
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use coerce::remote::RemoteActorRef;
+use coerce_macros::JsonMessage;
+use std::sync::Arc;
+use std::time::Duration;
+use util::{memory_node, node_addr, start_cluster, wait_until};
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+#[derive(Default)]
+pub struct Recorder {
+    received: Vec<u32>,
+}
+
+impl Actor for Recorder {}
+
+#[derive(JsonMessage, Serialize, Deserialize)]
+#[result("()")]
+pub struct Record(u32);
+
+pub struct GetRecorded;
+
+impl Message for GetRecorded {
+    type Result = Vec<u32>;
+}
+
+#[async_trait]
+impl Handler<Record> for Recorder {
+    async fn handle(&mut self, message: Record, _ctx: &mut ActorContext) {
+        self.received.push(message.0);
+    }
+}
+
+#[async_trait]
+impl Handler<GetRecorded> for Recorder {
+    async fn handle(&mut self, _: GetRecorded, _ctx: &mut ActorContext) -> Vec<u32> {
+        self.received.clone()
+    }
+}
+
+fn delivery_node(
+    id: NodeId,
+    system: ActorSystem,
+    transport: TransportRef,
+) -> RemoteActorSystemBuilder {
+    memory_node(id, system, transport)
+        .with_handlers(|handlers| handlers.with_handler::<Recorder, Record>("Recorder.Record"))
+}
+
+async fn deliver(
+    producer: &LocalActorRef<ProducerController>,
+    system: &RemoteActorSystem,
+    value: u32,
+) -> u64 {
+    let deliver = Deliver::new::<Recorder, Record>(Record(value), system).unwrap();
+    producer.send(deliver).await.unwrap().unwrap()
+}
+
+async fn wait_until_confirmed(producer: &LocalActorRef<ProducerController>) -> bool {
+    wait_until(
+        || async move { producer.send(UnconfirmedCount).await.unwrap() == 0 },
+        Duration::from_secs(5),
+    )
+    .await
+}
+
+#[tokio::test]
+pub async fn test_remote_reliable_delivery_deduplicates_redeliveries() {
+    util::create_trace_logger();
+
+    let injector = FaultInjector::new();
+    let memory: TransportRef = Arc::new(MemoryTransport::new());
+    let systems = start_cluster(2, |id| {
+        delivery_node(id, ActorSystem::new(), injector.transport(memory.clone()))
+    })
+    .await;
+
+    let (remote, remote_2) = (&systems[0], &systems[1]);
+
+    let recorder = Recorder::default()
+        .into_actor(Some("recorder"), remote.actor_system())
+        .await
+        .unwrap();
+
+    let _ = ConsumerController::new("recorder")
+        .into_actor(Some("recorder-consumer"), remote.actor_system())
+        .await
+        .unwrap();
+
+    let consumer = ActorRef::from(RemoteActorRef::<ConsumerController>::new(
+        "recorder-consumer".into(),
+        remote.node_id(),
+        remote_2.clone(),
+    ));
+
+    // Confirmations take longer to arrive than the redelivery interval,
+    // so every message is delivered more than once
+    injector.set_latency(
+        &node_addr(1),
+        &node_addr(2),
+        Duration::from_millis(150),
+        Duration::ZERO,
+    );
+
+    let producer = ProducerController::new(
+        "recorder-producer",
+        consumer,
+        ProducerConfig {
+            redelivery_interval: Duration::from_millis(50),
+            ..Default::default()
+        },
+    )
+    .into_actor(Some("recorder-producer"), remote_2.actor_system())
+    .await
+    .unwrap();
+
+    for i in 0..10 {
+        assert_eq!(deliver(&producer, remote_2, i).await, i as u64 + 1);
+    }
+
+    assert!(wait_until_confirmed(&producer).await);
+    assert_eq!(
+        recorder.send(GetRecorded).await.unwrap(),
+        (0..10).collect::<Vec<u32>>()
+    );
+}
+
+#[tokio::test]
+pub async fn test_remote_reliable_delivery_survives_producer_restart() {
+    util::create_trace_logger();
+
+    let system =
+        ActorSystem::new().to_persistent(Persistence::from(InMemoryStorageProvider::new()));
+    let remote = delivery_node(1, system, Arc::new(MemoryTransport::new()))
+        .build()
+        .await;
+
+    let consumer = ConsumerController::new("late-recorder")
+        .into_actor(Some("late-recorder-consumer"), remote.actor_system())
+        .await
+        .unwrap();
+
+    let config = ProducerConfig {
+        redelivery_interval: Duration::from_millis(500),
+        persistent: true,
+    };
+
+    let producer = ProducerController::new(
+        "late-recorder-producer",
+        ActorRef::from(consumer.clone()),
+        config.clone(),
+    )
+    .into_actor(Some("late-recorder-producer"), remote.actor_system())
+    .await
+    .unwrap();
+
+    // The consumer doesn't exist yet, so none of these can be confirmed
+    for i in 1..=3 {
+        deliver(&producer, &remote, i).await;
+    }
+
+    assert_eq!(producer.send(UnconfirmedCount).await.unwrap(), 3);
+    producer.stop().await.unwrap();
+
+    let recorder = Recorder::default()
+        .into_actor(Some("late-recorder"), remote.actor_system())
+        .await
+        .unwrap();
+
+    let producer =
+        ProducerController::new("late-recorder-producer", ActorRef::from(consumer), config)
+            .into_actor(Some("late-recorder-producer"), remote.actor_system())
+            .await
+            .unwrap();
+
+    assert_eq!(deliver(&producer, &remote, 4).await, 4);
+    assert!(wait_until_confirmed(&producer).await);
+    assert_eq!(recorder.send(GetRecorded).await.unwrap(), vec![1, 2, 3, 4]);
+}
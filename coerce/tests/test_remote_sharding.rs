@@ -2,10 +2,12 @@ use crate::util::{
     GetStatusRequest, GetStatusResponse, SetStatusRequest, TestActor, TestActorStatus,
 };
 
-use coerce::actor::message::Message;
+use coerce::actor::context::{ActorContext, StopReason};
+use coerce::actor::message::{Handler, Message};
 use coerce::actor::system::ActorSystem;
 use coerce::actor::{
-    Actor, ActorCreationErr, ActorFactory, ActorRecipe, ActorRef, IntoActor, LocalActorRef,
+    Actor, ActorCreationErr, ActorFactory, ActorId, ActorRecipe, ActorRef, IntoActor, IntoActorId,
+    LocalActorRef,
 };
 use coerce::persistent::journal::provider::inmemory::InMemoryStorageProvider;
 use coerce::persistent::Persistence;
@@ -17,11 +19,13 @@ use coerce::remote::cluster::sharding::coordinator::{
 };
 
 use coerce::remote::cluster::sharding::host::stats::GetStats;
-use coerce::remote::cluster::sharding::host::ShardHost;
+use coerce::remote::cluster::sharding::host::{PassivateEntity, ShardHost};
 use coerce::remote::cluster::sharding::Sharding;
 use coerce::remote::handler::{ActorHandler, RemoteActorHandler};
 use coerce::remote::net::server::RemoteServer;
 use coerce::remote::system::{NodeId, RemoteActorSystem};
+use std::time::Duration;
+use tokio::sync::mpsc;
 
 mod sharding;
 pub mod util;
@@ -63,6 +67,48 @@ impl ActorFactory for TestActorFactory {
     }
 }
 
+pub struct StopReportingActor {
+    stopped: mpsc::UnboundedSender<(ActorId, Option<StopReason>)>,
+}
+
+#[async_trait]
+impl Actor for StopReportingActor {
+    async fn stopped(&mut self, ctx: &mut ActorContext) {
+        let _ = self
+            .stopped
+            .send((ctx.id().clone(), ctx.stop_reason().cloned()));
+    }
+}
+
+#[derive(JsonMessage, Serialize, Deserialize)]
+#[result("()")]
+pub struct Touch;
+
+#[async_trait]
+impl Handler<Touch> for StopReportingActor {
+    async fn handle(&mut self, _message: Touch, _ctx: &mut ActorContext) {}
+}
+
+#[derive(Clone)]
+pub struct StopReportingActorFactory {
+    stopped: mpsc::UnboundedSender<(ActorId, Option<StopReason>)>,
+}
+
+#[async_trait]
+impl ActorFactory for StopReportingActorFactory {
+    type Actor = StopReportingActor;
+    type Recipe = TestActorRecipe;
+
+    async fn create(
+        &self,
+        _recipe: TestActorRecipe,
+    ) -> Result<StopReportingActor, ActorCreationErr> {
+        Ok(StopReportingActor {
+            stopped: self.stopped.clone(),
+        })
+    }
+}
+
 async fn create_shard_coordinator<T: Actor>(
     remote: &RemoteActorSystem,
     node_id: NodeId,
@@ -77,6 +123,7 @@ async fn create_shard_coordinator<T: Actor>(
     shard_coordinator.add_host(ShardHostState {
         node_id,
         node_tag,
+        roles: vec![],
         shards: Default::default(),
         actor: shard_host,
         status: ShardHostStatus::Ready,
@@ -265,3 +312,51 @@ pub async fn test_shard_host_actor_request() {
 
     assert_eq!(res_after_system_restart.is_ok(), true);
 }
+
+#[tokio::test]
+pub async fn test_shard_passivated_entity_stop_reason() {
+    util::create_trace_logger();
+
+    let (tx, mut stopped) = mpsc::unbounded_channel();
+    let factory = StopReportingActorFactory { stopped: tx };
+
+    let sys = ActorSystem::new().to_persistent(Persistence::from(InMemoryStorageProvider::new()));
+    let remote = RemoteActorSystem::builder()
+        .with_actor_system(sys)
+        .with_tag("system-one")
+        .with_actors(|a| {
+            a.with_actor(factory)
+                .with_handler::<StopReportingActor, Touch>("Touch")
+        })
+        .with_id(1)
+        .single_node()
+        .build()
+        .await;
+
+    let sharding = Sharding::<StopReportingActorFactory>::builder(remote.clone())
+        .build()
+        .await;
+
+    let sharded_actor = sharding.get("passivated-actor", Some(TestActorRecipe));
+    sharded_actor.send(Touch).await.expect("touch");
+
+    sharding
+        .notify_host(PassivateEntity {
+            actor_id: "passivated-actor".into_actor_id(),
+        })
+        .unwrap();
+
+    let (actor_id, reason) = tokio::time::timeout(Duration::from_secs(2), stopped.recv())
+        .await
+        .expect("entity stopped")
+        .unwrap();
+
+    assert_eq!(actor_id, "passivated-actor".into_actor_id());
+    assert_eq!(reason, Some(StopReason::Passivated));
+
+    // the passivated entity is started again from its recipe on the next request
+    sharded_actor
+        .send(Touch)
+        .await
+        .expect("touch after passivation");
+}
@@ -79,6 +79,7 @@ async fn create_shard_coordinator<T: Actor>(
     shard_coordinator.add_host(ShardHostState {
         node_id,
         node_tag,
+        roles: vec![],
         shards: Default::default(),
         actor: shard_host,
         status: ShardHostStatus::Ready,
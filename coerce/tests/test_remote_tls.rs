@@ -0,0 +1,174 @@
+use coerce::actor::system::ActorSystem;
+use coerce::remote::net::client::config::RemoteClientConfig;
+use coerce::remote::net::tls::{TlsConfig, TlsConfigRef};
+use coerce::remote::system::RemoteActorSystem;
+use rcgen::{BasicConstraints, Certificate, CertificateParams, DnType, IsCa};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::AsyncReadExt;
+use tokio::net::TcpStream;
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+struct TestCertificates {
+    ca_pem: String,
+    node_pem: String,
+    node_key_pem: String,
+}
+
+fn generate_ca(name: &str) -> Certificate {
+    let mut params = CertificateParams::new(vec![]);
+    params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+    params.distinguished_name.push(DnType::CommonName, name);
+
+    Certificate::from_params(params).unwrap()
+}
+
+fn generate_certificates(ca: &Certificate) -> TestCertificates {
+    let node =
+        Certificate::from_params(CertificateParams::new(vec!["localhost".to_string()])).unwrap();
+
+    TestCertificates {
+        ca_pem: ca.serialize_pem().unwrap(),
+        node_pem: node.serialize_pem_with_signer(ca).unwrap(),
+        node_key_pem: node.serialize_private_key_pem(),
+    }
+}
+
+fn tls_config(certificates: &TestCertificates, mutual_tls: bool) -> TlsConfigRef {
+    Arc::new(
+        TlsConfig::builder()
+            .certificate_chain_pem(certificates.node_pem.as_bytes())
+            .unwrap()
+            .private_key_pem(certificates.node_key_pem.as_bytes())
+            .unwrap()
+            .trusted_ca_pem(certificates.ca_pem.as_bytes())
+            .unwrap()
+            .mutual_tls(mutual_tls)
+            .build()
+            .unwrap(),
+    )
+}
+
+async fn tls_node(id: u64, tls: TlsConfigRef) -> RemoteActorSystem {
+    RemoteActorSystem::builder()
+        .with_tag(format!("tls-node-{}", id))
+        .with_id(id)
+        .with_actor_system(ActorSystem::new())
+        .configure(move |c| c.tls(tls))
+        .build()
+        .await
+}
+
+#[tokio::test]
+pub async fn test_remote_tls_cluster_formation() {
+    util::create_trace_logger();
+
+    let ca = generate_ca("coerce-test-ca");
+    let tls = tls_config(&generate_certificates(&ca), true);
+
+    let remote = tls_node(1, tls.clone()).await;
+    let remote_2 = tls_node(2, tls).await;
+
+    remote
+        .clone()
+        .cluster_worker()
+        .listen_addr("localhost:30301")
+        .start()
+        .await;
+
+    remote_2
+        .clone()
+        .cluster_worker()
+        .listen_addr("localhost:30302")
+        .with_seed_addr("localhost:30301")
+        .start()
+        .await;
+
+    let nodes_a = remote.get_nodes().await;
+    let nodes_b = remote_2.get_nodes().await;
+
+    assert_eq!(nodes_a.len(), 2);
+    assert_eq!(nodes_b.len(), 2);
+}
+
+#[tokio::test]
+pub async fn test_remote_tls_rejects_untrusted_peers() {
+    util::create_trace_logger();
+
+    let ca = generate_ca("coerce-test-ca");
+    let certificates = generate_certificates(&ca);
+    let remote = tls_node(1, tls_config(&certificates, true)).await;
+
+    remote
+        .cluster_worker()
+        .listen_addr("localhost:30303")
+        .start()
+        .await;
+
+    let trusted = tls_config(&certificates, true);
+    let stream = TcpStream::connect("localhost:30303").await.unwrap();
+    let mut stream = trusted.connect("localhost:30303", stream).await.unwrap();
+    let mut buf = [0u8; 64];
+    assert!(stream.read(&mut buf).await.unwrap() > 0);
+
+    let untrusted_ca = generate_ca("untrusted-ca");
+    let untrusted = tls_config(&generate_certificates(&untrusted_ca), true);
+    let stream = TcpStream::connect("localhost:30303").await.unwrap();
+    assert!(untrusted.connect("localhost:30303", stream).await.is_err());
+
+    let no_client_certificate = tls_config(&certificates, false);
+    let stream = TcpStream::connect("localhost:30303").await.unwrap();
+    let read = match no_client_certificate
+        .connect("localhost:30303", stream)
+        .await
+    {
+        Ok(mut stream) => tokio::time::timeout(Duration::from_secs(1), stream.read(&mut buf))
+            .await
+            .unwrap(),
+        Err(e) => Err(e),
+    };
+
+    assert!(matches!(read, Err(_) | Ok(0)));
+}
+
+#[tokio::test]
+pub async fn test_remote_tls_handshake_timeout() {
+    util::create_trace_logger();
+
+    let ca = generate_ca("coerce-test-ca");
+    let tls = tls_config(&generate_certificates(&ca), true);
+    let remote = RemoteActorSystem::builder()
+        .with_tag("tls-node-1")
+        .with_id(1)
+        .with_actor_system(ActorSystem::new())
+        .with_client_config(RemoteClientConfig {
+            handshake_timeout: Duration::from_millis(200),
+            ..Default::default()
+        })
+        .configure(move |c| c.tls(tls))
+        .build()
+        .await;
+
+    remote
+        .cluster_worker()
+        .listen_addr("localhost:30305")
+        .start()
+        .await;
+
+    // The peer connects but never starts the TLS handshake, the server drops the connection
+    // rather than waiting on it indefinitely
+    let mut stream = TcpStream::connect("localhost:30305").await.unwrap();
+    let mut buf = [0u8; 64];
+    let read = tokio::time::timeout(Duration::from_secs(2), stream.read(&mut buf))
+        .await
+        .expect("connection was not dropped");
+
+    assert!(matches!(read, Ok(0) | Err(_)));
+}
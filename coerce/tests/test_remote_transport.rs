@@ -0,0 +1,238 @@
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::Handler;
+use coerce::actor::scheduler::ActorType::Tracked;
+use coerce::actor::system::ActorSystem;
+use coerce::actor::{Actor, ActorRef, IntoActorId};
+use coerce::remote::net::stream::NetworkStream;
+use coerce::remote::net::transport::{
+    MemoryTransport, PeerAddr, Transport, TransportListener, TransportRef,
+};
+use coerce::remote::system::RemoteActorSystem;
+use coerce::remote::RemoteActorRef;
+use coerce_macros::JsonMessage;
+use std::io;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+pub struct EchoActor;
+
+impl Actor for EchoActor {}
+
+#[derive(JsonMessage, Serialize, Deserialize)]
+#[result("String")]
+pub struct Echo(String);
+
+#[async_trait]
+impl Handler<Echo> for EchoActor {
+    async fn handle(&mut self, message: Echo, _ctx: &mut ActorContext) -> String {
+        message.0
+    }
+}
+
+/// Carries connections over a `MemoryTransport`, counting the bytes written to them.
+struct CountingTransport {
+    inner: MemoryTransport,
+    bytes_written: Arc<AtomicUsize>,
+}
+
+struct CountingListener {
+    inner: Box<dyn TransportListener>,
+    bytes_written: Arc<AtomicUsize>,
+}
+
+struct CountingStream {
+    inner: NetworkStream,
+    bytes_written: Arc<AtomicUsize>,
+}
+
+impl CountingStream {
+    fn wrap(inner: NetworkStream, bytes_written: &Arc<AtomicUsize>) -> NetworkStream {
+        NetworkStream::custom(CountingStream {
+            inner,
+            bytes_written: bytes_written.clone(),
+        })
+    }
+}
+
+#[async_trait]
+impl Transport for CountingTransport {
+    async fn listen(&self, addr: &str) -> io::Result<Box<dyn TransportListener>> {
+        Ok(Box::new(CountingListener {
+            inner: self.inner.listen(addr).await?,
+            bytes_written: self.bytes_written.clone(),
+        }))
+    }
+
+    async fn connect(&self, addr: &str) -> io::Result<NetworkStream> {
+        let stream = self.inner.connect(addr).await?;
+        Ok(CountingStream::wrap(stream, &self.bytes_written))
+    }
+}
+
+#[async_trait]
+impl TransportListener for CountingListener {
+    async fn accept(&mut self) -> io::Result<(NetworkStream, PeerAddr)> {
+        let (stream, peer_addr) = self.inner.accept().await?;
+        Ok((CountingStream::wrap(stream, &self.bytes_written), peer_addr))
+    }
+
+    fn local_addr(&self) -> String {
+        self.inner.local_addr()
+    }
+}
+
+impl AsyncRead for CountingStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for CountingStream {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let res = Pin::new(&mut self.inner).poll_write(cx, buf);
+        if let Poll::Ready(Ok(len)) = &res {
+            self.bytes_written.fetch_add(*len, Ordering::Relaxed);
+        }
+
+        res
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
+
+async fn transport_node(id: u64, transport: TransportRef) -> RemoteActorSystem {
+    RemoteActorSystem::builder()
+        .with_tag(format!("transport-node-{}", id))
+        .with_id(id)
+        .with_actor_system(ActorSystem::new())
+        .with_handlers(|handlers| handlers.with_handler::<EchoActor, Echo>("EchoActor.Echo"))
+        .configure(move |c| c.transport(transport.clone()))
+        .build()
+        .await
+}
+
+async fn assert_echo_round_trip(transport: TransportRef, listen_addr: &str, listen_addr_2: &str) {
+    let remote = transport_node(1, transport.clone()).await;
+    let remote_2 = transport_node(2, transport).await;
+
+    remote
+        .clone()
+        .cluster_worker()
+        .listen_addr(listen_addr)
+        .start()
+        .await;
+
+    remote_2
+        .clone()
+        .cluster_worker()
+        .listen_addr(listen_addr_2)
+        .with_seed_addr(listen_addr)
+        .start()
+        .await;
+
+    assert_eq!(remote.get_nodes().await.len(), 2);
+    assert_eq!(remote_2.get_nodes().await.len(), 2);
+
+    let actor_id = "echo-actor".into_actor_id();
+    let _ = remote
+        .actor_system()
+        .new_actor(actor_id.clone(), EchoActor, Tracked)
+        .await
+        .unwrap();
+
+    let actor_ref = ActorRef::from(RemoteActorRef::<EchoActor>::new(
+        actor_id,
+        remote.node_id(),
+        remote_2.clone(),
+    ));
+
+    let reply = actor_ref.send(Echo("hello".to_string())).await.unwrap();
+    assert_eq!(reply, "hello");
+}
+
+#[tokio::test]
+pub async fn test_remote_memory_transport_cluster() {
+    util::create_trace_logger();
+
+    let transport: TransportRef = Arc::new(MemoryTransport::new());
+    assert_echo_round_trip(transport, "memory-node-1", "memory-node-2").await;
+}
+
+#[tokio::test]
+pub async fn test_remote_custom_stream_transport_cluster() {
+    util::create_trace_logger();
+
+    let bytes_written = Arc::new(AtomicUsize::new(0));
+    let transport: TransportRef = Arc::new(CountingTransport {
+        inner: MemoryTransport::new(),
+        bytes_written: bytes_written.clone(),
+    });
+
+    assert_echo_round_trip(transport, "custom-node-1", "custom-node-2").await;
+    assert!(bytes_written.load(Ordering::Relaxed) > 0);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+pub async fn test_remote_unix_transport_cluster() {
+    use coerce::remote::net::transport::UnixTransport;
+
+    util::create_trace_logger();
+
+    let dir = std::env::temp_dir().join(format!("coerce-uds-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let socket_1 = dir.join("node-1.sock").display().to_string();
+    let socket_2 = dir.join("node-2.sock").display().to_string();
+
+    assert_echo_round_trip(Arc::new(UnixTransport), &socket_1, &socket_2).await;
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+pub async fn test_remote_memory_transport_addresses() {
+    let transport = MemoryTransport::new();
+
+    let error = transport.connect("memory-node").await.err().unwrap();
+    assert_eq!(error.kind(), io::ErrorKind::ConnectionRefused);
+
+    let listener = transport.listen("memory-node").await.unwrap();
+    assert_eq!(listener.local_addr(), "memory-node");
+
+    let error = transport.listen("memory-node").await.err().unwrap();
+    assert_eq!(error.kind(), io::ErrorKind::AddrInUse);
+
+    assert!(transport.connect("memory-node").await.is_ok());
+
+    drop(listener);
+
+    let error = transport.connect("memory-node").await.err().unwrap();
+    assert_eq!(error.kind(), io::ErrorKind::ConnectionRefused);
+    assert!(transport.listen("memory-node").await.is_ok());
+}
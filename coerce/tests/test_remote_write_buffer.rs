@@ -0,0 +1,234 @@
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::Handler;
+use coerce::actor::scheduler::ActorType::Tracked;
+use coerce::actor::system::ActorSystem;
+use coerce::actor::{Actor, ActorRef, ActorRefErr, IntoActorId};
+use coerce::remote::actor::message::FailBufferedWrites;
+use coerce::remote::actor::RemoteResponse;
+use coerce::remote::net::client::buffer::{
+    BufferOverflowPolicy, BufferedWrite, WriteBuffer, WriteBufferConfig,
+};
+use coerce::remote::net::client::send::Write;
+use coerce::remote::net::client::{ClientType, RemoteClient};
+use coerce::remote::net::message::SessionEvent;
+use coerce::remote::net::proto::network::MessageRequest;
+use coerce::remote::net::transport::{MemoryTransport, TransportRef};
+use coerce::remote::system::RemoteActorSystem;
+use coerce::remote::RemoteActorRef;
+use coerce_macros::JsonMessage;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::oneshot;
+use util::wait_until;
+use uuid::Uuid;
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+pub struct EchoActor;
+
+impl Actor for EchoActor {}
+
+#[derive(JsonMessage, Serialize, Deserialize)]
+#[result("String")]
+pub struct Echo(String);
+
+#[async_trait]
+impl Handler<Echo> for EchoActor {
+    async fn handle(&mut self, message: Echo, _ctx: &mut ActorContext) -> String {
+        message.0
+    }
+}
+
+async fn buffer_node(
+    id: u64,
+    transport: TransportRef,
+    write_buffer: WriteBufferConfig,
+) -> RemoteActorSystem {
+    RemoteActorSystem::builder()
+        .with_tag(format!("buffer-node-{}", id))
+        .with_id(id)
+        .with_actor_system(ActorSystem::new())
+        .with_handlers(|handlers| handlers.with_handler::<EchoActor, Echo>("EchoActor.Echo"))
+        .configure(move |c| {
+            c.transport(transport.clone())
+                .write_buffer(write_buffer.clone())
+        })
+        .build()
+        .await
+}
+
+fn buffered_write(len: usize) -> BufferedWrite {
+    BufferedWrite {
+        bytes: vec![0; len],
+        request_id: None,
+    }
+}
+
+fn request(system: &RemoteActorSystem) -> (SessionEvent, oneshot::Receiver<RemoteResponse>) {
+    let (res_tx, res_rx) = oneshot::channel();
+    let message_id = Uuid::new_v4();
+    system.push_request(message_id, res_tx);
+
+    let event = SessionEvent::NotifyActor(MessageRequest {
+        message_id: message_id.to_string(),
+        handler_type: "EchoActor.Echo".to_string(),
+        actor_id: "echo-actor".to_string(),
+        requires_response: true,
+        ..Default::default()
+    });
+
+    (event, res_rx)
+}
+
+async fn assert_node_unavailable(res_rx: oneshot::Receiver<RemoteResponse>, node_id: u64) {
+    let res = tokio::time::timeout(Duration::from_secs(1), res_rx)
+        .await
+        .expect("request was not failed")
+        .unwrap();
+
+    match res {
+        RemoteResponse::Err(e) => assert_eq!(e, ActorRefErr::NodeUnavailable(node_id)),
+        RemoteResponse::Ok(_) => panic!("expected request to fail"),
+    }
+}
+
+#[test]
+pub fn test_remote_write_buffer_limits() {
+    let mut buffer = WriteBuffer::new(WriteBufferConfig {
+        max_messages: 2,
+        max_bytes: 100,
+        overflow_policy: BufferOverflowPolicy::RejectNewest,
+    });
+
+    assert!(buffer.push(buffered_write(10)).is_empty());
+    assert!(buffer.push(buffered_write(20)).is_empty());
+    assert_eq!(buffer.push(buffered_write(30)).len(), 1);
+    assert_eq!(buffer.len(), 2);
+    assert_eq!(buffer.bytes_total(), 30);
+
+    let mut buffer = WriteBuffer::new(WriteBufferConfig {
+        max_messages: 10,
+        max_bytes: 100,
+        overflow_policy: BufferOverflowPolicy::DropOldest,
+    });
+
+    assert!(buffer.push(buffered_write(40)).is_empty());
+    assert!(buffer.push(buffered_write(50)).is_empty());
+
+    let dropped = buffer.push(buffered_write(30));
+    assert_eq!(dropped.len(), 1);
+    assert_eq!(dropped[0].bytes.len(), 40);
+    assert_eq!(buffer.bytes_total(), 80);
+
+    // Writes larger than the buffer are always rejected
+    assert_eq!(buffer.push(buffered_write(101)).len(), 1);
+    assert_eq!(buffer.len(), 2);
+}
+
+#[tokio::test]
+pub async fn test_remote_write_buffer_fails_requests() {
+    util::create_trace_logger();
+
+    let system = buffer_node(
+        1,
+        Arc::new(MemoryTransport::new()),
+        WriteBufferConfig {
+            max_messages: 1,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let client = RemoteClient::new(
+        "unreachable-node".to_string(),
+        0,
+        system.clone(),
+        ClientType::Worker,
+    )
+    .await;
+
+    let (first, first_rx) = request(&system);
+    let (second, second_rx) = request(&system);
+
+    client.notify(Write(first)).unwrap();
+    client.notify(Write(second)).unwrap();
+
+    // The buffer only has room for one message, so the second is rejected straight away
+    assert_node_unavailable(second_rx, 0).await;
+
+    client.notify(FailBufferedWrites(2)).unwrap();
+    assert_node_unavailable(first_rx, 2).await;
+}
+
+#[tokio::test]
+pub async fn test_remote_send_to_terminated_node_fails_fast() {
+    util::create_trace_logger();
+
+    let transport: TransportRef = Arc::new(MemoryTransport::new());
+    let remote = buffer_node(1, transport.clone(), WriteBufferConfig::default()).await;
+    let remote_2 = buffer_node(2, transport, WriteBufferConfig::default()).await;
+
+    remote
+        .clone()
+        .cluster_worker()
+        .listen_addr("buffer-node-1")
+        .start()
+        .await;
+
+    remote_2
+        .clone()
+        .cluster_worker()
+        .listen_addr("buffer-node-2")
+        .with_seed_addr("buffer-node-1")
+        .start()
+        .await;
+
+    let actor_id = "echo-actor".into_actor_id();
+    let _ = remote
+        .actor_system()
+        .new_actor(actor_id.clone(), EchoActor, Tracked)
+        .await
+        .unwrap();
+
+    let actor_ref = ActorRef::from(RemoteActorRef::<EchoActor>::new(
+        actor_id,
+        remote.node_id(),
+        remote_2.clone(),
+    ));
+
+    assert_eq!(
+        actor_ref.send(Echo("hello".to_string())).await,
+        Ok("hello".to_string())
+    );
+
+    remote.actor_system().shutdown().await;
+
+    let node_id = remote.node_id();
+    let remote_2 = &remote_2;
+    wait_until(
+        || async move { remote_2.is_node_terminated(node_id) },
+        Duration::from_secs(5),
+    )
+    .await;
+
+    assert!(remote_2.is_node_terminated(remote.node_id()));
+
+    let res = tokio::time::timeout(
+        Duration::from_secs(1),
+        actor_ref.send(Echo("hello".to_string())),
+    )
+    .await
+    .expect("send to terminated node should fail fast");
+
+    assert_eq!(res, Err(ActorRefErr::NodeUnavailable(remote.node_id())));
+    assert_eq!(
+        actor_ref.notify(Echo("hello".to_string())).await,
+        Err(ActorRefErr::NodeUnavailable(remote.node_id()))
+    );
+}
@@ -1,4 +1,4 @@
-use coerce::actor::context::ActorContext;
+use coerce::actor::context::{ActorContext, StopReason};
 use coerce::actor::system::ActorSystem;
 use coerce::actor::{Actor, ActorId, IntoActor, IntoActorId};
 
@@ -14,7 +14,7 @@ extern crate async_trait;
 extern crate serde;
 
 struct TestActor {
-    child_terminated_cb: Option<tokio::sync::oneshot::Sender<ActorId>>,
+    child_terminated_cb: Option<tokio::sync::oneshot::Sender<(ActorId, StopReason)>>,
 }
 
 #[async_trait]
@@ -27,9 +27,18 @@ impl Actor for TestActor {
         let _ = child.stop().await;
     }
 
-    async fn on_child_stopped(&mut self, id: &ActorId, _ctx: &mut ActorContext) {
-        info!("child terminated (id={})", &id);
-        let _ = self.child_terminated_cb.take().unwrap().send(id.clone());
+    async fn on_child_stopped(
+        &mut self,
+        id: &ActorId,
+        reason: &StopReason,
+        _ctx: &mut ActorContext,
+    ) {
+        info!("child terminated (id={}, reason={})", &id, &reason);
+        let _ = self
+            .child_terminated_cb
+            .take()
+            .unwrap()
+            .send((id.clone(), reason.clone()));
     }
 }
 
@@ -53,8 +62,11 @@ pub async fn test_actor_child_spawn_and_stop() {
     .await
     .expect("create actor");
 
-    on_child_stopped
+    let (child_id, reason) = on_child_stopped
         .await
         .expect("parent didn't receive the child-terminated notification");
+
+    assert_eq!(child_id, "child".into_actor_id());
+    assert_eq!(reason, StopReason::Normal);
     system.shutdown().await;
 }
@@ -6,9 +6,17 @@ use coerce_macros::JsonMessage;
 use serde::Serialize;
 
 use coerce::actor::system::ActorSystem;
-use coerce::remote::system::builder::RemoteSystemConfigBuilder;
+use coerce::persistent::journal::provider::inmemory::InMemoryStorageProvider;
+use coerce::persistent::Persistence;
+use coerce::remote::cluster::node::NodeStatus;
+use coerce::remote::heartbeat::HeartbeatConfig;
+use coerce::remote::net::transport::{MemoryTransport, TransportRef};
+use coerce::remote::system::builder::{RemoteActorSystemBuilder, RemoteSystemConfigBuilder};
 use coerce::remote::system::{NodeId, RemoteActorSystem};
+use std::future::Future;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
 use tracing::Level;
 use tracing_subscriber::fmt::format::FmtSpan;
 
@@ -148,6 +156,151 @@ where
     remote
 }
 
+/// The address node `id` listens on, in clusters started with [`start_cluster`].
+pub fn node_addr(id: NodeId) -> String {
+    format!("node-{}", id)
+}
+
+/// A node that connects to the rest of the cluster over `transport`, with a fast heartbeat so
+/// the cluster forms quickly.
+pub fn memory_node(
+    id: NodeId,
+    system: ActorSystem,
+    transport: TransportRef,
+) -> RemoteActorSystemBuilder {
+    RemoteActorSystem::builder()
+        .with_tag(node_addr(id))
+        .with_id(id)
+        .with_actor_system(system)
+        .configure(move |c| {
+            c.transport(transport).heartbeat(HeartbeatConfig {
+                interval: Duration::from_millis(100),
+                ..Default::default()
+            })
+        })
+}
+
+/// A heartbeat that notices unreachable nodes within a few hundred milliseconds, and declares
+/// them terminated after a second.
+pub fn fast_failure_detection() -> HeartbeatConfig {
+    HeartbeatConfig {
+        interval: Duration::from_millis(100),
+        ping_timeout: Duration::from_millis(100),
+        unhealthy_node_heartbeat_timeout: Duration::from_millis(300),
+        terminated_node_heartbeat_timeout: Duration::from_millis(1000),
+    }
+}
+
+/// Builds and starts `nodes` nodes, with ids from 1, each joining the cluster through node 1.
+pub async fn start_cluster<F>(nodes: usize, mut node: F) -> Vec<RemoteActorSystem>
+where
+    F: FnMut(NodeId) -> RemoteActorSystemBuilder,
+{
+    let mut systems = vec![];
+    for id in 1..=nodes as NodeId {
+        let remote = node(id).build().await;
+
+        let worker = remote.clone().cluster_worker().listen_addr(node_addr(id));
+        if id == 1 {
+            worker.start().await;
+        } else {
+            worker.with_seed_addr(node_addr(1)).start().await;
+        }
+
+        systems.push(remote);
+    }
+
+    systems
+}
+
+/// Starts a cluster over a `MemoryTransport`, where node `n` has the roles `node_roles[n - 1]`,
+/// and waits for it to form.
+pub async fn roles_cluster<F>(
+    node_roles: &[&[&str]],
+    leader_role: Option<&str>,
+    handlers: F,
+) -> Vec<RemoteActorSystem>
+where
+    F: 'static + Clone + (FnOnce(&mut RemoteSystemConfigBuilder) -> &mut RemoteSystemConfigBuilder),
+{
+    let transport: TransportRef = Arc::new(MemoryTransport::new());
+    let systems = start_cluster(node_roles.len(), |id| {
+        let leader_role = leader_role.map(|r| r.to_string());
+        let system =
+            ActorSystem::new().to_persistent(Persistence::from(InMemoryStorageProvider::new()));
+
+        memory_node(id, system, transport.clone())
+            .with_roles(node_roles[id as usize - 1].iter())
+            .with_handlers(handlers.clone())
+            .configure(move |c| match leader_role {
+                Some(leader_role) => c.leader_role(leader_role),
+                None => c,
+            })
+    })
+    .await;
+
+    wait_for_cluster(&systems).await;
+    systems
+}
+
+/// Waits until every node sees every other node as healthy, and knows who the leader is.
+pub async fn wait_for_cluster(systems: &[RemoteActorSystem]) -> bool {
+    wait_until(
+        || async move {
+            for system in systems {
+                if healthy_nodes(system).await != systems.len() || system.current_leader().is_none()
+                {
+                    return false;
+                }
+            }
+
+            true
+        },
+        Duration::from_secs(5),
+    )
+    .await
+}
+
+pub async fn healthy_nodes(system: &RemoteActorSystem) -> usize {
+    system
+        .get_nodes()
+        .await
+        .into_iter()
+        .filter(|n| n.status == NodeStatus::Healthy)
+        .count()
+}
+
+/// The status `system` has for node `node_id`, if it knows about the node.
+pub async fn node_status(system: &RemoteActorSystem, node_id: NodeId) -> Option<NodeStatus> {
+    system
+        .get_nodes()
+        .await
+        .into_iter()
+        .find(|n| n.id == node_id)
+        .map(|n| n.status)
+}
+
+/// Polls `condition` every 100ms until it holds, returning `false` if it still doesn't after
+/// `timeout`.
+pub async fn wait_until<F, Fut>(mut condition: F, timeout: Duration) -> bool
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = bool>,
+{
+    let deadline = tokio::time::Instant::now() + timeout;
+    loop {
+        if condition().await {
+            return true;
+        }
+
+        if tokio::time::Instant::now() >= deadline {
+            return false;
+        }
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+}
+
 pub fn create_trace_logger() {
     let _ = tracing_subscriber::fmt()
         // enable everything
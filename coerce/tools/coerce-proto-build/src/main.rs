@@ -1,4 +1,5 @@
-use protobuf_codegen::Customize;
+use protobuf::reflect::FieldDescriptor;
+use protobuf_codegen::{Customize, CustomizeCallback};
 use std::path::Path;
 
 struct ProtobufFile {
@@ -6,6 +7,26 @@ struct ProtobufFile {
     pub output_dir: &'static str,
 }
 
+// Payload fields that are decoded as `bytes::Bytes`, sharing the frame buffer rather than copying
+const TOKIO_BYTES_FIELDS: &[&str] = &[
+    "coerce.network.ClientResult.result",
+    "coerce.network.MessageRequest.message",
+    "coerce.network.StreamPublishEvent.message",
+    "coerce.sharding.RemoteEntityRequest.message",
+];
+
+struct TokioBytesFields;
+
+impl CustomizeCallback for TokioBytesFields {
+    fn field(&self, field: &FieldDescriptor) -> Customize {
+        if TOKIO_BYTES_FIELDS.contains(&field.full_name().as_str()) {
+            Customize::default().tokio_bytes(true)
+        } else {
+            Customize::default()
+        }
+    }
+}
+
 fn main() -> std::io::Result<()> {
     if !Path::new("coerce").exists() {
         panic!("could not find coerce root directory, please run from the coerce repository root");
@@ -26,6 +47,18 @@ fn main() -> std::io::Result<()> {
                 "coerce/src/protocol/persistent/journal.proto",
                 "coerce/src/persistent/journal/proto",
             ),
+            (
+                "coerce/src/protocol/delivery.proto",
+                "coerce/src/remote/delivery/proto",
+            ),
+            (
+                "coerce/src/protocol/swim.proto",
+                "coerce/src/remote/cluster/swim/proto",
+            ),
+            (
+                "coerce/src/protocol/raft.proto",
+                "coerce/src/remote/raft/proto",
+            ),
         ]
         .into_iter(),
     );
@@ -49,6 +82,7 @@ fn compile_proto<I: Iterator<Item = (&'static str, &'static str)>>(
     for file in protobuf_files {
         protobuf_codegen::Codegen::new()
             .customize(Customize::default().gen_mod_rs(true))
+            .customize_callback(TokioBytesFields)
             .out_dir(file.1)
             .input(file.0)
             .include(include_dir)
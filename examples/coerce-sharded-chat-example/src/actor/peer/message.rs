@@ -1,6 +1,6 @@
 use crate::actor::stream::ChatMessage;
 use crate::protocol;
-use coerce::actor::message::{Message, MessageUnwrapErr, MessageWrapErr};
+use coerce::actor::message::{Bytes, Message, MessageUnwrapErr, MessageWrapErr};
 use protobuf::well_known_types::wrappers::{StringValue, UInt64Value};
 use protobuf::Message as ProtoMessage;
 use protobuf::MessageField;
@@ -42,7 +42,7 @@ impl Message for JoinChat {
         .map_err(|_| MessageWrapErr::SerializationErr)
     }
 
-    fn from_bytes(b: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
+    fn from_bytes(b: Bytes) -> Result<Self, MessageUnwrapErr> {
         let msg = protocol::chat::JoinChat::parse_from_bytes(&b);
         match msg {
             Ok(msg) => Ok(Self {
@@ -98,7 +98,7 @@ impl Message for SendChatMessage {
         .map_err(|_| MessageWrapErr::SerializationErr)
     }
 
-    fn from_bytes(b: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
+    fn from_bytes(b: Bytes) -> Result<Self, MessageUnwrapErr> {
         let msg = protocol::chat::SendChatMessage::parse_from_bytes(&b);
         match msg {
             Ok(msg) => match msg.message.into_option() {
@@ -124,7 +124,7 @@ impl Message for LeaveChat {
         .map_err(|_| MessageWrapErr::SerializationErr)
     }
 
-    fn from_bytes(b: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
+    fn from_bytes(b: Bytes) -> Result<Self, MessageUnwrapErr> {
         let msg = protocol::chat::LeaveChat::parse_from_bytes(&b);
         match msg {
             Ok(msg) => Ok(Self(msg.chat_stream_id)),
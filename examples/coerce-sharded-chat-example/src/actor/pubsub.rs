@@ -1,4 +1,5 @@
 use crate::actor::stream::ChatMessage;
+use coerce::actor::message::Bytes;
 use coerce::remote::net::StreamData;
 use coerce::remote::stream::pubsub::Topic;
 
@@ -23,7 +24,7 @@ impl Topic for ChatStreamTopic {
 }
 
 impl StreamData for ChatReceive {
-    fn read_from_bytes(data: Vec<u8>) -> Option<Self> {
+    fn read_from_bytes(data: Bytes) -> Option<Self> {
         serde_json::from_slice(&data).unwrap()
     }
 
@@ -19,6 +19,17 @@ impl Message for Write {
     type Result = ();
 }
 
+pub(crate) struct WriteIf {
+    pub key: String,
+    pub expected_sequence: i64,
+    pub entry: JournalEntry,
+    pub result_channel: Sender<anyhow::Result<bool>>,
+}
+
+impl Message for WriteIf {
+    type Result = ();
+}
+
 pub(crate) struct ReadSnapshot(pub String, pub Sender<anyhow::Result<Option<JournalEntry>>>);
 
 impl Message for ReadSnapshot {
@@ -43,6 +54,23 @@ impl Message for Delete {
 
 impl<C: 'static + Send + Sync> Actor for RedisJournal<C> where C: Clone {}
 
+// Adds the entry only if the highest score in the set is still ARGV[1] (0 when the set is empty),
+// the check and the write are atomic since redis runs the whole script at once.
+const WRITE_IF_SCRIPT: &str = r#"
+local latest = redis.call('ZREVRANGE', KEYS[1], 0, 0, 'WITHSCORES')
+local latest_sequence = 0
+if latest[2] then
+    latest_sequence = tonumber(latest[2])
+end
+
+if latest_sequence ~= tonumber(ARGV[1]) then
+    return 0
+end
+
+redis.call('ZADD', KEYS[1], ARGV[2], ARGV[3])
+return 1
+"#;
+
 #[async_trait]
 impl<C: 'static + ConnectionLike + Send + Sync> Handler<Write> for RedisJournal<C>
 where
@@ -68,6 +96,32 @@ where
     }
 }
 
+#[async_trait]
+impl<C: 'static + ConnectionLike + Send + Sync> Handler<WriteIf> for RedisJournal<C>
+where
+    C: Clone,
+{
+    async fn handle(&mut self, message: WriteIf, _ctx: &mut ActorContext) {
+        let connection = self.0.clone();
+        let _ = tokio::spawn(async move {
+            let mut connection = connection;
+            let written = redis::cmd("EVAL")
+                .arg(WRITE_IF_SCRIPT)
+                .arg(1)
+                .arg(message.key)
+                .arg(message.expected_sequence)
+                .arg(message.entry.sequence)
+                .arg(message.entry.write_to_bytes().expect("serialized journal"))
+                .query_async::<C, bool>(&mut connection)
+                .await;
+
+            let _ = message
+                .result_channel
+                .send(written.map_err(anyhow::Error::new));
+        });
+    }
+}
+
 #[async_trait]
 impl<C: 'static + ConnectionLike + Send + Sync> Handler<ReadSnapshot> for RedisJournal<C>
 where
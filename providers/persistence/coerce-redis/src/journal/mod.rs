@@ -1,4 +1,4 @@
-use crate::journal::actor::{Delete, ReadMessages, ReadSnapshot, RedisJournal, Write};
+use crate::journal::actor::{Delete, ReadMessages, ReadSnapshot, RedisJournal, Write, WriteIf};
 use coerce::actor::context::ActorContext;
 use coerce::actor::message::{Handler, Message};
 use coerce::actor::system::ActorSystem;
@@ -137,6 +137,25 @@ where
         rx.await?
     }
 
+    async fn write_snapshot_if(
+        &self,
+        persistence_id: &str,
+        expected_sequence: i64,
+        entry: JournalEntry,
+    ) -> anyhow::Result<bool> {
+        let (result_channel, rx) = oneshot::channel();
+        let key = (self.key_provider_fn)(persistence_id, "snapshot", self.config.as_ref());
+
+        self.redis_journal.notify(WriteIf {
+            key,
+            expected_sequence,
+            entry,
+            result_channel,
+        })?;
+
+        rx.await?
+    }
+
     async fn write_message(&self, persistence_id: &str, entry: JournalEntry) -> anyhow::Result<()> {
         let (result_channel, rx) = oneshot::channel();
         let key = (self.key_provider_fn)(persistence_id, "journal", self.config.as_ref());
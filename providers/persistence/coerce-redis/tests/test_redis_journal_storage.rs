@@ -117,5 +117,48 @@ pub async fn test_redis_journal_read_write_messages() {
     assert_eq!(latest_messages[1].sequence, 2);
 }
 
+#[tokio::test]
+pub async fn test_redis_journal_write_snapshot_if() {
+    let persistence_id = "hi";
+    let system = ActorSystem::new();
+    let provider = RedisStorageProvider::connect(
+        RedisStorageConfig {
+            nodes: vec!["redis://127.0.0.1:6379/".to_string()],
+            key_prefix: "test_redis_journal_write_snapshot_if:".to_string(),
+            cluster: false,
+            use_key_hashtags: false,
+        },
+        &system,
+    )
+    .await;
+
+    let redis = provider.journal_storage().expect("redis journal storage");
+    let snapshot = |sequence| JournalEntry {
+        sequence,
+        payload_type: "test".to_string(),
+        bytes: vec![1, 3, 3, 7],
+    };
+
+    let first_write = redis
+        .write_snapshot_if(persistence_id, 0, snapshot(1))
+        .await;
+    let stale_write = redis
+        .write_snapshot_if(persistence_id, 0, snapshot(2))
+        .await;
+    let second_write = redis
+        .write_snapshot_if(persistence_id, 1, snapshot(2))
+        .await;
+    let latest_snapshot = redis.read_latest_snapshot(persistence_id).await;
+
+    redis.delete_all(persistence_id).await.expect("delete all");
+
+    assert!(first_write.expect("write snapshot"));
+    assert!(!stale_write.expect("write snapshot"));
+    assert!(second_write.expect("write snapshot"));
+
+    let latest_snapshot = latest_snapshot.expect("load latest snapshot").unwrap();
+    assert_eq!(latest_snapshot.sequence, 2);
+}
+
 #[tokio::test]
 pub async fn test_redis_journal_actor_integration() {}